@@ -0,0 +1,105 @@
+use ndarray::{concatenate, s, Array2, ArrayBase, Axis, Data, Ix2};
+use num::{Float, NumCast, One};
+
+use crate::{operations::conj_t, util::{Inverse, Lapack, LeastSquares, LinalgError, OperationNorm}};
+
+const MAX_ITER: usize = 100;
+
+/// Solves the continuous algebraic Riccati equation `AᴴX + XA - XBR⁻¹BᴴX + Q = 0` for the stabilizing solution `X`.
+///
+/// The stable invariant subspace of the Hamiltonian matrix `[A -BR⁻¹Bᴴ; -Q -Aᴴ]` is found with the matrix sign function,
+/// which fails to converge if the Hamiltonian has eigenvalues on the imaginary axis.
+pub fn care<T, SA, SB, SQ, SR>(a: &ArrayBase<SA, Ix2>, b: &ArrayBase<SB, Ix2>, q: &ArrayBase<SQ, Ix2>, r: &ArrayBase<SR, Ix2>) -> Result<Array2<T>, LinalgError>
+where
+    T: Lapack,
+    SA: Data<Elem = T>,
+    SB: Data<Elem = T>,
+    SQ: Data<Elem = T>,
+    SR: Data<Elem = T>
+{
+    let (a, b, q) = (a.to_owned(), b.to_owned(), q.to_owned());
+    let n = a.nrows();
+    if a.ncols() != n || b.nrows() != n || q.dim() != (n, n) || r.dim() != (b.ncols(), b.ncols())
+    {
+        return Err(LinalgError::Shape)
+    }
+
+    let g = b.dot(&r.inv()?).dot(&conj_t(&b));
+    let mut z = concatenate![Axis(0),
+        concatenate![Axis(1), a, g.map(|&g| -g)],
+        concatenate![Axis(1), q.map(|&q| -q), conj_t(&a).map(|&a| -a)]
+    ];
+
+    // Newton iteration for sign(Z), with Frobenius-norm scaling to speed up the first steps.
+    let half = T::Real::one()/(T::Real::one() + T::Real::one());
+    let tol = T::Real::epsilon()*<T::Real as NumCast>::from(100*n).unwrap();
+    let mut converged = false;
+    for _ in 0..MAX_ITER
+    {
+        let zinv = z.inv()?;
+        let c = Float::sqrt(zinv.opnorm_fro()?/z.opnorm_fro()?);
+        let (c, cinv): (T, T) = ((c*half).into(), (c.recip()*half).into());
+        let z_next = z.map(|&z| z*c) + zinv.map(|&z| z*cinv);
+        let delta = (&z_next - &z).opnorm_one()?;
+        z = z_next;
+        if delta <= tol*z.opnorm_one()?
+        {
+            converged = true;
+            break
+        }
+    }
+    if !converged
+    {
+        return Err(LinalgError::NoConvergence)
+    }
+
+    // The stable subspace [I; X] is the kernel of sign(H) + I.
+    for i in 0..2*n
+    {
+        z[(i, i)] = z[(i, i)] + T::one()
+    }
+    let lhs = z.slice(s![.., n..]).to_owned();
+    let rhs = z.slice(s![.., ..n]).map(|&z| -z);
+    let mut x = Array2::zeros((n, n));
+    for (j, rhs) in rhs.columns()
+        .into_iter()
+        .enumerate()
+    {
+        x.column_mut(j).assign(&lhs.least_squares(&rhs)?);
+    }
+
+    Ok(hermitian_part(x))
+}
+
+pub(crate) fn hermitian_part<T>(x: Array2<T>) -> Array2<T>
+where
+    T: Lapack
+{
+    let half: T = (T::Real::one()/(T::Real::one() + T::Real::one())).into();
+    (&x + &conj_t(&x)).map(|&x| x*half)
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::analysis::control::care;
+
+    #[test]
+    fn riccati()
+    {
+        // 2x - x² + 1 = 0
+        let x = care(&array![[1.0]], &array![[1.0]], &array![[1.0]], &array![[1.0]]).unwrap();
+        assert!((x[(0, 0)] - (1.0 + 2.0f64.sqrt())).abs() < 1e-10);
+
+        // Double integrator
+        let a = array![[0.0, 1.0], [0.0, 0.0]];
+        let b = array![[0.0], [1.0]];
+        let q = array![[1.0, 0.0], [0.0, 0.0]];
+        let r = array![[1.0]];
+        let x = care(&a, &b, &q, &r).unwrap();
+        let e = a.t().dot(&x) + x.dot(&a) - x.dot(&b).dot(&b.t()).dot(&x) + &q;
+        assert!(e.iter().all(|e: &f64| e.abs() < 1e-10));
+    }
+}
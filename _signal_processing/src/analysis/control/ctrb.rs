@@ -0,0 +1,82 @@
+use ndarray::{concatenate, Array2, Axis};
+use num::{complex::ComplexFloat, Zero};
+use option_trait::Maybe;
+
+use crate::{operations::column_space, systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix}, transforms::system::ToSs, util::{Lapack, LinalgError}, System};
+
+/// The controllability matrix `[B AB ... Aⁿ⁻¹B]` of a state-space system.
+///
+/// The system is controllable if the matrix has full rank `n`. Singular values below `tol` times the largest one
+/// are counted as zero, with `tol` defaulting to `nε`.
+pub trait Ctrb: System
+{
+    fn ctrb(&self) -> Array2<Self::Set>;
+
+    fn ctrb_rank<TOL>(&self, tol: TOL) -> Result<usize, LinalgError>
+    where
+        TOL: Maybe<<Self::Set as ComplexFloat>::Real>;
+}
+
+impl<T, A, B, C, D> Ctrb for Ss<T, A, B, C, D>
+where
+    T: Lapack,
+    A: SsAMatrix<T, B, C, D>,
+    B: SsBMatrix<T, A, C, D>,
+    C: SsCMatrix<T, A, B, D>,
+    D: SsDMatrix<T, A, B, C>,
+    Self: Clone + ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    fn ctrb(&self) -> Array2<T>
+    {
+        let Ss {a, b, ..} = self.clone().to_ss();
+        ctrb(&a, &b)
+    }
+
+    fn ctrb_rank<TOL>(&self, tol: TOL) -> Result<usize, LinalgError>
+    where
+        TOL: Maybe<T::Real>
+    {
+        Ok(column_space(self.ctrb(), tol.option().unwrap_or_else(T::Real::zero))?.ncols())
+    }
+}
+
+/// `[B AB ... Aⁿ⁻¹B]`
+pub(crate) fn ctrb<T>(a: &Array2<T>, b: &Array2<T>) -> Array2<T>
+where
+    T: ComplexFloat + 'static
+{
+    let n = a.nrows();
+    let mut blocks = Vec::with_capacity(n);
+    let mut akb = b.clone();
+    for _ in 0..n
+    {
+        let next = a.dot(&akb);
+        blocks.push(akb);
+        akb = next;
+    }
+    let views: Vec<_> = blocks.iter()
+        .map(|b| b.view())
+        .collect();
+    concatenate(Axis(1), &views)
+        .unwrap_or_else(|_| Array2::zeros((n, 0)))
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::{analysis::control::Ctrb, systems::Ss};
+
+    #[test]
+    fn ctrb()
+    {
+        let h = Ss::new(array![[-1.0, 1.0], [0.0, -2.0]], array![[0.0], [1.0]], array![[1.0, 0.0]], array![[0.0]]);
+        assert_eq!(h.ctrb(), array![[0.0, 1.0], [1.0, -2.0]]);
+        assert_eq!(h.ctrb_rank(()), Ok(2));
+
+        let h = Ss::new(array![[-1.0, 0.0], [0.0, -2.0]], array![[1.0], [0.0]], array![[1.0, 1.0]], array![[0.0]]);
+        assert_eq!(h.ctrb_rank(()), Ok(1));
+    }
+}
@@ -0,0 +1,77 @@
+use ndarray::{Array2, ArrayBase, Data, Ix2};
+use num::{Float, NumCast};
+
+use crate::{analysis::control::hermitian_part, operations::conj_t, util::{Inverse, Lapack, LinalgError, OperationNorm}};
+
+const MAX_ITER: usize = 100;
+
+/// Solves the discrete algebraic Riccati equation `AᴴXA - X - AᴴXB(R + BᴴXB)⁻¹BᴴXA + Q = 0` for the stabilizing solution `X`.
+///
+/// Uses the structure-preserving doubling algorithm, which converges quadratically as long as the symplectic pencil
+/// has no eigenvalues on the unit circle.
+pub fn dare<T, SA, SB, SQ, SR>(a: &ArrayBase<SA, Ix2>, b: &ArrayBase<SB, Ix2>, q: &ArrayBase<SQ, Ix2>, r: &ArrayBase<SR, Ix2>) -> Result<Array2<T>, LinalgError>
+where
+    T: Lapack,
+    SA: Data<Elem = T>,
+    SB: Data<Elem = T>,
+    SQ: Data<Elem = T>,
+    SR: Data<Elem = T>
+{
+    let n = a.nrows();
+    if a.ncols() != n || b.nrows() != n || q.dim() != (n, n) || r.dim() != (b.ncols(), b.ncols())
+    {
+        return Err(LinalgError::Shape)
+    }
+
+    let mut ak = a.to_owned();
+    let mut gk = b.dot(&r.inv()?).dot(&conj_t(&b.to_owned()));
+    let mut hk = q.to_owned();
+
+    let tol = T::Real::epsilon()*<T::Real as NumCast>::from(100*n).unwrap();
+    for _ in 0..MAX_ITER
+    {
+        // W = (I + GₖHₖ)⁻¹
+        let w = (Array2::eye(n) + gk.dot(&hk)).inv()?;
+        let akh = conj_t(&ak);
+        let wa = w.dot(&ak);
+
+        let h_next = &hk + &akh.dot(&hk).dot(&wa);
+        let g_next = &gk + &ak.dot(&w).dot(&gk).dot(&akh);
+        ak = ak.dot(&wa);
+
+        let delta = (&h_next - &hk).opnorm_one()?;
+        hk = h_next;
+        gk = g_next;
+        if delta <= tol*hk.opnorm_one()?
+        {
+            return Ok(hermitian_part(hk))
+        }
+    }
+
+    Err(LinalgError::NoConvergence)
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::analysis::control::dare;
+
+    #[test]
+    fn riccati()
+    {
+        // x² - x - 1 = 0
+        let x = dare(&array![[1.0]], &array![[1.0]], &array![[1.0]], &array![[1.0]]).unwrap();
+        assert!((x[(0, 0)] - (1.0 + 5.0f64.sqrt())/2.0).abs() < 1e-10);
+
+        let a = array![[1.0, 0.1], [0.0, 1.0]];
+        let b = array![[0.005], [0.1]];
+        let q = array![[1.0, 0.0], [0.0, 0.1]];
+        let r = array![[0.5]];
+        let x = dare(&a, &b, &q, &r).unwrap();
+        let k = (&r + &b.t().dot(&x).dot(&b)).map(|s| 1.0/s).dot(&b.t()).dot(&x).dot(&a);
+        let e = a.t().dot(&x).dot(&a) - &x - a.t().dot(&x).dot(&b).dot(&k) + &q;
+        assert!(e.iter().all(|e: &f64| e.abs() < 1e-9));
+    }
+}
@@ -0,0 +1,77 @@
+use ndarray::Array2;
+
+use crate::{operations::conj_t, systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix}, transforms::system::ToSs, util::{dlyap, lyap, Lapack, LinalgError}, Plane, System};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GramType
+{
+    Controllability,
+    Observability
+}
+
+/// The controllability or observability Gramian of a stable state-space system.
+///
+/// In the S-plane, these solve `AWc + WcAᴴ + BBᴴ = 0` and `AᴴWo + WoA + CᴴC = 0`.
+/// In the Z-plane, they solve `AWcAᴴ - Wc + BBᴴ = 0` and `AᴴWoA - Wo + CᴴC = 0`.
+pub trait Gram: System
+{
+    fn gram(&self, gram_type: GramType, plane: Plane) -> Result<Array2<Self::Set>, LinalgError>;
+}
+
+impl<T, A, B, C, D> Gram for Ss<T, A, B, C, D>
+where
+    T: Lapack,
+    A: SsAMatrix<T, B, C, D>,
+    B: SsBMatrix<T, A, C, D>,
+    C: SsCMatrix<T, A, B, D>,
+    D: SsDMatrix<T, A, B, C>,
+    Self: Clone + ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    fn gram(&self, gram_type: GramType, plane: Plane) -> Result<Array2<T>, LinalgError>
+    {
+        let Ss {a, b, c, ..} = self.clone().to_ss();
+        gram(&a, &b, &c, gram_type, plane)
+    }
+}
+
+pub(crate) fn gram<T>(a: &Array2<T>, b: &Array2<T>, c: &Array2<T>, gram_type: GramType, plane: Plane) -> Result<Array2<T>, LinalgError>
+where
+    T: Lapack
+{
+    let (a, q) = match gram_type
+    {
+        GramType::Controllability => (a.clone(), b.dot(&conj_t(b))),
+        GramType::Observability => (conj_t(a), conj_t(c).dot(c))
+    };
+    match plane
+    {
+        Plane::S => lyap(&a, &q),
+        Plane::Z => dlyap(&a, &q)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::{analysis::control::{Gram, GramType}, systems::Ss, Plane};
+
+    #[test]
+    fn gram()
+    {
+        // Wc = ∫e^(-2t)dt = 1/2, Wo = ∫4e^(-2t)dt = 2
+        let h = Ss::new(array![[-1.0]], array![[1.0]], array![[2.0]], array![[0.0]]);
+
+        let wc = h.gram(GramType::Controllability, Plane::S).unwrap();
+        let wo = h.gram(GramType::Observability, Plane::S).unwrap();
+        assert!((wc[(0, 0)] - 0.5f64).abs() < 1e-12);
+        assert!((wo[(0, 0)] - 2.0f64).abs() < 1e-12);
+
+        // Wc = Σ0.25ᵏ = 4/3
+        let h = Ss::new(array![[0.5]], array![[1.0]], array![[1.0]], array![[0.0]]);
+        let wc = h.gram(GramType::Controllability, Plane::Z).unwrap();
+        assert!((wc[(0, 0)] - 4.0/3.0f64).abs() < 1e-12);
+    }
+}
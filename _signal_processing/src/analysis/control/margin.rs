@@ -0,0 +1,165 @@
+use num::{complex::ComplexFloat, traits::{float::TotalOrder, FloatConst}, Complex, Float};
+
+use crate::{analysis::control::Siso, transforms::system::ToZpk, util::Lapack, Plane, System};
+
+const GRID_LEN: usize = 2048;
+const BISECTIONS: usize = 64;
+
+/// All stability margins of a SISO open-loop system, with the frequencies at which they occur.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Margins<R>
+{
+    /// Gain margins, as factors, with their phase crossover frequencies.
+    pub gain: Vec<(R, R)>,
+    /// Phase margins in radians, with their gain crossover frequencies.
+    pub phase: Vec<(R, R)>,
+    /// Delay margins with their gain crossover frequencies, in seconds in the S-plane or samples in the Z-plane.
+    ///
+    /// This is the smallest added delay which brings the phase at the crossover to -π.
+    pub delay: Vec<(R, R)>
+}
+
+impl<R> Margins<R>
+where
+    R: Float + TotalOrder
+{
+    /// The gain margin closest to instability, that is the one with the smallest `|log(gm)|`.
+    pub fn min_gain(&self) -> Option<(R, R)>
+    {
+        self.gain.iter()
+            .copied()
+            .min_by(|a, b| a.0.ln().abs().total_cmp(&b.0.ln().abs()))
+    }
+
+    /// The phase margin with the smallest magnitude.
+    pub fn min_phase(&self) -> Option<(R, R)>
+    {
+        self.phase.iter()
+            .copied()
+            .min_by(|a, b| a.0.abs().total_cmp(&b.0.abs()))
+    }
+
+    /// The smallest delay margin.
+    pub fn min_delay(&self) -> Option<(R, R)>
+    {
+        self.delay.iter()
+            .copied()
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+    }
+}
+
+/// Gain, phase and delay margins of a SISO open-loop system `L`, for unity negative feedback.
+///
+/// The phase crossovers, where `L` is real and negative, give the gain margins `1/|L|`.
+/// The gain crossovers, where `|L| = 1`, give the phase margins `∠L + π`.
+/// Crossovers are located on a logarithmic frequency grid and refined by bisection.
+pub trait Margin: System
+{
+    fn margin(&self, plane: Plane) -> Margins<<Self::Set as ComplexFloat>::Real>;
+}
+
+impl<T, S> Margin for S
+where
+    T: Lapack,
+    S: ToZpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, T, (), ()> + System<Set = T> + Clone
+{
+    fn margin(&self, plane: Plane) -> Margins<T::Real>
+    {
+        let h = Siso::new(self.clone());
+        let w = h.grid(plane, GRID_LEN);
+        let l = |w| h.eval(Siso::boundary(w, plane));
+
+        let gain = crossings(&w, |w| l(w).im)
+            .into_iter()
+            .filter_map(|w| {
+                let l = l(w);
+                (l.re < T::Real::zero()).then(|| (l.norm().recip(), w))
+            })
+            .collect();
+
+        let phase: Vec<_> = crossings(&w, |w| l(w).norm() - T::Real::one())
+            .into_iter()
+            .map(|w| {
+                let mut pm = l(w).arg() + T::Real::PI();
+                if pm > T::Real::PI()
+                {
+                    pm = pm - T::Real::TAU()
+                }
+                (pm, w)
+            })
+            .collect();
+
+        let delay = phase.iter()
+            .map(|&(pm, w)| {
+                let pm = if pm < T::Real::zero() {pm + T::Real::TAU()} else {pm};
+                (pm/w, w)
+            })
+            .collect();
+
+        Margins {
+            gain,
+            phase,
+            delay
+        }
+    }
+}
+
+/// Frequencies where `f` changes sign between adjacent grid points, refined by bisection.
+fn crossings<R, F>(w: &[R], f: F) -> Vec<R>
+where
+    R: Float,
+    F: Fn(R) -> R
+{
+    let two = R::one() + R::one();
+    w.windows(2)
+        .filter_map(|w| {
+            let (mut a, mut b) = (w[0], w[1]);
+            let fa = f(a);
+            if fa.signum() == f(b).signum() || fa.is_nan()
+            {
+                return None
+            }
+            for _ in 0..BISECTIONS
+            {
+                let m = (a + b)/two;
+                if f(m).signum() == fa.signum()
+                {
+                    a = m
+                }
+                else
+                {
+                    b = m
+                }
+            }
+            Some((a + b)/two)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{analysis::control::Margin, systems::Zpk, Plane};
+
+    #[test]
+    fn margin()
+    {
+        // L = 4/(s + 1)³ has its phase crossover at √3 rad/s, where |L| = 1/2.
+        let l = Zpk::new(vec![], vec![-1.0; 3], 4.0);
+
+        let margins = l.margin(Plane::S);
+
+        let (gm, wg) = margins.min_gain().unwrap();
+        assert!((gm - 2.0).abs() < 1e-9);
+        assert!((wg - 3.0f64.sqrt()).abs() < 1e-9);
+
+        // |L| = 1 at ω = √(4^(2/3) - 1)
+        let (pm, wc) = margins.min_phase().unwrap();
+        let wc_exact = (4.0f64.powf(2.0/3.0) - 1.0).sqrt();
+        assert!((wc - wc_exact).abs() < 1e-9);
+        assert!((pm - (core::f64::consts::PI - 3.0*wc_exact.atan())).abs() < 1e-9);
+
+        let (dm, _) = margins.min_delay().unwrap();
+        assert!((dm - pm/wc).abs() < 1e-12);
+    }
+}
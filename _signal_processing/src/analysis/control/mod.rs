@@ -0,0 +1,141 @@
+moddef::moddef!(
+    flat(pub) mod {
+        care,
+        ctrb,
+        dare,
+        gram,
+        margin,
+        nyquist,
+        obsv,
+        rlocus
+    }
+);
+
+use ndarray::Array2;
+use num::{complex::ComplexFloat, Complex, Float, One, Zero};
+
+use crate::{systems::Zpk, transforms::system::ToZpk, util::{EigVals, Lapack, LinalgError}, Plane, System};
+
+/// The zeros, poles and gain of a SISO system, all as complex numbers.
+struct Siso<R>
+where
+    R: Float
+{
+    z: Vec<Complex<R>>,
+    p: Vec<Complex<R>>,
+    k: Complex<R>
+}
+
+impl<R> Siso<R>
+where
+    R: Float
+{
+    fn new<T, S>(sys: S) -> Self
+    where
+        T: Lapack<Real = R>,
+        S: ToZpk<Complex<R>, Vec<Complex<R>>, Vec<Complex<R>>, T, (), ()> + System<Set = T>
+    {
+        let Zpk::<_, Vec<_>, Vec<_>, _> {z, p, k} = sys.to_zpk((), ());
+        Self {
+            z: z.into_inner(),
+            p: p.into_inner(),
+            k: k.into()
+        }
+    }
+
+    fn eval(&self, s: Complex<R>) -> Complex<R>
+    {
+        let num = self.z.iter()
+            .fold(self.k, |h, &z| h*(s - z));
+        self.p.iter()
+            .fold(num, |h, &p| h/(s - p))
+    }
+
+    /// Logarithmically spaced frequencies, covering the poles and zeros by two decades on either side in the S-plane,
+    /// or four decades below Nyquist in the Z-plane.
+    fn grid(&self, plane: Plane, n: usize) -> Vec<R>
+    {
+        let ten = R::from(10.0).unwrap();
+        let (lo, hi) = match plane
+        {
+            Plane::S => {
+                let mags: Vec<_> = self.z.iter()
+                    .chain(self.p.iter())
+                    .map(|x| x.norm())
+                    .filter(|x| !x.is_zero())
+                    .collect();
+                let min = mags.iter().copied().fold(R::infinity(), R::min);
+                let max = mags.iter().copied().fold(R::zero(), R::max);
+                if mags.is_empty()
+                {
+                    (-(R::one() + R::one()), R::one() + R::one())
+                }
+                else
+                {
+                    (min.log10().floor() - R::one() - R::one(), max.log10().ceil() + R::one() + R::one())
+                }
+            },
+            Plane::Z => {
+                let pi = R::from(core::f64::consts::PI).unwrap();
+                (pi.log10() - R::from(4.0).unwrap(), pi.log10())
+            }
+        };
+        let nm1 = R::from(n.max(2) - 1).unwrap();
+        (0..n.max(2)).map(|i| ten.powf(lo + (hi - lo)*R::from(i).unwrap()/nm1))
+            .collect()
+    }
+
+    /// The point on the stability boundary at frequency `w`.
+    fn boundary(w: R, plane: Plane) -> Complex<R>
+    {
+        match plane
+        {
+            Plane::S => Complex::new(R::zero(), w),
+            Plane::Z => Complex::cis(w)
+        }
+    }
+}
+
+/// Coefficients of `(x - r₁)(x - r₂)...`, in descending order.
+pub(crate) fn poly<R>(roots: &[Complex<R>]) -> Vec<Complex<R>>
+where
+    R: Float
+{
+    let mut c = vec![Complex::one()];
+    for &r in roots
+    {
+        c.push(Complex::zero());
+        for i in (1..c.len()).rev()
+        {
+            c[i] = c[i] - r*c[i - 1];
+        }
+    }
+    c
+}
+
+/// Roots of a polynomial with descending coefficients, as the eigenvalues of its companion matrix.
+pub(crate) fn roots<R>(c: &[Complex<R>]) -> Result<Vec<Complex<R>>, LinalgError>
+where
+    R: Float,
+    Complex<R>: Lapack<Real = R>
+{
+    let c: Vec<_> = c.iter()
+        .copied()
+        .skip_while(|c| c.is_zero())
+        .collect();
+    let n = c.len().saturating_sub(1);
+    if n == 0
+    {
+        return Ok(vec![])
+    }
+    let mut m = Array2::zeros((n, n));
+    for j in 0..n
+    {
+        m[(0, j)] = -c[j + 1]/c[0];
+    }
+    for i in 1..n
+    {
+        m[(i, i - 1)] = Complex::one();
+    }
+    Ok(m.eigvals()?.to_vec())
+}
@@ -0,0 +1,143 @@
+use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float};
+
+use crate::{analysis::control::Siso, transforms::system::ToZpk, util::Lapack, Plane, System};
+
+const GRID_LEN: usize = 1024;
+const MAX_REFINE: usize = 32;
+
+/// Nyquist-curve data for a SISO open-loop system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NyquistData<R>
+{
+    /// Frequencies from the negative to the positive end of the stability boundary.
+    pub w: Vec<R>,
+    /// The open-loop response at those frequencies.
+    pub h: Vec<Complex<R>>,
+    /// Counterclockwise encirclements of `-1`.
+    pub encirclements: isize,
+    /// Open-loop poles in the unstable region. Poles on the stability boundary count as stable.
+    pub unstable_poles: usize
+}
+
+impl<R> NyquistData<R>
+{
+    /// Closed-loop poles in the unstable region under unity negative feedback, `Z = P - N`.
+    pub fn closed_loop_unstable_poles(&self) -> isize
+    {
+        self.unstable_poles as isize - self.encirclements
+    }
+}
+
+/// The Nyquist curve of a SISO open-loop system, and the number of times it encircles `-1`.
+///
+/// The contour is indented just outside of any poles on the stability boundary. Between grid points, the frequency interval
+/// is split until the curve turns by less than a quarter turn around `-1`, so that no encirclement is missed.
+pub trait Nyquist: System
+{
+    fn nyquist(&self, plane: Plane) -> NyquistData<<Self::Set as ComplexFloat>::Real>;
+}
+
+impl<T, S> Nyquist for S
+where
+    T: Lapack,
+    S: ToZpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, T, (), ()> + System<Set = T> + Clone
+{
+    fn nyquist(&self, plane: Plane) -> NyquistData<T::Real>
+    {
+        let h = Siso::new(self.clone());
+
+        let delta = Float::sqrt(T::Real::epsilon());
+        let contour = |w: T::Real| match plane
+        {
+            Plane::S => Complex::new(delta, w),
+            Plane::Z => Complex::from_polar(T::Real::one() + delta, w)
+        };
+        let l = |w| h.eval(contour(w));
+
+        let positive = h.grid(plane, GRID_LEN);
+        let mut w: Vec<_> = positive.iter()
+            .rev()
+            .map(|&w| -w)
+            .chain(core::iter::once(T::Real::zero()))
+            .chain(positive.iter().copied())
+            .collect();
+        if let Plane::Z = plane
+        {
+            // Close the contour around the unit circle.
+            let pi = T::Real::PI();
+            *w.first_mut().unwrap() = -pi;
+            *w.last_mut().unwrap() = pi;
+        }
+        let hw: Vec<_> = w.iter()
+            .map(|&w| l(w))
+            .collect();
+
+        let quarter = T::Real::FRAC_PI_2();
+        let mut winding = T::Real::zero();
+        for i in 1..w.len()
+        {
+            winding = winding + turn(&l, w[i - 1], w[i], hw[i - 1], hw[i], quarter, MAX_REFINE);
+        }
+        let encirclements = (winding/T::Real::TAU()).round().to_isize().unwrap();
+
+        let unstable_poles = h.p.iter()
+            .filter(|&&p| match plane
+            {
+                Plane::S => p.re > T::Real::zero(),
+                Plane::Z => p.norm() > T::Real::one()
+            })
+            .count();
+
+        NyquistData {
+            w,
+            h: hw,
+            encirclements,
+            unstable_poles
+        }
+    }
+}
+
+/// The angle that `1 + L` turns between two frequencies.
+fn turn<R, F>(l: &F, w0: R, w1: R, h0: Complex<R>, h1: Complex<R>, max: R, depth: usize) -> R
+where
+    R: Float,
+    F: Fn(R) -> Complex<R>
+{
+    let (a, b) = (h0 + R::one(), h1 + R::one());
+    let dphi = (b/a).arg();
+    if dphi.abs() <= max || depth == 0
+    {
+        return dphi
+    }
+    let wm = (w0 + w1)/(R::one() + R::one());
+    let hm = l(wm);
+    turn(l, w0, wm, h0, hm, max, depth - 1) + turn(l, wm, w1, hm, h1, max, depth - 1)
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{analysis::control::Nyquist, systems::Zpk, Plane};
+
+    #[test]
+    fn nyquist()
+    {
+        // L = 2/(s - 1) is stabilized by unity feedback, with one counterclockwise encirclement.
+        let l = Zpk::new(vec![], vec![1.0], 2.0);
+        let n = l.nyquist(Plane::S);
+        assert_eq!(n.unstable_poles, 1);
+        assert_eq!(n.encirclements, 1);
+        assert_eq!(n.closed_loop_unstable_poles(), 0);
+
+        // L = 10/(s + 1)³ is destabilized, with two clockwise encirclements.
+        let l = Zpk::new(vec![], vec![-1.0; 3], 10.0);
+        let n = l.nyquist(Plane::S);
+        assert_eq!(n.encirclements, -2);
+        assert_eq!(n.closed_loop_unstable_poles(), 2);
+
+        // L = 0.5/(z - 0.5) in discrete time.
+        let l = Zpk::new(vec![], vec![0.5], 0.5);
+        let n = l.nyquist(Plane::Z);
+        assert_eq!(n.closed_loop_unstable_poles(), 0);
+    }
+}
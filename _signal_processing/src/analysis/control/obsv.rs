@@ -0,0 +1,71 @@
+use ndarray::Array2;
+use num::{complex::ComplexFloat, Zero};
+use option_trait::Maybe;
+
+use crate::{analysis::control::ctrb, operations::{column_space, conj_t}, systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix}, transforms::system::ToSs, util::{Lapack, LinalgError}, System};
+
+/// The observability matrix `[C; CA; ...; CAⁿ⁻¹]` of a state-space system.
+///
+/// The system is observable if the matrix has full rank `n`. Singular values below `tol` times the largest one
+/// are counted as zero, with `tol` defaulting to `nε`.
+pub trait Obsv: System
+{
+    fn obsv(&self) -> Array2<Self::Set>;
+
+    fn obsv_rank<TOL>(&self, tol: TOL) -> Result<usize, LinalgError>
+    where
+        TOL: Maybe<<Self::Set as ComplexFloat>::Real>;
+}
+
+impl<T, A, B, C, D> Obsv for Ss<T, A, B, C, D>
+where
+    T: Lapack,
+    A: SsAMatrix<T, B, C, D>,
+    B: SsBMatrix<T, A, C, D>,
+    C: SsCMatrix<T, A, B, D>,
+    D: SsDMatrix<T, A, B, C>,
+    Self: Clone + ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    fn obsv(&self) -> Array2<T>
+    {
+        let Ss {a, c, ..} = self.clone().to_ss();
+        obsv(&a, &c)
+    }
+
+    fn obsv_rank<TOL>(&self, tol: TOL) -> Result<usize, LinalgError>
+    where
+        TOL: Maybe<T::Real>
+    {
+        Ok(column_space(conj_t(&self.obsv()), tol.option().unwrap_or_else(T::Real::zero))?.ncols())
+    }
+}
+
+/// `[C; CA; ...; CAⁿ⁻¹]`
+pub(crate) fn obsv<T>(a: &Array2<T>, c: &Array2<T>) -> Array2<T>
+where
+    T: ComplexFloat + 'static
+{
+    // Oᵀ = [Cᵀ AᵀCᵀ ...], which avoids conjugating complex entries.
+    ctrb(&a.t().to_owned(), &c.t().to_owned())
+        .reversed_axes()
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::{analysis::control::Obsv, systems::Ss};
+
+    #[test]
+    fn obsv()
+    {
+        let h = Ss::new(array![[-1.0, 1.0], [0.0, -2.0]], array![[0.0], [1.0]], array![[1.0, 0.0]], array![[0.0]]);
+        assert_eq!(h.obsv(), array![[1.0, 0.0], [-1.0, 1.0]]);
+        assert_eq!(h.obsv_rank(()), Ok(2));
+
+        let h = Ss::new(array![[-1.0, 0.0], [0.0, -2.0]], array![[1.0], [1.0]], array![[0.0, 1.0]], array![[0.0]]);
+        assert_eq!(h.obsv_rank(()), Ok(1));
+    }
+}
@@ -0,0 +1,148 @@
+use num::{complex::ComplexFloat, traits::float::TotalOrder, Complex, Float, NumCast, Zero};
+use thiserror::Error;
+
+use crate::{analysis::control::{poly, roots, Siso}, transforms::system::ToZpk, util::{Lapack, LinalgError, TwoSidedRange}, System};
+
+const MAX_POINTS: usize = 10000;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum RLocusError
+{
+    #[error("The root locus needs more than {} gains to cover the range.", MAX_POINTS)]
+    TooManyPoints,
+    #[error(transparent)]
+    Linalg(#[from] LinalgError)
+}
+
+/// The root locus of a SISO open-loop system `L`, that is the closed-loop poles under the feedback gain `k` for `k` in the given range.
+///
+/// The gain step is adapted so that no pole moves by more than a tenth of the scale of the locus in one step.
+/// The branches are kept continuous by matching each pole to the nearest pole of the previous step.
+///
+/// Returns the gains, and for each gain, the closed-loop poles in branch order.
+/// Fails rather than truncating the locus if the range cannot be covered in `MAX_POINTS` gains.
+pub trait RLocus: System
+{
+    fn rlocus<K>(&self, gains: K) -> Result<(Vec<<Self::Set as ComplexFloat>::Real>, Vec<Vec<Complex<<Self::Set as ComplexFloat>::Real>>>), RLocusError>
+    where
+        K: TwoSidedRange<<Self::Set as ComplexFloat>::Real>;
+}
+
+impl<T, S> RLocus for S
+where
+    T: Lapack<Real: TotalOrder>,
+    Complex<T::Real>: Lapack<Real = T::Real>,
+    S: ToZpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, T, (), ()> + System<Set = T> + Clone
+{
+    fn rlocus<K>(&self, gains: K) -> Result<(Vec<T::Real>, Vec<Vec<Complex<T::Real>>>), RLocusError>
+    where
+        K: TwoSidedRange<T::Real>
+    {
+        let h = Siso::new(self.clone());
+
+        // 1 + kL = 0  ⇔  den + k·k₀·num = 0
+        let num: Vec<_> = poly(&h.z).into_iter()
+            .map(|c| c*h.k)
+            .collect();
+        let den = poly(&h.p);
+        let len = num.len().max(den.len());
+        let char_poly = |k: T::Real| -> Vec<Complex<T::Real>> {
+            (0..len).map(|i| {
+                let d = (i + den.len()).checked_sub(len).map(|j| den[j]).unwrap_or_else(Complex::zero);
+                let n = (i + num.len()).checked_sub(len).map(|j| num[j]).unwrap_or_else(Complex::zero);
+                d + n*k
+            }).collect()
+        };
+
+        let (k0, k1) = (*gains.start(), *gains.end());
+        let span = k1 - k0;
+        let ten = <T::Real as NumCast>::from(10.0).unwrap();
+        let min_step = span*Float::powi(ten, -9);
+
+        let mut k = vec![k0];
+        let mut r = vec![roots(&char_poly(k0))?];
+        let mut step = span/<T::Real as NumCast>::from(100).unwrap();
+        let two = T::Real::one() + T::Real::one();
+        while *k.last().unwrap() < k1
+        {
+            if k.len() >= MAX_POINTS
+            {
+                return Err(RLocusError::TooManyPoints)
+            }
+            let prev = r.last().unwrap();
+            let scale = prev.iter()
+                .map(|r| r.norm())
+                .fold(T::Real::one(), T::Real::max);
+            let kn = (*k.last().unwrap() + step).min(k1);
+            let next = match_branches(prev, roots(&char_poly(kn))?);
+            let moved = prev.iter()
+                .zip(next.iter())
+                .map(|(a, b)| (*a - *b).norm())
+                .fold(T::Real::zero(), T::Real::max);
+            if moved > scale/ten && step > min_step
+            {
+                step = step/two;
+                continue
+            }
+            if moved < scale/(ten*ten)
+            {
+                step = step*two;
+            }
+            k.push(kn);
+            r.push(next);
+        }
+
+        Ok((k, r))
+    }
+}
+
+/// Orders `next` so that each root follows the nearest unclaimed root of `prev`.
+fn match_branches<R>(prev: &[Complex<R>], mut next: Vec<Complex<R>>) -> Vec<Complex<R>>
+where
+    R: Float + TotalOrder
+{
+    if prev.len() != next.len()
+    {
+        return next
+    }
+    let mut ordered = Vec::with_capacity(next.len());
+    for p in prev
+    {
+        let (i, _) = next.iter()
+            .enumerate()
+            .min_by(|a, b| (*a.1 - *p).norm().total_cmp(&(*b.1 - *p).norm()))
+            .unwrap();
+        ordered.push(next.swap_remove(i));
+    }
+    ordered
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{analysis::control::RLocus, systems::Zpk};
+
+    #[test]
+    fn rlocus()
+    {
+        // L = 1/(s(s + 2)), whose poles meet at -1 for k = 1 and then split vertically.
+        let l = Zpk::new(vec![], vec![0.0, -2.0], 1.0);
+
+        let (k, r) = l.rlocus(0.0..=5.0).unwrap();
+
+        assert_eq!(*k.first().unwrap(), 0.0);
+        assert_eq!(*k.last().unwrap(), 5.0);
+        for (k, r) in k.iter().zip(r.iter())
+        {
+            assert_eq!(r.len(), 2);
+            for r in r
+            {
+                let e = r*r + r*2.0 + k;
+                assert!(e.norm() < 1e-9);
+            }
+        }
+        // s² + 2s + 5 = 0 at k = 5
+        let last = r.last().unwrap();
+        assert!(last.iter().all(|r| (r.re + 1.0).abs() < 1e-9 && (r.im.abs() - 2.0).abs() < 1e-9));
+    }
+}
@@ -0,0 +1,139 @@
+use core::ops::Deref;
+
+use num::complex::ComplexFloat;
+use option_trait::Maybe;
+
+use crate::{operations::filtering::normalize_tf, quantities::{MaybeList, MaybeOwnedList}, systems::{Sos, Tf}, System};
+
+/// Steady-state initial conditions for the step response.
+///
+/// The state is given in transposed direct form II, the same convention as [`FiltIc`](crate::analysis::FiltIc) and the streaming processors,
+/// so that filtering a constant input `x` from the state `x*zi` gives a constant output without transients.
+/// For second-order sections, the state has two values per section.
+///
+/// If the filter has a pole at `z = 1`, there is no steady state, and the returned state is zero.
+pub trait FilterZi: System
+{
+    fn filter_zi(&self) -> Vec<Self::Set>;
+}
+
+impl<T, B, A> FilterZi for Tf<T, B, A>
+where
+    T: ComplexFloat,
+    B: MaybeList<T>,
+    A: MaybeList<T>
+{
+    fn filter_zi(&self) -> Vec<T>
+    {
+        let b = self.b.to_vec_option()
+            .unwrap_or_else(|| vec![T::one()]);
+        let a = self.a.to_vec_option()
+            .unwrap_or_else(|| vec![T::one()]);
+        let (b, a) = normalize_tf::<T, T>(&b, &a);
+        lfilter_zi(&b, &a)
+    }
+}
+
+impl<T, B, A, S> FilterZi for Sos<T, B, A, S>
+where
+    T: ComplexFloat,
+    B: Maybe<[T; 3]> + MaybeOwnedList<T>,
+    A: Maybe<[T; 3]> + MaybeOwnedList<T>,
+    S: MaybeList<Tf<T, B, A>>
+{
+    fn filter_zi(&self) -> Vec<T>
+    {
+        let one = T::one();
+        let zero = T::zero();
+
+        // Each section sees the step scaled by the DC gain of the sections before it.
+        let mut scale = one;
+        let mut zi = vec![];
+        for sos in self.sos.deref()
+            .as_view_slice_option()
+            .unwrap_or(&[])
+        {
+            let b = sos.b.deref()
+                .as_option()
+                .copied()
+                .unwrap_or([zero, zero, one]);
+            let a = sos.a.deref()
+                .as_option()
+                .copied()
+                .unwrap_or([zero, zero, one]);
+            let (mut b, mut a) = normalize_tf::<T, T>(&b, &a);
+            b.resize(3, zero);
+            a.resize(3, zero);
+            zi.extend(lfilter_zi(&b, &a).into_iter().map(|zi| zi*scale));
+            scale = scale*b.iter().copied().sum::<T>()/a.iter().copied().sum::<T>();
+        }
+        zi
+    }
+}
+
+/// The steady-state transposed direct form II state of `b/a`, which must be normalized and of equal length.
+pub(crate) fn lfilter_zi<T>(b: &[T], a: &[T]) -> Vec<T>
+where
+    T: ComplexFloat
+{
+    let n = b.len().saturating_sub(1);
+    let kdc = b.iter().copied().sum::<T>()/a.iter().copied().sum::<T>();
+    if !kdc.is_finite()
+    {
+        return vec![T::zero(); n]
+    }
+
+    // At steady state, wᵢ = Σⱼ₌ᵢ₊₁ (bⱼ - kdc aⱼ).
+    let mut s = T::zero();
+    let mut zi: Vec<T> = b[1..].iter()
+        .zip(a[1..].iter())
+        .rev()
+        .map(|(&b, &a)| {
+            s = s + b - kdc*a;
+            s
+        }).collect();
+    zi.reverse();
+    zi
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{analysis::{FiltIc, FilterZi}, operations::filtering::{SosProcessor, TfProcessor}, systems::{Sos, Tf}};
+
+    #[test]
+    fn steady_state()
+    {
+        let h = Tf::new(vec![0.2, 0.3, 0.1], vec![1.0, -0.5, 0.25]);
+        let zi = h.filter_zi();
+
+        // scipy.signal.lfilter_zi([0.2, 0.3, 0.1], [1.0, -0.5, 0.25])
+        let zi_ref = [0.6, -0.1];
+        for (zi, zi_ref) in zi.iter().zip(zi_ref)
+        {
+            assert!((zi - zi_ref).abs() < 1e-12);
+        }
+
+        // The state agrees with the one found by filtic from a settled step response.
+        let kdc = 0.6/0.75;
+        let zi_filtic = h.as_view().filtic([kdc; 2], [1.0; 2]);
+        for (zi, zi_filtic) in zi.iter().zip(zi_filtic)
+        {
+            assert!((zi - zi_filtic).abs() < 1e-12);
+        }
+
+        let mut p = TfProcessor::new(h);
+        p.restore_state(&zi);
+        assert!((0..16).all(|_| (p.process_sample(1.0) - kdc).abs() < 1e-12));
+
+        let sos = Sos::new(vec![
+            Tf::new([0.1, 0.2, 0.1], [1.0, -0.6, 0.2]),
+            Tf::new([1.0, -1.0, 0.5], [1.0, 0.3, 0.4])
+        ]);
+        let zi = sos.filter_zi();
+        let kdc = 0.4/0.6*0.5/1.7;
+        let mut p = SosProcessor::new(sos);
+        p.restore_state(&zi);
+        assert!((0..16).all(|_| (p.process_sample(1.0) - kdc).abs() < 1e-12));
+    }
+}
@@ -1,7 +1,7 @@
 use core::ops::{AddAssign, Deref, DivAssign, MulAssign};
 
 use ndarray::{Array1, Array2};
-use ndarray_linalg::{Lapack, Solve};
+use crate::util::{Lapack, Solve};
 use num::{complex::ComplexFloat, One};
 use option_trait::Maybe;
 
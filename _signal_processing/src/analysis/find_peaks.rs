@@ -1,6 +1,6 @@
 use core::{iter::Sum, ops::{RangeInclusive, SubAssign}};
 
-use ndarray_linalg::{Lapack};
+use crate::util::Lapack;
 use num::{Float, NumCast};
 use option_trait::{Maybe, StaticMaybe};
 
@@ -0,0 +1,174 @@
+use ndarray::{Array2, Array3};
+use num::{complex::ComplexFloat, Complex, Float, Zero};
+
+use crate::{systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix}, transforms::system::ToSs, util::{self, LinalgError}, Plane, System};
+
+/// Frequency response of a state-space system, evaluated without going through a polynomial form.
+///
+/// `A` is reduced to Hessenberg form once, `A = QHQᴴ`, after which `(sI - H)X = QᴴB` is solved for each frequency in `O(n²)` operations per input.
+///
+/// In the S-plane, the response is evaluated at `s = jω`, with `ω` in radians per second.
+/// In the Z-plane, it is evaluated at `z = eʲʷ`, with `ω` in radians per sample.
+///
+/// The result is indexed by output, input and frequency.
+pub trait FreqResp<'a, W>: System
+where
+    W: IntoIterator<Item = <Self::Set as ComplexFloat>::Real>
+{
+    fn freqresp(&'a self, w: W, plane: Plane) -> Result<Array3<Complex<<Self::Set as ComplexFloat>::Real>>, LinalgError>;
+}
+
+impl<'a, T, A, B, C, D, W> FreqResp<'a, W> for Ss<T, A, B, C, D>
+where
+    T: ComplexFloat + Into<Complex<T::Real>>,
+    A: SsAMatrix<T, B, C, D>,
+    B: SsBMatrix<T, A, C, D>,
+    C: SsCMatrix<T, A, B, D>,
+    D: SsDMatrix<T, A, B, C>,
+    W: IntoIterator<Item = T::Real>,
+    Self: Clone + ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    fn freqresp(&'a self, w: W, plane: Plane) -> Result<Array3<Complex<T::Real>>, LinalgError>
+    {
+        let Ss {a, b, c, d, ..} = self.clone().to_ss();
+        let a: Array2<Complex<T::Real>> = a.map(|&a| a.into());
+        let b: Array2<Complex<T::Real>> = b.map(|&b| b.into());
+        let c: Array2<Complex<T::Real>> = c.map(|&c| c.into());
+        let d: Array2<Complex<T::Real>> = d.map(|&d| d.into());
+
+        let (q, h) = util::hessenberg(&a)?;
+        let bt = q.t().map(|q| q.conj()).dot(&b);
+        let ct = c.dot(&q);
+
+        let w: Vec<_> = w.into_iter().collect();
+        let (nout, nin) = d.dim();
+        let mut g = Array3::zeros((nout, nin, w.len()));
+        for (k, &w) in w.iter()
+            .enumerate()
+        {
+            let s = match plane
+            {
+                Plane::S => Complex::new(T::Real::zero(), w),
+                Plane::Z => Complex::cis(w)
+            };
+            let x = solve_shifted_hessenberg(&h, s, bt.clone());
+            let gk = ct.dot(&x) + &d;
+            g.slice_mut(ndarray::s![.., .., k]).assign(&gk);
+        }
+
+        Ok(g)
+    }
+}
+
+/// Solves `(sI - H)X = B` for upper Hessenberg `H`, by Gaussian elimination with partial pivoting.
+///
+/// Only adjacent rows are ever candidates for pivoting, since there is a single subdiagonal.
+/// If `s` is an eigenvalue of `H`, the solution will contain infinities.
+fn solve_shifted_hessenberg<R>(h: &Array2<Complex<R>>, s: Complex<R>, mut x: Array2<Complex<R>>) -> Array2<Complex<R>>
+where
+    R: Float
+{
+    let n = h.nrows();
+    let mut m = h.map(|&h| -h);
+    for i in 0..n
+    {
+        m[(i, i)] = m[(i, i)] + s
+    }
+
+    for k in 0..n.saturating_sub(1)
+    {
+        if m[(k + 1, k)].norm() > m[(k, k)].norm()
+        {
+            for j in k..n
+            {
+                m.swap((k, j), (k + 1, j))
+            }
+            for j in 0..x.ncols()
+            {
+                x.swap((k, j), (k + 1, j))
+            }
+        }
+        let l = m[(k + 1, k)]/m[(k, k)];
+        if l.is_zero()
+        {
+            continue
+        }
+        for j in k..n
+        {
+            let u = m[(k, j)];
+            m[(k + 1, j)] = m[(k + 1, j)] - l*u
+        }
+        for j in 0..x.ncols()
+        {
+            let u = x[(k, j)];
+            x[(k + 1, j)] = x[(k + 1, j)] - l*u
+        }
+    }
+
+    for i in (0..n).rev()
+    {
+        for j in 0..x.ncols()
+        {
+            let y = (i + 1..n).fold(x[(i, j)], |y, l| y - m[(i, l)]*x[(l, j)]);
+            x[(i, j)] = y/m[(i, i)]
+        }
+    }
+    x
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+    use num::Complex;
+
+    use crate::{analysis::FreqResp, systems::Ss, Plane};
+
+    #[test]
+    fn mimo_s()
+    {
+        let h = Ss::new(
+            array![[-1.0, 2.0, 0.0], [0.0, -3.0, 1.0], [1.0, 0.0, -2.0]],
+            array![[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]],
+            array![[1.0, 0.0, 0.0], [0.0, 1.0, 1.0]],
+            array![[0.0, 0.5], [0.0, 0.0]]
+        );
+        let w = [0.0, 0.5, 3.0, 40.0];
+
+        let g = h.freqresp(w, Plane::S).unwrap();
+        assert_eq!(g.dim(), (2, 2, 4));
+
+        for (k, &w) in w.iter().enumerate()
+        {
+            // G(s) = C(sI - A)⁻¹B + D, solved directly
+            let s = Complex::new(0.0, w);
+            let m = h.a.map(|&a| -Complex::from(a)) + &ndarray::Array2::from_diag_elem(3, s);
+            for j in 0..2
+            {
+                let b = h.b.column(j).map(|&b| Complex::from(b));
+                let x = crate::util::Solve::solve(&m, &b).unwrap();
+                for i in 0..2
+                {
+                    let y = h.c.row(i).iter().zip(x.iter()).map(|(&c, &x)| x*c).sum::<Complex<f64>>() + h.d[(i, j)];
+                    assert!((g[(i, j, k)] - y).norm() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn siso_z()
+    {
+        // y[n] = 0.5y[n - 1] + u[n - 1], so H(z) = 1/(z - 0.5)
+        let h = Ss::new(array![[0.5]], array![[1.0]], array![[1.0]], array![[0.0]]);
+        let w = [0.0, 1.0, core::f64::consts::PI];
+
+        let g = h.freqresp(w, Plane::Z).unwrap();
+        for (k, &w) in w.iter().enumerate()
+        {
+            let y = 1.0/(Complex::cis(w) - 0.5);
+            assert!((g[(0, 0, k)] - y).norm() < 1e-12);
+        }
+    }
+}
@@ -117,7 +117,7 @@ where
 impl<'a, T, B, A> ImpZ<'a, B::RowsMapped<Vec<T>>, Vec<<T as ComplexFloat>::Real>, ()> for Tf<T, B, A>
 where
     Complex<<T as ComplexFloat>::Real>: From<T> + AddAssign + SubAssign + MulAssign + DivAssign + DivAssign<<T as ComplexFloat>::Real>,
-    T: ComplexFloat + ndarray_linalg::Lapack<Complex = Complex<<T as ComplexFloat>::Real>>,
+    T: ComplexFloat + crate::util::Lapack,
     B: MaybeLists<T>,
     A: MaybeList<T>,
     B::RowsMapped<Vec<T>>: Lists<T>,
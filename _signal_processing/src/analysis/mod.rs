@@ -1,13 +1,18 @@
 moddef::moddef!(
+    pub mod {
+        control
+    },
     flat(pub) mod {
         cceps,
         cpsd,
+        filter_zi,
         filternorm,
         filtic_u,
         filtic,
         filtord,
         find_freqs,
         find_peaks,
+        freqresp,
         freqs,
         freqz,
         fwhm,
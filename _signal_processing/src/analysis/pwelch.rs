@@ -1,6 +1,6 @@
 use core::{iter::Sum, ops::{AddAssign, Div, MulAssign, SubAssign}};
 
-use ndarray_linalg::Lapack;
+use crate::util::Lapack;
 use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float, NumCast, Zero};
 use option_trait::{Maybe, NotVoid, StaticMaybe};
 
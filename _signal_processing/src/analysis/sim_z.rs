@@ -1,7 +1,7 @@
 use core::ops::Mul;
 
 use ndarray::{Array1, Array2};
-use ndarray_linalg::Lapack;
+use crate::util::Lapack;
 use num::{complex::ComplexFloat, traits::real::Real, NumCast, One, Zero};
 use option_trait::{Maybe, StaticMaybe};
 
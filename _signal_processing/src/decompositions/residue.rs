@@ -1,6 +1,6 @@
 use core::ops::{AddAssign, Div, DivAssign, Mul, MulAssign, SubAssign};
 
-use ndarray_linalg::Lapack;
+use crate::util::Lapack;
 use num::{complex::ComplexFloat, traits::{float::TotalOrder, Euclid, FloatConst}, Complex, Float, One, Zero};
 
 use option_trait::Maybe;
@@ -18,7 +18,7 @@ pub trait Residue: System
 
 impl<T, B, A, R> Residue for Tf<T, B, A>
 where
-    T: ComplexFloat<Real = R> + Lapack<Complex = Complex<R>> + 'static,
+    T: ComplexFloat<Real = R> + Lapack + 'static,
     R: Float + FloatConst + TotalOrder + Into<T>,
     B: MaybeList<T>,
     A: MaybeList<T>,
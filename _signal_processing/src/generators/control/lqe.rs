@@ -0,0 +1,92 @@
+use ndarray::{concatenate, Array2, Axis};
+
+use crate::{analysis::control::{care, dare}, operations::conj_t, systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix}, transforms::system::ToSs, util::{Inverse, Lapack, LinalgError}, Plane, System};
+
+/// Steady-state Kalman estimator design.
+///
+/// For the plant `x' = Ax + Bu + Gw`, `y = Cx + Du + v`, with process noise covariance `E{wwᴴ} = Qn` and measurement noise covariance `E{vvᴴ} = Rn`,
+/// finds the estimator gain `L` of `x̂' = Ax̂ + Bu + L(y - Cx̂ - Du)`. In the Z-plane, `x̂'` is the one-step prediction `x̂[k+1]`.
+///
+/// Returns `L`, the steady-state error covariance `P`, and the estimator as a system with inputs `[u; y]` and outputs `[ŷ; x̂]`.
+pub trait Lqe: System
+{
+    type Output: System<Set = Self::Set>;
+
+    fn lqe(self, g: &Array2<Self::Set>, qn: &Array2<Self::Set>, rn: &Array2<Self::Set>, plane: Plane) -> Result<(Array2<Self::Set>, Array2<Self::Set>, Self::Output), LinalgError>;
+}
+
+impl<T, A, B, C, D> Lqe for Ss<T, A, B, C, D>
+where
+    T: Lapack,
+    A: SsAMatrix<T, B, C, D>,
+    B: SsBMatrix<T, A, C, D>,
+    C: SsCMatrix<T, A, B, D>,
+    D: SsDMatrix<T, A, B, C>,
+    Self: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    type Output = Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>;
+
+    fn lqe(self, g: &Array2<T>, qn: &Array2<T>, rn: &Array2<T>, plane: Plane) -> Result<(Array2<T>, Array2<T>, Self::Output), LinalgError>
+    {
+        let Ss {a, b, c, d, ..} = self.to_ss();
+        if g.nrows() != a.nrows() || qn.dim() != (g.ncols(), g.ncols())
+        {
+            return Err(LinalgError::Shape)
+        }
+        let (ah, ch) = (conj_t(&a), conj_t(&c));
+        let gqg = g.dot(qn).dot(&conj_t(g));
+
+        // The estimator is the dual of the regulator of (Aᴴ, Cᴴ).
+        let (l, p) = match plane
+        {
+            Plane::S => {
+                // L = PCᴴRn⁻¹
+                let p = care(&ah, &ch, &gqg, rn)?;
+                (p.dot(&ch).dot(&rn.inv()?), p)
+            },
+            Plane::Z => {
+                // L = APCᴴ(CPCᴴ + Rn)⁻¹
+                let p = dare(&ah, &ch, &gqg, rn)?;
+                let pch = p.dot(&ch);
+                (a.dot(&pch).dot(&(rn + &c.dot(&pch)).inv()?), p)
+            }
+        };
+
+        let n = a.nrows();
+        let (ny, nu) = d.dim();
+        let sys = Ss::new(
+            &a - &l.dot(&c),
+            concatenate![Axis(1), &b - &l.dot(&d), l],
+            concatenate![Axis(0), c, Array2::eye(n)],
+            concatenate![Axis(0),
+                concatenate![Axis(1), d, Array2::zeros((ny, ny))],
+                Array2::zeros((n, nu + ny))
+            ]
+        );
+        Ok((l, p, sys))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::{generators::control::Lqe, systems::Ss, util::EigVals, Plane};
+
+    #[test]
+    fn lqe()
+    {
+        // x' = -x + w, y = x + v with unit covariances gives P = √2 - 1.
+        let h = Ss::new(array![[-1.0]], array![[1.0]], array![[1.0]], array![[0.0]]);
+
+        let (l, p, est) = h.lqe(&array![[1.0]], &array![[1.0]], &array![[1.0]], Plane::S).unwrap();
+
+        assert!((p[(0, 0)] - (2.0f64.sqrt() - 1.0)).abs() < 1e-9);
+        assert!((l[(0, 0)] - p[(0, 0)]).abs() < 1e-12);
+        assert_eq!(est.b.dim(), (1, 2));
+        assert_eq!(est.c.dim(), (2, 1));
+        assert!(est.a.eigvals().unwrap().iter().all(|p| p.re < 0.0));
+    }
+}
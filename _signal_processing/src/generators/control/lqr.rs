@@ -0,0 +1,84 @@
+use ndarray::Array2;
+
+use crate::{analysis::control::{care, dare}, operations::conj_t, systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix}, transforms::system::ToSs, util::{Inverse, Lapack, LinalgError}, Plane, System};
+
+/// Linear-quadratic regulator design.
+///
+/// Finds the state-feedback gain `K` of `u = -Kx` which minimizes `∫(xᴴQx + uᴴRu)dt` in the S-plane,
+/// or `Σ(xᴴQx + uᴴRu)` in the Z-plane.
+///
+/// Returns `K`, the solution `X` of the associated Riccati equation, and the closed-loop system `(A - BK, B, C - DK, D)`.
+pub trait Lqr: System
+{
+    type Output: System<Set = Self::Set>;
+
+    fn lqr(self, q: &Array2<Self::Set>, r: &Array2<Self::Set>, plane: Plane) -> Result<(Array2<Self::Set>, Array2<Self::Set>, Self::Output), LinalgError>;
+}
+
+impl<T, A, B, C, D> Lqr for Ss<T, A, B, C, D>
+where
+    T: Lapack,
+    A: SsAMatrix<T, B, C, D>,
+    B: SsBMatrix<T, A, C, D>,
+    C: SsCMatrix<T, A, B, D>,
+    D: SsDMatrix<T, A, B, C>,
+    Self: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    type Output = Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>;
+
+    fn lqr(self, q: &Array2<T>, r: &Array2<T>, plane: Plane) -> Result<(Array2<T>, Array2<T>, Self::Output), LinalgError>
+    {
+        let Ss {a, b, c, d, ..} = self.to_ss();
+        let bh = conj_t(&b);
+
+        let (k, x) = match plane
+        {
+            Plane::S => {
+                // K = R⁻¹BᴴX
+                let x = care(&a, &b, q, r)?;
+                (r.inv()?.dot(&bh).dot(&x), x)
+            },
+            Plane::Z => {
+                // K = (R + BᴴXB)⁻¹BᴴXA
+                let x = dare(&a, &b, q, r)?;
+                let bhx = bh.dot(&x);
+                ((r + &bhx.dot(&b)).inv()?.dot(&bhx).dot(&a), x)
+            }
+        };
+
+        let sys = super::closed_loop(a, b, c, d, &k);
+        Ok((k, x, sys))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::{generators::control::Lqr, systems::Ss, util::EigVals, Plane};
+
+    #[test]
+    fn lqr()
+    {
+        // Double integrator, for which K = [1 √2] when Q = diag(1, 0) and R = 1.
+        let h = Ss::new(array![[0.0, 1.0], [0.0, 0.0]], array![[0.0], [1.0]], array![[1.0, 0.0]], array![[0.0]]);
+
+        let (k, _, h) = h.lqr(&array![[1.0, 0.0], [0.0, 0.0]], &array![[1.0]], Plane::S).unwrap();
+
+        assert!((k[(0, 0)] - 1.0f64).abs() < 1e-9);
+        assert!((k[(0, 1)] - 2.0f64.sqrt()).abs() < 1e-9);
+        assert!(h.a.eigvals().unwrap().iter().all(|p| p.re < 0.0));
+    }
+
+    #[test]
+    fn dlqr()
+    {
+        let h = Ss::new(array![[1.0, 0.1], [0.0, 1.0]], array![[0.005], [0.1]], array![[1.0, 0.0]], array![[0.0]]);
+
+        let (_, _, h) = h.lqr(&array![[1.0, 0.0], [0.0, 0.1]], &array![[0.5]], Plane::Z).unwrap();
+
+        assert!(h.a.eigvals().unwrap().iter().all(|p| p.norm() < 1.0));
+    }
+}
@@ -0,0 +1,20 @@
+moddef::moddef!(
+    flat(pub) mod {
+        lqe,
+        lqr,
+        place
+    }
+);
+
+use ndarray::Array2;
+
+use crate::{systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix}, util::Lapack};
+
+/// The system under state feedback `u = v - Kx`, that is `(A - BK, B, C - DK, D)`.
+fn closed_loop<T>(a: Array2<T>, b: Array2<T>, c: Array2<T>, d: Array2<T>, k: &Array2<T>) -> Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>
+where
+    T: Lapack,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    Ss::new(a - b.dot(k), b, c - d.dot(k), d)
+}
@@ -0,0 +1,380 @@
+use ndarray::{s, Array1, Array2};
+use num::{complex::ComplexFloat, Complex, Float, NumCast, Zero};
+use thiserror::Error;
+
+use crate::{analysis::control::ctrb, operations::conj_t, systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix}, transforms::system::ToSs, util::{Inverse, Lapack, LinalgError, TruncateIm, SVD}, System};
+
+const MAX_SWEEPS: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum PlaceError
+{
+    #[error("The number of poles must equal the number of states.")]
+    PoleCount,
+    #[error("The input matrix must have full column rank.")]
+    InputRankDeficient,
+    #[error("A pole cannot be placed more times than there are inputs.")]
+    Multiplicity,
+    #[error("The system is not controllable.")]
+    Uncontrollable,
+    #[error("Complex poles of a real system must come in conjugate pairs.")]
+    NotConjugate,
+    #[error(transparent)]
+    Linalg(#[from] LinalgError)
+}
+
+/// Pole placement by state feedback.
+///
+/// Finds the gain `K` such that `A - BK` has the given poles. Single-input systems use Ackermann's formula.
+/// Multi-input systems use the Kautsky-Nichols-Van Dooren method, which picks the eigenvectors of the closed-loop system
+/// to be as well-conditioned as it can, so that the poles are robust to perturbations.
+///
+/// Returns `K` and the closed-loop system `(A - BK, B, C - DK, D)`.
+pub trait Place: System
+{
+    type Output: System<Set = Self::Set>;
+
+    fn place<P>(self, poles: P) -> Result<(Array2<Self::Set>, Self::Output), PlaceError>
+    where
+        P: IntoIterator<Item = Complex<<Self::Set as ComplexFloat>::Real>>;
+}
+
+impl<T, A, B, C, D> Place for Ss<T, A, B, C, D>
+where
+    T: Lapack,
+    Complex<T::Real>: Lapack<Real = T::Real>,
+    A: SsAMatrix<T, B, C, D>,
+    B: SsBMatrix<T, A, C, D>,
+    C: SsCMatrix<T, A, B, D>,
+    D: SsDMatrix<T, A, B, C>,
+    Self: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    type Output = Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>;
+
+    fn place<P>(self, poles: P) -> Result<(Array2<T>, Self::Output), PlaceError>
+    where
+        P: IntoIterator<Item = Complex<T::Real>>
+    {
+        let Ss {a, b, c, d, ..} = self.to_ss();
+        let poles: Vec<_> = poles.into_iter().collect();
+        let (n, m) = b.dim();
+        if poles.len() != n
+        {
+            return Err(PlaceError::PoleCount)
+        }
+
+        let ac: Array2<Complex<T::Real>> = a.map(|&a| a.into());
+        let bc: Array2<Complex<T::Real>> = b.map(|&b| b.into());
+        let real = ac.iter()
+            .chain(bc.iter())
+            .all(|x| x.im.is_zero());
+        let partner = if real
+        {
+            conjugate_pairs(&poles).ok_or(PlaceError::NotConjugate)?
+        }
+        else
+        {
+            vec![None; n]
+        };
+
+        let k = if m == 1
+        {
+            ackermann(&ac, &bc, &poles)?
+        }
+        else
+        {
+            knv(&ac, &bc, &poles, &partner, real)?
+        };
+        let k = k.map(|&k| k.truncate_im::<T>());
+
+        let sys = super::closed_loop(a, b, c, d, &k);
+        Ok((k, sys))
+    }
+}
+
+/// For each pole, the index of its complex conjugate if that comes later in the list.
+///
+/// Returns `None` if some complex pole has no conjugate.
+fn conjugate_pairs<R>(poles: &[Complex<R>]) -> Option<Vec<Option<usize>>>
+where
+    R: Float
+{
+    let tol = R::epsilon().sqrt();
+    let mut partner = vec![None; poles.len()];
+    let mut taken = vec![false; poles.len()];
+    for i in 0..poles.len()
+    {
+        if taken[i] || poles[i].im.abs() <= tol*poles[i].norm()
+        {
+            continue
+        }
+        let j = (i + 1..poles.len())
+            .find(|&j| !taken[j] && (poles[j] - poles[i].conj()).norm() <= tol*poles[i].norm())?;
+        partner[i] = Some(j);
+        taken[i] = true;
+        taken[j] = true;
+    }
+    Some(partner)
+}
+
+/// `K = eₙᵀ𝒞⁻¹φ(A)`, where `𝒞` is the controllability matrix and `φ` is the desired characteristic polynomial.
+fn ackermann<R>(a: &Array2<Complex<R>>, b: &Array2<Complex<R>>, poles: &[Complex<R>]) -> Result<Array2<Complex<R>>, PlaceError>
+where
+    R: Float,
+    Complex<R>: Lapack<Real = R>
+{
+    let n = a.nrows();
+    let cinv = ctrb(a, b).inv()
+        .map_err(|error| match error
+        {
+            LinalgError::Singular => PlaceError::Uncontrollable,
+            error => error.into()
+        })?;
+
+    // φ(A) = (A - p₁I)(A - p₂I)...(A - pₙI)
+    let mut phi = Array2::eye(n);
+    for &p in poles
+    {
+        let mut ap = a.clone();
+        for i in 0..n
+        {
+            ap[(i, i)] = ap[(i, i)] - p
+        }
+        phi = phi.dot(&ap);
+    }
+
+    Ok(cinv.slice(s![n - 1..n, ..]).dot(&phi))
+}
+
+/// Kautsky-Nichols-Van Dooren method 0.
+///
+/// With `B = [U₀ U₁][Z; 0]`, each closed-loop eigenvector `xⱼ` must lie in the kernel of `U₁ᴴ(A - λⱼI)`.
+/// The eigenvectors are chosen in turn to be as orthogonal as possible to the others, after which `K = Z⁻¹U₀ᴴ(A - XΛX⁻¹)`.
+///
+/// For a real system, the eigenvectors of real poles are kept real and those of conjugate poles are kept conjugate, so that `K` is real.
+fn knv<R>(a: &Array2<Complex<R>>, b: &Array2<Complex<R>>, poles: &[Complex<R>], partner: &[Option<usize>], real: bool) -> Result<Array2<Complex<R>>, PlaceError>
+where
+    R: Float,
+    Complex<R>: Lapack<Real = R>
+{
+    let (n, m) = b.dim();
+    let tol = R::epsilon()*<R as NumCast>::from(n).unwrap();
+
+    let (u, sigma, vh) = b.svd(true, true)?;
+    let (u, vh) = (u.unwrap(), vh.unwrap());
+    let smax = sigma.iter().copied().fold(R::zero(), R::max);
+    if sigma.iter().filter(|&&s| s > tol*smax).count() < m
+    {
+        return Err(PlaceError::InputRankDeficient)
+    }
+    for p in poles
+    {
+        if poles.iter().filter(|&q| (*q - *p).norm() <= tol.sqrt()*(p.norm() + R::one())).count() > m
+        {
+            return Err(PlaceError::Multiplicity)
+        }
+    }
+    let u0 = u.slice(s![.., ..m]).to_owned();
+    let u1h = conj_t(&u.slice(s![.., m..]).to_owned());
+    let mut z = vh;
+    for (mut row, &s) in z.rows_mut().into_iter().zip(sigma.iter())
+    {
+        row.map_inplace(|z| *z = z.scale(s));
+    }
+
+    // Bases for the allowed eigenvectors of each pole.
+    let mut bases = Vec::with_capacity(n);
+    for &p in poles
+    {
+        let basis = if m == n
+        {
+            Array2::eye(n)
+        }
+        else
+        {
+            let mut ap = a.clone();
+            for i in 0..n
+            {
+                ap[(i, i)] = ap[(i, i)] - p
+            }
+            let (_, _, vh) = u1h.dot(&ap).svd(false, true)?;
+            conj_t(&vh.unwrap().slice(s![n - m.., ..]).to_owned())
+        };
+        bases.push(basis);
+    }
+
+    let mut skip = vec![false; n];
+    for p in partner.iter().flatten()
+    {
+        skip[*p] = true;
+    }
+    let real_pole: Vec<_> = (0..n).map(|j| real && !skip[j] && partner[j].is_none())
+        .collect();
+
+    let mut x = Array2::zeros((n, n));
+    for (j, basis) in bases.iter().enumerate()
+    {
+        let mut xj = basis.sum_axis(ndarray::Axis(1));
+        if real_pole[j]
+        {
+            xj = real_vector(xj);
+        }
+        x.column_mut(j).assign(&normalize(xj));
+    }
+    for (j, p) in partner.iter().enumerate()
+    {
+        if let Some(p) = *p
+        {
+            let xj = x.column(j).map(|x| x.conj());
+            x.column_mut(p).assign(&xj);
+        }
+    }
+
+    if n > 1
+    {
+        for _ in 0..MAX_SWEEPS
+        {
+            for j in (0..n).filter(|&j| !skip[j])
+            {
+                // The direction orthogonal to all other eigenvectors.
+                let others: Vec<_> = (0..n).filter(|&i| i != j).collect();
+                let xo = x.select(ndarray::Axis(1), &others);
+                let (uo, _, _) = xo.svd(true, false)?;
+                let y = uo.unwrap().column(n - 1).to_owned();
+
+                let basis = &bases[j];
+                let mut xj = basis.dot(&conj_t(basis).dot(&y));
+                if real_pole[j]
+                {
+                    xj = real_vector(xj);
+                }
+                if xj.iter().map(|x| x.norm_sqr()).fold(R::zero(), |s, x| s + x) <= tol*tol
+                {
+                    continue
+                }
+                let xj = normalize(xj);
+                if let Some(p) = partner[j]
+                {
+                    x.column_mut(p).assign(&xj.map(|x| x.conj()));
+                }
+                x.column_mut(j).assign(&xj);
+            }
+        }
+    }
+
+    // A - BK = XΛX⁻¹
+    let mut xl = x.clone();
+    for (mut col, &p) in xl.columns_mut().into_iter().zip(poles)
+    {
+        col.map_inplace(|x| *x = *x*p);
+    }
+    let acl = xl.dot(&x.inv()?);
+    Ok(z.inv()?.dot(&conj_t(&u0)).dot(&(a - &acl)))
+}
+
+/// The real or imaginary part of `x`, whichever is larger.
+///
+/// If `x` lies in a subspace spanned by real vectors, so do both of its parts.
+fn real_vector<R>(x: Array1<Complex<R>>) -> Array1<Complex<R>>
+where
+    R: Float
+{
+    let (re, im) = x.iter()
+        .fold((R::zero(), R::zero()), |(re, im), x| (re + x.re*x.re, im + x.im*x.im));
+    if re >= im
+    {
+        x.map(|x| Complex::new(x.re, R::zero()))
+    }
+    else
+    {
+        x.map(|x| Complex::new(x.im, R::zero()))
+    }
+}
+
+fn normalize<R>(x: Array1<Complex<R>>) -> Array1<Complex<R>>
+where
+    R: Float
+{
+    let norm = x.iter()
+        .map(|x| x.norm_sqr())
+        .fold(R::zero(), |s, x| s + x)
+        .sqrt();
+    if norm.is_zero()
+    {
+        return x
+    }
+    x.map(|&x| x/norm)
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+    use num::Complex;
+
+    use crate::{generators::control::Place, systems::Ss, util::EigVals};
+
+    fn assert_poles(a: &ndarray::Array2<f64>, mut expected: Vec<Complex<f64>>)
+    {
+        let mut p = a.eigvals().unwrap().to_vec();
+        for p in [&mut p, &mut expected]
+        {
+            p.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap().then(a.im.partial_cmp(&b.im).unwrap()));
+        }
+        for (p, e) in p.into_iter().zip(expected)
+        {
+            assert!((p - e).norm() < 1e-8, "{p} != {e}");
+        }
+    }
+
+    #[test]
+    fn ackermann()
+    {
+        let h = Ss::new(array![[0.0, 1.0], [-2.0, -3.0]], array![[0.0], [1.0]], array![[1.0, 0.0]], array![[0.0]]);
+        let poles = vec![Complex::new(-2.0, 1.0), Complex::new(-2.0, -1.0)];
+
+        let (k, h) = h.place(poles.clone()).unwrap();
+
+        // s² + 4s + 5
+        assert!((k[(0, 0)] - 3.0).abs() < 1e-12 && (k[(0, 1)] - 1.0).abs() < 1e-12);
+        assert_poles(&h.a, poles);
+    }
+
+    #[test]
+    fn knv()
+    {
+        let h = Ss::new(
+            array![[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, -2.0, 0.5]],
+            array![[1.0, 0.0], [0.0, 0.0], [0.0, 1.0]],
+            array![[1.0, 0.0, 0.0]],
+            array![[0.0, 0.0]]
+        );
+        let poles = vec![Complex::new(-1.0, 0.0), Complex::new(-2.0, 3.0), Complex::new(-2.0, -3.0)];
+
+        let (_, h) = h.place(poles.clone()).unwrap();
+
+        assert_poles(&h.a, poles);
+    }
+
+    #[test]
+    fn knv_real_poles()
+    {
+        let h = Ss::new(
+            array![
+                [1.0, 2.0, 0.0, 1.0],
+                [0.5, -1.0, 1.0, 0.0],
+                [0.0, 1.0, 2.0, -1.0],
+                [1.0, 0.0, 0.5, -0.5]
+            ],
+            array![[1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [0.0, 0.5]],
+            array![[1.0, 0.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0]],
+            array![[0.0, 0.0], [0.0, 0.0]]
+        );
+        let poles = vec![Complex::new(-1.0, 0.0), Complex::new(-2.0, 0.0), Complex::new(-3.0, 1.5), Complex::new(-3.0, -1.5)];
+
+        let (_, h) = h.place(poles.clone()).unwrap();
+
+        assert_poles(&h.a, poles);
+    }
+}
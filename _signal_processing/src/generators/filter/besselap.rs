@@ -16,7 +16,7 @@ where
 
 impl<T> BesselAP<usize> for Zpk<Complex<T>, (), Vec<Complex<T>>, T>
 where
-    T: Float + FloatConst + AddAssign + MulAssign + Into<Complex<T>> + ComplexFloat<Real = T> + ndarray_linalg::Lapack<Complex = Complex<T>>,
+    T: Float + FloatConst + AddAssign + MulAssign + Into<Complex<T>> + ComplexFloat<Real = T> + crate::util::Lapack,
     Complex<T>: From<T> + AddAssign + SubAssign + MulAssign + DivAssign + DivAssign<T>,
 {
     fn besselap(order: usize) -> Self
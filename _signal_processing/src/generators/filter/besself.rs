@@ -2,7 +2,7 @@ use ndarray::prelude::Array2;
 use num::{traits::FloatConst, Complex, Float};
 use option_trait::Maybe;
 
-use crate::{generators::filter::{BesselAP, FilterGenError, FilterGenPlane, FilterGenType}, transforms::{domain::Bilinear, filter::SfTrans, system::{ToSos, ToSs, ToTf}}, quantities::MaybeList, systems::{Sos, Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix, Tf, Zpk}, System};
+use crate::{generators::filter::{BesselAP, FilterGenError, FilterGenPlane, FilterGenType}, transforms::{domain::Bilinear, filter::{SfTrans, Zp2Sos}, system::{ToSos, ToSs, ToTf}}, quantities::MaybeList, systems::{Sos, Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix, Tf, Zpk}, System};
 
 pub trait BesselF<O>: System + Sized
 where
@@ -156,7 +156,7 @@ where
 impl<T> BesselF<usize> for Sos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>>
 where
     T: Float + FloatConst,
-    Zpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T>: BesselF<usize> + ToSos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>, (), ()> + System<Set = T>
+    Zpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T>: BesselF<usize> + ToSos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>, (), ()> + Zp2Sos<Output = Self> + System<Set = T>
 {
     fn besself<const F: usize>(
         order: usize,
@@ -170,7 +170,14 @@ where
     {
         let zpk = Zpk::besself(order, frequencies, filter_type, plane)?;
     
-        Ok(zpk.to_sos((), ()))
+        if let FilterGenPlane::Z { .. } = plane
+        {
+            super::sos_from_zpk(zpk)
+        }
+        else
+        {
+            Ok(zpk.to_sos((), ()))
+        }
     }
 }
 
@@ -4,7 +4,7 @@ use ndarray::Array2;
 use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float};
 use option_trait::Maybe;
 
-use crate::{generators::filter::{Cheb1AP, FilterGenError, FilterGenPlane, FilterGenType}, transforms::{domain::Bilinear, filter::SfTrans, system::{ToSos, ToSs, ToTf}}, systems::{Sos, Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix, Tf, Zpk}, System};
+use crate::{generators::filter::{Cheb1AP, FilterGenError, FilterGenPlane, FilterGenType}, transforms::{domain::Bilinear, filter::{SfTrans, Zp2Sos}, system::{ToSos, ToSs, ToTf}}, systems::{Sos, Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix, Tf, Zpk}, System};
 
 pub trait Cheby1<O>: System + Sized
 where
@@ -160,7 +160,7 @@ where
 impl<T> Cheby1<usize> for Sos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>>
 where
     T: Float + FloatConst,
-    Zpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T>: Cheby1<usize> + ToSos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>, (), ()> + System<Set = T>
+    Zpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T>: Cheby1<usize> + ToSos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>, (), ()> + Zp2Sos<Output = Self> + System<Set = T>
 {
     fn cheby1<const F: usize>(
         order: usize,
@@ -175,7 +175,14 @@ where
     {
         let zpk = Zpk::cheby1(order, ripple, frequencies, filter_type, plane)?;
     
-        Ok(zpk.to_sos((), ()))
+        if let FilterGenPlane::Z { .. } = plane
+        {
+            super::sos_from_zpk(zpk)
+        }
+        else
+        {
+            Ok(zpk.to_sos((), ()))
+        }
     }
 }
 
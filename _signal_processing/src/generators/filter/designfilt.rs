@@ -0,0 +1,532 @@
+use core::ops::{DivAssign, MulAssign};
+
+use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float, One};
+use thiserror::Error;
+
+use crate::{generators::{filter::{firpmord, kaiserord, FilterBandError, FilterGenError, FilterGenPlane, FilterGenType, Fir1, FirLS, FirPm, FirPmError, FirPmOrdError, FirPmType, IirDesign, IirFilterType}, window::{WindowGen, WindowRange}}, systems::{ComplexRealError, Sos, Tf, Zpk}, transforms::{filter::{SosOrder, SosScale, Zp2Sos}, system::{ToSos, ToTf, ToZpk}}, windows::Kaiser, System};
+
+/// The accuracy, persistence, robustness and target passed to [`FirPm::firpm`].
+const FIRPM_ACCURACY: f64 = 3.0;
+const FIRPM_PERSISTENCE: f64 = 3.0;
+const FIRPM_ROBUSTNESS: f64 = 3.0;
+const FIRPM_TARGET: f64 = 3.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+pub enum DesignFiltError
+{
+    #[error("The band edges do not describe a filter of the given response type.")]
+    EdgesDoNotMatchResponse,
+    #[error("FIR filters can only be designed for the Z-plane.")]
+    AnalogFir,
+    #[error(transparent)]
+    FilterBand(#[from] FilterBandError),
+    #[error(transparent)]
+    FilterGen(#[from] FilterGenError),
+    #[error(transparent)]
+    FirPmOrd(#[from] FirPmOrdError),
+    #[error(transparent)]
+    FirPm(#[from] FirPmError),
+    #[error(transparent)]
+    ComplexReal(#[from] ComplexRealError)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DesignMethod
+{
+    /// An IIR filter of minimum order, as estimated by [`buttord`](crate::generators::filter::buttord), [`cheb1ord`](crate::generators::filter::cheb1ord), [`cheb2ord`](crate::generators::filter::cheb2ord) or [`ellipord`](crate::generators::filter::ellipord).
+    Iir(IirFilterType),
+    /// A Parks-McClellan FIR filter.
+    Equiripple,
+    /// A Kaiser window FIR filter, with the order given by [`kaiserord`](crate::generators::filter::kaiserord).
+    KaiserWindow,
+    /// A least-squares FIR filter.
+    LeastSquares
+}
+
+/// A filter specification, as used by [`DesignFilt`].
+///
+/// Edges are in the same unit as the sampling frequency, or if no sampling frequency is specified, normalized to the Nyquist frequency.
+/// For analog filters, they are in radians per second.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FilterSpec<T>
+where
+    T: Float
+{
+    pub response: FilterGenType,
+    /// The passband edges. Low-pass and high-pass filters only use the first.
+    pub passband: [T; 2],
+    /// The stopband edges. Low-pass and high-pass filters only use the first.
+    pub stopband: [T; 2],
+    /// The largest peak-to-peak passband ripple, in dB.
+    pub passband_ripple: T,
+    /// The smallest stopband attenuation, in dB.
+    pub stopband_attenuation: T,
+    pub plane: FilterGenPlane<T>,
+    pub method: DesignMethod
+}
+
+impl<T> FilterSpec<T>
+where
+    T: Float
+{
+    /// A digital Butterworth specification with 1 dB passband ripple and 60 dB stopband attenuation.
+    fn new(response: FilterGenType, passband: [T; 2], stopband: [T; 2]) -> Self
+    {
+        Self {
+            response,
+            passband,
+            stopband,
+            passband_ripple: T::one(),
+            stopband_attenuation: T::from(60.0).unwrap(),
+            plane: FilterGenPlane::Z { sampling_frequency: None },
+            method: DesignMethod::Iir(IirFilterType::Butterworth)
+        }
+    }
+
+    pub fn lowpass(passband_edge: T, stopband_edge: T) -> Self
+    {
+        Self::new(FilterGenType::LowPass, [passband_edge; 2], [stopband_edge; 2])
+    }
+
+    pub fn highpass(passband_edge: T, stopband_edge: T) -> Self
+    {
+        Self::new(FilterGenType::HighPass, [passband_edge; 2], [stopband_edge; 2])
+    }
+
+    pub fn bandpass(passband_edges: [T; 2], stopband_edges: [T; 2]) -> Self
+    {
+        Self::new(FilterGenType::BandPass, passband_edges, stopband_edges)
+    }
+
+    pub fn bandstop(passband_edges: [T; 2], stopband_edges: [T; 2]) -> Self
+    {
+        Self::new(FilterGenType::BandStop, passband_edges, stopband_edges)
+    }
+
+    pub fn with_passband_ripple(self, passband_ripple: T) -> Self
+    {
+        Self {
+            passband_ripple,
+            ..self
+        }
+    }
+
+    pub fn with_stopband_attenuation(self, stopband_attenuation: T) -> Self
+    {
+        Self {
+            stopband_attenuation,
+            ..self
+        }
+    }
+
+    pub fn with_plane(self, plane: FilterGenPlane<T>) -> Self
+    {
+        Self {
+            plane,
+            ..self
+        }
+    }
+
+    pub fn with_method(self, method: DesignMethod) -> Self
+    {
+        Self {
+            method,
+            ..self
+        }
+    }
+
+    fn validate(&self) -> Result<(), DesignFiltError>
+    {
+        let ([p1, p2], [s1, s2]) = (self.passband, self.stopband);
+        let ok = match self.response
+        {
+            FilterGenType::LowPass => p1 < s1,
+            FilterGenType::HighPass => s1 < p1,
+            FilterGenType::BandPass => s1 < p1 && p1 < p2 && p2 < s2,
+            FilterGenType::BandStop => p1 < s1 && s1 < s2 && s2 < p2
+        };
+        if !ok
+        {
+            return Err(DesignFiltError::EdgesDoNotMatchResponse)
+        }
+        if self.plane == FilterGenPlane::S && !matches!(self.method, DesignMethod::Iir(_))
+        {
+            return Err(DesignFiltError::AnalogFir)
+        }
+        Ok(())
+    }
+}
+
+/// How well a designed filter meets its specification.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DesignReport<T>
+where
+    T: Float
+{
+    pub order: usize,
+    /// The achieved peak-to-peak passband ripple, in dB.
+    pub passband_ripple: T,
+    /// The achieved minimum stopband attenuation, in dB.
+    pub stopband_attenuation: T,
+    pub meets_specification: bool
+}
+
+/// Designs a filter from a [`FilterSpec`], choosing the minimum order which meets it.
+///
+/// IIR orders are given by the matching order estimation function.
+/// FIR orders start at the estimate, and are then adjusted to the lowest order which meets the specification on a dense grid, if any does within twice the estimate.
+/// The returned report gives the response actually achieved.
+pub trait DesignFilt: System + Sized
+{
+    fn designfilt(spec: &FilterSpec<<Self::Set as ComplexFloat>::Real>) -> Result<(Self, DesignReport<<Self::Set as ComplexFloat>::Real>), DesignFiltError>;
+}
+
+/// A designed filter, before conversion to the requested form.
+enum Designed<T>
+where
+    T: Float
+{
+    Iir(Zpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T>),
+    Fir(Vec<T>)
+}
+
+impl<T> DesignFilt for Zpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T>
+where
+    T: Float + FloatConst + MulAssign + DivAssign,
+    Self: IirDesign + System<Set = T>,
+    Tf<T, Vec<T>, ()>: FirPm + FirLS<usize> + Fir1<(), Vec<T>, T, Vec<T>, true> + System<Set = T>,
+    Tf<T, Vec<T>, Vec<T>>: ToZpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T, (), ()> + System<Set = T>
+{
+    fn designfilt(spec: &FilterSpec<T>) -> Result<(Self, DesignReport<T>), DesignFiltError>
+    {
+        let (h, report) = design(spec)?;
+        Ok((
+            match h
+            {
+                Designed::Iir(zpk) => zpk,
+                Designed::Fir(b) => Tf::new(b, vec![T::one()]).to_zpk((), ())
+            },
+            report
+        ))
+    }
+}
+
+impl<T> DesignFilt for Tf<T, Vec<T>, Vec<T>>
+where
+    T: Float + FloatConst + MulAssign + DivAssign,
+    Zpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T>: IirDesign + ToTf<T, Vec<T>, Vec<T>, (), ()> + System<Set = T>,
+    Tf<T, Vec<T>, ()>: FirPm + FirLS<usize> + Fir1<(), Vec<T>, T, Vec<T>, true> + System<Set = T>,
+    Self: System<Set = T>
+{
+    fn designfilt(spec: &FilterSpec<T>) -> Result<(Self, DesignReport<T>), DesignFiltError>
+    {
+        let (h, report) = design(spec)?;
+        Ok((
+            match h
+            {
+                Designed::Iir(zpk) => zpk.to_tf((), ()),
+                Designed::Fir(b) => Tf::new(b, vec![T::one()])
+            },
+            report
+        ))
+    }
+}
+
+impl<T> DesignFilt for Sos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>>
+where
+    T: Float + FloatConst + MulAssign + DivAssign,
+    Zpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T>: IirDesign + ToSos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>, (), ()> + Zp2Sos<Output = Self> + System<Set = T>,
+    Tf<T, Vec<T>, ()>: FirPm + FirLS<usize> + Fir1<(), Vec<T>, T, Vec<T>, true> + System<Set = T>,
+    Tf<T, Vec<T>, Vec<T>>: Zp2Sos<Output = Self> + System<Set = T>,
+    Self: System<Set = T>
+{
+    fn designfilt(spec: &FilterSpec<T>) -> Result<(Self, DesignReport<T>), DesignFiltError>
+    {
+        let (h, report) = design(spec)?;
+        Ok((
+            match h
+            {
+                Designed::Iir(zpk) => if let FilterGenPlane::Z { .. } = spec.plane
+                {
+                    zpk.zp2sos(SosOrder::Up, SosScale::None)?.0
+                }
+                else
+                {
+                    zpk.to_sos((), ())
+                },
+                Designed::Fir(b) => Tf::new(b, vec![T::one()]).zp2sos(SosOrder::Up, SosScale::None)?.0
+            },
+            report
+        ))
+    }
+}
+
+fn design<T>(spec: &FilterSpec<T>) -> Result<(Designed<T>, DesignReport<T>), DesignFiltError>
+where
+    T: Float + FloatConst + MulAssign + DivAssign,
+    Zpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T>: IirDesign + System<Set = T>,
+    Tf<T, Vec<T>, ()>: FirPm + FirLS<usize> + Fir1<(), Vec<T>, T, Vec<T>, true> + System<Set = T>
+{
+    spec.validate()?;
+
+    let zero = T::zero();
+    let one = T::one();
+    let two = one + one;
+    let ten = T::from(10.0).unwrap();
+    let twenty = ten + ten;
+    let band = matches!(spec.response, FilterGenType::BandPass | FilterGenType::BandStop);
+
+    let method = match spec.method
+    {
+        DesignMethod::Iir(filter_type) => {
+            let (p, s) = (spec.passband, spec.stopband);
+            let (rp, rs) = (spec.passband_ripple, spec.stopband_attenuation);
+            let zpk = if band
+            {
+                Zpk::iir_design(p, s, rp, rs, spec.plane, filter_type)?
+            }
+            else
+            {
+                Zpk::iir_design([p[0]], [s[0]], rp, rs, spec.plane, filter_type)?
+            };
+            let h = Designed::Iir(zpk);
+            let report = report(spec, &h);
+            return Ok((h, report))
+        },
+        method => method
+    };
+
+    // FIR filters are designed with edges normalized to the Nyquist frequency.
+    let nyq = match spec.plane
+    {
+        FilterGenPlane::Z { sampling_frequency } => sampling_frequency.unwrap_or(two)/two,
+        FilterGenPlane::S => one
+    };
+    let (p, s) = (spec.passband.map(|f| f/nyq), spec.stopband.map(|f| f/nyq));
+
+    let ap = ten.powf(spec.passband_ripple/twenty);
+    let dp = (ap - one)/(ap + one);
+    let ds = ten.powf(-spec.stopband_attenuation/twenty);
+
+    let fir = |b: Tf<T, Vec<T>, ()>| Designed::Fir(b.b.into_inner());
+
+    macro_rules! fir_design {
+        ($frequencies:expr, $amplitudes:expr, $passes:expr, $deviations:expr) => {{
+            // Filters which pass the Nyquist frequency must be of even order.
+            let step = if *$amplitudes.last().unwrap() != zero {2} else {1};
+            match method
+            {
+                DesignMethod::KaiserWindow => {
+                    let (n0, wn, beta, filter_type) = kaiserord($frequencies, $passes, $deviations, ())?;
+                    search(n0, step, |n| {
+                        let w: Vec<T> = Kaiser {beta}
+                            .window_gen(n + 1, WindowRange::Symmetric);
+                        Ok(fir(Tf::fir1((), wn.clone(), filter_type, w, true, ())?))
+                    }, |h| report(spec, h))
+                },
+                DesignMethod::Equiripple | DesignMethod::LeastSquares => {
+                    let (n0, f, a, w) = firpmord($frequencies, $amplitudes, $deviations, ())?;
+                    search(n0, step, |n| Ok(if method == DesignMethod::Equiripple
+                        {
+                            let c = |c: f64| T::from(c).unwrap();
+                            let (h, _, ()) = Tf::firpm(
+                                n, f, a, w, FirPmType::Symmetric, (),
+                                c(FIRPM_ACCURACY), c(FIRPM_PERSISTENCE), c(FIRPM_ROBUSTNESS), c(FIRPM_TARGET)
+                            )?;
+                            fir(h)
+                        }
+                        else
+                        {
+                            fir(Tf::firls(n, f, a, w, ())?)
+                        }
+                    ), |h| report(spec, h))
+                },
+                DesignMethod::Iir(_) => unreachable!()
+            }
+        }};
+    }
+
+    match spec.response
+    {
+        FilterGenType::LowPass => fir_design!([p[0], s[0]], [one, zero], [true, false], [dp, ds]),
+        FilterGenType::HighPass => fir_design!([s[0], p[0]], [zero, one], [false, true], [ds, dp]),
+        FilterGenType::BandPass => fir_design!([s[0], p[0], p[1], s[1]], [zero, one, zero], [false, true, false], [ds, dp, ds]),
+        FilterGenType::BandStop => fir_design!([p[0], s[0], s[1], p[1]], [one, zero, one], [true, false, true], [dp, ds, dp])
+    }
+}
+
+/// Finds the lowest order meeting the specification, starting at an estimate `n0`.
+///
+/// Orders which fail to design are treated as not meeting the specification.
+/// If no order up to twice the estimate meets it, the design at the estimate is returned.
+fn search<T, E>(
+    n0: usize,
+    step: usize,
+    mut design: impl FnMut(usize) -> Result<Designed<T>, E>,
+    report: impl Fn(&Designed<T>) -> DesignReport<T>
+) -> Result<(Designed<T>, DesignReport<T>), E>
+where
+    T: Float
+{
+    let first = design(n0).map(|h| {
+        let r = report(&h);
+        (h, r)
+    });
+    match first
+    {
+        Ok(mut best) if best.1.meets_specification => {
+            let mut n = n0;
+            while n > step
+            {
+                n -= step;
+                match design(n)
+                {
+                    Ok(h) => {
+                        let r = report(&h);
+                        if !r.meets_specification
+                        {
+                            break
+                        }
+                        best = (h, r)
+                    },
+                    Err(_) => break
+                }
+            }
+            Ok(best)
+        },
+        first => {
+            let mut n = n0;
+            while n + step <= 2*n0
+            {
+                n += step;
+                if let Ok(h) = design(n)
+                {
+                    let r = report(&h);
+                    if r.meets_specification
+                    {
+                        return Ok((h, r))
+                    }
+                }
+            }
+            first
+        }
+    }
+}
+
+/// Measures the response of a design on a grid over each passband and stopband.
+///
+/// Analog stopbands extending to infinity are measured up to a hundred times their edge.
+fn report<T>(spec: &FilterSpec<T>, h: &Designed<T>) -> DesignReport<T>
+where
+    T: Float + FloatConst
+{
+    let zero = T::zero();
+    let one = T::one();
+    let two = one + one;
+    let hundred = T::from(100.0).unwrap();
+
+    // Converts to angular frequency, where the digital Nyquist frequency is π.
+    let (scale, analog) = match spec.plane
+    {
+        FilterGenPlane::Z { sampling_frequency } => (two*T::PI()/sampling_frequency.unwrap_or(two), false),
+        FilterGenPlane::S => (one, true)
+    };
+    let ([p1, p2], [s1, s2]) = (spec.passband.map(|f| f*scale), spec.stopband.map(|f| f*scale));
+    let end = |edge: T| if analog {edge*hundred} else {T::PI()};
+
+    let (passbands, stopbands) = match spec.response
+    {
+        FilterGenType::LowPass => (vec![(zero, p1)], vec![(s1, end(s1))]),
+        FilterGenType::HighPass => (vec![(p1, end(p1))], vec![(zero, s1)]),
+        FilterGenType::BandPass => (vec![(p1, p2)], vec![(zero, s1), (s2, end(s2))]),
+        FilterGenType::BandStop => (vec![(zero, p1), (p2, end(p2))], vec![(s1, s2)])
+    };
+
+    let gain_db = |w: T| {
+        let h = match h
+        {
+            Designed::Iir(zpk) => {
+                let x = if analog {Complex::new(zero, w)} else {Complex::cis(w)};
+                let num = zpk.z.iter().fold(Complex::one(), |h, &z| h*(x - z));
+                let den = zpk.p.iter().fold(Complex::one(), |h, &p| h*(x - p));
+                num/den*zpk.k
+            },
+            Designed::Fir(b) => {
+                let zi = Complex::cis(-w);
+                b.iter().rev().fold(Complex::from(zero), |h, &b| h*zi + b)
+            }
+        };
+        T::from(20.0).unwrap()*h.norm().log10()
+    };
+    const N: usize = 256;
+    let grid = |(a, b): (T, T)| (0..N).map(move |i| a + (b - a)*T::from(i).unwrap()/T::from(N - 1).unwrap());
+
+    let pass: Vec<T> = passbands.into_iter().flat_map(grid).map(gain_db).collect();
+    let stop: Vec<T> = stopbands.into_iter().flat_map(grid).map(gain_db).collect();
+    let max = |g: &[T]| g.iter().copied().fold(T::neg_infinity(), T::max);
+    let min = |g: &[T]| g.iter().copied().fold(T::infinity(), T::min);
+
+    // Attenuation is measured from the nominal passband gain of 0 dB, not from the passband peak.
+    let passband_ripple = max(&pass) - min(&pass);
+    let stopband_attenuation = -max(&stop);
+
+    // Leave room for the round-off of designs which meet an edge exactly.
+    let tol = T::from(1e-6).unwrap();
+    let order = match h
+    {
+        Designed::Iir(zpk) => zpk.p.len().max(zpk.z.len()),
+        Designed::Fir(b) => b.len().saturating_sub(1)
+    };
+    DesignReport {
+        order,
+        passband_ripple,
+        stopband_attenuation,
+        meets_specification: passband_ripple <= spec.passband_ripple + tol && stopband_attenuation >= spec.stopband_attenuation - tol
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{generators::filter::{DesignFilt, DesignMethod, FilterGenPlane, FilterSpec, IirFilterType}, systems::{Sos, Tf, Zpk}};
+
+    #[test]
+    fn designs_meet_specification()
+    {
+        let plane = FilterGenPlane::Z { sampling_frequency: Some(48000.0) };
+        let spec = FilterSpec::lowpass(4000.0, 6000.0)
+            .with_passband_ripple(0.5)
+            .with_stopband_attenuation(50.0)
+            .with_plane(plane);
+
+        let mut orders = vec![];
+        for filter_type in [IirFilterType::Butterworth, IirFilterType::Chebyshev1, IirFilterType::Chebyshev2, IirFilterType::Elliptic]
+        {
+            let (sos, report): (Sos<f64, _, _, _>, _) = Sos::designfilt(&spec.with_method(DesignMethod::Iir(filter_type))).unwrap();
+            assert!(report.meets_specification, "{filter_type:?}: {report:?}");
+            assert_eq!(sos.sos.len(), report.order.div_ceil(2));
+            orders.push(report.order);
+        }
+        // Elliptic filters are the most selective.
+        assert!(orders[3] < orders[1] && orders[1] < orders[0]);
+
+        for method in [DesignMethod::Equiripple, DesignMethod::KaiserWindow, DesignMethod::LeastSquares]
+        {
+            let spec = FilterSpec::bandpass([8000.0, 12000.0], [6000.0, 14000.0])
+                .with_stopband_attenuation(40.0)
+                .with_plane(plane)
+                .with_method(method);
+            let (h, report): (Tf<f64, Vec<_>, Vec<_>>, _) = Tf::designfilt(&spec).unwrap();
+            assert_eq!(h.b.len(), report.order + 1);
+            assert!(report.meets_specification, "{method:?}: {report:?}");
+        }
+
+        let (_, report): (Zpk<_, Vec<_>, Vec<_>, f64>, _) = Zpk::designfilt(&FilterSpec::highpass(2.0, 1.0)
+            .with_plane(FilterGenPlane::S)
+            .with_method(DesignMethod::Iir(IirFilterType::Elliptic))
+        ).unwrap();
+        assert!(report.meets_specification);
+
+        assert!(Tf::<f64, Vec<_>, Vec<_>>::designfilt(&FilterSpec::lowpass(0.3, 0.2)).is_err());
+    }
+}
@@ -0,0 +1,323 @@
+use ndarray::{Array1, Array2};
+use num::{complex::ComplexFloat, traits::{float::TotalOrder, FloatConst}, Float};
+use option_trait::Maybe;
+
+use crate::{generators::filter::{FilterGenType, FirClsError}, util::{Lapack, LeastSquares, Solve}, validate_band_edges, System, systems::Tf};
+
+/// The range of the error between the achieved and the desired amplitude response within one band of an FIR filter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FirBandRipple<T>
+where
+    T: Float
+{
+    pub min_error: T,
+    pub max_error: T
+}
+
+impl<T> FirBandRipple<T>
+where
+    T: Float
+{
+    /// The largest deviation from the desired response.
+    pub fn deviation(&self) -> T
+    {
+        self.min_error.abs().max(self.max_error.abs())
+    }
+}
+
+/// Constrained least-squares design of linear-phase FIR filters.
+///
+/// The squared error over the whole band is minimized, while the local extrema of the amplitude response are kept within the upper and lower bound of each band.
+/// There are no transition bands. The response crosses over at each band edge, as steeply as the bounds allow.
+/// An odd order is designed as one less, and padded with a zero, as in [`FirLS`](crate::generators::filter::FirLS).
+///
+/// Both methods return the filter and the achieved ripple in each band.
+pub trait FirCls: System + Sized
+{
+    /// Designs a filter with piecewise constant magnitudes, where `frequencies` are the band edges from zero to the Nyquist frequency.
+    fn fircls<const B: usize, FS>(
+        order: usize,
+        frequencies: [<Self::Set as ComplexFloat>::Real; B + 1],
+        magnitudes: [<Self::Set as ComplexFloat>::Real; B],
+        upper: [<Self::Set as ComplexFloat>::Real; B],
+        lower: [<Self::Set as ComplexFloat>::Real; B],
+        sampling_frequency: FS
+    ) -> Result<(Self, [FirBandRipple<<Self::Set as ComplexFloat>::Real>; B]), FirClsError>
+    where
+        FS: Maybe<<Self::Set as ComplexFloat>::Real>,
+        [(); B + 1]:;
+
+    /// Designs a low-pass or high-pass filter, with the given maximum deviations in the passband and stopband.
+    fn fircls1<FS>(
+        order: usize,
+        cutoff: <Self::Set as ComplexFloat>::Real,
+        passband_deviation: <Self::Set as ComplexFloat>::Real,
+        stopband_deviation: <Self::Set as ComplexFloat>::Real,
+        filter_type: FilterGenType,
+        sampling_frequency: FS
+    ) -> Result<(Self, [FirBandRipple<<Self::Set as ComplexFloat>::Real>; 2]), FirClsError>
+    where
+        FS: Maybe<<Self::Set as ComplexFloat>::Real>;
+}
+
+impl<T> FirCls for Tf<T, Vec<T>, ()>
+where
+    T: Float + FloatConst + TotalOrder + Lapack<Real = T>
+{
+    fn fircls<const B: usize, FS>(
+        order: usize,
+        frequencies: [T; B + 1],
+        magnitudes: [T; B],
+        upper: [T; B],
+        lower: [T; B],
+        sampling_frequency: FS
+    ) -> Result<(Self, [FirBandRipple<T>; B]), FirClsError>
+    where
+        FS: Maybe<T>,
+        [(); B + 1]:
+    {
+        design(order, frequencies, magnitudes, upper, lower, sampling_frequency.option())
+    }
+
+    fn fircls1<FS>(
+        order: usize,
+        cutoff: T,
+        passband_deviation: T,
+        stopband_deviation: T,
+        filter_type: FilterGenType,
+        sampling_frequency: FS
+    ) -> Result<(Self, [FirBandRipple<T>; 2]), FirClsError>
+    where
+        FS: Maybe<T>
+    {
+        let zero = T::zero();
+        let one = T::one();
+        let (dp, ds) = (passband_deviation, stopband_deviation);
+
+        let (magnitudes, upper, lower) = match filter_type
+        {
+            FilterGenType::LowPass => ([one, zero], [one + dp, ds], [one - dp, -ds]),
+            FilterGenType::HighPass => ([zero, one], [ds, one + dp], [-ds, one - dp]),
+            FilterGenType::BandPass | FilterGenType::BandStop => return Err(FirClsError::InvalidFilterType)
+        };
+        let sampling_frequency = sampling_frequency.option();
+        let nyquist = sampling_frequency.map(|fs| fs/(one + one))
+            .unwrap_or(one);
+
+        design(order, [zero, cutoff, nyquist], magnitudes, upper, lower, sampling_frequency)
+    }
+}
+
+fn design<T, const B: usize>(
+    order: usize,
+    mut frequencies: [T; B + 1],
+    magnitudes: [T; B],
+    upper: [T; B],
+    lower: [T; B],
+    sampling_frequency: Option<T>
+) -> Result<(Tf<T, Vec<T>, ()>, [FirBandRipple<T>; B]), FirClsError>
+where
+    T: Float + FloatConst + TotalOrder + Lapack<Real = T>
+{
+    const MAX_ITERATIONS: usize = 100;
+
+    validate_band_edges(&mut frequencies, sampling_frequency)?;
+
+    let zero = T::zero();
+    let one = T::one();
+    let two = one + one;
+    let pi = T::PI();
+
+    if frequencies[0] != zero || frequencies[B] != one
+    {
+        return Err(FirClsError::EdgesNotSpanning)
+    }
+    if magnitudes.iter()
+        .zip(upper.iter().zip(lower.iter()))
+        .any(|(&m, (&u, &l))| !(l < m && m < u))
+    {
+        return Err(FirClsError::InvalidBounds)
+    }
+
+    // The amplitude response is A(ω) = Σ aₖcos(kω), and the squared error is Σ λₖ(aₖ - âₖ)², where â is the unconstrained solution.
+    let l = order/2;
+    let kf = |k: usize| T::from(k).unwrap();
+    let lambda_inv = Array1::from_shape_fn(l + 1, |k| if k == 0 {pi.recip()} else {two/pi});
+
+    let mut a_ls = Array1::from_elem(l + 1, zero);
+    for (&m, f) in magnitudes.iter()
+        .zip(frequencies.windows(2))
+    {
+        let (w1, w2) = (f[0]*pi, f[1]*pi);
+        for (k, a) in a_ls.iter_mut()
+            .enumerate()
+        {
+            *a = *a + if k == 0
+            {
+                m*(w2 - w1)
+            }
+            else
+            {
+                m*((kf(k)*w2).sin() - (kf(k)*w1).sin())/kf(k)
+            }*lambda_inv[k]
+        }
+    }
+
+    let g = 32*(l + 1);
+    let w: Vec<T> = (0..g).map(|i| kf(i)*pi/kf(g - 1))
+        .collect();
+    let band: Vec<usize> = w.iter()
+        .map(|&w| frequencies[1..].iter()
+            .position(|&f| w <= f*pi)
+            .unwrap_or(B - 1)
+        ).collect();
+    let c = Array2::from_shape_fn((g, l + 1), |(i, k)| (kf(k)*w[i]).cos());
+
+    let find_extrema = |amp: &Array1<T>| (0..g).filter_map(|i| {
+            let left = amp[if i > 0 {i - 1} else {1}];
+            let right = amp[if i + 1 < g {i + 1} else {g - 2}];
+            if amp[i] >= left && amp[i] >= right
+            {
+                Some((i, true))
+            }
+            else if amp[i] <= left && amp[i] <= right
+            {
+                Some((i, false))
+            }
+            else
+            {
+                None
+            }
+        }).collect::<Vec<_>>();
+
+    let tol = T::epsilon().sqrt();
+    let mut a = a_ls.clone();
+    for _ in 0..MAX_ITERATIONS
+    {
+        let amp = c.dot(&a);
+        let extrema = find_extrema(&amp);
+
+        // Constrain every extremum which reaches its bound.
+        let mut worst = zero;
+        let mut active = vec![];
+        for &(i, is_max) in extrema.iter()
+        {
+            let b = band[i];
+            if is_max && amp[i] > upper[b] - tol
+            {
+                worst = worst.max(amp[i] - upper[b]);
+                active.push((i, upper[b], is_max))
+            }
+            else if !is_max && amp[i] < lower[b] + tol
+            {
+                worst = worst.max(lower[b] - amp[i]);
+                active.push((i, lower[b], is_max))
+            }
+        }
+
+        if worst <= tol
+        {
+            let mut h: Vec<T> = a.iter()
+                .skip(1)
+                .rev()
+                .map(|&a| a/two)
+                .chain(core::iter::once(a[0]))
+                .chain(a.iter()
+                    .skip(1)
+                    .map(|&a| a/two)
+                ).collect();
+            h.resize(order + 1, zero);
+
+            let ripple = core::array::from_fn(|b| {
+                // The response is monotonic across the band edges, so only its extrema count as ripple.
+                let mut e: Vec<T> = extrema.iter()
+                    .filter(|&&(i, _)| band[i] == b)
+                    .map(|&(i, _)| amp[i] - magnitudes[b])
+                    .collect();
+                if e.is_empty()
+                {
+                    e = (0..g).filter(|&i| band[i] == b)
+                        .map(|i| amp[i] - magnitudes[b])
+                        .collect()
+                }
+                FirBandRipple {
+                    min_error: e.iter().fold(T::infinity(), |m, &e| m.min(e)),
+                    max_error: e.iter().fold(T::neg_infinity(), |m, &e| m.max(e))
+                }
+            });
+
+            return Ok((Tf::new(h, ()), ripple))
+        }
+
+        // Lagrange multipliers for the active constraints. A constraint which pulls the wrong way is dropped.
+        loop
+        {
+            if active.is_empty()
+            {
+                a = a_ls.clone();
+                break
+            }
+            let gm = Array2::from_shape_fn((active.len(), l + 1), |(j, k)| c[(active[j].0, k)]);
+            let m = (&gm*&lambda_inv).dot(&gm.t());
+            let r = gm.dot(&a_ls) - Array1::from_iter(active.iter().map(|&(_, bound, _)| bound));
+            let mu = m.solve(&r)
+                .or_else(|_| m.least_squares(&r))
+                .map_err(|_| FirClsError::NoConvergence)?;
+
+            let wrong = active.iter()
+                .zip(mu.iter())
+                .enumerate()
+                .filter(|&(_, (&(_, _, is_max), &mu))| if is_max {mu < zero} else {mu > zero})
+                .max_by(|(_, (_, mu1)), (_, (_, mu2))| mu1.abs().total_cmp(&mu2.abs()))
+                .map(|(j, _)| j);
+            if let Some(j) = wrong
+            {
+                active.remove(j);
+                continue
+            }
+
+            a = &a_ls - &(gm.t().dot(&mu)*&lambda_inv);
+            break
+        }
+    }
+
+    Err(FirClsError::NoConvergence)
+}
+
+#[cfg(test)]
+mod test
+{
+    use num::Complex;
+
+    use crate::{generators::filter::{FilterGenType, FirCls}, systems::Tf};
+
+    #[test]
+    fn bounds()
+    {
+        let gain = |h: &Tf<f64, Vec<f64>, ()>, f: f64| h.b.iter()
+            .rev()
+            .fold(Complex::from(0.0), |y, &b| y*Complex::cis(-core::f64::consts::PI*f) + b)
+            .norm();
+
+        let (h, ripple) = Tf::fircls(30, [0.0, 0.4, 1.0], [1.0, 0.0], [1.02, 0.02], [0.98, -0.02], ()).unwrap();
+        assert_eq!(h.b.len(), 31);
+        assert!(ripple[0].deviation() <= 0.02 + 1e-6 && ripple[1].deviation() <= 0.02 + 1e-6);
+        for i in 0..=1000
+        {
+            let f = i as f64/1000.0;
+            let g = gain(&h, f);
+            if f < 0.3
+            {
+                assert!((g - 1.0).abs() < 0.0205, "{f}: {g}");
+            }
+            else if f > 0.5
+            {
+                assert!(g < 0.0205, "{f}: {g}");
+            }
+        }
+
+        let (h, ripple) = Tf::fircls1(40, 3000.0, 0.01, 0.001, FilterGenType::HighPass, 8000.0).unwrap();
+        assert!(ripple[0].deviation() <= 0.001 + 1e-6 && ripple[1].deviation() <= 0.01 + 1e-6);
+        assert!(gain(&h, 0.0) < 0.0011 && (gain(&h, 1.0) - 1.0).abs() < 0.0101);
+    }
+}
@@ -0,0 +1,190 @@
+use ndarray::{Array1, Array2};
+use num::{complex::ComplexFloat, traits::FloatConst, Float};
+use option_trait::Maybe;
+
+use crate::{generators::filter::{FirBandRipple, FirLpNormError}, util::{Lapack, LeastSquares, Solve}, validate_band_edges, System, systems::Tf};
+
+/// Least-p-norm design of linear-phase FIR filters.
+///
+/// Minimizes `Σ W|A(ω) - D(ω)|ᵖ` over the bands, by iteratively reweighted least squares, where the norm is raised gradually from 2.
+/// A norm of 2 gives a least-squares design like [`FirLS`](crate::generators::filter::FirLS), and as the norm grows, the design approaches the equiripple design of [`FirPm`](crate::generators::filter::FirPm).
+/// The bands, magnitudes and positive weights are given as in [`FirLS`](crate::generators::filter::FirLS), and an odd order is likewise designed as one less, and padded with a zero.
+///
+/// Returns the filter and the achieved ripple in each band.
+pub trait FirLpNorm: System + Sized
+{
+    fn firlpnorm<const B2: usize, FS, W>(
+        order: usize,
+        frequencies: [<Self::Set as ComplexFloat>::Real; B2],
+        magnitudes: [<Self::Set as ComplexFloat>::Real; B2],
+        weights: W,
+        norm: <Self::Set as ComplexFloat>::Real,
+        sampling_frequency: FS
+    ) -> Result<(Self, [FirBandRipple<<Self::Set as ComplexFloat>::Real>; B2/2]), FirLpNormError>
+    where
+        FS: Maybe<<Self::Set as ComplexFloat>::Real>,
+        W: Maybe<[<Self::Set as ComplexFloat>::Real; B2/2]>,
+        [(); 0 - B2 % 2]:,
+        [(); B2/2 - 1]:,
+        [(); B2/2]:;
+}
+
+impl<T> FirLpNorm for Tf<T, Vec<T>, ()>
+where
+    T: Float + FloatConst + Lapack<Real = T>
+{
+    fn firlpnorm<const B2: usize, FS, W>(
+        order: usize,
+        mut frequencies: [T; B2],
+        magnitudes: [T; B2],
+        weights: W,
+        norm: T,
+        sampling_frequency: FS
+    ) -> Result<(Self, [FirBandRipple<T>; B2/2]), FirLpNormError>
+    where
+        FS: Maybe<T>,
+        W: Maybe<[T; B2/2]>,
+        [(); 0 - B2 % 2]:,
+        [(); B2/2 - 1]:,
+        [(); B2/2]:
+    {
+        const MAX_ITERATIONS: usize = 200;
+
+        validate_band_edges(&mut frequencies, sampling_frequency.option())?;
+
+        let zero = T::zero();
+        let one = T::one();
+        let two = one + one;
+        let pi = T::PI();
+
+        if !(norm >= two) || !norm.is_finite()
+        {
+            return Err(FirLpNormError::InvalidNorm)
+        }
+
+        let weight: [T; B2/2] = weights.option()
+            .unwrap_or([one; B2/2]);
+        if weight.iter().any(|&w| !(w > zero) || !w.is_finite())
+        {
+            return Err(FirLpNormError::InvalidWeights)
+        }
+
+        // A dense grid over the bands, with points in proportion to their widths.
+        let l = order/2;
+        let kf = |k: usize| T::from(k).unwrap();
+        let g = kf(16*(l + 1));
+        let total = frequencies.chunks(2)
+            .fold(zero, |s, f| s + f[1] - f[0]);
+        let mut grid = vec![];
+        for (b, (f, m)) in frequencies.chunks(2)
+            .zip(magnitudes.chunks(2))
+            .enumerate()
+        {
+            let count = if total > zero
+            {
+                (g*(f[1] - f[0])/total).round().to_usize().unwrap().max(2)
+            }
+            else
+            {
+                2
+            };
+            for i in 0..count
+            {
+                let x = kf(i)/kf(count - 1);
+                grid.push((pi*(f[0] + (f[1] - f[0])*x), m[0] + (m[1] - m[0])*x, b))
+            }
+        }
+
+        let c = Array2::from_shape_fn((grid.len(), l + 1), |(i, k)| (kf(k)*grid[i].0).cos());
+        let d = Array1::from_iter(grid.iter().map(|&(_, d, _)| d));
+        let wls = |ws: &Array1<T>| {
+            let cw = Array2::from_shape_fn(c.dim(), |(i, k)| c[(i, k)]*ws[i]);
+            let m = cw.t().dot(&c);
+            let r = cw.t().dot(&d);
+            m.solve(&r)
+                .or_else(|_| m.least_squares(&r))
+                .map_err(|_| FirLpNormError::NoConvergence)
+        };
+
+        let w = Array1::from_iter(grid.iter().map(|&(_, _, b)| weight[b]));
+        let mut a = wls(&w)?;
+        let mut p = two;
+        let tol = T::epsilon().sqrt();
+        for _ in 0..MAX_ITERATIONS
+        {
+            p = norm.min(p*T::from(1.5).unwrap());
+
+            // Each point is weighted by |e|ᵖ⁻², normalized to avoid overflow, and only part of the step is taken, for convergence.
+            let e = (c.dot(&a) - &d).mapv(|e| e.abs());
+            let e_max = e.fold(zero, |m, &e| m.max(e));
+            if e_max == zero
+            {
+                break
+            }
+            let ws = Array1::from_shape_fn(w.len(), |i| w[i]*(e[i]/e_max).powf(p - two));
+            let lambda = (p - one).recip();
+            let a_next = wls(&ws)?.mapv(|a| a*lambda) + &a.mapv(|a| a*(one - lambda));
+
+            let step = (&a_next - &a).fold(zero, |m, &da| m.max(da.abs()));
+            a = a_next;
+            if p == norm && step <= tol*a.fold(zero, |m, &a| m.max(a.abs()))
+            {
+                break
+            }
+        }
+
+        let e = c.dot(&a) - &d;
+        let ripple = core::array::from_fn(|b| {
+            let e: Vec<T> = grid.iter()
+                .zip(e.iter())
+                .filter(|((_, _, band), _)| *band == b)
+                .map(|(_, &e)| e)
+                .collect();
+            FirBandRipple {
+                min_error: e.iter().fold(T::infinity(), |m, &e| m.min(e)),
+                max_error: e.iter().fold(T::neg_infinity(), |m, &e| m.max(e))
+            }
+        });
+
+        let mut h: Vec<T> = a.iter()
+            .skip(1)
+            .rev()
+            .map(|&a| a/two)
+            .chain(core::iter::once(a[0]))
+            .chain(a.iter()
+                .skip(1)
+                .map(|&a| a/two)
+            ).collect();
+        h.resize(order + 1, zero);
+
+        Ok((Tf::new(h, ()), ripple))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{generators::filter::{FirLpNorm, FirLpNormError}, systems::Tf};
+
+    #[test]
+    fn approaches_equiripple()
+    {
+        let design = |p: f64| Tf::firlpnorm(30, [0.0, 0.3, 0.4, 1.0], [1.0, 1.0, 0.0, 0.0], (), p, ())
+            .map(|(_, ripple): (Tf<f64, Vec<f64>, ()>, _)| ripple.map(|r| r.deviation()));
+
+        let ls = design(2.0).unwrap();
+        let lp = design(128.0).unwrap();
+
+        // Least squares overshoots at the band edges.
+        assert!(ls[0] > 0.05 && ls[1] > 0.06);
+        // A high norm spreads the error evenly, near the equiripple deviation of 0.025.
+        assert!(lp[0] < 0.0255 && lp[1] < 0.0255);
+        assert!((lp[0] - lp[1]).abs() < 1e-3);
+
+        assert_eq!(design(1.0), Err(FirLpNormError::InvalidNorm));
+        assert_eq!(
+            Tf::<f64, Vec<f64>, ()>::firlpnorm(30, [0.0, 0.3, 0.4, 1.0], [1.0, 1.0, 0.0, 0.0], [1.0, 0.0], 2.0, ()).err(),
+            Some(FirLpNormError::InvalidWeights)
+        );
+    }
+}
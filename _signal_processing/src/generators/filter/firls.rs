@@ -1,7 +1,7 @@
 use core::ops::{Div, Mul, MulAssign, Sub};
 
 use ndarray::Array2;
-use ndarray_linalg::{solve::Solve, Lapack, least_squares::LeastSquaresSvd};
+use crate::util::{Solve, Lapack, LeastSquares};
 use num::{complex::ComplexFloat, traits::FloatConst, Float, NumCast, One, Zero};
 use option_trait::Maybe;
 
@@ -174,7 +174,7 @@ where
             .map(|a| a.to_vec())
             .unwrap_or_else(|_| {
                 q.least_squares(&b.column(0))
-                    .map(|a| a.solution.to_vec())
+                    .map(|a| a.to_vec())
                     .unwrap()
             });
 
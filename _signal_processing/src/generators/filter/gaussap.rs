@@ -0,0 +1,103 @@
+use num::{traits::FloatConst, Complex, Float, One};
+use option_trait::Maybe;
+
+use crate::{generators::filter::magnitude_poles, quantities::ProductSequence, util::{Lapack, LinalgError}, System, systems::Zpk};
+
+/// Analog prototype of a Gaussian filter.
+///
+/// The magnitude response approximates a Gaussian, by truncating the Taylor series of `1/|H(jω)|² = exp(aω²)` after the given order.
+/// This gives a step response with almost no overshoot, and a group delay which is nearly flat in the passband.
+/// The frequency is scaled to put the cutoff at `-3 dB` at 1 rad/s.
+pub trait GaussAP<O>: System + Sized
+where
+    Self::Set: Float,
+    O: Maybe<usize>
+{
+    fn gaussap(order: O) -> Result<Self, LinalgError>;
+}
+
+impl<T> GaussAP<usize> for Zpk<Complex<T>, (), Vec<Complex<T>>, T>
+where
+    T: Float + FloatConst,
+    Complex<T>: Lapack<Real = T>
+{
+    fn gaussap(order: usize) -> Result<Self, LinalgError>
+    {
+        if order == 0
+        {
+            return Ok(Self::one())
+        }
+
+        let one = T::one();
+        let two = one + one;
+        let taylor = |a: T, n: usize| (1..=n).scan(one, |t, j| {
+                *t = *t*a/T::from(j).unwrap();
+                Some(*t)
+            }).fold(one, |s, t| s + t);
+
+        // Newton's method for the scale a with a truncated exp(a) = 2.
+        let mut a = T::LN_2();
+        for _ in 0..64
+        {
+            let da = (taylor(a, order) - two)/taylor(a, order - 1);
+            a = a - da;
+            if da.abs() <= T::epsilon()*a
+            {
+                break
+            }
+        }
+
+        let c: Vec<T> = (0..=order).scan(one, |t, j| {
+                if j > 0
+                {
+                    *t = *t*a/T::from(j).unwrap();
+                }
+                Some(*t)
+            }).collect();
+        let (p, k) = magnitude_poles(&c)?;
+        Ok(Zpk {
+            z: ProductSequence::new(()),
+            p: ProductSequence::new(p),
+            k
+        })
+    }
+}
+impl<T, const N: usize> GaussAP<()> for Zpk<Complex<T>, (), [Complex<T>; N], T>
+where
+    T: Float + FloatConst,
+    Zpk<Complex<T>, (), Vec<Complex<T>>, T>: GaussAP<usize> + System<Set = T>
+{
+    fn gaussap((): ()) -> Result<Self, LinalgError>
+    {
+        let Zpk { z, p, k } = Zpk::gaussap(N)?;
+
+        Ok(Zpk {
+            z,
+            p: p.try_into().map_err(|_| ()).unwrap(),
+            k
+        })
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use num::Complex;
+
+    use crate::{generators::filter::GaussAP, analysis::FreqS, systems::Zpk};
+
+    #[test]
+    fn cutoff()
+    {
+        for n in 1..10
+        {
+            let h: Zpk<_, (), Vec<_>, f64> = Zpk::gaussap(n).unwrap();
+            assert_eq!(h.p.len(), n);
+            assert!(h.p.iter().all(|p| p.re < 0.0));
+
+            let [h0, h1] = h.freqs([0.0, 1.0].map(|w| Complex::new(0.0, w)));
+            assert!((h0.norm() - 1.0).abs() < 1e-9);
+            assert!((h1.norm_sqr() - 0.5).abs() < 1e-9);
+        }
+    }
+}
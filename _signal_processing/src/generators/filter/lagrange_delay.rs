@@ -0,0 +1,61 @@
+use num::{complex::ComplexFloat, Float};
+
+use crate::{generators::filter::FracDelayError, systems::Tf, System};
+
+/// Designs a Lagrange interpolation FIR filter with a given fractional delay, in samples.
+///
+/// The frequency response is maximally flat at DC. The delay must be in `[0, order]`, and works best within half a sample of `order/2`.
+/// There, the group delay stays within 0.1 samples of the delay up to about 0.25π for order 2, 0.4π for order 4 and 0.5π for order 8.
+pub trait LagrangeDelay: System + Sized
+{
+    fn lagrange_delay(delay: <Self::Set as ComplexFloat>::Real, order: usize) -> Result<Self, FracDelayError>;
+}
+
+impl<T> LagrangeDelay for Tf<T, Vec<T>, ()>
+where
+    T: Float
+{
+    fn lagrange_delay(delay: T, order: usize) -> Result<Self, FracDelayError>
+    {
+        if order < 1
+        {
+            return Err(FracDelayError::ZeroOrder)
+        }
+        if !(delay >= T::zero() && delay <= T::from(order).unwrap())
+        {
+            return Err(FracDelayError::DelayOutOfRange)
+        }
+
+        // hₙ = ∏ (D - k)/(n - k), for k ≠ n
+        let b = (0..=order).map(|n| (0..=order).filter(|&k| k != n)
+                .map(|k| (T::from(k).unwrap(), T::from(n).unwrap()))
+                .fold(T::one(), |h, (k, n)| h*(delay - k)/(n - k))
+            ).collect();
+        Ok(Tf::new(b, ()))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{generators::filter::{assert_group_delay, LagrangeDelay}, systems::Tf};
+
+    #[test]
+    fn flat_group_delay()
+    {
+        // Integer delays give a pure delay.
+        let h = Tf::lagrange_delay(2.0, 4).unwrap();
+        assert_eq!(*h.b, vec![0.0, 0.0, 1.0, 0.0, 0.0]);
+
+        for (order, bandwidth) in [(2, 0.25), (4, 0.4), (8, 0.5)]
+        {
+            for delay in [order as f64/2.0 - 0.4, order as f64/2.0 + 0.1, order as f64/2.0 + 0.4]
+            {
+                let h = Tf::lagrange_delay(delay, order).unwrap();
+                assert!((h.b.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+
+                assert_group_delay(&h, delay, bandwidth);
+            }
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use num::{traits::FloatConst, Complex, Float, One, Zero};
+use option_trait::Maybe;
+
+use crate::{analysis::control::roots, quantities::ProductSequence, util::{Lapack, LinalgError}, System, systems::Zpk};
+
+/// Analog prototype of a Legendre, or optimum-L, filter.
+///
+/// It has the steepest cutoff of all filters with a monotonic magnitude response.
+/// Like [`ButtAP`](crate::generators::filter::ButtAP), the cutoff is at `-3 dB` at 1 rad/s.
+pub trait LegendreAP<O>: System + Sized
+where
+    Self::Set: Float,
+    O: Maybe<usize>
+{
+    fn legendreap(order: O) -> Result<Self, LinalgError>;
+}
+
+impl<T> LegendreAP<usize> for Zpk<Complex<T>, (), Vec<Complex<T>>, T>
+where
+    T: Float + FloatConst,
+    Complex<T>: Lapack<Real = T>
+{
+    fn legendreap(order: usize) -> Result<Self, LinalgError>
+    {
+        if order == 0
+        {
+            return Ok(Self::one())
+        }
+
+        let mut c = optimum_l(order);
+        c[0] = T::one();
+        let (p, k) = magnitude_poles(&c)?;
+        Ok(Zpk {
+            z: ProductSequence::new(()),
+            p: ProductSequence::new(p),
+            k
+        })
+    }
+}
+impl<T, const N: usize> LegendreAP<()> for Zpk<Complex<T>, (), [Complex<T>; N], T>
+where
+    T: Float + FloatConst,
+    Zpk<Complex<T>, (), Vec<Complex<T>>, T>: LegendreAP<usize> + System<Set = T>
+{
+    fn legendreap((): ()) -> Result<Self, LinalgError>
+    {
+        let Zpk { z, p, k } = Zpk::legendreap(N)?;
+
+        Ok(Zpk {
+            z,
+            p: p.try_into().map_err(|_| ()).unwrap(),
+            k
+        })
+    }
+}
+
+/// The optimum-L polynomial `Lₙ(ω²)`, in ascending powers of `ω²`, such that `|H(jω)|² = 1/(1 + Lₙ(ω²))`.
+///
+/// It is the integral of a squared sum of Legendre polynomials, weighted to make it as steep as possible at `ω = 1`, where it equals one.
+pub(crate) fn optimum_l<T>(order: usize) -> Vec<T>
+where
+    T: Float
+{
+    let zero = T::zero();
+    let one = T::one();
+    let two = one + one;
+    let add = |a: &[T], b: &[T]| -> Vec<T> {
+        (0..a.len().max(b.len())).map(|i| a.get(i).copied().unwrap_or(zero) + b.get(i).copied().unwrap_or(zero))
+            .collect()
+    };
+    let mul = |a: &[T], b: &[T]| -> Vec<T> {
+        let mut c = vec![zero; a.len() + b.len() - 1];
+        for (i, &a) in a.iter().enumerate()
+        {
+            for (j, &b) in b.iter().enumerate()
+            {
+                c[i + j] = c[i + j] + a*b
+            }
+        }
+        c
+    };
+    let eval = |c: &[T], x: T| c.iter()
+        .rev()
+        .fold(zero, |y, &c| y*x + c);
+    let float = |n: usize| T::from(n).unwrap();
+
+    // Weights of the Legendre polynomials, and the weight of the integrand.
+    let (k, a, w) = if order % 2 == 1
+    {
+        let k = (order - 1)/2;
+        let a0 = (two.sqrt()*float(k + 1)).recip();
+        (k, (0..=k).map(|i| float(2*i + 1)*a0).collect::<Vec<_>>(), vec![one])
+    }
+    else
+    {
+        let k = (order - 2)/2;
+        let d = (float(k + 1)*float(k + 2)).sqrt();
+        (k, (0..=k).map(|i| if i % 2 == k % 2 {float(2*i + 1)/d} else {zero}).collect(), vec![one, one])
+    };
+
+    // (i + 1)Pᵢ₊₁(x) = (2i + 1)xPᵢ(x) - iPᵢ₋₁(x)
+    let mut p_prev = vec![one];
+    let mut p = vec![zero, one];
+    let mut sum = vec![a[0]];
+    for (i, &a) in a.iter().enumerate().skip(1)
+    {
+        sum = add(&sum, &p.iter().map(|&p| p*a).collect::<Vec<_>>());
+        if i < k
+        {
+            let i = float(i);
+            let next = add(
+                &core::iter::once(zero).chain(p.iter().copied()).map(|p| p*(two*i + one)/(i + one)).collect::<Vec<_>>(),
+                &p_prev.iter().map(|&p| -p*i/(i + one)).collect::<Vec<_>>()
+            );
+            p_prev = core::mem::replace(&mut p, next);
+        }
+    }
+
+    let integrand = mul(&mul(&sum, &sum), &w);
+    let integral: Vec<T> = core::iter::once(zero)
+        .chain(integrand.iter().enumerate().map(|(i, &c)| c/float(i + 1)))
+        .collect();
+
+    // Substitutes x = 2ω² - 1, integrating from x = -1.
+    let mut l = vec![-eval(&integral, -one)];
+    let mut term = vec![one];
+    for &c in integral.iter()
+    {
+        l = add(&l, &term.iter().map(|&t| t*c).collect::<Vec<_>>());
+        term = mul(&term, &[-one, two]);
+    }
+    l[0] = zero;
+    l
+}
+
+/// The stable poles and gain of an all-pole filter with `|H(jω)|² = 1/c(ω²)`, where `c` is in ascending powers of `ω²`, and `c(0) = 1`.
+pub(crate) fn magnitude_poles<T>(c: &[T]) -> Result<(Vec<Complex<T>>, T), LinalgError>
+where
+    T: Float,
+    Complex<T>: Lapack<Real = T>
+{
+    let n = c.len() - 1;
+
+    // H(s)H(-s) = 1/c(-s²), in descending powers of s.
+    let mut q = vec![Complex::zero(); 2*n + 1];
+    for (j, &c) in c.iter().enumerate()
+    {
+        q[2*(n - j)] = Complex::from(if j % 2 == 1 {-c} else {c})
+    }
+    let mut p = roots(&q)?;
+    p.sort_by(|a, b| a.re.total_cmp(&b.re));
+    p.truncate(n);
+
+    // Unity gain at DC.
+    let k = p.iter()
+        .fold(Complex::one(), |k, &p| k*-p)
+        .re;
+    Ok((p, k))
+}
+
+#[cfg(test)]
+mod test
+{
+    use num::Complex;
+
+    use crate::{generators::filter::{optimum_l, LegendreAP}, analysis::FreqS, systems::Zpk};
+
+    #[test]
+    fn optimum_l_polynomials()
+    {
+        let l3: Vec<f64> = optimum_l(3);
+        let l6: Vec<f64> = optimum_l(6);
+        for (l, l_ref) in l3.iter().zip([0.0, 1.0, -3.0, 3.0])
+            .chain(l6.iter().zip([0.0, 0.0, 6.0, -40.0, 105.0, -120.0, 50.0]))
+        {
+            assert!((l - l_ref).abs() < 1e-9);
+        }
+
+        for n in 1..8
+        {
+            let h: Zpk<_, (), Vec<_>, f64> = Zpk::legendreap(n).unwrap();
+            assert_eq!(h.p.len(), n);
+            assert!(h.p.iter().all(|p| p.re < 0.0));
+
+            let w = [0.0, 0.5, 1.0, 2.0];
+            let h_f = h.freqs(w.map(|w| Complex::new(0.0, w)));
+            let l: Vec<f64> = optimum_l(n);
+            for (h, w) in h_f.iter().zip(w)
+            {
+                let h2 = 1.0/(1.0 + l.iter().rev().fold(0.0, |y, &c| y*w*w + c));
+                assert!((h.norm_sqr() - h2).abs() < 1e-9);
+            }
+        }
+    }
+}
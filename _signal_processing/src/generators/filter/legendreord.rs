@@ -0,0 +1,221 @@
+use core::ops::{DivAssign, MulAssign};
+
+use num::{traits::FloatConst, Float};
+
+use crate::{validate_filter_bands, generators::filter::{optimum_l, FilterGenPlane, FilterGenType, FilterBandError, LegendreOrdError}};
+
+/// The highest order tried. The optimum-L polynomial is built in the monomial basis, which loses accuracy quickly beyond this order.
+const MAX_ORDER: usize = 18;
+
+/// The lowest order of a Legendre filter which meets the specification, and its natural (`-3 dB`) frequencies.
+///
+/// The natural frequencies are chosen so that the passband edges are met exactly.
+/// The ripple and attenuation are in dB, and must be positive.
+pub fn legendreord<T, const F: usize>(
+    mut passband_frequencies: [T; F],
+    mut stopband_frequencies: [T; F],
+    passband_ripple: T,
+    stopband_attenuation: T,
+    plane: FilterGenPlane<T>
+) -> Result<(usize, [T; F], FilterGenType), LegendreOrdError>
+where
+    T: Float + FloatConst + MulAssign + DivAssign,
+    [(); F - 1]:,
+    [(); 2 - F]:
+{
+    if !(passband_ripple > T::zero() && passband_ripple.is_finite() && stopband_attenuation > T::zero() && stopband_attenuation.is_finite())
+    {
+        return Err(LegendreOrdError::InvalidRipple)
+    }
+
+    let two = T::from(2.0).unwrap();
+    let t = if let FilterGenPlane::Z { sampling_frequency } = plane
+    {
+        let t = sampling_frequency.unwrap_or(two);
+        for wc in passband_frequencies.iter_mut()
+        {
+            if *wc > t/two
+            {
+                return Err(FilterBandError::EdgesOutOfRange.into())
+            }
+            *wc = two/t*(T::PI()**wc/t).tan()
+        }
+        for wc in stopband_frequencies.iter_mut()
+        {
+            if *wc > t/two
+            {
+                return Err(FilterBandError::EdgesOutOfRange.into())
+            }
+            *wc = two/t*(T::PI()**wc/t).tan()
+        }
+        Some(t)
+    }
+    else
+    {
+        None
+    };
+
+    validate_filter_bands(&passband_frequencies, &stopband_frequencies, t)?;
+
+    let filter_type;
+    let mut wp;
+    let mut ws;
+
+    let pp = passband_frequencies.iter().fold(T::one(), |p, &w| p*w);
+    let ps = stopband_frequencies.iter().fold(T::one(), |p, &w| p*w);
+
+    let one = T::one();
+
+    if F == 2
+    {
+        if passband_frequencies[0] > stopband_frequencies[0]
+        {
+            filter_type = FilterGenType::BandPass;
+
+            if pp < ps
+            {
+                stopband_frequencies[1] = pp/stopband_frequencies[0]
+            }
+            else
+            {
+                stopband_frequencies[0] = pp/stopband_frequencies[1]
+            }
+
+            wp = passband_frequencies[1] - passband_frequencies[0];
+            ws = stopband_frequencies[1] - stopband_frequencies[0];
+        }
+        else
+        {
+            filter_type = FilterGenType::BandStop;
+
+            if pp > ps
+            {
+                passband_frequencies[1] = ps/passband_frequencies[0]
+            }
+            else
+            {
+                passband_frequencies[0] = ps/passband_frequencies[1]
+            }
+
+            wp = pp/(passband_frequencies[1] - passband_frequencies[0]);
+            ws = pp/(stopband_frequencies[1] - stopband_frequencies[0]);
+        }
+
+        ws /= wp;
+        wp = one;
+    }
+    else if passband_frequencies[0] > stopband_frequencies[0]
+    {
+        filter_type = FilterGenType::HighPass;
+
+        wp = stopband_frequencies[0];
+        ws = passband_frequencies[0];
+    }
+    else
+    {
+        filter_type = FilterGenType::LowPass;
+
+        wp = passband_frequencies[0];
+        ws = stopband_frequencies[0];
+    }
+
+    let wa = ws/wp;
+
+    let ten = T::from(10.0).unwrap();
+    let eps_p = ten.powf(passband_ripple/ten) - one;
+    let eps_s = ten.powf(stopband_attenuation/ten) - one;
+
+    // There is no closed form for the order, but Lₙ is increasing, so each order is tried in turn up to the highest.
+    let eval = |l: &[T], u: T| l.iter()
+        .rev()
+        .fold(T::zero(), |y, &c| y*u + c);
+    let (n, up) = (1..=MAX_ORDER).map(|n| {
+            let l = optimum_l(n);
+            let mut hi = one;
+            while eval(&l, hi) < eps_p
+            {
+                hi = hi*two
+            }
+            let mut lo = T::zero();
+            while hi - lo > T::epsilon()*hi
+            {
+                let mid = (lo + hi)/two;
+                if eval(&l, mid) < eps_p
+                {
+                    lo = mid
+                }
+                else
+                {
+                    hi = mid
+                }
+            }
+            (n, lo, eval(&l, lo*wa*wa) >= eps_s)
+        }).find(|&(_, _, ok)| ok)
+        .map(|(n, up, _)| (n, up))
+        .ok_or(LegendreOrdError::OrderTooHigh)?;
+
+    // The passband edge of the prototype.
+    let omega_p = up.sqrt();
+
+    let wn: [T; F] = match filter_type
+    {
+        FilterGenType::LowPass => passband_frequencies.map(|w| w/omega_p),
+        FilterGenType::HighPass => passband_frequencies.map(|w| w*omega_p),
+        FilterGenType::BandPass | FilterGenType::BandStop => {
+            let bw = passband_frequencies[1] - passband_frequencies[0];
+            let bw = if filter_type == FilterGenType::BandPass {bw/omega_p} else {bw*omega_p};
+            let w0 = passband_frequencies[0]*passband_frequencies[1];
+            let s = (bw*bw + two*two*w0).sqrt();
+            let edges = [(s - bw)/two, (s + bw)/two];
+            core::array::from_fn(|i| edges[i])
+        }
+    };
+
+    if let Some(t) = t
+    {
+        Ok((n, wn.map(|w| (w*t/two).atan()*two/T::PI()), filter_type))
+    }
+    else
+    {
+        Ok((n, wn, filter_type))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::generators::filter::{legendreord, optimum_l, FilterGenPlane, FilterGenType, LegendreOrdError};
+
+    #[test]
+    fn order()
+    {
+        let (n, wn, filter_type) = legendreord([1.0], [2.0], 1.0, 40.0, FilterGenPlane::S).unwrap();
+        assert_eq!(filter_type, FilterGenType::LowPass);
+
+        // Steeper than Butterworth, which needs order 8.
+        assert_eq!(n, 6);
+
+        // The passband edge is met exactly, and the stopband with margin.
+        let l: Vec<f64> = optimum_l(n);
+        let atten = |w: f64| {
+            let u = (w/wn[0]).powi(2);
+            10.0*(1.0 + l.iter().rev().fold(0.0, |y, &c| y*u + c)).log10()
+        };
+        assert!((atten(1.0) - 1.0).abs() < 1e-9);
+        assert!(atten(2.0) >= 40.0);
+
+        let (_, wn, filter_type) = legendreord([0.2, 0.5], [0.1, 0.6], 3.0, 30.0, FilterGenPlane::Z { sampling_frequency: None }).unwrap();
+        assert_eq!(filter_type, FilterGenType::BandPass);
+        assert!(wn[0] < 0.2 && wn[1] > 0.5);
+    }
+
+    #[test]
+    fn invalid_spec()
+    {
+        for (rp, rs) in [(0.0, 40.0), (f64::NAN, 40.0), (1.0, f64::INFINITY), (-1.0, 40.0)]
+        {
+            assert_eq!(legendreord([1.0], [2.0], rp, rs, FilterGenPlane::S), Err(LegendreOrdError::InvalidRipple));
+        }
+        assert_eq!(legendreord([1.0], [1.001], 1e-9, 300.0, FilterGenPlane::S), Err(LegendreOrdError::OrderTooHigh));
+    }
+}
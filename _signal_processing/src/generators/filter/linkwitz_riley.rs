@@ -0,0 +1,136 @@
+use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float};
+
+use crate::{generators::filter::{Butter, FilterGenError, FilterGenPlane, FilterGenType}, transforms::{filter::Zp2Sos, system::{ToSos, ToTf}}, systems::{Sos, Tf, Zpk}, System};
+
+/// Designs a Linkwitz-Riley crossover, returning the low-pass and high-pass filters.
+///
+/// Each filter is a Butterworth filter of half the order, squared, so both are `-6 dB` at the crossover frequency.
+/// For orders 2, 6, 10, ... the high-pass is inverted, so that for any even order the two outputs sum to an all-pass.
+pub trait LinkwitzRiley: System + Sized
+{
+    fn linkwitz_riley(
+        order: usize,
+        frequency: <Self::Set as ComplexFloat>::Real,
+        plane: FilterGenPlane<<Self::Set as ComplexFloat>::Real>
+    ) -> Result<(Self, Self), FilterGenError>;
+}
+
+impl<T> LinkwitzRiley for Zpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T>
+where
+    T: Float + FloatConst,
+    Self: Butter<usize> + System<Set = T>
+{
+    fn linkwitz_riley(
+        order: usize,
+        frequency: T,
+        plane: FilterGenPlane<T>
+    ) -> Result<(Self, Self), FilterGenError>
+    {
+        if order < 1
+        {
+            return Err(FilterGenError::ZeroOrder)
+        }
+        if order % 2 == 1
+        {
+            return Err(FilterGenError::OddOrder)
+        }
+        let n = order/2;
+
+        let square = |h: Self, invert: bool| {
+            let Zpk { z, p, k } = h;
+            let (z, p) = (z.into_inner(), p.into_inner());
+            Zpk::new(
+                z.iter().chain(z.iter()).copied().collect(),
+                p.iter().chain(p.iter()).copied().collect(),
+                if invert {-k*k} else {k*k}
+            )
+        };
+
+        let lp = Zpk::butter(n, [frequency], FilterGenType::LowPass, plane)?;
+        let hp = Zpk::butter(n, [frequency], FilterGenType::HighPass, plane)?;
+
+        Ok((square(lp, false), square(hp, n % 2 == 1)))
+    }
+}
+
+impl<T> LinkwitzRiley for Tf<T, Vec<T>, Vec<T>>
+where
+    T: Float + FloatConst,
+    Zpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T>: LinkwitzRiley + ToTf<T, Vec<T>, Vec<T>, (), ()> + System<Set = T>
+{
+    fn linkwitz_riley(
+        order: usize,
+        frequency: T,
+        plane: FilterGenPlane<T>
+    ) -> Result<(Self, Self), FilterGenError>
+    {
+        let (lp, hp) = Zpk::linkwitz_riley(order, frequency, plane)?;
+
+        Ok((lp.to_tf((), ()), hp.to_tf((), ())))
+    }
+}
+
+impl<T> LinkwitzRiley for Sos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>>
+where
+    T: Float + FloatConst,
+    Zpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T>: LinkwitzRiley + ToSos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>, (), ()> + Zp2Sos<Output = Self> + System<Set = T>
+{
+    fn linkwitz_riley(
+        order: usize,
+        frequency: T,
+        plane: FilterGenPlane<T>
+    ) -> Result<(Self, Self), FilterGenError>
+    {
+        let (lp, hp) = Zpk::linkwitz_riley(order, frequency, plane)?;
+
+        // The repeated poles are paired into the same sections, which are then the squared Butterworth sections.
+        if let FilterGenPlane::Z { .. } = plane
+        {
+            Ok((super::sos_from_zpk(lp)?, super::sos_from_zpk(hp)?))
+        }
+        else
+        {
+            Ok((lp.to_sos((), ()), hp.to_sos((), ())))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use num::Complex;
+
+    use crate::{generators::filter::{FilterGenError, FilterGenPlane, LinkwitzRiley}, systems::{Sos, Tf}};
+
+    #[test]
+    fn all_pass_sum()
+    {
+        let fs = 48000.0;
+        let fc = 2000.0;
+        let plane = FilterGenPlane::Z { sampling_frequency: Some(fs) };
+        let eval = |h: &Tf<f64, Vec<f64>, Vec<f64>>, zi: Complex<f64>| {
+            let p = |c: &[f64]| c.iter().rev().fold(Complex::from(0.0), |y, &c| y*zi + c);
+            p(&h.b)/p(&h.a)
+        };
+
+        for order in [2, 4, 8]
+        {
+            let (lp, hp) = Tf::linkwitz_riley(order, fc, plane).unwrap();
+            for f in [0.0, 100.0, 1000.0, fc, 5000.0, 20000.0]
+            {
+                let zi = Complex::cis(-2.0*core::f64::consts::PI*f/fs);
+                let (h_lp, h_hp) = (eval(&lp, zi), eval(&hp, zi));
+                assert!(((h_lp + h_hp).norm() - 1.0).abs() < 1e-9, "{order}: {f}");
+                if f == fc
+                {
+                    assert!((h_lp.norm() - 0.5).abs() < 1e-9 && (h_hp.norm() - 0.5).abs() < 1e-9);
+                }
+            }
+
+            let (lp, _): (Sos<f64, _, _, _>, _) = Sos::linkwitz_riley(order, fc, plane).unwrap();
+            assert_eq!(lp.sos.len(), order/2);
+        }
+
+        assert_eq!(Tf::linkwitz_riley(3, fc, plane), Err(FilterGenError::OddOrder));
+    }
+}
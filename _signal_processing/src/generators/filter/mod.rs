@@ -1,6 +1,8 @@
 use num::Float;
 use thiserror::Error;
 
+use crate::{systems::ComplexRealError, transforms::filter::{SosOrder, SosScale, Zp2Sos}};
+
 moddef::moddef!(
     flat(pub) mod {
         besselap,
@@ -14,25 +16,36 @@ moddef::moddef!(
         cheb2ord,
         cheby1,
         cheby2,
+        designfilt,
         ellip,
         ellipap,
         ellipord,
         fir1,
         fir2,
+        fircls,
         firgr,
+        firlpnorm,
         firls,
         firpm,
         firpmord,
         gammatone_fir,
         gammatone_iir,
+        gaussap,
         iir_comb,
         iir_design,
         iir_notch,
         iir_peak,
         kaiserord,
+        lagrange_delay,
+        legendreap,
+        legendreord,
+        linkwitz_riley,
         pei_tseng_notch,
         qp_kaiser,
-        sgolay
+        rbj_biquad,
+        sgolay,
+        sinc_delay,
+        thiran
     }
 );
 
@@ -69,12 +82,27 @@ pub enum FilterGenError
 {
     #[error("Filter order must be at least 1.")]
     ZeroOrder,
+    #[error("Filter order must be even.")]
+    OddOrder,
     #[error("Frequencies must be monotonic starting at zero.")]
     FrequenciesNotNondecreasing,
     #[error("Frequencies must be positive, and if the filter is digital; less than 1/2 the sampling frequency, or if no sampling frequency is specified, between 0 and 1.")]
     FrequenciesOutOfRange,
     #[error("List of frequencies and list of magnitudes must have equal length.")]
     FrequenciesAndMagnitudesDifferentLength,
+    #[error(transparent)]
+    ComplexReal(#[from] ComplexRealError),
+}
+
+/// Splits a digital design into second-order sections.
+///
+/// Poles are paired with their nearest zeros, and the sections are ordered by increasing pole radius, the same way as scipy's `output='sos'`.
+pub(crate) fn sos_from_zpk<S>(sys: S) -> Result<S::Output, FilterGenError>
+where
+    S: Zp2Sos<Set: Float>
+{
+    let (sos, _) = sys.zp2sos(SosOrder::Up, SosScale::None)?;
+    Ok(sos)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Error)]
@@ -88,4 +116,68 @@ pub enum FilterBandError
     InvalidSamplingFrequency,
     #[error("One band must surround the other.")]
     BandNotSurrounding,
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+pub enum FirClsError
+{
+    #[error(transparent)]
+    FilterBand(#[from] FilterBandError),
+    #[error("Band edges must span from zero to the Nyquist frequency.")]
+    EdgesNotSpanning,
+    #[error("Each band must have lower bound < magnitude < upper bound.")]
+    InvalidBounds,
+    #[error("Only low-pass and high-pass filters can be designed this way.")]
+    InvalidFilterType,
+    #[error("The constraints could not be met.")]
+    NoConvergence
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+pub enum FirLpNormError
+{
+    #[error(transparent)]
+    FilterBand(#[from] FilterBandError),
+    #[error("The norm must be finite and at least 2.")]
+    InvalidNorm,
+    #[error("Weights must be positive and finite.")]
+    InvalidWeights,
+    #[error("The weighted least-squares system could not be solved.")]
+    NoConvergence
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+pub enum FracDelayError
+{
+    #[error("Filter order must be at least 1.")]
+    ZeroOrder,
+    #[error("Delay is out of range for the given order.")]
+    DelayOutOfRange
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+pub enum LegendreOrdError
+{
+    #[error(transparent)]
+    FilterBand(#[from] FilterBandError),
+    #[error("Passband ripple and stopband attenuation must be positive and finite.")]
+    InvalidRipple,
+    #[error("The specification cannot be met by a filter of reasonable order.")]
+    OrderTooHigh
+}
+
+/// Asserts that the group delay of `h` stays within 0.1 samples of `delay` for frequencies up to `bandwidth`·π.
+#[cfg(test)]
+fn assert_group_delay<H>(h: &H, delay: f64, bandwidth: f64)
+where
+    H: for<'a> crate::analysis::GrpDelay<'a, [f64; 1024], [f64; 1024], ()>
+{
+    let (gd, w) = h.grpdelay((), ());
+    for (gd, w) in gd.into_iter().zip(w)
+    {
+        if w <= bandwidth*core::f64::consts::PI
+        {
+            assert!((gd - delay).abs() < 0.1, "{delay}: {gd} at {w}");
+        }
+    }
+}
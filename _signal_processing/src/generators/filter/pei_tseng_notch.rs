@@ -1,7 +1,7 @@
 use core::ops::{DivAssign, MulAssign};
 
 use ndarray::{Array1, Array2};
-use ndarray_linalg::{Lapack, Solve};
+use crate::util::{Lapack, Solve};
 use num::{complex::ComplexFloat, traits::FloatConst, Float};
 use option_trait::Maybe;
 use thiserror::Error;
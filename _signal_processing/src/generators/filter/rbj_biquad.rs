@@ -0,0 +1,365 @@
+use num::{complex::ComplexFloat, traits::FloatConst, Float};
+use thiserror::Error;
+
+use crate::{generators::filter::FilterGenPlane, systems::{Sos, Tf}, System};
+
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+pub enum BiquadError
+{
+    #[error("Frequency must be positive, and if the filter is digital; less than 1/2 the sampling frequency, or if no sampling frequency is specified, between 0 and 1.")]
+    FrequencyOutOfRange,
+    #[error("Sampling frequency must be a positive number.")]
+    InvalidSamplingFrequency,
+    #[error("Quality factor, bandwidth and slope must be positive, and the slope must not be too steep for the gain.")]
+    InvalidWidth,
+    #[error("Shelf slope can only be used with shelving filters.")]
+    SlopeNotShelf
+}
+
+/// How the width of a biquad is specified.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BiquadWidth<T>
+where
+    T: Float
+{
+    /// The quality factor.
+    Q(T),
+    /// The bandwidth in octaves, between the -3 dB points for band filters, or the midpoint gain for peaking and shelving filters.
+    Bandwidth(T),
+    /// The shelf slope, where `1` is the steepest slope which is still monotonic. Only for shelving filters.
+    Slope(T)
+}
+
+/// The biquads of the Audio EQ Cookbook by Robert Bristow-Johnson.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BiquadShape<T>
+where
+    T: Float
+{
+    LowPass {
+        width: BiquadWidth<T>
+    },
+    HighPass {
+        width: BiquadWidth<T>
+    },
+    /// Band-pass with constant skirt gain, where the peak gain is `Q`.
+    BandPassSkirt {
+        width: BiquadWidth<T>
+    },
+    /// Band-pass with a constant peak gain of 0 dB.
+    BandPassPeak {
+        width: BiquadWidth<T>
+    },
+    Notch {
+        width: BiquadWidth<T>
+    },
+    AllPass {
+        width: BiquadWidth<T>
+    },
+    Peaking {
+        gain_db: T,
+        width: BiquadWidth<T>
+    },
+    LowShelf {
+        gain_db: T,
+        width: BiquadWidth<T>
+    },
+    HighShelf {
+        gain_db: T,
+        width: BiquadWidth<T>
+    }
+}
+
+/// One band of a parametric equalizer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EqBand<T>
+where
+    T: Float
+{
+    pub shape: BiquadShape<T>,
+    pub frequency: T
+}
+
+impl<T> EqBand<T>
+where
+    T: Float
+{
+    /// The bands of a graphic equalizer, as peaking filters at the given center frequencies.
+    ///
+    /// Each band is as wide as the average spacing to its neighbours, in octaves, so that adjacent bands meet at their midpoint gains.
+    pub fn graphic(frequencies: &[T], gains_db: &[T]) -> Vec<Self>
+    {
+        assert_eq!(frequencies.len(), gains_db.len(), "There must be one gain per band.");
+        let one = T::one();
+        let two = one + one;
+        let octaves = |f1: T, f2: T| (f2/f1).abs().log2();
+        let n = frequencies.len();
+        (0..n).map(|i| {
+                let bw = match (i.checked_sub(1).map(|j| frequencies[j]), frequencies.get(i + 1))
+                {
+                    (Some(fl), Some(&fh)) => octaves(fl, fh)/two,
+                    (Some(fl), None) => octaves(fl, frequencies[i]),
+                    (None, Some(&fh)) => octaves(frequencies[i], fh),
+                    (None, None) => one
+                };
+                Self {
+                    shape: BiquadShape::Peaking {
+                        gain_db: gains_db[i],
+                        width: BiquadWidth::Bandwidth(bw)
+                    },
+                    frequency: frequencies[i]
+                }
+            }).collect()
+    }
+}
+
+/// Designs a biquad from the Audio EQ Cookbook.
+///
+/// The cookbook designs are second-order analog prototypes, tuned to the given frequency.
+/// For an analog filter, the frequency is in radians per second.
+/// For a digital filter, the prototype is mapped by the bilinear transform, prewarped at the given frequency.
+pub trait RbjBiquad: System + Sized
+{
+    fn rbj_biquad(
+        shape: BiquadShape<<Self::Set as ComplexFloat>::Real>,
+        frequency: <Self::Set as ComplexFloat>::Real,
+        plane: FilterGenPlane<<Self::Set as ComplexFloat>::Real>
+    ) -> Result<Self, BiquadError>;
+}
+
+impl<T> RbjBiquad for Tf<T, [T; 3], [T; 3]>
+where
+    T: Float + FloatConst
+{
+    fn rbj_biquad(
+        shape: BiquadShape<T>,
+        frequency: T,
+        plane: FilterGenPlane<T>
+    ) -> Result<Self, BiquadError>
+    {
+        let zero = T::zero();
+        let one = T::one();
+        let two = one + one;
+
+        // The normalized frequency, and the warping of bandwidths, of the digital filter.
+        let digital = if let FilterGenPlane::Z { sampling_frequency } = plane
+        {
+            let fs = sampling_frequency.unwrap_or(two);
+            if !(fs > zero)
+            {
+                return Err(BiquadError::InvalidSamplingFrequency)
+            }
+            if !(zero < frequency && frequency < fs/two)
+            {
+                return Err(BiquadError::FrequencyOutOfRange)
+            }
+            let w0 = two*T::PI()*frequency/fs;
+            Some((w0, w0/w0.sin()))
+        }
+        else
+        {
+            if !(frequency > zero)
+            {
+                return Err(BiquadError::FrequencyOutOfRange)
+            }
+            None
+        };
+
+        let (gain_db, width, shelf) = match shape
+        {
+            BiquadShape::LowPass { width }
+            | BiquadShape::HighPass { width }
+            | BiquadShape::BandPassSkirt { width }
+            | BiquadShape::BandPassPeak { width }
+            | BiquadShape::Notch { width }
+            | BiquadShape::AllPass { width } => (zero, width, false),
+            BiquadShape::Peaking { gain_db, width } => (gain_db, width, false),
+            BiquadShape::LowShelf { gain_db, width }
+            | BiquadShape::HighShelf { gain_db, width } => (gain_db, width, true)
+        };
+        let a = T::from(10.0).unwrap().powf(gain_db/T::from(40.0).unwrap());
+        let sqrt_a = a.sqrt();
+
+        let iq = match width
+        {
+            BiquadWidth::Q(q) => q.recip(),
+            BiquadWidth::Bandwidth(bw) => {
+                let warp = digital.map(|(_, warp)| warp).unwrap_or(one);
+                two*(T::LN_2()/two*bw*warp).sinh()
+            },
+            BiquadWidth::Slope(s) => {
+                if !shelf
+                {
+                    return Err(BiquadError::SlopeNotShelf)
+                }
+                if !(s > zero)
+                {
+                    return Err(BiquadError::InvalidWidth)
+                }
+                ((a + a.recip())*(s.recip() - one) + two).sqrt()
+            }
+        };
+        if !(iq > zero && iq.is_finite())
+        {
+            return Err(BiquadError::InvalidWidth)
+        }
+
+        // The prototype tuned to 1 rad/s, in descending powers of s.
+        let (b, a): ([T; 3], [T; 3]) = match shape
+        {
+            BiquadShape::LowPass { .. } => ([zero, zero, one], [one, iq, one]),
+            BiquadShape::HighPass { .. } => ([one, zero, zero], [one, iq, one]),
+            BiquadShape::BandPassSkirt { .. } => ([zero, one, zero], [one, iq, one]),
+            BiquadShape::BandPassPeak { .. } => ([zero, iq, zero], [one, iq, one]),
+            BiquadShape::Notch { .. } => ([one, zero, one], [one, iq, one]),
+            BiquadShape::AllPass { .. } => ([one, -iq, one], [one, iq, one]),
+            BiquadShape::Peaking { .. } => ([one, iq*a, one], [one, iq/a, one]),
+            BiquadShape::LowShelf { .. } => ([a, a*sqrt_a*iq, a*a], [a, sqrt_a*iq, one]),
+            BiquadShape::HighShelf { .. } => ([a*a, a*sqrt_a*iq, a], [one, sqrt_a*iq, a])
+        };
+
+        if let Some((w0, _)) = digital
+        {
+            // s = k(1 - z⁻¹)/(1 + z⁻¹), with k chosen so that 1 rad/s maps to w0.
+            let k = (w0/two).tan().recip();
+            let k2 = k*k;
+            let bilinear = |[p2, p1, p0]: [T; 3]| [
+                p2*k2 + p1*k + p0,
+                two*(p0 - p2*k2),
+                p2*k2 - p1*k + p0
+            ];
+            let (b, a) = (bilinear(b), bilinear(a));
+            let a0 = a[0];
+            Ok(Tf::new(b.map(|b| b/a0), a.map(|a| a/a0)))
+        }
+        else
+        {
+            let scale = |[p2, p1, p0]: [T; 3]| [p2, p1*frequency, p0*frequency*frequency];
+            Ok(Tf::new(scale(b), scale(a)))
+        }
+    }
+}
+
+impl<T> RbjBiquad for Sos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>>
+where
+    T: Float + FloatConst
+{
+    fn rbj_biquad(
+        shape: BiquadShape<T>,
+        frequency: T,
+        plane: FilterGenPlane<T>
+    ) -> Result<Self, BiquadError>
+    {
+        Ok(Sos::new(vec![Tf::rbj_biquad(shape, frequency, plane)?]))
+    }
+}
+
+/// Cascades the biquads of a parametric or graphic equalizer into second-order sections, one section per band.
+pub trait ParametricEq: System + Sized
+{
+    fn parametric_eq(
+        bands: &[EqBand<<Self::Set as ComplexFloat>::Real>],
+        plane: FilterGenPlane<<Self::Set as ComplexFloat>::Real>
+    ) -> Result<Self, BiquadError>;
+}
+
+impl<T> ParametricEq for Sos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>>
+where
+    T: Float + FloatConst
+{
+    fn parametric_eq(
+        bands: &[EqBand<T>],
+        plane: FilterGenPlane<T>
+    ) -> Result<Self, BiquadError>
+    {
+        Ok(Sos::new(bands.iter()
+            .map(|band| Tf::rbj_biquad(band.shape, band.frequency, plane))
+            .collect::<Result<_, _>>()?
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use num::Complex;
+
+    use crate::{generators::filter::{BiquadError, BiquadShape, BiquadWidth, EqBand, FilterGenPlane, ParametricEq, RbjBiquad}, systems::{Sos, Tf}};
+
+    fn gain_db(h: &Tf<f64, [f64; 3], [f64; 3]>, f: f64, fs: f64) -> f64
+    {
+        let zi = Complex::cis(-2.0*core::f64::consts::PI*f/fs);
+        let eval = |c: &[f64; 3]| c[0] + zi*(c[1] + zi*c[2]);
+        20.0*(eval(&h.b)/eval(&h.a)).norm().log10()
+    }
+
+    #[test]
+    fn cookbook()
+    {
+        let fs = 48000.0;
+        let plane = FilterGenPlane::Z { sampling_frequency: Some(fs) };
+
+        for width in [BiquadWidth::Q(0.7), BiquadWidth::Bandwidth(2.0)]
+        {
+            let h = Tf::rbj_biquad(BiquadShape::Peaking { gain_db: 6.0, width }, 1000.0, plane).unwrap();
+            assert!((gain_db(&h, 1000.0, fs) - 6.0).abs() < 1e-9);
+            assert!(gain_db(&h, 1.0, fs).abs() < 1e-3);
+        }
+
+        let h = Tf::rbj_biquad(BiquadShape::LowShelf { gain_db: -9.0, width: BiquadWidth::Slope(1.0) }, 200.0, plane).unwrap();
+        assert!((gain_db(&h, 0.0, fs) + 9.0).abs() < 1e-9);
+        assert!((gain_db(&h, 200.0, fs) + 4.5).abs() < 1e-9);
+        assert!(gain_db(&h, fs/2.0, fs).abs() < 1e-9);
+
+        let h = Tf::rbj_biquad(BiquadShape::HighShelf { gain_db: 4.0, width: BiquadWidth::Q(0.5) }, 5000.0, plane).unwrap();
+        assert!(gain_db(&h, 0.0, fs).abs() < 1e-9);
+        assert!((gain_db(&h, fs/2.0, fs) - 4.0).abs() < 1e-9);
+
+        let h = Tf::rbj_biquad(BiquadShape::LowPass { width: BiquadWidth::Q(2.0) }, 3000.0, plane).unwrap();
+        assert!((gain_db(&h, 3000.0, fs) - 20.0*2f64.log10()).abs() < 1e-9);
+
+        let h = Tf::rbj_biquad(BiquadShape::BandPassPeak { width: BiquadWidth::Q(4.0) }, 3000.0, plane).unwrap();
+        assert!(gain_db(&h, 3000.0, fs).abs() < 1e-9);
+
+        let h = Tf::rbj_biquad(BiquadShape::AllPass { width: BiquadWidth::Q(1.0) }, 3000.0, plane).unwrap();
+        assert!((0..24).all(|i| gain_db(&h, i as f64*1000.0, fs).abs() < 1e-9));
+
+        // Analog prototypes are tuned in radians per second.
+        let h = Tf::rbj_biquad(BiquadShape::HighPass { width: BiquadWidth::Q(1.0) }, 10.0, FilterGenPlane::S).unwrap();
+        assert_eq!((*h.b, *h.a), ([1.0, 0.0, 0.0], [1.0, 10.0, 100.0]));
+
+        assert_eq!(
+            Tf::rbj_biquad(BiquadShape::Notch { width: BiquadWidth::Slope(1.0) }, 1000.0, plane),
+            Err(BiquadError::SlopeNotShelf)
+        );
+        assert_eq!(
+            Tf::rbj_biquad(BiquadShape::Notch { width: BiquadWidth::Q(1.0) }, 30000.0, plane),
+            Err(BiquadError::FrequencyOutOfRange)
+        );
+    }
+
+    #[test]
+    fn graphic_eq()
+    {
+        let fs = 48000.0;
+        let f = [125.0, 250.0, 500.0, 1000.0, 2000.0, 4000.0, 8000.0];
+        let g = [3.0, 0.0, -2.0, 0.0, 0.0, 4.0, 0.0];
+        let bands = EqBand::graphic(&f, &g);
+        for (band, (&f, &g)) in bands.iter().zip(f.iter().zip(g.iter()))
+        {
+            assert_eq!(*band, EqBand { shape: BiquadShape::Peaking { gain_db: g, width: BiquadWidth::Bandwidth(1.0) }, frequency: f });
+        }
+
+        let sos: Sos<f64, _, _, _> = Sos::parametric_eq(&bands, FilterGenPlane::Z { sampling_frequency: Some(fs) }).unwrap();
+        assert_eq!(sos.sos.len(), f.len());
+
+        // Bands an octave apart barely interact, so the cascade is close to each gain at its center.
+        for (&f, &g) in f.iter().zip(g.iter())
+        {
+            let total: f64 = sos.sos.iter()
+                .map(|h| gain_db(h, f, fs))
+                .sum();
+            assert!((total - g).abs() < 1.0, "{f}: {total} vs {g}");
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use core::ops::{Deref, DerefMut, Mul, MulAssign};
 
 use ndarray::Array2;
-use ndarray_linalg::Lapack;
+use crate::util::Lapack;
 use num::{complex::ComplexFloat, Float, NumCast};
 use option_trait::{Maybe, PureMaybe, PureStaticMaybe, StaticMaybe, ops::MaybeAnd};
 use thiserror::Error;
@@ -32,7 +32,7 @@ where
 
 impl<L, T, B, N, NN> SGolay<L, N> for Tf<T, B>
 where
-    T: ComplexFloat<Real: Lapack<Real = <T as ComplexFloat>::Real> + Into<T>> + Mul<<T as ComplexFloat>::Real, Output = T> + MulAssign,
+    T: ComplexFloat<Real: Lapack + Into<T>> + Mul<<T as ComplexFloat>::Real, Output = T> + MulAssign,
     Vec<T>: TryInto<B>,
     Vec<Tf<T, B>>: TryInto<L>,
     L: OwnedList<Tf<T, B>> + MaybeLenEq<B, true>,
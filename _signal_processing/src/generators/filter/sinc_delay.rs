@@ -0,0 +1,78 @@
+use num::{complex::ComplexFloat, traits::FloatConst, Float};
+
+use crate::{generators::filter::FracDelayError, systems::Tf, util, System};
+
+/// Designs a windowed-sinc FIR filter with a given fractional delay, in samples.
+///
+/// The ideal impulse response `sinc(n - D)` is truncated to `order + 1` taps by a Kaiser window with shape parameter `beta`, centered at the delay, and normalized to unity gain at DC.
+/// The delay must be in `[0, order]`, and works best within half a sample of `order/2`.
+/// There, with `beta = 4`, the group delay stays within 0.1 samples of the delay up to about 0.8π for order 15 and 0.9π for order 31.
+pub trait SincDelay: System + Sized
+{
+    fn sinc_delay(
+        delay: <Self::Set as ComplexFloat>::Real,
+        order: usize,
+        beta: <Self::Set as ComplexFloat>::Real
+    ) -> Result<Self, FracDelayError>;
+}
+
+impl<T> SincDelay for Tf<T, Vec<T>, ()>
+where
+    T: Float + FloatConst
+{
+    fn sinc_delay(delay: T, order: usize, beta: T) -> Result<Self, FracDelayError>
+    {
+        if order < 1
+        {
+            return Err(FracDelayError::ZeroOrder)
+        }
+        let zero = T::zero();
+        let one = T::one();
+        if !(delay >= zero && delay <= T::from(order).unwrap())
+        {
+            return Err(FracDelayError::DelayOutOfRange)
+        }
+
+        let half_width = T::from(order + 1).unwrap()/(one + one);
+        let mut b: Vec<T> = (0..=order).map(|n| {
+                let t = T::from(n).unwrap() - delay;
+                let u = t/half_width;
+                let w = if u.abs() <= one
+                {
+                    util::i0(beta*(one - u*u).sqrt())/util::i0(beta)
+                }
+                else
+                {
+                    zero
+                };
+                let sinc = if t == zero {one} else {(T::PI()*t).sin()/(T::PI()*t)};
+                sinc*w
+            }).collect();
+        let dc = b.iter()
+            .fold(zero, |s, &b| s + b);
+        for b in b.iter_mut()
+        {
+            *b = *b/dc
+        }
+        Ok(Tf::new(b, ()))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{generators::filter::{assert_group_delay, SincDelay}, systems::Tf};
+
+    #[test]
+    fn flat_group_delay()
+    {
+        for (order, bandwidth) in [(15, 0.8), (31, 0.9)]
+        {
+            for delay in [order as f64/2.0 - 0.4, order as f64/2.0, order as f64/2.0 + 0.3]
+            {
+                let h = Tf::sinc_delay(delay, order, 4.0).unwrap();
+                assert_group_delay(&h, delay, bandwidth);
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use num::{complex::ComplexFloat, Float};
+
+use crate::{generators::filter::FracDelayError, systems::Tf, System};
+
+/// Designs a Thiran all-pass filter with a given fractional delay, in samples.
+///
+/// The group delay is maximally flat at DC. The filter is stable for delays greater than `order - 1`, and works best with delays in `[order - 0.5, order + 0.5)`.
+/// In that range, the group delay stays within 0.1 samples of the delay up to about 0.3π for order 2, 0.45π for order 4 and 0.55π for order 8.
+pub trait Thiran: System + Sized
+{
+    fn thiran(delay: <Self::Set as ComplexFloat>::Real, order: usize) -> Result<Self, FracDelayError>;
+}
+
+impl<T> Thiran for Tf<T, Vec<T>, Vec<T>>
+where
+    T: Float
+{
+    fn thiran(delay: T, order: usize) -> Result<Self, FracDelayError>
+    {
+        if order < 1
+        {
+            return Err(FracDelayError::ZeroOrder)
+        }
+        let n = T::from(order).unwrap();
+        if !(delay > n - T::one()) || !delay.is_finite()
+        {
+            return Err(FracDelayError::DelayOutOfRange)
+        }
+
+        // aₖ = (-1)ᵏ C(N, k) ∏ (D - N + i)/(D - N + k + i), for i = 0..=N
+        let mut binom = T::one();
+        let a: Vec<T> = (0..=order).map(|k| {
+                if k > 0
+                {
+                    binom = binom*T::from(order + 1 - k).unwrap()/T::from(k).unwrap();
+                }
+                let kf = T::from(k).unwrap();
+                let p = (0..=order).map(|i| T::from(i).unwrap())
+                    .fold(T::one(), |p, i| if k == 0 {p} else {p*(delay - n + i)/(delay - n + kf + i)});
+                if k % 2 == 1 {-binom*p} else {binom*p}
+            }).collect();
+
+        let b = a.iter()
+            .rev()
+            .copied()
+            .collect();
+        Ok(Tf::new(b, a))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{generators::filter::{assert_group_delay, FracDelayError, Thiran}, systems::Tf};
+
+    #[test]
+    fn flat_group_delay()
+    {
+        for (order, bandwidth) in [(2, 0.3), (4, 0.45), (8, 0.55)]
+        {
+            for delay in [order as f64 - 0.4, order as f64, order as f64 + 0.3]
+            {
+                let h = Tf::thiran(delay, order).unwrap();
+                assert!(h.a.iter().rev().zip(h.b.iter()).all(|(a, b)| a == b));
+
+                assert_group_delay(&h, delay, bandwidth);
+            }
+        }
+
+        assert_eq!(Tf::<f64, Vec<_>, Vec<_>>::thiran(0.5, 2), Err(FracDelayError::DelayOutOfRange));
+    }
+}
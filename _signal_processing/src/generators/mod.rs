@@ -1,6 +1,7 @@
 moddef::moddef!(
     pub mod {
         bspline,
+        control,
         filter,
         matrix,
         pulse,
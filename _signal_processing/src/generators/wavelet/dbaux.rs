@@ -1,6 +1,6 @@
 use core::ops::{DivAssign, MulAssign};
 
-use ndarray_linalg::Lapack;
+use crate::util::Lapack;
 use num::{traits::{float::TotalOrder, FloatConst}, Complex, Float, One};
 use option_trait::Maybe;
 
@@ -12,7 +12,7 @@ moddef::moddef!(
 
 pub fn dbaux<T, S>(order: usize, scale: S) -> Vec<T>
 where
-    T: Float + FloatConst + MulAssign + DivAssign + TotalOrder + Lapack<Complex = Complex<T>>,
+    T: Float + FloatConst + MulAssign + DivAssign + TotalOrder + Lapack,
     S: Maybe<T>
 {
     let one = T::one();
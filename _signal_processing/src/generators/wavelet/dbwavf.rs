@@ -1,12 +1,12 @@
 use core::ops::{DivAssign, MulAssign};
 
-use ndarray_linalg::Lapack;
+use crate::util::Lapack;
 use num::{traits::{float::TotalOrder, FloatConst}, Complex, Float};
 use option_trait::Maybe;
 
 pub fn dbwavf<T>(order: usize) -> Vec<T>
 where
-    T: Float + FloatConst + MulAssign + DivAssign + TotalOrder + Lapack<Complex = Complex<T>>,
+    T: Float + FloatConst + MulAssign + DivAssign + TotalOrder + Lapack,
     (): Maybe<T>
 {
     crate::generators::wavelet::dbaux(order, ())
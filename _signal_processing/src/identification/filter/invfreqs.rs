@@ -1,6 +1,6 @@
 use core::ops::{AddAssign, Mul, MulAssign};
 
-use ndarray_linalg::{Lapack, QRInto, SVDInto, Solve, SVD};
+use crate::util::{Lapack, QRInto, SVDInto, Solve, SVD};
 use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float, One};
 use option_trait::{Maybe, StaticMaybe};
 use ndarray::{Array1, Array2};
@@ -3,7 +3,7 @@ use core::ops::{AddAssign, Mul, MulAssign};
 use ndarray::{Array1, Array2};
 use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float, One};
 use option_trait::{Maybe, StaticMaybe};
-use ndarray_linalg::{qr::QRInto, Lapack, svd::{SVDInto, SVD}, solve::Solve};
+use crate::util::{QRInto, Lapack, SVDInto, SVD, Solve};
 
 use crate::{quantities::{List, MaybeList}, System, systems::Tf, util::TruncateIm};
 
@@ -0,0 +1,248 @@
+use ndarray::{s, Array2};
+use num::{complex::ComplexFloat, Float, NumCast, One, Zero};
+use thiserror::Error;
+
+use crate::{analysis::control::{gram, GramType}, operations::minreal::conj_t, systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix}, transforms::system::ToSs, util::{EigVals, Inverse, Lapack, LinalgError, SVD}, Plane, System};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BalRedMethod
+{
+    /// Discards the weakest states of the balanced realization.
+    #[default]
+    Truncate,
+    /// Sets the derivatives of the weakest states to zero (singular perturbation), which keeps the DC gain exact.
+    MatchDc
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum BalRedError
+{
+    #[error("The system must be stable for its Gramians to exist.")]
+    Unstable,
+    #[error("The reduced order cannot exceed the number of states.")]
+    OrderTooHigh,
+    #[error(transparent)]
+    Linalg(#[from] LinalgError)
+}
+
+/// The Hankel singular values of a stable state-space system, in descending order.
+///
+/// They measure how much each state of the balanced realization contributes to the input-output behaviour,
+/// and are a guide for choosing the order of a reduced model.
+pub trait Hsvd: System
+{
+    fn hsvd(self, plane: Plane) -> Result<Vec<<Self::Set as ComplexFloat>::Real>, BalRedError>;
+}
+
+/// Model-order reduction by balanced truncation or singular perturbation.
+///
+/// Returns the reduced system, the Hankel singular values of the original system, and the bound `2(σᵣ₊₁ + ... + σₙ)`
+/// on the H∞-norm of the error between the two.
+pub trait BalRed: System
+{
+    type Output: System<Set = Self::Set>;
+
+    fn balred(self, order: usize, plane: Plane, method: BalRedMethod) -> Result<(Self::Output, Vec<<Self::Set as ComplexFloat>::Real>, <Self::Set as ComplexFloat>::Real), BalRedError>;
+}
+
+impl<T, A, B, C, D> Hsvd for Ss<T, A, B, C, D>
+where
+    T: Lapack,
+    A: SsAMatrix<T, B, C, D>,
+    B: SsBMatrix<T, A, C, D>,
+    C: SsCMatrix<T, A, B, D>,
+    D: SsDMatrix<T, A, B, C>,
+    Self: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    fn hsvd(self, plane: Plane) -> Result<Vec<T::Real>, BalRedError>
+    {
+        let Ss {a, b, c, ..} = self.to_ss();
+        let (_, _, hsv) = balance(&a, &b, &c, plane)?;
+        Ok(hsv)
+    }
+}
+
+impl<T, A, B, C, D> BalRed for Ss<T, A, B, C, D>
+where
+    T: Lapack,
+    A: SsAMatrix<T, B, C, D>,
+    B: SsBMatrix<T, A, C, D>,
+    C: SsCMatrix<T, A, B, D>,
+    D: SsDMatrix<T, A, B, C>,
+    Self: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    type Output = Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>;
+
+    fn balred(self, order: usize, plane: Plane, method: BalRedMethod) -> Result<(Self::Output, Vec<T::Real>, T::Real), BalRedError>
+    {
+        let Ss {a, b, c, d, ..} = self.to_ss();
+        if order > a.nrows()
+        {
+            return Err(BalRedError::OrderTooHigh)
+        }
+
+        let (t, tinv, hsv) = balance(&a, &b, &c, plane)?;
+        let two = T::Real::one() + T::Real::one();
+        let bound = hsv.iter()
+            .skip(order)
+            .fold(T::Real::zero(), |s, &h| s + h)*two;
+
+        // States with zero Hankel singular value are already dropped by the balancing, so the order may exceed what is left.
+        let a = tinv.dot(&a).dot(&t);
+        let b = tinv.dot(&b);
+        let c = c.dot(&t);
+        let r = order.min(a.nrows());
+
+        let (a11, a12, a21, a22) = (
+            a.slice(s![..r, ..r]),
+            a.slice(s![..r, r..]),
+            a.slice(s![r.., ..r]),
+            a.slice(s![r.., r..])
+        );
+        let (b1, b2) = (b.slice(s![..r, ..]), b.slice(s![r.., ..]));
+        let (c1, c2) = (c.slice(s![.., ..r]), c.slice(s![.., r..]));
+
+        let sys = match method
+        {
+            BalRedMethod::Truncate => Ss::new(a11.to_owned(), b1.to_owned(), c1.to_owned(), d),
+            BalRedMethod::MatchDc => {
+                // Continuous: the fast states satisfy 0 = A₂₁x₁ + A₂₂x₂ + B₂u.
+                // Discrete: they satisfy x₂ = A₂₁x₁ + A₂₂x₂ + B₂u.
+                let m = match plane
+                {
+                    Plane::S => a22.map(|&a| -a),
+                    Plane::Z => Array2::eye(a22.nrows()) - a22
+                }.inv()?;
+                let m_a21 = m.dot(&a21);
+                let m_b2 = m.dot(&b2);
+                Ss::new(
+                    &a11 + &a12.dot(&m_a21),
+                    &b1 + &a12.dot(&m_b2),
+                    &c1 + &c2.dot(&m_a21),
+                    d + c2.dot(&m_b2)
+                )
+            }
+        };
+
+        Ok((sys, hsv, bound))
+    }
+}
+
+/// The square-root balancing transformation.
+///
+/// Returns `(T, T⁻¹, σ)` where `T⁻¹AT`, `T⁻¹B` and `CT` is the balanced realization and `σ` are the Hankel singular values.
+/// Only states with nonzero Hankel singular values are kept, so `T` is `n×k` with `k ≤ n`.
+fn balance<T>(a: &Array2<T>, b: &Array2<T>, c: &Array2<T>, plane: Plane) -> Result<(Array2<T>, Array2<T>, Vec<T::Real>), BalRedError>
+where
+    T: Lapack
+{
+    let n = a.nrows();
+    if n == 0
+    {
+        return Ok((Array2::zeros((0, 0)), Array2::zeros((0, 0)), vec![]))
+    }
+
+    let stable = a.eigvals()?
+        .iter()
+        .all(|p| match plane
+        {
+            Plane::S => p.re < Zero::zero(),
+            Plane::Z => p.norm() < One::one()
+        });
+    if !stable
+    {
+        return Err(BalRedError::Unstable)
+    }
+
+    let wc = gram(a, b, c, GramType::Controllability, plane)?;
+    let wo = gram(a, b, c, GramType::Observability, plane)?;
+
+    let lc = sqrt_factor(wc)?;
+    let lo = sqrt_factor(wo)?;
+    let loh = conj_t(&lo);
+
+    let (u, sigma, vh) = loh.dot(&lc).svd(true, true)?;
+    let (u, vh) = (u.unwrap(), vh.unwrap());
+    let hsv = sigma.to_vec();
+
+    let threshold = hsv.first()
+        .copied()
+        .unwrap_or_else(Zero::zero)*T::Real::epsilon()*<T::Real as NumCast>::from(n).unwrap();
+    let k = hsv.iter()
+        .take_while(|&&s| s > threshold)
+        .count();
+    let scale: Vec<T> = hsv[..k].iter()
+        .map(|&s| Float::sqrt(s).recip().into())
+        .collect();
+
+    let mut t = lc.dot(&conj_t(&vh.slice(s![..k, ..]).to_owned()));
+    let mut tinv = conj_t(&u.slice(s![.., ..k]).to_owned()).dot(&loh);
+    for (j, &s) in scale.iter()
+        .enumerate()
+    {
+        t.column_mut(j).map_inplace(|t| *t = *t*s);
+        tinv.row_mut(j).map_inplace(|t| *t = *t*s);
+    }
+
+    Ok((t, tinv, hsv))
+}
+
+/// A factor `L` with `W = LLᴴ` for a Hermitian positive semidefinite `W`, which need not be invertible.
+fn sqrt_factor<T>(w: Array2<T>) -> Result<Array2<T>, LinalgError>
+where
+    T: Lapack
+{
+    let (u, sigma, _) = w.svd(true, false)?;
+    let mut l = u.unwrap();
+    for (mut col, &s) in l.columns_mut()
+        .into_iter()
+        .zip(sigma.iter())
+    {
+        let s: T = Float::sqrt(s).into();
+        col.map_inplace(|l| *l = *l*s);
+    }
+    Ok(l)
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::{array, Array2};
+
+    use crate::{operations::{BalRed, BalRedMethod, Hsvd}, systems::Ss, Plane};
+
+    fn plant() -> Ss<f64, Array2<f64>, Array2<f64>, Array2<f64>, Array2<f64>>
+    {
+        // 1/(s + 1) + 0.01/(s + 20)
+        Ss::new(
+            array![[-1.0, 0.0], [0.0, -20.0]],
+            array![[1.0], [0.1]],
+            array![[1.0, 0.1]],
+            array![[0.0]]
+        )
+    }
+
+    #[test]
+    fn hsvd()
+    {
+        let hsv = plant().hsvd(Plane::S).unwrap();
+
+        assert_eq!(hsv.len(), 2);
+        assert!(hsv[0] > hsv[1] && hsv[1] > 0.0);
+    }
+
+    #[test]
+    fn balred()
+    {
+        let (h, hsv, bound) = plant().balred(1, Plane::S, BalRedMethod::Truncate).unwrap();
+        assert_eq!(h.a.dim(), (1, 1));
+        assert!((bound - 2.0*hsv[1]).abs() < 1e-15);
+
+        // Singular perturbation keeps the DC gain, which is 1 + 0.01/20.
+        let (h, _, _) = plant().balred(1, Plane::S, BalRedMethod::MatchDc).unwrap();
+        let dc = h.d[(0, 0)] - h.c[(0, 0)]*h.b[(0, 0)]/h.a[(0, 0)];
+        assert!((dc - 1.0005).abs() < 1e-9);
+    }
+}
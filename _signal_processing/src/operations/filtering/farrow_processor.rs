@@ -0,0 +1,132 @@
+use num::complex::ComplexFloat;
+
+/// A Farrow structure for fractional delays which may change every sample.
+///
+/// Each tap of a Lagrange interpolator is a polynomial in the delay, so the filter is realized as a bank of fixed FIR branches, whose outputs are combined by Horner's rule in the delay.
+/// For any fixed delay, the output is identical to filtering with [`LagrangeDelay`](crate::generators::filter::LagrangeDelay) of the same order.
+/// The delay should be in `[0, order]`, and works best within half a sample of `order/2`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FarrowProcessor<W>
+where
+    W: ComplexFloat
+{
+    /// Branch coefficients, where `c[m][n]` is the coefficient of `dᵐ` in tap `n`.
+    c: Vec<Vec<W>>,
+    w: Vec<W>
+}
+
+impl<W> FarrowProcessor<W>
+where
+    W: ComplexFloat,
+    W::Real: Into<W>
+{
+    pub fn new(order: usize) -> Self
+    {
+        let zero = W::Real::zero();
+        let one = W::Real::one();
+        let mut c = vec![vec![W::zero(); order + 1]; order + 1];
+        for n in 0..=order
+        {
+            // ∏ (d - k)/(n - k), for k ≠ n, in ascending powers of d.
+            let mut p = vec![one];
+            for k in (0..=order).filter(|&k| k != n)
+            {
+                let (k, nf) = (W::Real::from(k).unwrap(), W::Real::from(n).unwrap());
+                let mut q = vec![zero; p.len() + 1];
+                for (i, &p) in p.iter().enumerate()
+                {
+                    q[i + 1] = q[i + 1] + p/(nf - k);
+                    q[i] = q[i] - p*k/(nf - k);
+                }
+                p = q;
+            }
+            for (m, p) in p.into_iter().enumerate()
+            {
+                c[m][n] = p.into()
+            }
+        }
+        Self {
+            c,
+            w: vec![W::zero(); order + 1]
+        }
+    }
+
+    pub fn order(&self) -> usize
+    {
+        self.w.len() - 1
+    }
+
+    /// Delays one sample by `delay` samples.
+    pub fn process_sample<X>(&mut self, x: X, delay: W::Real) -> W
+    where
+        X: Into<W>
+    {
+        self.w.rotate_right(1);
+        self.w[0] = x.into();
+        let d: W = delay.into();
+        self.c.iter()
+            .rev()
+            .map(|c| c.iter()
+                .zip(self.w.iter())
+                .fold(W::zero(), |v, (&c, &w)| v + c*w)
+            ).fold(W::zero(), |y, v| y*d + v)
+    }
+
+    /// Delays `x` into `y` by a per-sample delay. All must have the same length.
+    pub fn process_block<X>(&mut self, x: &[X], delay: &[W::Real], y: &mut [W])
+    where
+        X: Into<W> + Copy
+    {
+        assert_eq!(x.len(), y.len(), "Input and output blocks must have equal length.");
+        assert_eq!(x.len(), delay.len(), "There must be one delay per sample.");
+        for ((&x, &delay), y) in x.iter()
+            .zip(delay.iter())
+            .zip(y.iter_mut())
+        {
+            *y = self.process_sample(x, delay)
+        }
+    }
+
+    pub fn reset(&mut self)
+    {
+        self.w.fill(W::zero())
+    }
+
+    /// The last `order + 1` input samples, most recent first.
+    pub fn state(&self) -> &[W]
+    {
+        &self.w
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{generators::filter::LagrangeDelay, operations::filtering::{FarrowProcessor, Filter}, systems::Tf};
+
+    #[test]
+    fn matches_lagrange()
+    {
+        let x: Vec<f64> = (0..64).map(|i| ((i*7 % 11) as f64 - 5.0)/5.0).collect();
+
+        let mut p = FarrowProcessor::new(3);
+        let y_ref = Tf::lagrange_delay(1.3, 3).unwrap().filter(x.clone(), ());
+        let mut y = vec![0.0; x.len()];
+        p.process_block(&x, &vec![1.3; x.len()], &mut y);
+        for (y, y_ref) in y.iter().zip(y_ref.iter())
+        {
+            assert!((y - y_ref).abs() < 1e-12);
+        }
+
+        // With a varying delay, each output uses the taps for its own delay.
+        p.reset();
+        let delay: Vec<f64> = (0..x.len()).map(|i| 1.0 + (i as f64/10.0).sin()).collect();
+        p.process_block(&x, &delay, &mut y);
+        for (n, (&delay, &y)) in delay.iter().zip(y.iter()).enumerate().skip(3)
+        {
+            let h = Tf::lagrange_delay(delay, 3).unwrap();
+            let y_ref: f64 = h.b.iter().enumerate().map(|(k, &h)| h*x[n - k]).sum();
+            assert!((y - y_ref).abs() < 1e-12);
+        }
+    }
+}
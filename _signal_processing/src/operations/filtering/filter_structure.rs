@@ -0,0 +1,228 @@
+use num::complex::ComplexFloat;
+use option_trait::Maybe;
+
+use crate::{quantities::{List, MaybeList, MaybeOwnedList}, operations::filtering::{SosProcessor, TfProcessor}, systems::{Sos, Tf}, transforms::filter::{latc, LatcError}, System};
+
+/// The realization used when running a recursive filter.
+///
+/// All structures compute the same transfer function, but differ in state size and in how rounding errors propagate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilterStructure
+{
+    /// Separate delay lines for the input and output. Uses twice the state of the other forms, but cannot overflow internally.
+    DirectFormI,
+    /// A single delay line for the feedback part, followed by the feedforward part.
+    DirectFormII,
+    #[default]
+    TransposedDirectFormII,
+    /// A normalized lattice of orthogonal rotations, with a ladder tapping each stage. Well suited for high-Q and low-precision work.
+    LatticeLadder
+}
+
+impl FilterStructure
+{
+    /// The length of the delay line for a normalized filter of order `n`.
+    pub(crate) fn state_len(self, n: usize) -> usize
+    {
+        match self
+        {
+            Self::DirectFormI => 2*n,
+            _ => n
+        }
+    }
+
+    /// Converts normalized coefficients, in powers of `z⁻¹` with `a₀ = 1`, to the coefficients used by [`step`](Self::step).
+    ///
+    /// For the lattice-ladder, `a` holds the reflection coefficients `kₘ`, `c` holds `√(1 - |kₘ|²)`, and `b` holds the ladder coefficients rescaled for the normalized lattice.
+    pub(crate) fn realize<W>(self, b: Vec<W>, a: Vec<W>) -> Result<(Vec<W>, Vec<W>, Vec<W>), LatcError>
+    where
+        W: ComplexFloat
+    {
+        match self
+        {
+            Self::LatticeLadder => {
+                let (k, v) = latc(&b, &a)?;
+                let a: Vec<W> = core::iter::once(W::one())
+                    .chain(k)
+                    .collect();
+                let c: Vec<W> = a.iter()
+                    .map(|&k| (W::one() - k*k.conj()).sqrt())
+                    .collect();
+
+                // The normalized signals at stage m are scaled by α = cₘ₊₁⋯cₙ relative to the unnormalized lattice.
+                let mut b = v;
+                let mut alpha = W::one();
+                for m in (0..b.len()).rev()
+                {
+                    b[m] = b[m]/alpha;
+                    if m > 0
+                    {
+                        alpha = alpha*c[m]
+                    }
+                }
+                Ok((b, a, c))
+            },
+            _ => Ok((b, a, vec![]))
+        }
+    }
+
+    /// Runs one sample through the realization. Does not allocate.
+    pub(crate) fn step<W>(self, b: &[W], a: &[W], c: &[W], w: &mut [W], x: W) -> W
+    where
+        W: ComplexFloat
+    {
+        let n = b.len() - 1;
+        match self
+        {
+            Self::DirectFormI => {
+                let (wx, wy) = w.split_at_mut(n);
+                let mut y = b[0]*x;
+                for i in 0..n
+                {
+                    y = y + b[i + 1]*wx[i] - a[i + 1]*wy[i]
+                }
+                if n > 0
+                {
+                    wx.rotate_right(1);
+                    wx[0] = x;
+                    wy.rotate_right(1);
+                    wy[0] = y;
+                }
+                y
+            },
+            Self::DirectFormII => {
+                let mut v = x;
+                for i in 0..n
+                {
+                    v = v - a[i + 1]*w[i]
+                }
+                let mut y = b[0]*v;
+                for i in 0..n
+                {
+                    y = y + b[i + 1]*w[i]
+                }
+                if n > 0
+                {
+                    w.rotate_right(1);
+                    w[0] = v;
+                }
+                y
+            },
+            Self::TransposedDirectFormII => {
+                let y = b[0]*x + w.first().copied().unwrap_or_else(W::zero);
+                for i in 0..n
+                {
+                    let next = if i + 1 < n {w[i + 1]} else {W::zero()};
+                    w[i] = b[i + 1]*x - a[i + 1]*y + next;
+                }
+                y
+            },
+            Self::LatticeLadder => {
+                // w[m] holds gₘ from the previous sample. Stage m reads gₘ₋₁ before stage m + 1 overwrites it.
+                let mut f = x;
+                let mut y = W::zero();
+                for m in (1..=n).rev()
+                {
+                    let (k, c, g) = (a[m], c[m], w[m - 1]);
+                    let gm = k.conj()*f + c*g;
+                    f = c*f - k*g;
+                    y = y + b[m]*gm;
+                    if m < n
+                    {
+                        w[m] = gm
+                    }
+                }
+                if n > 0
+                {
+                    w[0] = f
+                }
+                y + b[0]*f
+            }
+        }
+    }
+}
+
+/// Filters a signal through a SISO system, using the chosen realization structure.
+///
+/// The filter starts at rest. For the lattice-ladder, an error is returned if the denominator has a reflection coefficient of unit magnitude.
+pub trait FilterStructured<X, XX>: System
+where
+    XX: List<X>
+{
+    type Output;
+
+    fn filter_structured(self, x: XX, structure: FilterStructure) -> Result<Self::Output, LatcError>;
+}
+
+impl<W, T, B, A, X, XX> FilterStructured<X, XX> for Tf<T, B, A>
+where
+    T: ComplexFloat + Into<W>,
+    W: ComplexFloat,
+    B: MaybeList<T>,
+    A: MaybeList<T>,
+    X: Into<W> + Copy,
+    XX: List<X>
+{
+    type Output = XX::Mapped<W>;
+
+    fn filter_structured(self, x: XX, structure: FilterStructure) -> Result<Self::Output, LatcError>
+    {
+        let mut p = TfProcessor::<W>::with_structure(self, structure)?;
+        Ok(x.map_to_owned(|&x| p.process_sample(x)))
+    }
+}
+
+impl<W, T, B, A, S, X, XX> FilterStructured<X, XX> for Sos<T, B, A, S>
+where
+    T: ComplexFloat + Into<W>,
+    W: ComplexFloat,
+    B: Maybe<[T; 3]> + MaybeOwnedList<T>,
+    A: Maybe<[T; 3]> + MaybeOwnedList<T>,
+    S: MaybeList<Tf<T, B, A>>,
+    X: Into<W> + Copy,
+    XX: List<X>
+{
+    type Output = XX::Mapped<W>;
+
+    fn filter_structured(self, x: XX, structure: FilterStructure) -> Result<Self::Output, LatcError>
+    {
+        let mut p = SosProcessor::<W>::with_structure(self, structure)?;
+        Ok(x.map_to_owned(|&x| p.process_sample(x)))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{operations::filtering::{FilterStructure, FilterStructured}, systems::{Sos, Tf}};
+
+    const STRUCTURES: [FilterStructure; 4] = [
+        FilterStructure::DirectFormI,
+        FilterStructure::DirectFormII,
+        FilterStructure::TransposedDirectFormII,
+        FilterStructure::LatticeLadder
+    ];
+
+    #[test]
+    fn structures_agree()
+    {
+        let h = Tf::new(vec![0.05, 0.1, 0.15, 0.1, 0.05], vec![1.0, -1.6, 1.4, -0.6, 0.1]);
+        let sos = Sos::new(vec![
+            Tf::new([0.1, 0.2, 0.1], [1.0, -1.8, 0.9]),
+            Tf::new([1.0, -0.5, 0.25], [1.0, 0.3, 0.4])
+        ]);
+        let x: Vec<f64> = (0..128).map(|i| ((i*7 % 17) as f64 - 8.0)/8.0).collect();
+
+        let y_ref: Vec<f64> = h.clone().filter_structured(x.clone(), FilterStructure::default()).unwrap();
+        let y_sos_ref: Vec<f64> = sos.clone().filter_structured(x.clone(), FilterStructure::default()).unwrap();
+        for structure in STRUCTURES
+        {
+            let y: Vec<f64> = h.clone().filter_structured(x.clone(), structure).unwrap();
+            let y_sos: Vec<f64> = sos.clone().filter_structured(x.clone(), structure).unwrap();
+            for (y, y_ref) in y.iter().zip(y_ref.iter()).chain(y_sos.iter().zip(y_sos_ref.iter()))
+            {
+                assert!((y - y_ref).abs() < 1e-10, "{structure:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,337 @@
+use core::ops::Deref;
+
+use ndarray::{Array1, Array2};
+use num::complex::ComplexFloat;
+use option_trait::Maybe;
+use thiserror::Error;
+
+use crate::{analysis::FilterZi, operations::filtering::{normalize_tf, SosProcessor, TfProcessor}, quantities::{List, MaybeList, MaybeOwnedList}, systems::{Sos, Tf}, util::{Lapack, LeastSquares, LinalgError}, System};
+
+/// How the signal is extended at its edges before forward-backward filtering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FiltFiltPadType
+{
+    /// Point-symmetric about the edge sample, which preserves its value and slope.
+    #[default]
+    Odd,
+    /// Mirror-symmetric about the edge sample.
+    Even,
+    /// The edge sample repeated.
+    Constant,
+    /// No extension.
+    None
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FiltFiltMethod
+{
+    /// Extends the signal, then filters forward and backward, starting each pass from the steady state of its first sample.
+    ///
+    /// If no pad length is given, it is three times the number of filter taps.
+    Pad
+    {
+        padtype: FiltFiltPadType,
+        padlen: Option<usize>
+    },
+    /// Gustafsson's method, which chooses the initial states of both passes so that forward-backward and backward-forward filtering agree.
+    Gustafsson
+}
+
+impl Default for FiltFiltMethod
+{
+    fn default() -> Self
+    {
+        Self::Pad {
+            padtype: FiltFiltPadType::default(),
+            padlen: None
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum FiltFiltError
+{
+    #[error("The signal must be longer than the pad length of {padlen}, but has length {len}.")]
+    PadTooLong
+    {
+        padlen: usize,
+        len: usize
+    },
+    #[error(transparent)]
+    Linalg(#[from] LinalgError)
+}
+
+/// Zero-phase forward-backward filtering of a SISO system, with a choice of edge handling.
+///
+/// With the default method, this matches scipy's `filtfilt` for transfer functions and `sosfiltfilt` for second-order sections.
+pub trait FiltFiltWith<X, XX>: System
+where
+    XX: List<X>
+{
+    type Output;
+
+    fn filtfilt_with(&self, x: XX, method: FiltFiltMethod) -> Result<Self::Output, FiltFiltError>;
+}
+
+impl<W, T, B, A, X, XX> FiltFiltWith<X, XX> for Tf<T, B, A>
+where
+    T: ComplexFloat + Into<W>,
+    W: ComplexFloat + Lapack,
+    B: MaybeList<T>,
+    A: MaybeList<T>,
+    X: Into<W> + Copy,
+    XX: List<X>,
+    Self: Clone
+{
+    type Output = XX::Mapped<W>;
+
+    fn filtfilt_with(&self, x: XX, method: FiltFiltMethod) -> Result<Self::Output, FiltFiltError>
+    {
+        let zi: Vec<W> = self.filter_zi()
+            .into_iter()
+            .map(|zi| zi.into())
+            .collect();
+        let mut p = TfProcessor::<W>::new(self.clone());
+        let padlen = 3*(zi.len() + 1);
+
+        let y = filtfilt(&mut p, x.to_vec().into_iter().map(|x| x.into()).collect(), &zi, method, padlen)?;
+        let mut y = y.into_iter();
+        Ok(x.map_to_owned(|_| y.next().unwrap()))
+    }
+}
+
+impl<W, T, B, A, S, X, XX> FiltFiltWith<X, XX> for Sos<T, B, A, S>
+where
+    T: ComplexFloat + Into<W>,
+    W: ComplexFloat + Lapack,
+    B: Maybe<[T; 3]> + MaybeOwnedList<T>,
+    A: Maybe<[T; 3]> + MaybeOwnedList<T>,
+    S: MaybeList<Tf<T, B, A>>,
+    X: Into<W> + Copy,
+    XX: List<X>,
+    Self: Clone
+{
+    type Output = XX::Mapped<W>;
+
+    fn filtfilt_with(&self, x: XX, method: FiltFiltMethod) -> Result<Self::Output, FiltFiltError>
+    {
+        let one = T::one();
+        let zero = T::zero();
+
+        let zi: Vec<W> = self.filter_zi()
+            .into_iter()
+            .map(|zi| zi.into())
+            .collect();
+        let mut p = SosProcessor::<W>::new(self.clone());
+
+        // Sections of reduced order contribute fewer taps, like in sosfiltfilt.
+        let (mut nb, mut na) = (0, 0);
+        let sections = self.sos.deref()
+            .as_view_slice_option()
+            .unwrap_or(&[]);
+        for sos in sections
+        {
+            let b = sos.b.deref()
+                .as_option()
+                .copied()
+                .unwrap_or([zero, zero, one]);
+            let a = sos.a.deref()
+                .as_option()
+                .copied()
+                .unwrap_or([zero, zero, one]);
+            let (b, a) = normalize_tf::<T, T>(&b, &a);
+            nb += b.get(2).is_none_or(|&b| b == zero) as usize;
+            na += a.get(2).is_none_or(|&a| a == zero) as usize;
+        }
+        let padlen = 3*(2*sections.len() + 1 - nb.min(na));
+
+        let y = filtfilt(&mut p, x.to_vec().into_iter().map(|x| x.into()).collect(), &zi, method, padlen)?;
+        let mut y = y.into_iter();
+        Ok(x.map_to_owned(|_| y.next().unwrap()))
+    }
+}
+
+/// A single filtering pass from a given state.
+trait Pass<W>
+{
+    fn pass(&mut self, zi: &[W], x: &[W]) -> Vec<W>;
+}
+
+impl<W> Pass<W> for TfProcessor<W>
+where
+    W: ComplexFloat
+{
+    fn pass(&mut self, zi: &[W], x: &[W]) -> Vec<W>
+    {
+        self.restore_state(zi);
+        x.iter()
+            .map(|&x| self.process_sample(x))
+            .collect()
+    }
+}
+
+impl<W> Pass<W> for SosProcessor<W>
+where
+    W: ComplexFloat
+{
+    fn pass(&mut self, zi: &[W], x: &[W]) -> Vec<W>
+    {
+        self.restore_state(zi);
+        x.iter()
+            .map(|&x| self.process_sample(x))
+            .collect()
+    }
+}
+
+fn filtfilt<W, P>(p: &mut P, x: Vec<W>, zi: &[W], method: FiltFiltMethod, default_padlen: usize) -> Result<Vec<W>, FiltFiltError>
+where
+    W: ComplexFloat + Lapack,
+    P: Pass<W>
+{
+    let n = x.len();
+    if n == 0
+    {
+        return Ok(x)
+    }
+    let scaled = |s: W| -> Vec<W> {zi.iter().map(|&zi| zi*s).collect()};
+    let rev = |mut x: Vec<W>| {x.reverse(); x};
+
+    match method
+    {
+        FiltFiltMethod::Pad { padtype, padlen } => {
+            let edge = match padtype
+            {
+                FiltFiltPadType::None => 0,
+                _ => padlen.unwrap_or(default_padlen)
+            };
+            if edge > 0 && n <= edge
+            {
+                return Err(FiltFiltError::PadTooLong {
+                    padlen: edge,
+                    len: n
+                })
+            }
+
+            let two = W::one() + W::one();
+            let (x0, xn) = (x[0], x[n - 1]);
+            let (left, right): (Vec<W>, Vec<W>) = match padtype
+            {
+                FiltFiltPadType::Odd => (
+                    (1..=edge).rev().map(|i| two*x0 - x[i]).collect(),
+                    (1..=edge).map(|i| two*xn - x[n - 1 - i]).collect()
+                ),
+                FiltFiltPadType::Even => (
+                    (1..=edge).rev().map(|i| x[i]).collect(),
+                    (1..=edge).map(|i| x[n - 1 - i]).collect()
+                ),
+                FiltFiltPadType::Constant => (vec![x0; edge], vec![xn; edge]),
+                FiltFiltPadType::None => (vec![], vec![])
+            };
+            let ext: Vec<W> = left.into_iter()
+                .chain(x)
+                .chain(right)
+                .collect();
+
+            let y = rev(p.pass(&scaled(ext[0]), &ext));
+            let y = rev(p.pass(&scaled(y[0]), &y));
+            Ok(y[edge..edge + n].to_vec())
+        },
+        FiltFiltMethod::Gustafsson => {
+            let order = zi.len();
+            let zero = vec![W::zero(); order];
+            if order == 0
+            {
+                let y = rev(p.pass(&zero, &x));
+                return Ok(rev(p.pass(&zero, &y)))
+            }
+
+            // Obs propagates an initial state to the output with zero input, and S filters that response reversed.
+            let mut obs = Array2::zeros((n, order));
+            let mut s = Array2::zeros((n, order));
+            let mut e = zero.clone();
+            for k in 0..order
+            {
+                e[k] = W::one();
+                let o = p.pass(&e, &vec![W::zero(); n]);
+                let so = p.pass(&zero, &rev(o.clone()));
+                obs.column_mut(k).assign(&Array1::from(o));
+                s.column_mut(k).assign(&Array1::from(so));
+                e[k] = W::zero();
+            }
+            let flip = |m: &Array2<W>| Array2::from_shape_fn(m.dim(), |(i, j)| m[(n - 1 - i, j)]);
+            let (sr, obsr) = (flip(&s), flip(&obs));
+
+            let mut m = Array2::zeros((n, 2*order));
+            let mut w = Array2::zeros((n, 2*order));
+            for i in 0..n
+            {
+                for j in 0..order
+                {
+                    m[(i, j)] = sr[(i, j)] - obs[(i, j)];
+                    m[(i, order + j)] = obsr[(i, j)] - s[(i, j)];
+                    w[(i, j)] = sr[(i, j)];
+                    w[(i, order + j)] = obsr[(i, j)];
+                }
+            }
+
+            // The naive passes in both orders, from rest.
+            let y_fb = rev(p.pass(&zero, &rev(p.pass(&zero, &x))));
+            let y_bf = p.pass(&zero, &rev(p.pass(&zero, &rev(x))));
+            let delta: Array1<W> = y_bf.iter()
+                .zip(y_fb.iter())
+                .map(|(&bf, &fb)| bf - fb)
+                .collect();
+
+            let ic = m.least_squares(&delta)?;
+            let y = Array1::from(y_fb) + w.dot(&ic);
+            Ok(y.to_vec())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{operations::filtering::{FiltFiltError, FiltFiltMethod, FiltFiltPadType, FiltFiltWith}, systems::{Sos, Tf}};
+
+    #[test]
+    fn edges()
+    {
+        let sos = Sos::new(vec![
+            Tf::new([0.1, 0.2, 0.1], [1.0, -0.6, 0.2]),
+            Tf::new([1.0, -1.0, 0.5], [1.0, 0.3, 0.4])
+        ]);
+        let h = Tf::new(vec![0.1, 0.1, -0.05, 0.0, 0.05], vec![1.0, -0.3, 0.42, -0.18, 0.08]);
+        let kdc = 0.4/0.6*0.5/1.7;
+
+        // A constant signal passes without transients.
+        let x = vec![1.0; 40];
+        for padtype in [FiltFiltPadType::Odd, FiltFiltPadType::Even, FiltFiltPadType::Constant]
+        {
+            let y: Vec<f64> = sos.filtfilt_with(x.clone(), FiltFiltMethod::Pad {padtype, padlen: None}).unwrap();
+            assert!(y.iter().all(|y| (y - kdc*kdc).abs() < 1e-12));
+        }
+
+        // The transfer function and its sections agree, for every method.
+        let x: Vec<f64> = (0..40).map(|i| ((i*7 % 11) as f64 - 5.0)/5.0 + i as f64/10.0).collect();
+        for method in [
+            FiltFiltMethod::default(),
+            FiltFiltMethod::Pad {padtype: FiltFiltPadType::Even, padlen: Some(10)},
+            FiltFiltMethod::Pad {padtype: FiltFiltPadType::None, padlen: None},
+            FiltFiltMethod::Gustafsson
+        ]
+        {
+            let y: Vec<f64> = h.filtfilt_with(x.clone(), method).unwrap();
+            let y_sos: Vec<f64> = sos.filtfilt_with(x.clone(), method).unwrap();
+            for (y, y_sos) in y.iter().zip(y_sos.iter())
+            {
+                assert!((y - y_sos).abs() < 1e-9, "{method:?}");
+            }
+        }
+
+        assert_eq!(
+            FiltFiltWith::<f64, Vec<f64>>::filtfilt_with(&h, vec![0.0; 15], FiltFiltMethod::default()),
+            Err(FiltFiltError::PadTooLong {padlen: 15, len: 15})
+        );
+    }
+}
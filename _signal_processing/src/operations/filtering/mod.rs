@@ -1,9 +1,15 @@
 moddef::moddef!(
     flat(pub) mod {
+        farrow_processor,
         fftfilt,
         filter_mut,
         filter,
+        filter_structure,
+        filtfilt_with,
         filtfilt,
-        sgolayfilt
+        sgolayfilt,
+        sos_processor,
+        ss_processor,
+        tf_processor
     }
 );
\ No newline at end of file
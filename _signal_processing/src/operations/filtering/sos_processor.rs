@@ -0,0 +1,187 @@
+use core::ops::Deref;
+
+use num::complex::ComplexFloat;
+use option_trait::Maybe;
+
+use crate::{operations::filtering::{normalize_tf, FilterStructure}, quantities::{MaybeList, MaybeOwnedList}, systems::{Sos, Tf}, transforms::filter::LatcError};
+
+/// A preallocated, real-time-safe processor for a cascade of second-order sections.
+///
+/// Each section is normalized and converted to the state type `W` once, at construction.
+/// After that, none of the processing methods allocate. By default, each section is realized in transposed direct form II.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SosProcessor<W>
+where
+    W: ComplexFloat
+{
+    structure: FilterStructure,
+    b: Vec<[W; 3]>,
+    a: Vec<[W; 3]>,
+    c: Vec<[W; 3]>,
+    w: Vec<W>
+}
+
+impl<W> SosProcessor<W>
+where
+    W: ComplexFloat
+{
+    pub fn new<T, B, A, S>(sos: Sos<T, B, A, S>) -> Self
+    where
+        T: ComplexFloat + Into<W>,
+        B: Maybe<[T; 3]> + MaybeOwnedList<T>,
+        A: Maybe<[T; 3]> + MaybeOwnedList<T>,
+        S: MaybeList<Tf<T, B, A>>
+    {
+        let one = T::one();
+        let zero = T::zero();
+
+        let (b, a): (Vec<_>, Vec<_>) = sos.sos
+            .deref()
+            .as_view_slice_option()
+            .unwrap_or(&[])
+            .iter()
+            .map(|sos| {
+                let b = sos.b.deref()
+                    .as_option()
+                    .copied()
+                    .unwrap_or([zero, zero, one]);
+                let a = sos.a.deref()
+                    .as_option()
+                    .copied()
+                    .unwrap_or([zero, zero, one]);
+                let (b, a) = normalize_tf::<T, W>(&b, &a);
+                let section = |c: Vec<W>| core::array::from_fn(|i| c.get(i).copied().unwrap_or_else(W::zero));
+                (section(b), section(a))
+            }).unzip();
+        let w = vec![W::zero(); 2*b.len()];
+
+        Self {
+            structure: FilterStructure::TransposedDirectFormII,
+            b,
+            a,
+            c: vec![],
+            w
+        }
+    }
+
+    /// Like [`new`](Self::new), but with each section realized with the given structure.
+    pub fn with_structure<T, B, A, S>(sos: Sos<T, B, A, S>, structure: FilterStructure) -> Result<Self, LatcError>
+    where
+        T: ComplexFloat + Into<W>,
+        B: Maybe<[T; 3]> + MaybeOwnedList<T>,
+        A: Maybe<[T; 3]> + MaybeOwnedList<T>,
+        S: MaybeList<Tf<T, B, A>>
+    {
+        let Self {b, a, ..} = Self::new(sos);
+        let section = |c: Vec<W>| core::array::from_fn(|i| c.get(i).copied().unwrap_or_else(W::zero));
+        let mut c = Vec::with_capacity(b.len());
+        let (b, a): (Vec<_>, Vec<_>) = b.into_iter()
+            .zip(a)
+            .map(|(b, a)| {
+                let (bb, aa, cc) = structure.realize(b.to_vec(), a.to_vec())?;
+                c.push(section(cc));
+                Ok((section(bb), section(aa)))
+            }).collect::<Result<Vec<_>, LatcError>>()?
+            .into_iter()
+            .unzip();
+        let w = vec![W::zero(); structure.state_len(2)*b.len()];
+
+        Ok(Self {
+            structure,
+            b,
+            a,
+            c,
+            w
+        })
+    }
+
+    pub fn structure(&self) -> FilterStructure
+    {
+        self.structure
+    }
+
+    pub fn process_sample<X>(&mut self, x: X) -> W
+    where
+        X: Into<W>
+    {
+        let mut y = x.into();
+        let nw = self.structure.state_len(2);
+        let c = self.c.iter()
+            .map(|c| c.as_slice())
+            .chain(core::iter::repeat(&[][..]));
+        for (((b, a), c), w) in self.b.iter()
+            .zip(self.a.iter())
+            .zip(c)
+            .zip(self.w.chunks_exact_mut(nw))
+        {
+            y = self.structure.step(b, a, c, w, y)
+        }
+        y
+    }
+
+    /// Filters `x` into `y`, which must have the same length.
+    pub fn process_block<X>(&mut self, x: &[X], y: &mut [W])
+    where
+        X: Into<W> + Copy
+    {
+        assert_eq!(x.len(), y.len(), "Input and output blocks must have equal length.");
+        for (&x, y) in x.iter()
+            .zip(y.iter_mut())
+        {
+            *y = self.process_sample(x)
+        }
+    }
+
+    /// Filters `xy` in place.
+    pub fn process_block_mut(&mut self, xy: &mut [W])
+    {
+        for xy in xy.iter_mut()
+        {
+            *xy = self.process_sample(*xy)
+        }
+    }
+
+    pub fn reset(&mut self)
+    {
+        self.w.fill(W::zero())
+    }
+
+    /// The delay-line state, two values per section (four for direct form I), which may be copied out and later given to [`restore_state`](Self::restore_state).
+    pub fn state(&self) -> &[W]
+    {
+        &self.w
+    }
+
+    /// Restores a state previously taken with [`state`](Self::state). Panics if its length is wrong.
+    pub fn restore_state(&mut self, state: &[W])
+    {
+        self.w.copy_from_slice(state)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{operations::filtering::{Filter, SosProcessor}, systems::{Sos, Tf}};
+
+    #[test]
+    fn matches_filter()
+    {
+        let h = Sos::new(vec![
+            Tf::new([0.1, 0.2, 0.1], [1.0, -0.6, 0.2]),
+            Tf::new([1.0, -1.0, 0.5], [1.0, 0.3, 0.4])
+        ]);
+        let x: Vec<f64> = (0..64).map(|i| ((i*5 % 13) as f64 - 6.0)/6.0).collect();
+
+        let y_ref = h.clone().filter(x.clone(), ());
+
+        let mut p = SosProcessor::new(h);
+        let mut y = vec![0.0; x.len()];
+        p.process_block(&x, &mut y);
+        for (y, y_ref) in y.iter().zip(y_ref.iter())
+        {
+            assert!((y - y_ref).abs() < 1e-12);
+        }
+        assert_eq!(p.state().len(), 4);
+    }
+}
@@ -0,0 +1,200 @@
+use ndarray::{Array1, Array2};
+use num::complex::ComplexFloat;
+
+use crate::{quantities::Matrix, systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix}};
+
+/// A preallocated, real-time-safe processor for a discrete-time state-space system.
+///
+/// The matrices are converted to the state type `W` once, at construction, along with a scratch buffer for the next state.
+/// After that, none of the processing methods allocate.
+///
+/// Blocks are interleaved by frame, so a block of `N` frames holds `N` times the number of inputs (or outputs) values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SsProcessor<W>
+where
+    W: ComplexFloat
+{
+    a: Array2<W>,
+    b: Array2<W>,
+    c: Array2<W>,
+    d: Array2<W>,
+    x: Array1<W>,
+    x_next: Array1<W>,
+    u: Array1<W>
+}
+
+impl<W> SsProcessor<W>
+where
+    W: ComplexFloat
+{
+    pub fn new<T, A, B, C, D>(ss: Ss<T, A, B, C, D>) -> Self
+    where
+        T: ComplexFloat + Into<W>,
+        A: SsAMatrix<T, B, C, D>,
+        B: SsBMatrix<T, A, C, D>,
+        C: SsCMatrix<T, A, B, D>,
+        D: SsDMatrix<T, A, B, C>
+    {
+        let a = ss.a.to_array2().map(|&a| a.into());
+        let b = ss.b.to_array2().map(|&b| b.into());
+        let c = ss.c.to_array2().map(|&c| c.into());
+        let d = ss.d.to_array2().map(|&d| d.into());
+        let n = a.nrows();
+        let p = d.ncols();
+        Self {
+            a,
+            b,
+            c,
+            d,
+            x: Array1::zeros(n),
+            x_next: Array1::zeros(n),
+            u: Array1::zeros(p)
+        }
+    }
+
+    pub fn inputs(&self) -> usize
+    {
+        self.d.ncols()
+    }
+
+    pub fn outputs(&self) -> usize
+    {
+        self.d.nrows()
+    }
+
+    /// Processes one frame, with one value per input in `u` and one value per output in `y`.
+    pub fn process_sample<X>(&mut self, u: &[X], y: &mut [W])
+    where
+        X: Into<W> + Copy
+    {
+        assert_eq!(u.len(), self.inputs(), "Wrong number of inputs.");
+        assert_eq!(y.len(), self.outputs(), "Wrong number of outputs.");
+        for (s, &u) in self.u.iter_mut()
+            .zip(u.iter())
+        {
+            *s = u.into()
+        }
+        self.step(y)
+    }
+
+    /// Processes interleaved frames from `u` into `y`.
+    pub fn process_block<X>(&mut self, u: &[X], y: &mut [W])
+    where
+        X: Into<W> + Copy
+    {
+        let (p, q) = (self.inputs(), self.outputs());
+        let frames = if p == 0 {y.len()/q.max(1)} else {u.len()/p};
+        assert!(u.len() == frames*p && y.len() == frames*q, "Input and output blocks must hold the same number of frames.");
+        for i in 0..frames
+        {
+            self.process_sample(&u[i*p..(i + 1)*p], &mut y[i*q..(i + 1)*q])
+        }
+    }
+
+    /// Processes interleaved frames in place. The system must have as many outputs as inputs.
+    pub fn process_block_mut(&mut self, uy: &mut [W])
+    {
+        let p = self.inputs();
+        assert_eq!(p, self.outputs(), "In-place processing requires as many outputs as inputs.");
+        if p == 0
+        {
+            return
+        }
+        for frame in uy.chunks_exact_mut(p)
+        {
+            for (s, &u) in self.u.iter_mut()
+                .zip(frame.iter())
+            {
+                *s = u
+            }
+            self.step(frame)
+        }
+    }
+
+    fn step(&mut self, y: &mut [W])
+    {
+        // y = Cx + Du
+        for (i, y) in y.iter_mut()
+            .enumerate()
+        {
+            let mut s = W::zero();
+            for (&c, &x) in self.c.row(i).iter().zip(self.x.iter())
+            {
+                s = s + c*x
+            }
+            for (&d, &u) in self.d.row(i).iter().zip(self.u.iter())
+            {
+                s = s + d*u
+            }
+            *y = s
+        }
+        // x ← Ax + Bu
+        for (i, x_next) in self.x_next.iter_mut()
+            .enumerate()
+        {
+            let mut s = W::zero();
+            for (&a, &x) in self.a.row(i).iter().zip(self.x.iter())
+            {
+                s = s + a*x
+            }
+            for (&b, &u) in self.b.row(i).iter().zip(self.u.iter())
+            {
+                s = s + b*u
+            }
+            *x_next = s
+        }
+        core::mem::swap(&mut self.x, &mut self.x_next);
+    }
+
+    pub fn reset(&mut self)
+    {
+        self.x.fill(W::zero())
+    }
+
+    /// The state vector, which may be copied out and later given to [`restore_state`](Self::restore_state).
+    pub fn state(&self) -> &[W]
+    {
+        self.x.as_slice().unwrap()
+    }
+
+    /// Restores a state previously taken with [`state`](Self::state). Panics if its length is wrong.
+    pub fn restore_state(&mut self, state: &[W])
+    {
+        self.x.as_slice_mut()
+            .unwrap()
+            .copy_from_slice(state)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::{operations::filtering::SsProcessor, systems::Ss};
+
+    #[test]
+    fn mimo()
+    {
+        let h = Ss::new(
+            array![[0.5, 0.1], [0.0, 0.3]],
+            array![[1.0, 0.0], [0.0, 1.0]],
+            array![[1.0, 1.0]],
+            array![[0.0, 0.5]]
+        );
+        let mut p = SsProcessor::<f64>::new(h);
+
+        let u = [1.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        let mut y = [0.0; 3];
+        p.process_block(&u, &mut y);
+
+        // Impulse into the first input: y = [0, CB₁, CAB₁]
+        assert_eq!(y, [0.0, 1.0, 0.5]);
+
+        p.reset();
+        let mut y0 = [0.0];
+        p.process_sample(&[0.0, 2.0], &mut y0);
+        assert_eq!(y0, [1.0]);
+        assert_eq!(p.state(), &[0.0, 2.0]);
+    }
+}
@@ -0,0 +1,55 @@
+use ndarray::Array2;
+use num::complex::ComplexFloat;
+
+use crate::{systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix}, transforms::system::ToSs, System};
+
+/// Stacks two systems into one block-diagonal system, with the inputs and outputs of `rhs` following those of `self`.
+pub trait Append<Rhs>: System
+{
+    type Output: Sized;
+
+    fn append(self, rhs: Rhs) -> Self::Output;
+}
+
+impl<T, L, R> Append<R> for L
+where
+    T: ComplexFloat + 'static,
+    L: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>> + System<Set = T>,
+    R: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>> + System<Set = T>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    type Output = Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>;
+
+    fn append(self, rhs: R) -> Self::Output
+    {
+        let Ss {a: a1, b: b1, c: c1, d: d1, ..} = self.to_ss();
+        let Ss {a: a2, b: b2, c: c2, d: d2, ..} = rhs.to_ss();
+
+        Ss::new(
+            super::block_diag(&a1, &a2),
+            super::block_diag(&b1, &b2),
+            super::block_diag(&c1, &c2),
+            super::block_diag(&d1, &d2)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::{operations::interconnection::Append, systems::Ss};
+
+    #[test]
+    fn append()
+    {
+        let h1 = Ss::new(array![[-1.0]], array![[1.0]], array![[1.0]], array![[0.0]]);
+        let h2 = Ss::new(array![[-2.0]], array![[1.0, 2.0]], array![[3.0]], array![[0.0, 1.0]]);
+
+        let h = h1.append(h2);
+
+        assert_eq!(h.b, array![[1.0, 0.0, 0.0], [0.0, 1.0, 2.0]]);
+        assert_eq!(h.d, array![[0.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+    }
+}
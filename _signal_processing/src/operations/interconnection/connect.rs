@@ -0,0 +1,90 @@
+use ndarray::{Array2, ScalarOperand};
+
+use crate::{operations::interconnection::InterconnectionError, systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix}, transforms::system::ToSs, util::{Inverse, Lapack}, System};
+
+/// Interconnects the inputs and outputs of a block-diagonal system, typically built with [`Append`](crate::operations::interconnection::Append).
+///
+/// Each entry `(input, output, gain)` of `connections` adds `gain` times the given output to the given input,
+/// so that the internal inputs become `u = Qy + Ev`, where `E` routes the external inputs `v` onto the channels listed in `inputs`.
+/// Only the outputs listed in `outputs` are kept.
+pub trait Connect: System
+{
+    type Output: Sized;
+
+    fn connect(self, connections: &[(usize, usize, Self::Set)], inputs: &[usize], outputs: &[usize]) -> Result<Self::Output, InterconnectionError>;
+}
+
+impl<T, S> Connect for S
+where
+    T: Lapack + ScalarOperand,
+    S: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>> + System<Set = T>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    type Output = Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>;
+
+    fn connect(self, connections: &[(usize, usize, T)], inputs: &[usize], outputs: &[usize]) -> Result<Self::Output, InterconnectionError>
+    {
+        let Ss {a, b, c, d, ..} = self.to_ss();
+        let (p, q) = d.dim();
+
+        let mut qm = Array2::zeros((q, p));
+        for &(i, o, gain) in connections
+        {
+            if i >= q
+            {
+                return Err(InterconnectionError::Channel(i))
+            }
+            if o >= p
+            {
+                return Err(InterconnectionError::Channel(o))
+            }
+            qm[(i, o)] += gain;
+        }
+        if let Some(&i) = inputs.iter()
+            .find(|&&i| i >= q)
+            .or_else(|| outputs.iter().find(|&&o| o >= p))
+        {
+            return Err(InterconnectionError::Channel(i))
+        }
+        let e = Array2::from_shape_fn((q, inputs.len()), |(i, j)| if inputs[j] == i {T::one()} else {T::zero()});
+        let s = Array2::from_shape_fn((outputs.len(), p), |(i, j)| if outputs[i] == j {T::one()} else {T::zero()});
+
+        // y = F(Cx + DEv), F = (I - DQ)⁻¹
+        let f = (Array2::eye(p) - d.dot(&qm)).inv()?;
+        let fc = f.dot(&c);
+        let fde = f.dot(&d).dot(&e);
+
+        // u = QFCx + (QFD + I)Ev
+        Ok(Ss::new(
+            &a + &b.dot(&qm).dot(&fc),
+            b.dot(&(qm.dot(&fde) + &e)),
+            s.dot(&fc),
+            s.dot(&fde)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::{operations::interconnection::{Append, Connect}, systems::Ss};
+
+    #[test]
+    fn connect()
+    {
+        // 1/(s + 1) in series with 1/(s + 2), with negative feedback from the last output to the first input
+        let h1 = Ss::new(array![[-1.0]], array![[1.0]], array![[1.0]], array![[0.0]]);
+        let h2 = Ss::new(array![[-2.0]], array![[1.0]], array![[1.0]], array![[0.0]]);
+
+        let h = h1.append(h2)
+            .connect(&[(1, 0, 1.0), (0, 1, -1.0)], &[0], &[1])
+            .unwrap();
+
+        assert_eq!(h.a, array![[-1.0, -1.0], [1.0, -2.0]]);
+        assert_eq!(h.b, array![[1.0], [0.0]]);
+        assert_eq!(h.c, array![[0.0, 1.0]]);
+        assert_eq!(h.d, array![[0.0]]);
+    }
+}
@@ -0,0 +1,130 @@
+use ndarray::{concatenate, Array2, Axis, ScalarOperand};
+
+use crate::{operations::interconnection::InterconnectionError, systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix}, transforms::system::ToSs, util::{Inverse, Lapack}, System};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FeedbackSign
+{
+    #[default]
+    Negative,
+    Positive
+}
+
+/// Closes a feedback loop around `self`, with `rhs` in the feedback path.
+///
+/// ```txt
+/// u ──▶(±)──▶ self ──┬──▶ y
+///       ▲            │
+///       └─── rhs ◀───┘
+/// ```
+///
+/// With [`feedback_channels`](Feedback::feedback_channels), only the outputs of `self` listed in `feedout`
+/// are fed to `rhs`, and the outputs of `rhs` are added to the inputs of `self` listed in `feedin`.
+pub trait Feedback<Rhs>: System
+{
+    type Output: Sized;
+
+    fn feedback(self, rhs: Rhs, sign: FeedbackSign) -> Result<Self::Output, InterconnectionError>;
+
+    fn feedback_channels(self, rhs: Rhs, feedin: &[usize], feedout: &[usize], sign: FeedbackSign) -> Result<Self::Output, InterconnectionError>;
+}
+
+impl<T, L, R> Feedback<R> for L
+where
+    T: Lapack + ScalarOperand,
+    L: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>> + System<Set = T>,
+    R: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>> + System<Set = T>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    type Output = Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>;
+
+    fn feedback(self, rhs: R, sign: FeedbackSign) -> Result<Self::Output, InterconnectionError>
+    {
+        let sys1 = self.to_ss();
+        let feedin: Vec<_> = (0..sys1.d.ncols()).collect();
+        let feedout: Vec<_> = (0..sys1.d.nrows()).collect();
+        sys1.feedback_channels(rhs, &feedin, &feedout, sign)
+    }
+
+    fn feedback_channels(self, rhs: R, feedin: &[usize], feedout: &[usize], sign: FeedbackSign) -> Result<Self::Output, InterconnectionError>
+    {
+        let Ss {a: a1, b: b1, c: c1, d: d1, ..} = self.to_ss();
+        let Ss {a: a2, b: b2, c: c2, d: d2, ..} = rhs.to_ss();
+        let (p1, q1) = d1.dim();
+        if d2.dim() != (feedin.len(), feedout.len())
+        {
+            return Err(InterconnectionError::Dimensions)
+        }
+        if let Some(&i) = feedin.iter()
+            .find(|&&i| i >= q1)
+            .or_else(|| feedout.iter().find(|&&i| i >= p1))
+        {
+            return Err(InterconnectionError::Channel(i))
+        }
+
+        // Route the feedback system onto the selected channels, so that it maps all p₁ outputs to all q₁ inputs.
+        let sin = Array2::from_shape_fn((q1, feedin.len()), |(i, j)| if feedin[j] == i {T::one()} else {T::zero()});
+        let sout = Array2::from_shape_fn((feedout.len(), p1), |(i, j)| if feedout[i] == j {T::one()} else {T::zero()});
+        let b2 = b2.dot(&sout);
+        let c2 = sin.dot(&c2);
+        let d2 = sin.dot(&d2).dot(&sout);
+
+        let sigma = match sign
+        {
+            FeedbackSign::Negative => -T::one(),
+            FeedbackSign::Positive => T::one()
+        };
+
+        // y₁ = F(C₁x₁ + σD₁C₂x₂ + D₁u), F = (I - σD₁D₂)⁻¹
+        let f = (Array2::eye(p1) - d1.dot(&d2)*sigma).inv()?;
+        let fc1 = f.dot(&c1);
+        let fd1 = f.dot(&d1);
+        let fd1c2 = fd1.dot(&c2)*sigma;
+
+        // e = u + σ(C₂x₂ + D₂y₁)
+        let ec1 = d2.dot(&fc1)*sigma;
+        let ec2 = (&c2 + &d2.dot(&fd1c2))*sigma;
+        let eu = Array2::eye(q1) + d2.dot(&fd1)*sigma;
+
+        let a = concatenate![Axis(0),
+            concatenate![Axis(1), a1 + b1.dot(&ec1), b1.dot(&ec2)],
+            concatenate![Axis(1), b2.dot(&fc1), a2 + b2.dot(&fd1c2)]
+        ];
+        let b = concatenate![Axis(0), b1.dot(&eu), b2.dot(&fd1)];
+        let c = concatenate![Axis(1), fc1, fd1c2];
+
+        Ok(Ss::new(a, b, c, fd1))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::{array, Array2};
+
+    use crate::{operations::interconnection::{Feedback, FeedbackSign, InterconnectionError}, systems::Ss};
+
+    #[test]
+    fn feedback()
+    {
+        // 1/(s + 1) with unity negative feedback is 1/(s + 2)
+        let h = Ss::new(array![[-1.0]], array![[1.0]], array![[1.0]], array![[0.0]]);
+        let k = Ss::new(Array2::zeros((0, 0)), Array2::zeros((0, 1)), Array2::zeros((1, 0)), array![[1.0]]);
+
+        let h = h.feedback(k, FeedbackSign::Negative).unwrap();
+
+        assert_eq!(h.a, array![[-2.0]]);
+        assert_eq!(h.b, array![[1.0]]);
+        assert_eq!(h.c, array![[1.0]]);
+        assert_eq!(h.d, array![[0.0]]);
+    }
+
+    #[test]
+    fn algebraic_loop()
+    {
+        let h = Ss::new(array![[-1.0]], array![[1.0]], array![[1.0]], array![[1.0]]);
+        let k = Ss::new(array![[-1.0]], array![[1.0]], array![[0.0]], array![[1.0]]);
+
+        assert_eq!(h.feedback(k, FeedbackSign::Positive).err(), Some(InterconnectionError::AlgebraicLoop));
+    }
+}
@@ -0,0 +1,28 @@
+use thiserror::Error;
+
+use crate::util::LinalgError;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum InterconnectionError
+{
+    #[error("The number of outputs of one system does not match the number of inputs of the other.")]
+    Dimensions,
+    #[error("Channel index {0} is out of range.")]
+    Channel(usize),
+    #[error("The interconnection has an algebraic loop which is not well-posed.")]
+    AlgebraicLoop,
+    #[error(transparent)]
+    Linalg(LinalgError)
+}
+
+impl From<LinalgError> for InterconnectionError
+{
+    fn from(error: LinalgError) -> Self
+    {
+        match error
+        {
+            LinalgError::Singular => Self::AlgebraicLoop,
+            error => Self::Linalg(error)
+        }
+    }
+}
@@ -0,0 +1,26 @@
+moddef::moddef!(
+    flat(pub) mod {
+        append,
+        connect,
+        feedback,
+        interconnection_error,
+        parallel,
+        series
+    }
+);
+
+use ndarray::{s, Array2};
+use num::Zero;
+
+/// `[A 0; 0 B]`
+fn block_diag<T>(a: &Array2<T>, b: &Array2<T>) -> Array2<T>
+where
+    T: Zero + Clone
+{
+    let (m1, n1) = a.dim();
+    let (m2, n2) = b.dim();
+    let mut y = Array2::zeros((m1 + m2, n1 + n2));
+    y.slice_mut(s![..m1, ..n1]).assign(a);
+    y.slice_mut(s![m1.., n1..]).assign(b);
+    y
+}
@@ -0,0 +1,63 @@
+use ndarray::{concatenate, Array2, Axis};
+use num::complex::ComplexFloat;
+
+use crate::{operations::interconnection::InterconnectionError, systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix}, transforms::system::ToSs, System};
+
+/// Connects two systems in parallel, so that they share their inputs and their outputs are summed.
+pub trait Parallel<Rhs>: System
+{
+    type Output: Sized;
+
+    fn parallel(self, rhs: Rhs) -> Result<Self::Output, InterconnectionError>;
+}
+
+impl<T, L, R> Parallel<R> for L
+where
+    T: ComplexFloat + 'static,
+    L: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>> + System<Set = T>,
+    R: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>> + System<Set = T>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    type Output = Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>;
+
+    fn parallel(self, rhs: R) -> Result<Self::Output, InterconnectionError>
+    {
+        let Ss {a: a1, b: b1, c: c1, d: d1, ..} = self.to_ss();
+        let Ss {a: a2, b: b2, c: c2, d: d2, ..} = rhs.to_ss();
+        if d1.dim() != d2.dim()
+        {
+            return Err(InterconnectionError::Dimensions)
+        }
+
+        // A = diag(A₁, A₂), B = [B₁; B₂], C = [C₁ C₂], D = D₁ + D₂
+        Ok(Ss::new(
+            super::block_diag(&a1, &a2),
+            concatenate![Axis(0), b1, b2],
+            concatenate![Axis(1), c1, c2],
+            d1 + d2
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::{operations::interconnection::Parallel, systems::{Ss, Tf}};
+
+    #[test]
+    fn parallel()
+    {
+        let h1 = Ss::new(array![[-1.0]], array![[1.0]], array![[1.0]], array![[0.5]]);
+        // 1/(s + 2)
+        let h2 = Tf::new(vec![1.0], vec![1.0, 2.0]);
+
+        let h = h1.parallel(h2).unwrap();
+
+        assert_eq!(h.a.dim(), (2, 2));
+        assert_eq!(h.a[(0, 0)], -1.0);
+        assert_eq!(h.a[(1, 1)], -2.0);
+        assert_eq!(h.d, array![[0.5]]);
+    }
+}
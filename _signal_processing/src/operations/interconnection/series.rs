@@ -0,0 +1,71 @@
+use ndarray::{concatenate, Array2, Axis};
+use num::complex::ComplexFloat;
+
+use crate::{operations::interconnection::InterconnectionError, systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix}, transforms::system::ToSs, System};
+
+/// Connects two systems in series, so that the outputs of `self` drive the inputs of `rhs`.
+///
+/// ```txt
+/// u ──▶ self ──▶ rhs ──▶ y
+/// ```
+pub trait Series<Rhs>: System
+{
+    type Output: Sized;
+
+    fn series(self, rhs: Rhs) -> Result<Self::Output, InterconnectionError>;
+}
+
+impl<T, L, R> Series<R> for L
+where
+    T: ComplexFloat + 'static,
+    L: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>> + System<Set = T>,
+    R: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>> + System<Set = T>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    type Output = Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>;
+
+    fn series(self, rhs: R) -> Result<Self::Output, InterconnectionError>
+    {
+        let Ss {a: a1, b: b1, c: c1, d: d1, ..} = self.to_ss();
+        let Ss {a: a2, b: b2, c: c2, d: d2, ..} = rhs.to_ss();
+        if d1.nrows() != d2.ncols()
+        {
+            return Err(InterconnectionError::Dimensions)
+        }
+        let (n1, n2) = (a1.nrows(), a2.nrows());
+
+        // A = [A₁ 0; B₂C₁ A₂], B = [B₁; B₂D₁], C = [D₂C₁ C₂], D = D₂D₁
+        let a = concatenate![Axis(0),
+            concatenate![Axis(1), a1, Array2::zeros((n1, n2))],
+            concatenate![Axis(1), b2.dot(&c1), a2]
+        ];
+        let b = concatenate![Axis(0), b1, b2.dot(&d1)];
+        let c = concatenate![Axis(1), d2.dot(&c1), c2];
+        let d = d2.dot(&d1);
+
+        Ok(Ss::new(a, b, c, d))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::{operations::interconnection::Series, systems::Ss};
+
+    #[test]
+    fn series()
+    {
+        // 1/(s + 1) then 2/(s + 3)
+        let h1 = Ss::new(array![[-1.0]], array![[1.0]], array![[1.0]], array![[0.0]]);
+        let h2 = Ss::new(array![[-3.0]], array![[1.0]], array![[2.0]], array![[0.0]]);
+
+        let h = h1.series(h2).unwrap();
+
+        assert_eq!(h.a, array![[-1.0, 0.0], [1.0, -3.0]]);
+        assert_eq!(h.b, array![[1.0], [0.0]]);
+        assert_eq!(h.c, array![[0.0, 2.0]]);
+        assert_eq!(h.d, array![[0.0]]);
+    }
+}
@@ -0,0 +1,106 @@
+use ndarray::{s, Array2};
+use num::{complex::ComplexFloat, Float, NumCast, Zero};
+use option_trait::Maybe;
+
+use crate::{analysis::control::ctrb, systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix}, transforms::system::ToSs, util::{Lapack, LinalgError, SVD}, System};
+
+/// Minimal realization of a state-space system.
+///
+/// Uncontrollable and unobservable states are removed through a Kalman decomposition, leaving a realization with the same transfer function.
+/// A direction counts as uncontrollable or unobservable if its singular value is below `tol` times the largest one, which defaults to `√ε`.
+pub trait MinReal: System
+{
+    type Output: System<Set = Self::Set>;
+
+    fn minreal<TOL>(self, tol: TOL) -> Result<Self::Output, LinalgError>
+    where
+        TOL: Maybe<<Self::Set as ComplexFloat>::Real>;
+}
+
+impl<T, A, B, C, D> MinReal for Ss<T, A, B, C, D>
+where
+    T: Lapack,
+    A: SsAMatrix<T, B, C, D>,
+    B: SsBMatrix<T, A, C, D>,
+    C: SsCMatrix<T, A, B, D>,
+    D: SsDMatrix<T, A, B, C>,
+    Self: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    type Output = Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>;
+
+    fn minreal<TOL>(self, tol: TOL) -> Result<Self::Output, LinalgError>
+    where
+        TOL: Maybe<T::Real>
+    {
+        let tol = tol.option()
+            .unwrap_or_else(|| Float::sqrt(T::Real::epsilon()));
+
+        let Ss {a, b, c, d, ..} = self.to_ss();
+
+        // Restrict to the controllable subspace, which is the range of [B AB ... Aⁿ⁻¹B].
+        let u = column_space(ctrb(&a, &b), tol)?;
+        let uh = conj_t(&u);
+        let (a, b, c) = (uh.dot(&a).dot(&u), uh.dot(&b), c.dot(&u));
+
+        // Then remove the unobservable subspace, which is the kernel of [C; CA; ...; CAⁿ⁻¹], by keeping its orthogonal complement.
+        let v = column_space(ctrb(&conj_t(&a), &conj_t(&c)), tol)?;
+        let vh = conj_t(&v);
+
+        Ok(Ss::new(vh.dot(&a).dot(&v), vh.dot(&b), c.dot(&v), d))
+    }
+}
+
+pub(crate) fn conj_t<T>(a: &Array2<T>) -> Array2<T>
+where
+    T: ComplexFloat
+{
+    a.t().map(|a| a.conj())
+}
+
+/// An orthonormal basis for the range of `m`, leaving out directions with singular values below `tol` relative to the largest.
+pub(crate) fn column_space<T>(m: Array2<T>, tol: T::Real) -> Result<Array2<T>, LinalgError>
+where
+    T: Lapack
+{
+    let rows = m.nrows();
+    if m.ncols() == 0 || rows == 0
+    {
+        return Ok(Array2::zeros((rows, 0)))
+    }
+    let (u, sigma, _) = m.svd(true, false)?;
+    let u = u.unwrap();
+    let threshold = sigma.iter()
+        .copied()
+        .fold(T::Real::zero(), Float::max)*tol.max(T::Real::epsilon()*<T::Real as NumCast>::from(rows).unwrap());
+    let rank = sigma.iter()
+        .take_while(|&&s| s > threshold)
+        .count();
+    Ok(u.slice(s![.., ..rank]).to_owned())
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::{operations::MinReal, systems::Ss};
+
+    #[test]
+    fn minreal()
+    {
+        // The second state is not controllable, and the third is not observable.
+        let h = Ss::new(
+            array![[-1.0, 0.0, 0.0], [0.0, -2.0, 0.0], [0.0, 0.0, -3.0]],
+            array![[1.0], [0.0], [1.0]],
+            array![[2.0, 1.0, 0.0]],
+            array![[0.0]]
+        );
+
+        let h = h.minreal(()).unwrap();
+
+        assert_eq!(h.a.dim(), (1, 1));
+        assert!((h.a[(0, 0)] + 1.0f64).abs() < 1e-12);
+        assert!((h.c[(0, 0)]*h.b[(0, 0)] - 2.0f64).abs() < 1e-12);
+    }
+}
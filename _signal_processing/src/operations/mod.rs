@@ -2,11 +2,15 @@ moddef::moddef!(
     pub mod {
         convolution,
         filtering,
+        interconnection,
+        quantization,
         resampling
     },
     flat(pub) mod {
+        balred,
         decode,
         encode,
+        minreal,
         simplify,
         window
     }
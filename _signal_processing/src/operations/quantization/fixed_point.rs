@@ -0,0 +1,214 @@
+use num::{Float, NumCast};
+
+/// How values between two representable levels are rounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding
+{
+    /// To the nearest level, with ties away from zero.
+    #[default]
+    Nearest,
+    /// To the nearest level, with ties to the even level.
+    Convergent,
+    /// Toward negative infinity. This is what an arithmetic shift does in two's complement.
+    Floor,
+    /// Toward zero.
+    Zero
+}
+
+/// What happens to values outside the representable range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow
+{
+    /// Clamped to the largest or smallest representable value.
+    #[default]
+    Saturate,
+    /// Wrapped around, as in two's complement arithmetic.
+    Wrap
+}
+
+/// A signed two's complement fixed-point format.
+///
+/// A raw integer `r` represents the value `r·2⁻ᶠ`, where `f` is the number of fraction bits.
+/// For example, Q15 has a word length of 16 and 15 fraction bits.
+///
+/// The word length and fraction bits are checked on construction, so they can only be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedPoint
+{
+    word_length: u32,
+    fraction_bits: u32,
+    pub rounding: Rounding,
+    pub overflow: Overflow
+}
+
+impl FixedPoint
+{
+    /// A format rounding to nearest and saturating on overflow. The word length must be in `1..=64`, and there must be fewer than 128 fraction bits.
+    pub fn new(word_length: u32, fraction_bits: u32) -> Self
+    {
+        assert!((1..=64).contains(&word_length), "Word length must be between 1 and 64 bits.");
+        assert!(fraction_bits < 128, "There must be fewer than 128 fraction bits.");
+        Self {
+            word_length,
+            fraction_bits,
+            rounding: Rounding::default(),
+            overflow: Overflow::default()
+        }
+    }
+
+    pub fn q15() -> Self
+    {
+        Self::new(16, 15)
+    }
+
+    pub fn q31() -> Self
+    {
+        Self::new(32, 31)
+    }
+
+    pub fn word_length(&self) -> u32
+    {
+        self.word_length
+    }
+
+    pub fn fraction_bits(&self) -> u32
+    {
+        self.fraction_bits
+    }
+
+    pub fn with_rounding(self, rounding: Rounding) -> Self
+    {
+        Self {
+            rounding,
+            ..self
+        }
+    }
+
+    pub fn with_overflow(self, overflow: Overflow) -> Self
+    {
+        Self {
+            overflow,
+            ..self
+        }
+    }
+
+    pub fn max_raw(&self) -> i64
+    {
+        ((1i128 << (self.word_length - 1)) - 1) as i64
+    }
+
+    pub fn min_raw(&self) -> i64
+    {
+        (-(1i128 << (self.word_length - 1))) as i64
+    }
+
+    /// The value of the least significant bit.
+    pub fn lsb<T>(&self) -> T
+    where
+        T: Float
+    {
+        T::from(2.0).unwrap().powi(-(self.fraction_bits as i32))
+    }
+
+    /// Quantizes a real value to a raw integer.
+    pub fn to_raw<T>(&self, x: T) -> i64
+    where
+        T: Float
+    {
+        let x = x*T::from(2.0).unwrap().powi(self.fraction_bits as i32);
+        let r = match self.rounding
+        {
+            Rounding::Nearest => x.round(),
+            Rounding::Convergent => {
+                let f = x.floor();
+                let d = x - f;
+                let half = T::from(0.5).unwrap();
+                let odd = (f/T::from(2.0).unwrap()).fract() != T::zero();
+                if d > half || (d == half && odd) {f + T::one()} else {f}
+            },
+            Rounding::Floor => x.floor(),
+            Rounding::Zero => x.trunc()
+        };
+        // Far out-of-range values are clamped before the cast, which is exact after saturation or wrapping to at most 64 bits.
+        let r = <i128 as NumCast>::from(r.max(T::from(-2f64.powi(100)).unwrap()).min(T::from(2f64.powi(100)).unwrap()))
+            .unwrap_or(0);
+        self.overflow(r)
+    }
+
+    pub fn from_raw<T>(&self, r: i64) -> T
+    where
+        T: Float
+    {
+        T::from(r).unwrap()*self.lsb()
+    }
+
+    /// Quantizes a real value, returning the nearest representable real value.
+    pub fn quantize<T>(&self, x: T) -> T
+    where
+        T: Float
+    {
+        self.from_raw(self.to_raw(x))
+    }
+
+    /// Shifts a wide accumulator right by `shift` bits, rounding, then brings it into range.
+    pub fn requantize(&self, acc: i128, shift: u32) -> i64
+    {
+        if shift == 0
+        {
+            return self.overflow(acc)
+        }
+        let half = 1i128 << (shift - 1);
+        let floor = acc >> shift;
+        let rem = acc - (floor << shift);
+        let r = match self.rounding
+        {
+            Rounding::Floor => floor,
+            Rounding::Zero => if acc < 0 && rem != 0 {floor + 1} else {floor},
+            Rounding::Nearest => if rem > half || (rem == half && acc >= 0) {floor + 1} else {floor},
+            Rounding::Convergent => if rem > half || (rem == half && floor & 1 == 1) {floor + 1} else {floor}
+        };
+        self.overflow(r)
+    }
+
+    fn overflow(&self, r: i128) -> i64
+    {
+        let (min, max) = (self.min_raw() as i128, self.max_raw() as i128);
+        match self.overflow
+        {
+            Overflow::Saturate => r.clamp(min, max) as i64,
+            Overflow::Wrap => ((r - min).rem_euclid(1i128 << self.word_length) + min) as i64
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::operations::quantization::{FixedPoint, Overflow, Rounding};
+
+    #[test]
+    fn rounding_and_overflow()
+    {
+        let q = FixedPoint::new(8, 4);
+        assert_eq!(q.to_raw(1.03), 16);
+        assert_eq!(q.to_raw(-2.5/16.0), -3);
+        assert_eq!(q.with_rounding(Rounding::Convergent).to_raw(-2.5/16.0), -2);
+        assert_eq!(q.with_rounding(Rounding::Floor).to_raw(-2.5/16.0), -3);
+        assert_eq!(q.with_rounding(Rounding::Zero).to_raw(-2.5/16.0), -2);
+
+        assert_eq!(q.to_raw(100.0), 127);
+        assert_eq!(q.with_overflow(Overflow::Wrap).to_raw(8.0), -128);
+        assert_eq!(q.quantize(0.3), 5.0/16.0);
+        assert_eq!((q.word_length(), q.fraction_bits()), (8, 4));
+
+        // Requantizing agrees with quantizing the real value.
+        for rounding in [Rounding::Nearest, Rounding::Convergent, Rounding::Floor, Rounding::Zero]
+        {
+            let q = q.with_rounding(rounding);
+            for acc in -600i128..600
+            {
+                assert_eq!(q.requantize(acc, 3), q.to_raw(acc as f64/128.0), "{rounding:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use core::ops::Deref;
+
+use num::Float;
+use option_trait::Maybe;
+
+use crate::{operations::filtering::normalize_tf, quantities::{MaybeList, MaybeOwnedList}, systems::{Sos, Tf}};
+
+moddef::moddef!(
+    flat(pub) mod {
+        fixed_point,
+        fixed_point_filter,
+        qreport,
+        quantize
+    }
+);
+
+/// The normalized coefficients of a transfer function, in powers of `z⁻¹` with `a₀ = 1`.
+fn tf_sections<T, B, A>(tf: &Tf<T, B, A>) -> Vec<(Vec<T>, Vec<T>)>
+where
+    T: Float,
+    B: MaybeList<T>,
+    A: MaybeList<T>
+{
+    let b = tf.b.to_vec_option()
+        .unwrap_or_else(|| vec![T::one()]);
+    let a = tf.a.to_vec_option()
+        .unwrap_or_else(|| vec![T::one()]);
+    vec![normalize_tf(&b, &a)]
+}
+
+/// The normalized coefficients of each second-order section, in powers of `z⁻¹` with `a₀ = 1`.
+fn sos_sections<T, B, A, S>(sos: &Sos<T, B, A, S>) -> Vec<(Vec<T>, Vec<T>)>
+where
+    T: Float,
+    B: Maybe<[T; 3]> + MaybeOwnedList<T>,
+    A: Maybe<[T; 3]> + MaybeOwnedList<T>,
+    S: MaybeList<Tf<T, B, A>>
+{
+    let one = T::one();
+    let zero = T::zero();
+
+    sos.sos.deref()
+        .as_view_slice_option()
+        .unwrap_or(&[])
+        .iter()
+        .map(|sos| {
+            let b = sos.b.deref()
+                .as_option()
+                .copied()
+                .unwrap_or([zero, zero, one]);
+            let a = sos.a.deref()
+                .as_option()
+                .copied()
+                .unwrap_or([zero, zero, one]);
+            let (mut b, mut a) = normalize_tf(&b, &a);
+            b.resize(3, zero);
+            a.resize(3, zero);
+            (b, a)
+        }).collect()
+}
\ No newline at end of file
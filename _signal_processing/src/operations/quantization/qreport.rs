@@ -0,0 +1,317 @@
+use num::{traits::FloatConst, Complex, Float, NumCast};
+use option_trait::Maybe;
+
+use crate::{analysis::control::{poly, roots}, operations::quantization::{sos_sections, tf_sections, FixedPoint}, quantities::{MaybeList, MaybeOwnedList}, systems::{Sos, Tf, Zpk}, util::{Lapack, LinalgError}, System};
+
+/// What quantizing the coefficients of a filter does to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantizationReport<T>
+where
+    T: Float
+{
+    /// The distance from each zero to the nearest zero of the quantized filter.
+    pub zero_displacement: Vec<T>,
+    /// The distance from each pole to the nearest pole of the quantized filter.
+    pub pole_displacement: Vec<T>,
+    /// The largest absolute deviation of the quantized frequency response, on a grid over `[0, π]`.
+    pub max_deviation: T,
+    /// Whether all quantized poles are strictly inside the unit circle.
+    pub stable: bool,
+    /// The number of coefficients outside the range of the format.
+    pub overflows: usize,
+    /// The output round-off noise power, relative to that of a single rounding.
+    ///
+    /// Assumes each section rounds once after accumulating, as in direct form I, so that its noise is shaped by its own poles and every later section.
+    /// A zero-pole-gain system is taken to be a single section.
+    /// Infinite if the quantized filter is unstable.
+    pub noise_gain: T
+}
+
+impl<T> QuantizationReport<T>
+where
+    T: Float
+{
+    /// The output round-off noise power when rounding signals to the given format, which is `noise_gain·q²/12`.
+    pub fn noise_power(&self, data: FixedPoint) -> T
+    {
+        let q: T = data.lsb();
+        self.noise_gain*q*q/T::from(12.0).unwrap()
+    }
+}
+
+/// Analyzes the effect of quantizing the coefficients of a SISO filter to a fixed-point format, as done by [`Quantize`](crate::operations::quantization::Quantize).
+pub trait QReport: System
+where
+    Self::Set: Float
+{
+    fn qreport(&self, format: FixedPoint) -> Result<QuantizationReport<Self::Set>, LinalgError>;
+}
+
+impl<T, B, A> QReport for Tf<T, B, A>
+where
+    T: Float + FloatConst,
+    Complex<T>: Lapack<Real = T>,
+    B: MaybeList<T>,
+    A: MaybeList<T>
+{
+    fn qreport(&self, format: FixedPoint) -> Result<QuantizationReport<T>, LinalgError>
+    {
+        report(tf_sections(self), format)
+    }
+}
+
+impl<T, B, A, S> QReport for Sos<T, B, A, S>
+where
+    T: Float + FloatConst,
+    Complex<T>: Lapack<Real = T>,
+    B: Maybe<[T; 3]> + MaybeOwnedList<T>,
+    A: Maybe<[T; 3]> + MaybeOwnedList<T>,
+    S: MaybeList<Tf<T, B, A>>
+{
+    fn qreport(&self, format: FixedPoint) -> Result<QuantizationReport<T>, LinalgError>
+    {
+        report(sos_sections(self), format)
+    }
+}
+
+impl<T, Z, P> QReport for Zpk<Complex<T>, Z, P, T>
+where
+    T: Float + FloatConst,
+    Z: MaybeList<Complex<T>>,
+    P: MaybeList<Complex<T>>,
+    Self: System<Set = T>
+{
+    fn qreport(&self, format: FixedPoint) -> Result<QuantizationReport<T>, LinalgError>
+    {
+        Ok(zpk_report(self.zeros(), self.poles(), self.k, format))
+    }
+}
+
+fn report<T>(sections: Vec<(Vec<T>, Vec<T>)>, format: FixedPoint) -> Result<QuantizationReport<T>, LinalgError>
+where
+    T: Float + FloatConst,
+    Complex<T>: Lapack<Real = T>
+{
+    let overflows = sections.iter()
+        .flat_map(|(b, a)| b.iter().chain(a.iter()))
+        .filter(|&&c| is_overflow(c, format))
+        .count();
+
+    let quantized: Vec<(Vec<T>, Vec<T>)> = sections.iter()
+        .map(|(b, a)| (
+            b.iter().map(|&b| format.quantize(b)).collect(),
+            a.iter().map(|&a| format.quantize(a)).collect()
+        )).collect();
+
+    let all_roots = |sections: &[(Vec<T>, Vec<T>)], numerator: bool| -> Result<Vec<Complex<T>>, LinalgError> {
+        let mut r = vec![];
+        for (b, a) in sections
+        {
+            let c = if numerator {b} else {a};
+            let c: Vec<Complex<T>> = c.iter()
+                .map(|&c| Complex::from(c))
+                .collect();
+            r.append(&mut roots(&c)?);
+        }
+        Ok(r)
+    };
+    let (z, zq) = (all_roots(&sections, true)?, all_roots(&quantized, true)?);
+    let (p, pq) = (all_roots(&sections, false)?, all_roots(&quantized, false)?);
+
+    let stable = is_stable(&pq);
+    let h = |sections: &[(Vec<T>, Vec<T>)], zi: Complex<T>| sections.iter()
+        .map(|(b, a)| eval(b, zi)/eval(a, zi))
+        .fold(Complex::from(T::one()), |h, s| h*s);
+
+    Ok(QuantizationReport {
+        zero_displacement: displacement(&z, &zq),
+        pole_displacement: displacement(&p, &pq),
+        max_deviation: max_deviation(|zi| h(&sections, zi), |zi| h(&quantized, zi)),
+        stable,
+        overflows,
+        noise_gain: noise_gain(&quantized, &pq, stable)
+    })
+}
+
+fn zpk_report<T>(z: &[Complex<T>], p: &[Complex<T>], k: T, format: FixedPoint) -> QuantizationReport<T>
+where
+    T: Float + FloatConst
+{
+    let overflows = z.iter()
+        .chain(p.iter())
+        .flat_map(|z| [z.re, z.im])
+        .chain([k])
+        .filter(|&c| is_overflow(c, format))
+        .count();
+
+    let q = |z: &Complex<T>| Complex::new(format.quantize(z.re), format.quantize(z.im));
+    let zq: Vec<_> = z.iter().map(q).collect();
+    let pq: Vec<_> = p.iter().map(q).collect();
+    let kq = format.quantize(k);
+
+    let stable = is_stable(&pq);
+    let h = |z: &[Complex<T>], p: &[Complex<T>], k: T, zi: Complex<T>| {
+        let s = zi.inv();
+        z.iter().fold(Complex::new(k, T::zero()), |h, &z| h*(s - z))/p.iter().fold(Complex::new(T::one(), T::zero()), |h, &p| h*(s - p))
+    };
+
+    // Realized as a single section, with the denominator expanded from the quantized poles.
+    let a: Vec<T> = poly(&pq).into_iter()
+        .map(|a| a.re)
+        .collect();
+
+    QuantizationReport {
+        zero_displacement: displacement(z, &zq),
+        pole_displacement: displacement(p, &pq),
+        max_deviation: max_deviation(|zi| h(z, p, k, zi), |zi| h(&zq, &pq, kq, zi)),
+        stable,
+        overflows,
+        noise_gain: noise_gain(&[(vec![T::one()], a)], &pq, stable)
+    }
+}
+
+/// Whether a coefficient is outside the range of the format after rounding.
+fn is_overflow<T>(c: T, format: FixedPoint) -> bool
+where
+    T: Float
+{
+    let scale = T::from(2.0).unwrap().powi(format.fraction_bits() as i32);
+    let (min, max) = (T::from(format.min_raw()).unwrap(), T::from(format.max_raw()).unwrap());
+    let c = (c*scale).round();
+    c < min || c > max
+}
+
+fn is_stable<T>(p: &[Complex<T>]) -> bool
+where
+    T: Float
+{
+    p.iter()
+        .all(|p| p.norm() < T::one())
+}
+
+/// The largest absolute difference between two frequency responses, given as functions of `z⁻¹`, on a grid over `[0, π]`.
+fn max_deviation<T>(h: impl Fn(Complex<T>) -> Complex<T>, hq: impl Fn(Complex<T>) -> Complex<T>) -> T
+where
+    T: Float + FloatConst
+{
+    const N: usize = 512;
+    (0..N).map(|i| {
+            let zi = Complex::cis(-T::PI()*T::from(i).unwrap()/T::from(N - 1).unwrap());
+            (h(zi) - hq(zi)).norm()
+        }).fold(T::zero(), T::max)
+}
+
+/// The sum of the energies of the impulse responses from each section's rounding point to the output.
+fn noise_gain<T>(quantized: &[(Vec<T>, Vec<T>)], pq: &[Complex<T>], stable: bool) -> T
+where
+    T: Float
+{
+    if !stable
+    {
+        return T::infinity()
+    }
+
+    // Long enough for the slowest pole to decay below the epsilon.
+    let r = pq.iter()
+        .map(|p| p.norm())
+        .fold(T::zero(), T::max);
+    let n = <usize as NumCast>::from(T::epsilon().ln()/r.ln())
+        .unwrap_or(usize::MAX)
+        .clamp(64, 1 << 16);
+    (0..quantized.len()).map(|i| {
+            let mut h = vec![T::zero(); n];
+            h[0] = T::one();
+            filter(&[T::one()], &quantized[i].1, &mut h);
+            for (b, a) in quantized[i + 1..].iter()
+            {
+                filter(b, a, &mut h)
+            }
+            h.iter()
+                .map(|&h| h*h)
+                .fold(T::zero(), |a, b| a + b)
+        }).fold(T::zero(), |a, b| a + b)
+}
+
+/// A polynomial in `z⁻¹`, evaluated at `z⁻¹ = zi`.
+fn eval<T>(c: &[T], zi: Complex<T>) -> Complex<T>
+where
+    T: Float
+{
+    c.iter()
+        .rev()
+        .fold(Complex::from(T::zero()), |y, &c| y*zi + c)
+}
+
+/// Filters `x` in place in direct form I, with `a₀ = 1`.
+fn filter<T>(b: &[T], a: &[T], x: &mut [T])
+where
+    T: Float
+{
+    let xs = x.to_vec();
+    for n in 0..x.len()
+    {
+        let mut y = T::zero();
+        for (k, &b) in b.iter().enumerate().take(n + 1)
+        {
+            y = y + b*xs[n - k]
+        }
+        for (k, &a) in a.iter().enumerate().skip(1).take(n)
+        {
+            y = y - a*x[n - k]
+        }
+        x[n] = y;
+    }
+}
+
+/// Matches each root to its nearest counterpart, without reuse, and returns the distances.
+fn displacement<T>(r: &[Complex<T>], rq: &[Complex<T>]) -> Vec<T>
+where
+    T: Float
+{
+    let mut rq = rq.to_vec();
+    r.iter()
+        .map(|&r| {
+            let i = rq.iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| (**a - r).norm().total_cmp(&(**b - r).norm()))
+                .map(|(i, _)| i);
+            i.map(|i| (rq.swap_remove(i) - r).norm())
+                .unwrap_or_else(T::infinity)
+        }).collect()
+}
+
+#[cfg(test)]
+mod test
+{
+    use num::Complex;
+
+    use crate::{generators::filter::{Butter, FilterGenPlane, FilterGenType}, operations::quantization::{FixedPoint, QReport}, systems::{Sos, Tf, Zpk}, transforms::{filter::{SosOrder, SosScale, Zp2Sos}, system::ToTf}};
+
+    #[test]
+    fn sections_beat_direct_form()
+    {
+        let h: Zpk<Complex<f64>, Vec<_>, Vec<_>, f64> = Zpk::butter(8, [0.05], FilterGenType::LowPass, FilterGenPlane::Z { sampling_frequency: None })
+            .unwrap();
+        let tf: Tf<f64, Vec<_>, Vec<_>> = h.clone().to_tf((), ());
+        let (sos, _): (Sos<f64, _, _, _>, _) = h.zp2sos(SosOrder::Up, SosScale::LInf).unwrap();
+
+        // High-order direct form coefficients do not even fit in Q3.12, and the poles scatter.
+        let q = FixedPoint::new(16, 12);
+        let r_tf = tf.qreport(q).unwrap();
+        let r_sos = sos.qreport(q).unwrap();
+        assert!(r_tf.overflows > 0);
+        assert!(r_sos.overflows == 0 && r_sos.stable);
+
+        let max = |d: &[f64]| d.iter().copied().fold(0.0, f64::max);
+        assert!(max(&r_sos.pole_displacement) < max(&r_tf.pole_displacement));
+        assert!(max(&r_sos.pole_displacement) < 1e-2);
+        assert!(r_sos.max_deviation < 0.1);
+        assert!(r_sos.noise_gain.is_finite() && r_sos.noise_power(FixedPoint::q15()) > 0.0);
+
+        // Quantizing the roots directly keeps them close.
+        let r_zpk = h.qreport(q).unwrap();
+        assert!(r_zpk.overflows == 0 && r_zpk.stable);
+        assert!(max(&r_zpk.pole_displacement) < 1e-3);
+        assert!(r_zpk.noise_gain.is_finite());
+    }
+}
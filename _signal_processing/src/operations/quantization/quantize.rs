@@ -0,0 +1,95 @@
+use num::{Complex, Float};
+use option_trait::Maybe;
+
+use crate::{operations::quantization::{sos_sections, tf_sections, FixedPoint}, quantities::{MaybeList, MaybeOwnedList}, systems::{Sos, Tf, Zpk}, transforms::system::ToZpk, System};
+
+/// Quantizes the coefficients of a system to a fixed-point format.
+///
+/// Transfer functions and second-order sections are normalized to `a₀ = 1` first, since that coefficient is implicit in a fixed-point realization.
+/// For zero-pole-gain systems, the real and imaginary parts of each zero and pole are quantized, as well as the gain.
+pub trait Quantize: System
+{
+    type Output: System<Set = Self::Set>;
+
+    fn quantize(self, format: FixedPoint) -> Self::Output;
+}
+
+impl<T, B, A> Quantize for Tf<T, B, A>
+where
+    T: Float,
+    B: MaybeList<T>,
+    A: MaybeList<T>
+{
+    type Output = Tf<T, Vec<T>, Vec<T>>;
+
+    fn quantize(self, format: FixedPoint) -> Self::Output
+    {
+        let (b, a) = tf_sections(&self).pop().unwrap();
+        Tf::new(
+            b.into_iter().map(|b| format.quantize(b)).collect(),
+            a.into_iter().map(|a| format.quantize(a)).collect()
+        )
+    }
+}
+
+impl<T, B, A, S> Quantize for Sos<T, B, A, S>
+where
+    T: Float,
+    B: Maybe<[T; 3]> + MaybeOwnedList<T>,
+    A: Maybe<[T; 3]> + MaybeOwnedList<T>,
+    S: MaybeList<Tf<T, B, A>>
+{
+    type Output = Sos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>>;
+
+    fn quantize(self, format: FixedPoint) -> Self::Output
+    {
+        Sos::new(sos_sections(&self).into_iter()
+            .map(|(b, a)| Tf::new(
+                core::array::from_fn(|i| format.quantize(b[i])),
+                core::array::from_fn(|i| format.quantize(a[i]))
+            )).collect()
+        )
+    }
+}
+
+impl<T, Z, P> Quantize for Zpk<Complex<T>, Z, P, T>
+where
+    T: Float,
+    Z: MaybeList<Complex<T>>,
+    P: MaybeList<Complex<T>>,
+    Self: ToZpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T, (), ()> + System<Set = T>,
+    Zpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T>: System<Set = T>
+{
+    type Output = Zpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T>;
+
+    fn quantize(self, format: FixedPoint) -> Self::Output
+    {
+        let Zpk {z, p, k}: Self::Output = self.to_zpk((), ());
+        let q = |z: Complex<T>| Complex::new(format.quantize(z.re), format.quantize(z.im));
+        Zpk::new(
+            z.into_inner().into_iter().map(q).collect(),
+            p.into_inner().into_iter().map(q).collect(),
+            format.quantize(k)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{operations::quantization::{FixedPoint, Quantize}, systems::{Sos, Tf}};
+
+    #[test]
+    fn coefficients()
+    {
+        let q = FixedPoint::new(16, 14);
+        let h = Tf::new(vec![2.0, 0.2, 0.1], vec![2.0, -1.0, 0.5]);
+        let hq = h.quantize(q);
+        assert_eq!(*hq.a, vec![1.0, -0.5, 0.25]);
+        assert_eq!(*hq.b, vec![1.0, q.quantize(0.1), q.quantize(0.05)]);
+
+        // Saturation at the edge of the range [-2, 2).
+        let sos = Sos::new(vec![Tf::new([1.0, 2.0, 1.0], [1.0, -1.9, 0.95])]).quantize(q);
+        assert_eq!(sos.sos.iter().next().unwrap().b[1], 2.0 - 1.0/16384.0);
+    }
+}
@@ -0,0 +1,352 @@
+use core::{any::Any, ops::Mul};
+
+use ndarray::{s, Array2};
+use num::{complex::ComplexFloat, Complex, Float, NumCast, One, Zero};
+use thiserror::Error;
+
+use crate::{quantities::MaybeList, systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix, Tf, Zpk}, transforms::system::{ToSs, ToTf, ToZpk}, util::{self, Inverse, Lapack, LinalgError}, System};
+
+/// Methods of discretizing a continuous-time system.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum C2dMethod<T>
+{
+    /// Zero-order hold. The input is held constant between samples.
+    Zoh,
+    /// First-order hold. The input is interpolated linearly between samples.
+    Foh,
+    /// Impulse invariance. The impulse response of the discrete system is the sampled impulse response of the continuous system, scaled by the sampling period.
+    Impulse,
+    /// The bilinear transform, optionally prewarped so that the frequency responses match exactly at the given angular frequency.
+    Tustin
+    {
+        prewarp: Option<T>
+    },
+    /// Matched pole-zero mapping. Poles and zeros are mapped by `z = eˢᵀ`, and zeros at infinity are put at `z = -1`.
+    Matched
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum C2dError
+{
+    #[error("Non causal transfer function, i.e. it contains one or more poles at infinity.")]
+    NonCausal,
+    #[error("Prewarp frequency must be between zero and the Nyquist frequency.")]
+    InvalidPrewarp,
+    #[error("Matched pole-zero mapping is only defined for transfer functions and zero-pole-gain systems.")]
+    MatchedNotSupported,
+    #[error(transparent)]
+    Linalg(#[from] LinalgError)
+}
+
+/// Converts a continuous-time system into a discrete-time system.
+pub trait C2d: System
+{
+    type Output: Sized;
+
+    fn c2d(
+        self,
+        sampling_frequency: <Self::Set as ComplexFloat>::Real,
+        method: C2dMethod<<Self::Set as ComplexFloat>::Real>
+    ) -> Result<Self::Output, C2dError>;
+}
+
+impl<T, A, B, C, D> C2d for Ss<T, A, B, C, D>
+where
+    T: Lapack,
+    A: SsAMatrix<T, B, C, D>,
+    B: SsBMatrix<T, A, C, D>,
+    C: SsCMatrix<T, A, B, D>,
+    D: SsDMatrix<T, A, B, C>,
+    Self: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    type Output = Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>;
+
+    fn c2d(self, sampling_frequency: T::Real, method: C2dMethod<T::Real>) -> Result<Self::Output, C2dError>
+    {
+        let Ss {a, b, c, d, ..} = self.to_ss();
+        let t = sampling_frequency.recip();
+        let n = a.nrows();
+        let p = b.ncols();
+
+        match method
+        {
+            C2dMethod::Zoh => {
+                // exp([A B; 0 0]T) = [Ad Bd; 0 I]
+                let m = Array2::from_shape_fn((n + p, n + p), |(i, j)| if i >= n
+                {
+                    T::zero()
+                }
+                else if j < n
+                {
+                    a[(i, j)]*t.into()
+                }
+                else
+                {
+                    b[(i, j - n)]*t.into()
+                });
+                let e = util::expm(m)?;
+
+                Ok(Ss::new(
+                    e.slice(s![..n, ..n]).to_owned(),
+                    e.slice(s![..n, n..]).to_owned(),
+                    c,
+                    d
+                ))
+            },
+            C2dMethod::Foh => {
+                // exp([A B 0; 0 0 I/T; 0 0 0]T) = [Φ Γ₁ Λ; 0 I I; 0 0 I]
+                let m = Array2::from_shape_fn((n + 2*p, n + 2*p), |(i, j)| if i < n
+                {
+                    if j < n
+                    {
+                        a[(i, j)]*t.into()
+                    }
+                    else if j < n + p
+                    {
+                        b[(i, j - n)]*t.into()
+                    }
+                    else
+                    {
+                        T::zero()
+                    }
+                }
+                else if i < n + p && j == i + p
+                {
+                    T::one()
+                }
+                else
+                {
+                    T::zero()
+                });
+                let e = util::expm(m)?;
+                let phi = e.slice(s![..n, ..n]).to_owned();
+                let gamma1 = e.slice(s![..n, n..n + p]).to_owned();
+                let lambda = e.slice(s![..n, n + p..]).to_owned();
+
+                // With the state ξ = x - Λu, the non-causal dependency on the next input disappears.
+                let bd = gamma1 + (&phi - &Array2::eye(n)).dot(&lambda);
+                let dd = d + c.dot(&lambda);
+
+                Ok(Ss::new(phi, bd, c, dd))
+            },
+            C2dMethod::Impulse => {
+                let phi = util::expm(a.map(|&a| a*t.into()))?;
+                let bd = phi.dot(&b).map(|&b| b*t.into());
+                let dd = (c.dot(&b) + &d).map(|&d| d*t.into());
+
+                Ok(Ss::new(phi, bd, c, dd))
+            },
+            C2dMethod::Tustin {prewarp} => {
+                let k = tustin_gain(sampling_frequency, prewarp)
+                    .ok_or(C2dError::InvalidPrewarp)?;
+                let tk = (T::Real::one() + T::Real::one())/k;
+                let sqrt_tk = tk.sqrt();
+
+                // s = k(z - 1)/(z + 1), with the symmetric realization
+                let m = (Array2::eye(n) - a.map(|&a| a/k.into())).inv()?;
+                let ad = m.dot(&(Array2::eye(n) + a.map(|&a| a/k.into())));
+                let mb = m.dot(&b);
+                let dd = d + c.dot(&mb).map(|&x| x/k.into());
+                let bd = mb.map(|&x| x*sqrt_tk.into());
+                let cd = c.dot(&m).map(|&x| x*sqrt_tk.into());
+
+                Ok(Ss::new(ad, bd, cd, dd))
+            },
+            C2dMethod::Matched => Err(C2dError::MatchedNotSupported)
+        }
+    }
+}
+
+impl<T, B, A> C2d for Tf<T, B, A>
+where
+    T: Lapack,
+    B: MaybeList<T>,
+    A: MaybeList<T>,
+    Self: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>> + ToZpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, T, (), ()> + System<Set = T>,
+    Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>: C2d<Output = Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>> + ToTf<T, Vec<Vec<T>>, Vec<T>, usize, ()> + System<Set = T>,
+    Zpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, T>: C2d<Output = Zpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, T>> + System<Set = T>,
+    Zpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, T>: ToTf<T, Vec<T>, Vec<T>, (), ()>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    type Output = Tf<T, Vec<T>, Vec<T>>;
+
+    fn c2d(self, sampling_frequency: T::Real, method: C2dMethod<T::Real>) -> Result<Self::Output, C2dError>
+    {
+        if let C2dMethod::Matched = method
+        {
+            return Ok(self.to_zpk((), ())
+                .c2d(sampling_frequency, method)?
+                .to_tf((), ()))
+        }
+
+        let Tf {b, a} = self.to_ss()
+            .c2d(sampling_frequency, method)?
+            .to_tf(0, ());
+        Ok(Tf::new(b.into_inner().into_iter().next().unwrap_or_default(), a.into_inner()))
+    }
+}
+
+impl<T, Z, P, K> C2d for Zpk<T, Z, P, K>
+where
+    T: ComplexFloat,
+    K: Lapack<Real = T::Real> + Mul<T::Real, Output = K>,
+    Z: MaybeList<T>,
+    P: MaybeList<T>,
+    Self: ToZpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, K, (), ()> + ToSs<K, Array2<K>, Array2<K>, Array2<K>, Array2<K>> + System<Set = K>,
+    Ss<K, Array2<K>, Array2<K>, Array2<K>, Array2<K>>: C2d<Output = Ss<K, Array2<K>, Array2<K>, Array2<K>, Array2<K>>> + ToZpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, K, usize, usize> + System<Set = K>,
+    Array2<K>: SsAMatrix<K, Array2<K>, Array2<K>, Array2<K>> + SsBMatrix<K, Array2<K>, Array2<K>, Array2<K>> + SsCMatrix<K, Array2<K>, Array2<K>, Array2<K>> + SsDMatrix<K, Array2<K>, Array2<K>, Array2<K>>
+{
+    type Output = Zpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, K>;
+
+    fn c2d(self, sampling_frequency: T::Real, method: C2dMethod<T::Real>) -> Result<Self::Output, C2dError>
+    {
+        if let C2dMethod::Matched = method
+        {
+            let Zpk::<_, Vec<_>, Vec<_>, K> {z, p, k} = self.to_zpk((), ());
+            let (z, p) = (z.into_inner(), p.into_inner());
+            if z.len() > p.len()
+            {
+                return Err(C2dError::NonCausal)
+            }
+
+            let t = sampling_frequency.recip();
+            let zd: Vec<_> = z.iter()
+                .map(|&z| (z*t).exp())
+                .chain(core::iter::repeat_n(-Complex::one(), p.len() - z.len()))
+                .collect();
+            let pd: Vec<_> = p.iter()
+                .map(|&p| (p*t).exp())
+                .collect();
+
+            let gain = matched_gain(&z, &p, &zd, &pd, sampling_frequency);
+            let mut kd = k;
+            if let Some(kd) = <dyn Any>::downcast_mut::<Complex<T::Real>>(&mut kd as &mut dyn Any)
+            {
+                *kd = *kd*gain;
+            }
+            else
+            {
+                kd = kd*gain.re;
+            }
+
+            return Ok(Zpk::new(zd, pd, kd))
+        }
+
+        Ok(self.to_ss()
+            .c2d(sampling_frequency, method)?
+            .to_zpk(0, 0))
+    }
+}
+
+/// The constant `k` in the substitution `s = k(z - 1)/(z + 1)`.
+///
+/// This is `2fₛ`, or `ω/tan(ω/2fₛ)` if prewarped at the angular frequency `ω`, which must be below the Nyquist frequency.
+pub(crate) fn tustin_gain<R>(sampling_frequency: R, prewarp: Option<R>) -> Option<R>
+where
+    R: Float
+{
+    let two = R::one() + R::one();
+    match prewarp
+    {
+        None => Some(two*sampling_frequency),
+        Some(w) => {
+            let nyquist = R::from(core::f64::consts::PI).unwrap()*sampling_frequency;
+            if !(w > R::zero() && w < nyquist)
+            {
+                return None
+            }
+            Some(w/(w/(two*sampling_frequency)).tan())
+        }
+    }
+}
+
+/// The factor which makes the gain of the mapped system equal that of the original system at DC.
+///
+/// If there are poles or zeros at DC, the gains are matched at a tenth of the Nyquist frequency instead.
+pub(crate) fn matched_gain<R>(z: &[Complex<R>], p: &[Complex<R>], zd: &[Complex<R>], pd: &[Complex<R>], sampling_frequency: R) -> Complex<R>
+where
+    R: Float
+{
+    let eps = R::epsilon().sqrt()*sampling_frequency;
+    let s0 = if z.iter()
+        .chain(p.iter())
+        .any(|r| r.norm() <= eps)
+    {
+        Complex::new(R::zero(), R::from(core::f64::consts::PI/10.0).unwrap()*sampling_frequency)
+    }
+    else
+    {
+        Complex::zero()
+    };
+    let z0 = (s0/sampling_frequency).exp();
+
+    let hc = z.iter().map(|&z| s0 - z).fold(Complex::one(), Mul::mul)
+        /p.iter().map(|&p| s0 - p).fold(Complex::one(), Mul::mul);
+    let hd = zd.iter().map(|&z| z0 - z).fold(Complex::one(), Mul::mul)
+        /pd.iter().map(|&p| z0 - p).fold(Complex::one(), Mul::mul);
+    hc/hd
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::{array, Array2};
+    use num::Complex;
+
+    use crate::{systems::Ss, transforms::domain::{C2d, C2dMethod}};
+
+    type Ss1 = Ss<f64, Array2<f64>, Array2<f64>, Array2<f64>, Array2<f64>>;
+
+    fn first_order() -> Ss1
+    {
+        // 1/(s + 1)
+        Ss::new(array![[-1.0]], array![[1.0]], array![[1.0]], array![[0.0]])
+    }
+
+    fn eval(h: &Ss1, z: Complex<f64>) -> Complex<f64>
+    {
+        h.c[(0, 0)]*h.b[(0, 0)]/(z - h.a[(0, 0)]) + h.d[(0, 0)]
+    }
+
+    #[test]
+    fn zoh()
+    {
+        let fs = 10.0;
+        let h = first_order().c2d(fs, C2dMethod::Zoh).unwrap();
+
+        let e = (-1.0/fs).exp();
+        assert!((h.a[(0, 0)] - e).abs() < 1e-12);
+        assert!((h.b[(0, 0)] - (1.0 - e)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn foh_and_impulse()
+    {
+        let fs = 10.0;
+        let one = Complex::new(1.0, 0.0);
+
+        // First-order hold preserves the DC gain.
+        let h = first_order().c2d(fs, C2dMethod::Foh).unwrap();
+        assert!((eval(&h, one) - 1.0).norm() < 1e-12);
+
+        // The DC gain of impulse invariance is the sum of the sampled impulse response.
+        let h = first_order().c2d(fs, C2dMethod::Impulse).unwrap();
+        let t = 1.0/fs;
+        assert!((eval(&h, one) - t/(1.0 - (-t).exp())).norm() < 1e-12);
+    }
+
+    #[test]
+    fn tustin_prewarp()
+    {
+        let fs = 10.0;
+        let w = 20.0;
+        let h = first_order().c2d(fs, C2dMethod::Tustin {prewarp: Some(w)}).unwrap();
+
+        let hc = 1.0/(Complex::new(0.0, w) + 1.0);
+        let hd = eval(&h, Complex::new(0.0, w/fs).exp());
+        assert!((hc - hd).norm() < 1e-12);
+
+        assert!(first_order().c2d(fs, C2dMethod::Tustin {prewarp: Some(100.0)}).is_err());
+    }
+}
@@ -0,0 +1,279 @@
+use core::{any::Any, ops::{Div, Mul}};
+
+use ndarray::{s, Array2};
+use num::{complex::ComplexFloat, Complex, Float, One, Zero};
+use thiserror::Error;
+
+use crate::{quantities::MaybeList, systems::{Ss, SsAMatrix, SsBMatrix, SsCMatrix, SsDMatrix, Tf, Zpk}, transforms::{domain, system::{ToSs, ToTf, ToZpk}}, util::{self, Inverse, Lapack, LinalgError}, System};
+
+/// Methods of converting a discrete-time system into a continuous-time system.
+///
+/// These are the inverses of the corresponding [C2dMethod](crate::transforms::domain::C2dMethod)s.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum D2cMethod<T>
+{
+    /// Inverse of zero-order hold discretization.
+    Zoh,
+    /// The inverse bilinear transform, optionally prewarped at the given angular frequency.
+    Tustin
+    {
+        prewarp: Option<T>
+    },
+    /// Matched pole-zero mapping by `s = ln(z)/T`. Zeros at `z = -1` are taken to be zeros at infinity.
+    Matched
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum D2cError
+{
+    #[error("Zeros or poles at the origin have no continuous-time equivalent.")]
+    RootAtOrigin,
+    #[error("Prewarp frequency must be between zero and the Nyquist frequency.")]
+    InvalidPrewarp,
+    #[error("Matched pole-zero mapping is only defined for transfer functions and zero-pole-gain systems.")]
+    MatchedNotSupported,
+    #[error(transparent)]
+    Linalg(#[from] LinalgError)
+}
+
+/// Converts a discrete-time system into a continuous-time system.
+pub trait D2c: System
+{
+    type Output: Sized;
+
+    fn d2c(
+        self,
+        sampling_frequency: <Self::Set as ComplexFloat>::Real,
+        method: D2cMethod<<Self::Set as ComplexFloat>::Real>
+    ) -> Result<Self::Output, D2cError>;
+}
+
+impl<T, A, B, C, D> D2c for Ss<T, A, B, C, D>
+where
+    T: Lapack,
+    A: SsAMatrix<T, B, C, D>,
+    B: SsBMatrix<T, A, C, D>,
+    C: SsCMatrix<T, A, B, D>,
+    D: SsDMatrix<T, A, B, C>,
+    Self: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    type Output = Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>;
+
+    fn d2c(self, sampling_frequency: T::Real, method: D2cMethod<T::Real>) -> Result<Self::Output, D2cError>
+    {
+        let Ss {a, b, c, d, ..} = self.to_ss();
+        let n = a.nrows();
+        let p = b.ncols();
+
+        match method
+        {
+            D2cMethod::Zoh => {
+                // log([Ad Bd; 0 I])/T = [A B; 0 0]
+                let m = Array2::from_shape_fn((n + p, n + p), |(i, j)| if i < n
+                {
+                    if j < n {a[(i, j)]} else {b[(i, j - n)]}
+                }
+                else if i == j
+                {
+                    T::one()
+                }
+                else
+                {
+                    T::zero()
+                });
+                let l = util::logm(m)?
+                    .map(|&l| l*sampling_frequency.into());
+
+                Ok(Ss::new(
+                    l.slice(s![..n, ..n]).to_owned(),
+                    l.slice(s![..n, n..]).to_owned(),
+                    c,
+                    d
+                ))
+            },
+            D2cMethod::Tustin {prewarp} => {
+                let k = domain::tustin_gain(sampling_frequency, prewarp)
+                    .ok_or(D2cError::InvalidPrewarp)?;
+                let sqrt_2k: T = (k + k).sqrt().into();
+
+                // z = (k + s)/(k - s), inverting the symmetric realization
+                let m = (&a + &Array2::eye(n)).inv()?;
+                let ac = m.dot(&(&a - &Array2::eye(n))).map(|&x| x*k.into());
+                let mb = m.dot(&b);
+                let dc = d - c.dot(&mb);
+                let bc = mb.map(|&x| x*sqrt_2k);
+                let cc = c.dot(&m).map(|&x| x*sqrt_2k);
+
+                Ok(Ss::new(ac, bc, cc, dc))
+            },
+            D2cMethod::Matched => Err(D2cError::MatchedNotSupported)
+        }
+    }
+}
+
+impl<T, B, A> D2c for Tf<T, B, A>
+where
+    T: Lapack,
+    B: MaybeList<T>,
+    A: MaybeList<T>,
+    Self: ToSs<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>> + ToZpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, T, (), ()> + System<Set = T>,
+    Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>: D2c<Output = Ss<T, Array2<T>, Array2<T>, Array2<T>, Array2<T>>> + ToTf<T, Vec<Vec<T>>, Vec<T>, usize, ()> + System<Set = T>,
+    Zpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, T>: D2c<Output = Zpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, T>> + System<Set = T>,
+    Zpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, T>: ToTf<T, Vec<T>, Vec<T>, (), ()>,
+    Array2<T>: SsAMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsBMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsCMatrix<T, Array2<T>, Array2<T>, Array2<T>> + SsDMatrix<T, Array2<T>, Array2<T>, Array2<T>>
+{
+    type Output = Tf<T, Vec<T>, Vec<T>>;
+
+    fn d2c(self, sampling_frequency: T::Real, method: D2cMethod<T::Real>) -> Result<Self::Output, D2cError>
+    {
+        if let D2cMethod::Matched = method
+        {
+            return Ok(self.to_zpk((), ())
+                .d2c(sampling_frequency, method)?
+                .to_tf((), ()))
+        }
+
+        let Tf {b, a} = self.to_ss()
+            .d2c(sampling_frequency, method)?
+            .to_tf(0, ());
+        Ok(Tf::new(b.into_inner().into_iter().next().unwrap_or_default(), a.into_inner()))
+    }
+}
+
+impl<T, Z, P, K> D2c for Zpk<T, Z, P, K>
+where
+    T: ComplexFloat,
+    K: Lapack<Real = T::Real> + Div<T::Real, Output = K>,
+    Z: MaybeList<T>,
+    P: MaybeList<T>,
+    Self: ToZpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, K, (), ()> + ToSs<K, Array2<K>, Array2<K>, Array2<K>, Array2<K>> + System<Set = K>,
+    Ss<K, Array2<K>, Array2<K>, Array2<K>, Array2<K>>: D2c<Output = Ss<K, Array2<K>, Array2<K>, Array2<K>, Array2<K>>> + ToZpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, K, usize, usize> + System<Set = K>,
+    Array2<K>: SsAMatrix<K, Array2<K>, Array2<K>, Array2<K>> + SsBMatrix<K, Array2<K>, Array2<K>, Array2<K>> + SsCMatrix<K, Array2<K>, Array2<K>, Array2<K>> + SsDMatrix<K, Array2<K>, Array2<K>, Array2<K>>
+{
+    type Output = Zpk<Complex<T::Real>, Vec<Complex<T::Real>>, Vec<Complex<T::Real>>, K>;
+
+    fn d2c(self, sampling_frequency: T::Real, method: D2cMethod<T::Real>) -> Result<Self::Output, D2cError>
+    {
+        if let D2cMethod::Matched = method
+        {
+            let Zpk::<_, Vec<_>, Vec<_>, K> {z: zd, p: pd, k} = self.to_zpk((), ());
+            let (zd, pd) = (zd.into_inner(), pd.into_inner());
+            if zd.iter()
+                .chain(pd.iter())
+                .any(|r| r.norm().is_zero())
+            {
+                return Err(D2cError::RootAtOrigin)
+            }
+
+            let tol = T::Real::epsilon().sqrt();
+            let z: Vec<_> = zd.iter()
+                .filter(|&&z| (z + Complex::from(T::Real::one())).norm() > tol)
+                .map(|&z| z.ln()*sampling_frequency)
+                .collect();
+            let p: Vec<_> = pd.iter()
+                .map(|&p| p.ln()*sampling_frequency)
+                .collect();
+
+            let gain = domain::matched_gain(&z, &p, &zd, &pd, sampling_frequency);
+            let mut kc = k;
+            if let Some(kc) = <dyn Any>::downcast_mut::<Complex<T::Real>>(&mut kc as &mut dyn Any)
+            {
+                *kc = *kc/gain;
+            }
+            else
+            {
+                kc = kc/gain.re;
+            }
+
+            return Ok(Zpk::new(z, p, kc))
+        }
+
+        Ok(self.to_ss()
+            .d2c(sampling_frequency, method)?
+            .to_zpk(0, 0))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::{array, Array2};
+
+    use crate::{systems::{Ss, Tf, Zpk}, transforms::domain::{C2d, C2dMethod, D2c, D2cMethod}};
+
+    fn assert_close(a: &Array2<f64>, b: &Array2<f64>)
+    {
+        assert_eq!(a.dim(), b.dim());
+        assert!(a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 1e-8), "{a} != {b}");
+    }
+
+    #[test]
+    fn ss_round_trip()
+    {
+        let fs = 100.0;
+        let h = Ss::new(
+            array![[0.0, 1.0], [-50.0, -3.0]],
+            array![[0.0], [1.0]],
+            array![[2.0, 0.5]],
+            array![[0.1]]
+        );
+
+        for (c2d, d2c) in [
+            (C2dMethod::Zoh, D2cMethod::Zoh),
+            (C2dMethod::Tustin {prewarp: None}, D2cMethod::Tustin {prewarp: None}),
+            (C2dMethod::Tustin {prewarp: Some(20.0)}, D2cMethod::Tustin {prewarp: Some(20.0)})
+        ]
+        {
+            let hc = h.clone()
+                .c2d(fs, c2d)
+                .unwrap()
+                .d2c(fs, d2c)
+                .unwrap();
+
+            assert_close(&hc.a, &h.a);
+            assert_close(&hc.b, &h.b);
+            assert_close(&hc.c, &h.c);
+            assert_close(&hc.d, &h.d);
+        }
+    }
+
+    #[test]
+    fn zpk_round_trip()
+    {
+        let fs = 10.0;
+        let h = Zpk::new(vec![-2.0], vec![-1.0, -3.0], 4.0);
+
+        let hc = h.c2d(fs, C2dMethod::Matched)
+            .unwrap()
+            .d2c(fs, D2cMethod::Matched)
+            .unwrap();
+
+        let mut p: Vec<_> = hc.p.into_inner().into_iter().map(|p| p.re).collect();
+        p.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        let z: Vec<_> = hc.z.into_inner().into_iter().map(|z| z.re).collect();
+
+        assert!((z[0] + 2.0).abs() < 1e-9);
+        assert!((p[0] + 1.0).abs() < 1e-9 && (p[1] + 3.0).abs() < 1e-9);
+        assert!((hc.k - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn tf_round_trip()
+    {
+        let fs = 50.0;
+        let h = Tf::new(vec![1.0, 2.0], vec![1.0, 3.0, 2.0]);
+
+        let hc = h.clone()
+            .c2d(fs, C2dMethod::Zoh)
+            .unwrap()
+            .d2c(fs, D2cMethod::Zoh)
+            .unwrap();
+
+        let a0 = hc.a[0];
+        let b: Vec<_> = hc.b.iter().map(|b| b/a0).skip_while(|b: &f64| b.abs() < 1e-9).collect();
+        let a: Vec<_> = hc.a.iter().map(|a| a/a0).collect();
+        assert!(b.iter().zip([1.0, 2.0]).all(|(b, e)| (b - e).abs() < 1e-8), "{b:?}");
+        assert!(a.iter().zip([1.0, 3.0, 2.0]).all(|(a, e)| (a - e).abs() < 1e-8), "{a:?}");
+    }
+}
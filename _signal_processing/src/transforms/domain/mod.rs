@@ -1,6 +1,8 @@
 moddef::moddef!(
     flat(pub) mod {
         bilinear,
+        c2d,
+        d2c,
         impinvar
     }
 );
\ No newline at end of file
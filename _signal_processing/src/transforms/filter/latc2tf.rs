@@ -0,0 +1,40 @@
+use num::complex::ComplexFloat;
+
+use crate::{systems::Tf, System};
+
+/// Converts lattice-ladder coefficients back to a transfer function. This is the inverse of [`Tf2Latc`](crate::transforms::filter::Tf2Latc).
+///
+/// With no ladder coefficients beyond `v₀ = 1`, this is the all-pole transfer function `1/A(z)`.
+pub trait Latc2Tf: System + Sized
+{
+    fn latc2tf(k: &[Self::Set], v: &[Self::Set]) -> Self;
+}
+
+impl<T> Latc2Tf for Tf<T, Vec<T>, Vec<T>>
+where
+    T: ComplexFloat
+{
+    fn latc2tf(k: &[T], v: &[T]) -> Self
+    {
+        let n = k.len();
+        let mut a = vec![T::one()];
+        let mut b = vec![T::zero(); n + 1];
+        for m in 0..=n
+        {
+            if m > 0
+            {
+                // Step up from Aₘ₋₁ to Aₘ.
+                let km = k[m - 1];
+                let get = |i: usize| a.get(i).copied().unwrap_or_else(T::zero);
+                a = (0..=m).map(|i| get(i) + km*get(m - i).conj())
+                    .collect();
+            }
+            let vm = v.get(m).copied().unwrap_or_else(T::zero);
+            for i in 0..=m
+            {
+                b[i] = b[i] + vm*a[m - i].conj()
+            }
+        }
+        Tf::new(b, a)
+    }
+}
@@ -1,8 +1,11 @@
 moddef::moddef!(
     flat(pub) mod {
+        latc2tf,
         qmf,
         sftrans,
         stabilize,
-        zftrans
+        tf2latc,
+        zftrans,
+        zp2sos
     }
 );
\ No newline at end of file
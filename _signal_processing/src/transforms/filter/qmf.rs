@@ -1,6 +1,6 @@
 use core::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
 
-use ndarray_linalg::Lapack;
+use crate::util::Lapack;
 use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float, One};
 use option_trait::Maybe;
 
@@ -42,7 +42,7 @@ where
 impl<T, R, B, A> Qmf for Tf<T, B, A>
 where
     R: Float + FloatConst + Into<T> + 'static,
-    T: ComplexFloat<Real = R> + Lapack<Complex = Complex<R>> + Into<Complex<R>> + 'static,
+    T: ComplexFloat<Real = R> + Lapack + Into<Complex<R>> + 'static,
     B: MaybeLists<T, Owned: MaybeOwnedLists<T>>,
     A: MaybeList<T, Owned: MaybeOwnedList<T>>,
     Complex<R>: AddAssign + SubAssign + MulAssign + DivAssign + DivAssign<R> + MulAssign<T>,
@@ -1,6 +1,6 @@
 use core::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
 
-use ndarray_linalg::Lapack;
+use crate::util::Lapack;
 use num::{complex::ComplexFloat, Complex, One, Zero};
 use option_trait::Maybe;
 
@@ -15,7 +15,7 @@ pub trait Stabilize: System
 
 impl<T, B, A> Stabilize for Tf<T, B, A>
 where
-    T: ComplexFloat<Real: Into<T>> + Lapack<Complex = Complex<<T as ComplexFloat>::Real>> + 'static,
+    T: ComplexFloat<Real: Into<T>> + Lapack + 'static,
     B: MaybeLists<T>,
     A: MaybeList<T, Owned: MaybeOwnedList<T>>,
     Complex<<T as ComplexFloat>::Real>: ComplexFloat<Real = <T as ComplexFloat>::Real> + AddAssign + SubAssign + MulAssign + DivAssign + DivAssign<<T as ComplexFloat>::Real> + From<T> + TruncateIm,
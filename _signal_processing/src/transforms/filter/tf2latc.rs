@@ -0,0 +1,98 @@
+use num::{complex::ComplexFloat, Float};
+use thiserror::Error;
+
+use crate::{operations::filtering::normalize_tf, quantities::MaybeList, systems::Tf, System};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum LatcError
+{
+    #[error("A reflection coefficient has unit magnitude, so the denominator is not strictly minimum-phase.")]
+    UnitReflection
+}
+
+/// Converts a transfer function to a lattice-ladder structure.
+///
+/// Returns the reflection coefficients `k₁ ... kₙ` and the ladder coefficients `v₀ ... vₙ`, such that
+/// `A(z) = Aₙ(z)` is built by the step-up recursion `Aₘ(z) = Aₘ₋₁(z) + kₘz⁻¹Bₘ₋₁(z)`, where `Bₘ` is `Aₘ` reversed and conjugated,
+/// and `B(z) = Σvₘ Bₘ(z)`.
+///
+/// The coefficients are taken as powers of `z⁻¹`, after leading zeros are removed and the denominator is normalized.
+pub trait Tf2Latc: System
+{
+    fn tf2latc(self) -> Result<(Vec<Self::Set>, Vec<Self::Set>), LatcError>;
+}
+
+impl<T, B, A> Tf2Latc for Tf<T, B, A>
+where
+    T: ComplexFloat,
+    B: MaybeList<T>,
+    A: MaybeList<T>
+{
+    fn tf2latc(self) -> Result<(Vec<T>, Vec<T>), LatcError>
+    {
+        let b = self.b.to_vec_option()
+            .unwrap_or_else(|| vec![T::one()]);
+        let a = self.a.to_vec_option()
+            .unwrap_or_else(|| vec![T::one()]);
+        let (b, a) = normalize_tf::<T, T>(&b, &a);
+        latc(&b, &a)
+    }
+}
+
+/// The lattice-ladder coefficients of `b/a`, where `a` is monic and both have the same length.
+pub(crate) fn latc<T>(b: &[T], a: &[T]) -> Result<(Vec<T>, Vec<T>), LatcError>
+where
+    T: ComplexFloat
+{
+    let n = a.len() - 1;
+    let mut am = a.to_vec();
+    let mut c = b.to_vec();
+    let mut k = vec![T::zero(); n];
+    let mut v = vec![T::zero(); n + 1];
+    for m in (0..=n).rev()
+    {
+        // Remove the contribution of Bₘ, whose highest coefficient is 1.
+        v[m] = c[m];
+        for i in 0..=m
+        {
+            c[i] = c[i] - v[m]*am[m - i].conj()
+        }
+        if m == 0
+        {
+            break
+        }
+
+        // Step down from Aₘ to Aₘ₋₁.
+        let km = am[m];
+        let den = T::one() - km*km.conj();
+        if den.abs() <= T::Real::epsilon()
+        {
+            return Err(LatcError::UnitReflection)
+        }
+        k[m - 1] = km;
+        am = (0..m).map(|i| (am[i] - km*am[m - i].conj())/den)
+            .collect();
+    }
+    Ok((k, v))
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{systems::Tf, transforms::filter::{Latc2Tf, Tf2Latc}};
+
+    #[test]
+    fn round_trip()
+    {
+        let h = Tf::new(vec![0.5, 0.2, -0.1], vec![1.0, -0.9, 0.4]);
+
+        let (k, v) = h.clone().tf2latc().unwrap();
+        assert!((k[1] - 0.4f64).abs() < 1e-12);
+
+        let h2 = Tf::<f64, Vec<_>, Vec<_>>::latc2tf(&k, &v);
+        for (x, y) in h2.b.iter().zip(h.b.iter()).chain(h2.a.iter().zip(h.a.iter()))
+        {
+            assert!((x - y).abs() < 1e-12);
+        }
+    }
+}
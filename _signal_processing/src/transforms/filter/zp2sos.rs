@@ -0,0 +1,362 @@
+use num::{traits::FloatConst, Complex, Float, NumCast, One, Zero};
+
+use crate::{systems::{ComplexRealError, Sos, Tf, Zpk}, transforms::system::ToZpk, System};
+
+/// The order of the sections produced by [`Zp2Sos`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SosOrder
+{
+    /// The first section has the poles farthest from the unit circle.
+    #[default]
+    Up,
+    /// The first section has the poles closest to the unit circle.
+    Down
+}
+
+/// How the gain is distributed across the sections produced by [`Zp2Sos`].
+///
+/// When scaling, the numerator of every section but the last is scaled so that the response from the filter input to that section's output has unit norm.
+/// The last section takes up the remaining gain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SosScale
+{
+    /// The overall gain is put in the first section.
+    #[default]
+    None,
+    /// The sum of the absolute impulse response. This guarantees that no section output exceeds the largest input magnitude.
+    L1,
+    /// The energy of the impulse response. This bounds the output variance for white noise.
+    L2,
+    /// The peak magnitude of the frequency response. This bounds the output amplitude for sinusoids.
+    LInf
+}
+
+/// The resulting gain distribution of a [`Zp2Sos`] conversion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SosGains<T>
+where
+    T: Float
+{
+    /// The factor applied to each section's numerator, relative to a monic numerator.
+    pub gains: Vec<T>,
+    /// The norm of the response from the filter input to each section's output.
+    /// Measured with the chosen scaling norm, or the L∞ norm if the sections are not scaled.
+    pub norms: Vec<T>
+}
+
+/// Converts a digital system to second-order sections, with control over pairing, ordering and scaling.
+///
+/// Each pole pair, starting with the ones closest to the unit circle, is paired with the nearest zeros, in the same way as scipy's `zpk2sos` with `pairing='nearest'`.
+pub trait Zp2Sos: System
+where
+    Self::Set: Float
+{
+    type Output;
+
+    fn zp2sos(self, order: SosOrder, scale: SosScale) -> Result<(Self::Output, SosGains<Self::Set>), ComplexRealError>;
+}
+
+impl<T, S> Zp2Sos for S
+where
+    T: Float + FloatConst,
+    S: System<Set = T> + ToZpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T, (), ()>,
+    Zpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T>: ToZpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T, (), ()>
+{
+    type Output = Sos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>>;
+
+    fn zp2sos(self, order: SosOrder, scale: SosScale) -> Result<(Self::Output, SosGains<T>), ComplexRealError>
+    {
+        let zpk: Zpk<Complex<T>, Vec<Complex<T>>, Vec<Complex<T>>, T> = self.to_zpk((), ());
+        let (zc, pc, zr, pr, k) = zpk.complex_real(())?;
+
+        // One root of each conjugate pair, followed by the real roots.
+        let upper = |c: Vec<[Complex<T>; 2]>, r: Vec<T>| -> Vec<Complex<T>> {
+            c.into_iter()
+                .map(|[c1, c2]| if c1.im > T::zero() {c1} else {c2})
+                .chain(r.into_iter().map(Complex::from))
+                .collect()
+        };
+        let mut z = upper(zc, zr);
+        let mut p = upper(pc, pr);
+
+        // Pad with roots at the origin so that every section is of second order.
+        let nz = 2*z.iter().filter(|z| !is_real(z)).count() + z.iter().filter(|z| is_real(z)).count();
+        let np = 2*p.iter().filter(|p| !is_real(p)).count() + p.iter().filter(|p| is_real(p)).count();
+        z.extend(core::iter::repeat_n(Complex::zero(), np.saturating_sub(nz)));
+        p.extend(core::iter::repeat_n(Complex::zero(), nz.saturating_sub(np)));
+        let n = nz.max(np);
+        if n % 2 == 1
+        {
+            z.push(Complex::zero());
+            p.push(Complex::zero());
+        }
+
+        let mut sections = pair(z, p);
+        if sections.is_empty()
+        {
+            sections.push(([T::one(), T::zero(), T::zero()], [T::one(), T::zero(), T::zero()]))
+        }
+        if order == SosOrder::Up
+        {
+            sections.reverse()
+        }
+
+        let gains = distribute(&mut sections, k, scale);
+        let norms = cumulative_norms(&sections, if scale == SosScale::None {SosScale::LInf} else {scale});
+
+        Ok((
+            Sos::new(sections.into_iter()
+                .map(|(b, a)| Tf::new(b, a))
+                .collect()
+            ),
+            SosGains {
+                gains,
+                norms
+            }
+        ))
+    }
+}
+
+fn is_real<T>(z: &Complex<T>) -> bool
+where
+    T: Float
+{
+    z.im.is_zero()
+}
+
+/// The index of the root in `from` nearest to `to`, optionally restricted to real or complex roots.
+fn nearest<T>(from: &[Complex<T>], to: Complex<T>, real: Option<bool>) -> Option<usize>
+where
+    T: Float
+{
+    from.iter()
+        .enumerate()
+        .filter(|(_, z)| real.is_none_or(|real| is_real(z) == real))
+        .min_by(|(_, a), (_, b)| (**a - to).norm().total_cmp(&(**b - to).norm()))
+        .map(|(i, _)| i)
+}
+
+/// Section coefficients with the given roots, in descending powers of `z`.
+fn section<T>(roots: &[Complex<T>]) -> [T; 3]
+where
+    T: Float
+{
+    match *roots
+    {
+        [] => [T::zero(), T::zero(), T::one()],
+        [r] => [T::zero(), T::one(), -r.re],
+        [r1, r2] => {
+            if is_real(&r1)
+            {
+                [T::one(), -(r1.re + r2.re), r1.re*r2.re]
+            }
+            else
+            {
+                [T::one(), -(r1.re + r1.re), r1.norm_sqr()]
+            }
+        },
+        _ => unreachable!()
+    }
+}
+
+/// Pairs poles with zeros, with the poles closest to the unit circle first.
+fn pair<T>(mut z: Vec<Complex<T>>, mut p: Vec<Complex<T>>) -> Vec<([T; 3], [T; 3])>
+where
+    T: Float
+{
+    let mut sections = vec![];
+    let dist = |p: &Complex<T>| (T::one() - p.norm()).abs();
+    while let Some(i) = p.iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| dist(a).total_cmp(&dist(b)))
+        .map(|(i, _)| i)
+    {
+        let p1 = p.remove(i);
+        let take = |z: &mut Vec<Complex<T>>, real| nearest(z, p1, real).map(|i| z.remove(i));
+
+        if is_real(&p1) && p.iter().all(|p| !is_real(p))
+        {
+            // The last real pole is paired with the last real zero.
+            let z1 = take(&mut z, Some(true))
+                .unwrap_or_else(Complex::zero);
+            sections.push((section(&[z1, Complex::zero()]), section(&[p1, Complex::zero()])))
+        }
+        else if p.len() + 1 == z.len() && !is_real(&p1) && p.iter().filter(|p| is_real(p)).count() == 1 && z.iter().filter(|z| is_real(z)).count() == 1
+        {
+            // A complex zero must go here, so that the remaining real zero pairs with the remaining real pole.
+            let z1 = take(&mut z, Some(false)).unwrap();
+            sections.push((section(&[z1, z1.conj()]), section(&[p1, p1.conj()])))
+        }
+        else
+        {
+            let p2 = if is_real(&p1)
+            {
+                let i = p.iter()
+                    .enumerate()
+                    .filter(|(_, p)| is_real(p))
+                    .min_by(|(_, a), (_, b)| dist(a).total_cmp(&dist(b)))
+                    .map(|(i, _)| i)
+                    .unwrap();
+                p.remove(i)
+            }
+            else
+            {
+                p1.conj()
+            };
+            let b = match take(&mut z, None)
+            {
+                Some(z1) if !is_real(&z1) => section(&[z1, z1.conj()]),
+                Some(z1) => match take(&mut z, Some(true))
+                {
+                    Some(z2) => section(&[z1, z2]),
+                    None => section(&[z1])
+                },
+                None => section(&[])
+            };
+            sections.push((b, section(&[p1, p2])))
+        }
+    }
+    sections
+}
+
+/// Applies the overall gain `k` to the sections, and returns the numerator gain of each.
+fn distribute<T>(sections: &mut [([T; 3], [T; 3])], k: T, scale: SosScale) -> Vec<T>
+where
+    T: Float + FloatConst
+{
+    let l = sections.len();
+    let mut gains = vec![T::one(); l];
+    let apply = |c: &mut [T; 3], g: T| for c in c.iter_mut()
+    {
+        *c = *c*g
+    };
+    if scale == SosScale::None
+    {
+        gains[0] = k;
+        apply(&mut sections[0].0, k);
+    }
+    else
+    {
+        let mut total = T::one();
+        for i in 0..l - 1
+        {
+            let g = cumulative_norms(&sections[..=i], scale)[i].recip();
+            if g.is_finite()
+            {
+                apply(&mut sections[i].0, g);
+                gains[i] = g;
+                total = total*g;
+            }
+        }
+        gains[l - 1] = k/total;
+        apply(&mut sections[l - 1].0, gains[l - 1]);
+    }
+    gains
+}
+
+/// The norm of the response from the input to each section's output.
+fn cumulative_norms<T>(sections: &[([T; 3], [T; 3])], scale: SosScale) -> Vec<T>
+where
+    T: Float + FloatConst
+{
+    match scale
+    {
+        SosScale::LInf | SosScale::None => {
+            const N: usize = 2048;
+            let mut h = vec![Complex::<T>::one(); N];
+            let w: Vec<Complex<T>> = (0..N).map(|i| {
+                let w = T::PI()*<T as NumCast>::from(i).unwrap()/<T as NumCast>::from(N - 1).unwrap();
+                Complex::cis(w)
+            }).collect();
+            sections.iter()
+                .map(|(b, a)| {
+                    let eval = |c: &[T; 3], z: Complex<T>| (z*c[0] + c[1])*z + c[2];
+                    for (h, &z) in h.iter_mut().zip(w.iter())
+                    {
+                        *h = *h*eval(b, z)/eval(a, z)
+                    }
+                    h.iter()
+                        .map(|h| h.norm())
+                        .fold(T::zero(), T::max)
+                }).collect()
+        },
+        SosScale::L1 | SosScale::L2 => {
+            // Long enough for the slowest pole to decay below the epsilon.
+            let r = sections.iter()
+                .map(|(_, a)| {
+                    let d = a[1]*a[1] - <T as NumCast>::from(4.0).unwrap()*a[0]*a[2];
+                    if d < T::zero() {(a[2]/a[0]).abs().sqrt()} else {(a[1].abs() + d.sqrt())/(a[0] + a[0]).abs()}
+                }).fold(T::zero(), T::max);
+            let n = if r < T::one() {(T::epsilon().ln()/r.ln()).to_usize().unwrap_or(usize::MAX)} else {usize::MAX}
+                .clamp(64, 1 << 16);
+
+            let mut h = vec![T::zero(); n];
+            h[0] = T::one();
+            sections.iter()
+                .map(|(b, a)| {
+                    let mut w = [T::zero(); 2];
+                    for h in h.iter_mut()
+                    {
+                        let x = *h;
+                        let y = b[0]*x + w[0];
+                        w[0] = b[1]*x - a[1]*y + w[1];
+                        w[1] = b[2]*x - a[2]*y;
+                        *h = y;
+                    }
+                    if scale == SosScale::L1
+                    {
+                        h.iter().map(|h| h.abs()).fold(T::zero(), |a, b| a + b)
+                    }
+                    else
+                    {
+                        h.iter().map(|&h| h*h).fold(T::zero(), |a, b| a + b).sqrt()
+                    }
+                }).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use num::Complex;
+
+    use crate::{generators::filter::{Butter, FilterGenPlane, FilterGenType}, systems::{Tf, Zpk}, transforms::{filter::{SosOrder, SosScale, Zp2Sos}, system::ToTf}};
+
+    #[test]
+    fn pairing_and_scaling()
+    {
+        let h: Zpk<Complex<f64>, Vec<_>, Vec<_>, f64> = Zpk::butter(5, [0.2], FilterGenType::LowPass, FilterGenPlane::Z { sampling_frequency: None })
+            .unwrap();
+        let tf: Tf<f64, Vec<_>, Vec<_>> = h.clone().to_tf((), ());
+
+        let radius = |a: &[f64; 3]| if a[1]*a[1] < 4.0*a[2] {a[2].sqrt()} else {(a[1].abs() + (a[1]*a[1] - 4.0*a[2]).sqrt())/2.0};
+
+        for scale in [SosScale::None, SosScale::L1, SosScale::L2, SosScale::LInf]
+        {
+            for order in [SosOrder::Up, SosOrder::Down]
+            {
+                let (sos, gains) = h.clone().zp2sos(order, scale).unwrap();
+                assert_eq!(gains.gains.len(), 3);
+
+                let r: Vec<_> = sos.sos.iter().map(|s| radius(&s.a)).collect();
+                match order
+                {
+                    SosOrder::Up => assert!(r.is_sorted()),
+                    SosOrder::Down => assert!(r.iter().rev().is_sorted())
+                }
+                if scale != SosScale::None
+                {
+                    assert!(gains.norms[..2].iter().all(|&n| (n - 1.0).abs() < 1e-6), "{scale:?}");
+                }
+
+                // The overall response is unchanged. The sections add a pole and a zero at the origin.
+                let tf2: Tf<f64, Vec<_>, Vec<_>> = sos.to_tf((), ());
+                for (x, y) in tf2.b.iter().zip(tf.b.iter()).chain(tf2.a.iter().zip(tf.a.iter()))
+                {
+                    assert!((x - y).abs() < 1e-9);
+                }
+            }
+        }
+    }
+}
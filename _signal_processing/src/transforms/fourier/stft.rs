@@ -1,5 +1,5 @@
 use ndarray::Array2;
-use ndarray_linalg::Lapack;
+use crate::util::Lapack;
 use num::{complex::ComplexFloat, traits::FloatConst, Complex, NumCast, One, Zero};
 use option_trait::Maybe;
 use core::{iter::Sum, ops::{AddAssign, Div, MulAssign, Sub}};
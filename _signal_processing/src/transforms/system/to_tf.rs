@@ -1,7 +1,7 @@
 use core::{iter::Product, ops::Mul};
 
 use ndarray::{Array1, Array2};
-use ndarray_linalg::EigVals;
+use crate::util::EigVals;
 use num::{complex::ComplexFloat, Complex, One, Zero};
 
 use option_trait::{Maybe, NotVoid, PureStaticMaybe, StaticMaybe, ops::MaybeOr};
@@ -55,7 +55,7 @@ where
     B2: MaybeList<K> + Maybe<Vec<K>>,
     A2: MaybeList<K> + Maybe<Vec<K>>,
     Complex<<K as ComplexFloat>::Real>: From<K> + AddAssign + SubAssign + MulAssign + DivAssign + DivAssign<<K as ComplexFloat>::Real>,
-    K: ComplexFloat + ndarray_linalg::Lapack<Complex = Complex<<K as ComplexFloat>::Real>>
+    K: ComplexFloat + crate::util::Lapack
 {
     fn to_zpk(self, (): (), output: O) -> Zpk<Complex<<K as ComplexFloat>::Real>, Z, P, K>
     {
@@ -1,9 +1,12 @@
 #![allow(unused)]
 
-use ndarray_linalg::{Inverse, OperationNorm, Scalar, Lapack, error::{LinalgError, Result}};
 use ndarray::prelude::*;
 use statrs::function::factorial::{binomial, factorial};
 
+use super::{Inverse, Lapack, LinalgError, OperationNorm};
+
+type Result<T> = core::result::Result<T, LinalgError>;
+
 // These constants are hard-coded from Al-Mohy & Higham
 const THETA_3: f64 = 1.495_585_217_958_292e-2;
 const THETA_5: f64 = 2.539_398_330_063_23e-1;
@@ -61,17 +64,17 @@ const PADE_COEFFS_13: [f64; 14] = [
     1. / 64_764_752_532_480_000.,
 ];
 
-fn pade_approximation_3<S: Scalar<Real = f64> + Lapack>(
+fn pade_approximation_3<S: Lapack<Real = f64>>(
     a_1: &Array2<S>,
     a_2: &Array2<S>,
 ) -> Result<Array2<S>> {
     let mut evens: Array2<S> = Array2::<S>::eye(a_1.nrows());
-    // evens.mapv_inplace(|x| x * S::from_real(PADE_COEFFS_3[0]));
-    evens.scaled_add(S::from_real(PADE_COEFFS_3[2]), a_2);
+    // evens.mapv_inplace(|x| x * from_real::<S>(PADE_COEFFS_3[0]));
+    evens.scaled_add(from_real::<S>(PADE_COEFFS_3[2]), a_2);
 
     let mut odds: Array2<S> = Array2::<S>::eye(a_1.nrows());
-    odds.mapv_inplace(|x| x * S::from_real(PADE_COEFFS_3[1]));
-    odds.scaled_add(S::from_real(PADE_COEFFS_3[3]), a_2);
+    odds.mapv_inplace(|x| x * from_real::<S>(PADE_COEFFS_3[1]));
+    odds.scaled_add(from_real::<S>(PADE_COEFFS_3[3]), a_2);
     odds = odds.dot(a_1);
 
     odds.mapv_inplace(|x| -x);
@@ -80,20 +83,20 @@ fn pade_approximation_3<S: Scalar<Real = f64> + Lapack>(
     Ok(inverted.dot(&(odds + evens)))
 }
 
-fn pade_approximation_5<S: Scalar<Real = f64> + Lapack>(
+fn pade_approximation_5<S: Lapack<Real = f64>>(
     a_1: &Array2<S>,
     a_2: &Array2<S>,
     a_4: &Array2<S>,
 ) -> Result<Array2<S>> {
     let mut evens: Array2<S> = Array2::<S>::eye(a_1.nrows());
-    // evens.mapv_inplace(|x| S::from_real(PADE_COEFFS_5[0]) * x);
-    evens.scaled_add(S::from_real(PADE_COEFFS_5[2]), a_2);
-    evens.scaled_add(S::from_real(PADE_COEFFS_5[4]), a_4);
+    // evens.mapv_inplace(|x| from_real::<S>(PADE_COEFFS_5[0]) * x);
+    evens.scaled_add(from_real::<S>(PADE_COEFFS_5[2]), a_2);
+    evens.scaled_add(from_real::<S>(PADE_COEFFS_5[4]), a_4);
 
     let mut odds: Array2<S> = Array::eye(a_1.nrows());
-    odds.mapv_inplace(|x| S::from_real(PADE_COEFFS_5[1]) * x);
-    odds.scaled_add(S::from_real(PADE_COEFFS_5[3]), a_2);
-    odds.scaled_add(S::from_real(PADE_COEFFS_5[5]), a_4);
+    odds.mapv_inplace(|x| from_real::<S>(PADE_COEFFS_5[1]) * x);
+    odds.scaled_add(from_real::<S>(PADE_COEFFS_5[3]), a_2);
+    odds.scaled_add(from_real::<S>(PADE_COEFFS_5[5]), a_4);
     odds = odds.dot(a_1);
 
     odds.mapv_inplace(|x| -x);
@@ -102,23 +105,23 @@ fn pade_approximation_5<S: Scalar<Real = f64> + Lapack>(
     Ok(inverted.dot(&(odds + evens)))
 }
 
-fn pade_approximation_7<S: Scalar<Real = f64> + Lapack>(
+fn pade_approximation_7<S: Lapack<Real = f64>>(
     a_1: &Array2<S>,
     a_2: &Array2<S>,
     a_4: &Array2<S>,
     a_6: &Array2<S>,
 ) -> Result<Array2<S>> {
     let mut evens: Array2<S> = Array::eye(a_1.nrows());
-    // evens.mapv_inplace(|x| S::from_real(PADE_COEFFS_7[0]) * x);
-    evens.scaled_add(S::from_real(PADE_COEFFS_7[2]), a_2);
-    evens.scaled_add(S::from_real(PADE_COEFFS_7[4]), a_4);
-    evens.scaled_add(S::from_real(PADE_COEFFS_7[6]), a_6);
+    // evens.mapv_inplace(|x| from_real::<S>(PADE_COEFFS_7[0]) * x);
+    evens.scaled_add(from_real::<S>(PADE_COEFFS_7[2]), a_2);
+    evens.scaled_add(from_real::<S>(PADE_COEFFS_7[4]), a_4);
+    evens.scaled_add(from_real::<S>(PADE_COEFFS_7[6]), a_6);
 
     let mut odds: Array2<S> = Array::eye(a_1.nrows());
-    odds.mapv_inplace(|x| S::from_real(PADE_COEFFS_7[1]) * x);
-    odds.scaled_add(S::from_real(PADE_COEFFS_7[3]), a_2);
-    odds.scaled_add(S::from_real(PADE_COEFFS_7[5]), a_4);
-    odds.scaled_add(S::from_real(PADE_COEFFS_7[7]), a_6);
+    odds.mapv_inplace(|x| from_real::<S>(PADE_COEFFS_7[1]) * x);
+    odds.scaled_add(from_real::<S>(PADE_COEFFS_7[3]), a_2);
+    odds.scaled_add(from_real::<S>(PADE_COEFFS_7[5]), a_4);
+    odds.scaled_add(from_real::<S>(PADE_COEFFS_7[7]), a_6);
     odds = odds.dot(a_1);
 
     odds.mapv_inplace(|x| -x);
@@ -127,7 +130,7 @@ fn pade_approximation_7<S: Scalar<Real = f64> + Lapack>(
     Ok(inverted.dot(&(odds + evens)))
 }
 
-fn pade_approximation_9<S: Scalar<Real = f64> + Lapack>(
+fn pade_approximation_9<S: Lapack<Real = f64>>(
     a_1: &Array2<S>,
     a_2: &Array2<S>,
     a_4: &Array2<S>,
@@ -135,18 +138,18 @@ fn pade_approximation_9<S: Scalar<Real = f64> + Lapack>(
     a_8: &Array2<S>,
 ) -> Result<Array2<S>> {
     let mut evens: Array2<S> = Array::eye(a_1.nrows());
-    // evens.mapv_inplace(|x| S::from_real(PADE_COEFFS_9[0]) * x);
-    evens.scaled_add(S::from_real(PADE_COEFFS_9[2]), a_2);
-    evens.scaled_add(S::from_real(PADE_COEFFS_9[4]), a_4);
-    evens.scaled_add(S::from_real(PADE_COEFFS_9[6]), a_6);
-    evens.scaled_add(S::from_real(PADE_COEFFS_9[8]), a_8);
+    // evens.mapv_inplace(|x| from_real::<S>(PADE_COEFFS_9[0]) * x);
+    evens.scaled_add(from_real::<S>(PADE_COEFFS_9[2]), a_2);
+    evens.scaled_add(from_real::<S>(PADE_COEFFS_9[4]), a_4);
+    evens.scaled_add(from_real::<S>(PADE_COEFFS_9[6]), a_6);
+    evens.scaled_add(from_real::<S>(PADE_COEFFS_9[8]), a_8);
 
     let mut odds: Array2<S> = Array::eye(a_1.nrows());
-    odds.mapv_inplace(|x| S::from_real(PADE_COEFFS_9[1]) * x);
-    odds.scaled_add(S::from_real(PADE_COEFFS_9[3]), a_2);
-    odds.scaled_add(S::from_real(PADE_COEFFS_9[5]), a_4);
-    odds.scaled_add(S::from_real(PADE_COEFFS_9[7]), a_6);
-    odds.scaled_add(S::from_real(PADE_COEFFS_9[9]), a_8);
+    odds.mapv_inplace(|x| from_real::<S>(PADE_COEFFS_9[1]) * x);
+    odds.scaled_add(from_real::<S>(PADE_COEFFS_9[3]), a_2);
+    odds.scaled_add(from_real::<S>(PADE_COEFFS_9[5]), a_4);
+    odds.scaled_add(from_real::<S>(PADE_COEFFS_9[7]), a_6);
+    odds.scaled_add(from_real::<S>(PADE_COEFFS_9[9]), a_8);
     odds = odds.dot(a_1);
 
     odds.mapv_inplace(|x| -x);
@@ -157,34 +160,34 @@ fn pade_approximation_9<S: Scalar<Real = f64> + Lapack>(
 
 // Note: all input matrices should be scaled in the main expm
 // function.
-fn pade_approximation_13<S: Scalar<Real = f64> + Lapack>(
+fn pade_approximation_13<S: Lapack<Real = f64>>(
     a_1: &Array2<S>,
     a_2: &Array2<S>,
     a_4: &Array2<S>,
     a_6: &Array2<S>,
 ) -> Result<Array2<S>> {
     let mut evens_1: Array2<S> = Array::eye(a_1.nrows());
-    evens_1.mapv_inplace(|x| S::from_real(PADE_COEFFS_13[0]) * x);
-    evens_1.scaled_add(S::from_real(PADE_COEFFS_13[2]), a_2);
-    evens_1.scaled_add(S::from_real(PADE_COEFFS_13[4]), a_4);
-    evens_1.scaled_add(S::from_real(PADE_COEFFS_13[6]), a_6);
+    evens_1.mapv_inplace(|x| from_real::<S>(PADE_COEFFS_13[0]) * x);
+    evens_1.scaled_add(from_real::<S>(PADE_COEFFS_13[2]), a_2);
+    evens_1.scaled_add(from_real::<S>(PADE_COEFFS_13[4]), a_4);
+    evens_1.scaled_add(from_real::<S>(PADE_COEFFS_13[6]), a_6);
 
     let mut evens_2 = a_2.clone();
-    evens_2.mapv_inplace(|x| S::from_real(PADE_COEFFS_13[8]) * x);
-    evens_2.scaled_add(S::from_real(PADE_COEFFS_13[10]), a_4);
-    evens_2.scaled_add(S::from_real(PADE_COEFFS_13[12]), a_6);
+    evens_2.mapv_inplace(|x| from_real::<S>(PADE_COEFFS_13[8]) * x);
+    evens_2.scaled_add(from_real::<S>(PADE_COEFFS_13[10]), a_4);
+    evens_2.scaled_add(from_real::<S>(PADE_COEFFS_13[12]), a_6);
     let evens = evens_2.dot(a_6) + &evens_1;
 
     let mut odds_1: Array2<S> = Array::eye(a_1.nrows());
-    odds_1.mapv_inplace(|x| S::from_real(PADE_COEFFS_13[1]) * x);
-    odds_1.scaled_add(S::from_real(PADE_COEFFS_13[3]), a_2);
-    odds_1.scaled_add(S::from_real(PADE_COEFFS_13[5]), a_4);
-    odds_1.scaled_add(S::from_real(PADE_COEFFS_13[7]), a_6);
+    odds_1.mapv_inplace(|x| from_real::<S>(PADE_COEFFS_13[1]) * x);
+    odds_1.scaled_add(from_real::<S>(PADE_COEFFS_13[3]), a_2);
+    odds_1.scaled_add(from_real::<S>(PADE_COEFFS_13[5]), a_4);
+    odds_1.scaled_add(from_real::<S>(PADE_COEFFS_13[7]), a_6);
 
     let mut odds_2 = a_2.clone();
-    odds_2.mapv_inplace(|x| S::from_real(PADE_COEFFS_13[9]) * x);
-    odds_2.scaled_add(S::from_real(PADE_COEFFS_13[11]), a_4);
-    odds_2.scaled_add(S::from_real(PADE_COEFFS_13[13]), a_6);
+    odds_2.mapv_inplace(|x| from_real::<S>(PADE_COEFFS_13[9]) * x);
+    odds_2.scaled_add(from_real::<S>(PADE_COEFFS_13[11]), a_4);
+    odds_2.scaled_add(from_real::<S>(PADE_COEFFS_13[13]), a_6);
     odds_2 = odds_2.dot(a_6);
 
     let mut odds = (&odds_1 + &odds_2).dot(a_1);
@@ -196,12 +199,12 @@ fn pade_approximation_13<S: Scalar<Real = f64> + Lapack>(
 
 fn power_abs_norm<S>(input_matrix: &Array2<S>, p: usize) -> f64
 where
-    S: Scalar<Real = f64>,
+    S: Lapack<Real = f64>,
 {
     let mut v = Array1::<f64>::ones((input_matrix.ncols()).f());
     let abs_matrix = input_matrix.t().map(|x| x.abs());
     for _ in 0..p {
-        v.assign(&abs_matrix.dot(&v));
+        v = abs_matrix.dot(&v);
     }
     // return max col sum
     v.into_iter()
@@ -210,7 +213,7 @@ where
 }
 
 /// helper function used in Al-Mohy & Higham. Name is unchanged for both literature reference.
-fn ell<S: Scalar<Real = f64>>(a_matrix: &Array2<S>, m: u64) -> Result<i32> {
+fn ell<S: Lapack<Real = f64>>(a_matrix: &Array2<S>, m: u64) -> Result<i32> {
     if a_matrix.is_square() == false {
         return Err(LinalgError::NotSquare {
             rows: a_matrix.nrows() as i32,
@@ -228,6 +231,10 @@ fn ell<S: Scalar<Real = f64>>(a_matrix: &Array2<S>, m: u64) -> Result<i32> {
     Ok(i32::max(val, 0))
 }
 
+fn from_real<S: Lapack<Real = f64>>(x: f64) -> S {
+    x.into()
+}
+
 /// Calculates the leading term of the error series for the [m/m] Pade approximation to exp(x).
 fn pade_error_coefficient(m: u64) -> f64 {
     1.0 / (binomial(2 * m, m) * factorial(2 * m + 1))
@@ -236,7 +243,7 @@ fn pade_error_coefficient(m: u64) -> f64 {
 /// ## Matrix Exponentiation
 /// Computes matrix exponential based on the scaling-and-squaring algorithm by Al-Mohy and Higham [[1]].
 /// Currently restricted to matrices with entries that are either f64 or Complex64. 64 bit precision is required
-/// due to error calculations in [[1]] Utilizes Lapack calls so entries must satisfy LAPACK trait bounds.
+/// due to error calculations in [[1]].
 ///
 /// ### Caveats
 /// Currently confirmed accurate to f64 precision up to 1024x1024 sparse matrices. Dense matrices
@@ -245,7 +252,7 @@ fn pade_error_coefficient(m: u64) -> f64 {
 ///
 /// ### References
 /// [[1]] A New Scaling and Squaring Algorithm for the Matrix Exponential. Al-Mohy, Awad H. and Higham, Nicholas J. 2009. Siam J. Matrix Anal. Appl. Vol. 31, No. 3, pp. 970-989.
-pub fn expm<S: Scalar<Real = f64> + Lapack>(a_matrix: &Array2<S>) -> Result<Array2<S>> {
+pub fn expm<S: Lapack<Real = f64>>(a_matrix: &Array2<S>) -> Result<Array2<S>> {
     let mut a_2 = a_matrix.dot(a_matrix);
     let mut a_4 = a_2.dot(&a_2);
     let mut a_6 = a_2.dot(&a_4);
@@ -276,12 +283,12 @@ pub fn expm<S: Scalar<Real = f64> + Lapack>(a_matrix: &Array2<S>) -> Result<Arra
 
     let mut s = f64::max(0., (eta_5 / THETA_13).log2().ceil()) as i32;
     let mut a_scaled = a_matrix.clone();
-    let mut scaler = S::from_real(2.).powi(-s);
+    let mut scaler = from_real::<S>(2.).powi(-s);
     a_scaled.mapv_inplace(|x| x * scaler);
     s += ell(&a_scaled, 13)?;
 
     a_scaled.assign(a_matrix);
-    scaler = S::from_real(2.).powi(-s);
+    scaler = from_real::<S>(2.).powi(-s);
     a_scaled.mapv_inplace(|x| x * scaler);
     a_2.mapv_inplace(|x| x * scaler.powi(2));
     a_4.mapv_inplace(|x| x * scaler.powi(4));
@@ -292,4 +299,42 @@ pub fn expm<S: Scalar<Real = f64> + Lapack>(a_matrix: &Array2<S>) -> Result<Arra
         output = output.dot(&output);
     }
     Ok(output)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+    use num::Complex;
+
+    use super::expm;
+
+    #[test]
+    fn nilpotent()
+    {
+        let a = array![
+            [0.0, 1.0],
+            [0.0, 0.0]
+        ];
+
+        let e = expm(&a).unwrap() - array![[1.0, 1.0], [0.0, 1.0]];
+        assert!(e.iter().all(|e: &f64| e.abs() < 1e-12));
+    }
+
+    #[test]
+    fn rotation()
+    {
+        // exp([0 -θ; θ 0]) is a rotation by θ.
+        let theta = 2.5;
+        let a = array![
+            [Complex::new(0.0, 0.0), Complex::new(-theta, 0.0)],
+            [Complex::new(theta, 0.0), Complex::new(0.0, 0.0)]
+        ];
+
+        let e = expm(&a).unwrap() - array![
+            [Complex::new(theta.cos(), 0.0), Complex::new(-theta.sin(), 0.0)],
+            [Complex::new(theta.sin(), 0.0), Complex::new(theta.cos(), 0.0)]
+        ];
+        assert!(e.iter().all(|e| e.norm() < 1e-12));
+    }
+}
@@ -0,0 +1,78 @@
+use ndarray::{Array2, ArrayBase, ArrayView2, Data, Ix2};
+use num::{complex::ComplexFloat, Zero};
+
+use super::{error, Lapack, LinalgError};
+
+/// The Cholesky factorization `A = LLᴴ` of a hermitian positive-definite matrix, where `L` is lower-triangular.
+///
+/// Only the lower triangle of `A` is read.
+pub trait Cholesky
+{
+    type Output;
+
+    fn cholesky(&self) -> Result<Self::Output, LinalgError>;
+}
+
+impl<T, S> Cholesky for ArrayBase<S, Ix2>
+where
+    T: Lapack,
+    S: Data<Elem = T>
+{
+    type Output = Array2<T>;
+
+    fn cholesky(&self) -> Result<Self::Output, LinalgError>
+    {
+        T::_cholesky(self.view())
+    }
+}
+
+pub(super) fn cholesky<T>(a: ArrayView2<T>) -> Result<Array2<T>, LinalgError>
+where
+    T: ComplexFloat<Real: Into<T>>
+{
+    let n = error::assert_square(&a)?;
+
+    let mut l = Array2::<T>::zeros((n, n));
+    for j in 0..n
+    {
+        let d = (0..j).fold(a[(j, j)], |d, k| d - l[(j, k)]*l[(j, k)].conj())
+            .re();
+        if !(d > T::Real::zero())
+        {
+            return Err(LinalgError::NotPositiveDefinite)
+        }
+        let d: T = d.sqrt().into();
+        l[(j, j)] = d;
+
+        for i in j + 1..n
+        {
+            l[(i, j)] = (0..j).fold(a[(i, j)], |x, k| x - l[(i, k)]*l[(j, k)].conj())/d
+        }
+    }
+    Ok(l)
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::util::{Cholesky, LinalgError};
+
+    #[test]
+    fn cholesky()
+    {
+        let a = array![
+            [4.0, 2.0, -2.0],
+            [2.0, 10.0, 2.0],
+            [-2.0, 2.0, 5.0]
+        ];
+
+        let l = a.cholesky().unwrap();
+        let e = l.dot(&l.t()) - &a;
+
+        assert!(e.iter().all(|e: &f64| e.abs() < 1e-12));
+        assert_eq!(l[(0, 1)], 0.0);
+        assert_eq!(array![[1.0, 2.0], [2.0, 1.0]].cholesky(), Err(LinalgError::NotPositiveDefinite));
+    }
+}
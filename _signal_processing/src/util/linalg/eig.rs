@@ -0,0 +1,278 @@
+use ndarray::{Array1, Array2, ArrayBase, ArrayView2, Data, Ix2};
+use num::{complex::ComplexFloat, Complex, Float, NumCast, Zero};
+
+use super::{error, qr, Lapack, LinalgError};
+
+/// The eigenvalues of a square matrix.
+pub trait EigVals
+{
+    type EigVal;
+
+    fn eigvals(&self) -> Result<Self::EigVal, LinalgError>;
+}
+
+impl<T, S> EigVals for ArrayBase<S, Ix2>
+where
+    T: Lapack,
+    S: Data<Elem = T>
+{
+    type EigVal = Array1<Complex<T::Real>>;
+
+    fn eigvals(&self) -> Result<Self::EigVal, LinalgError>
+    {
+        T::_eigvals(self.view())
+    }
+}
+
+/// Reduces a square matrix to upper Hessenberg form with Householder reflections.
+///
+/// Returns `(Q, H)` such that `A = QHQᴴ`, where `Q` is unitary and `H` is zero below its first subdiagonal.
+pub fn hessenberg<T, S>(a: &ArrayBase<S, Ix2>) -> Result<(Array2<T>, Array2<T>), LinalgError>
+where
+    T: ComplexFloat<Real: Into<T>>,
+    S: Data<Elem = T>
+{
+    let n = error::assert_square(&a.view())?;
+
+    let mut h = a.to_owned();
+    let mut q = Array2::eye(n);
+    for k in 0..n.saturating_sub(2)
+    {
+        if let Some(v) = qr::householder((k + 1..n).map(|i| h[(i, k)]))
+        {
+            qr::reflect_left(&mut h, &v, k + 1);
+            qr::reflect_right(&mut h, &v, k + 1);
+            qr::reflect_right(&mut q, &v, k + 1);
+            for i in k + 2..n
+            {
+                h[(i, k)] = T::zero()
+            }
+        }
+    }
+
+    Ok((q, h))
+}
+
+/// The complex Schur decomposition of a square matrix.
+///
+/// Returns `(Z, T)` such that `A = ZTZᴴ`, where `Z` is unitary and `T` is upper triangular with the eigenvalues on its diagonal.
+pub fn schur<T, S>(a: &ArrayBase<S, Ix2>) -> Result<(Array2<Complex<T::Real>>, Array2<Complex<T::Real>>), LinalgError>
+where
+    T: ComplexFloat + Into<Complex<T::Real>>,
+    S: Data<Elem = T>
+{
+    let a: Array2<Complex<T::Real>> = a.map(|&a| a.into());
+    let (mut z, mut h) = hessenberg(&a)?;
+    qr_iterate(&mut h, Some(&mut z))?;
+    Ok((z, h))
+}
+
+/// Finds the eigenvalues with the single-shift complex QR algorithm on the Hessenberg form.
+pub(super) fn eigvals<T>(a: ArrayView2<T>) -> Result<Array1<Complex<T::Real>>, LinalgError>
+where
+    T: ComplexFloat + Into<Complex<T::Real>>
+{
+    let a: Array2<Complex<T::Real>> = a.map(|&a| a.into());
+    let (_, mut h) = hessenberg(&a)?;
+    qr_iterate(&mut h, None)?;
+    Ok(h.diag().to_owned())
+}
+
+/// Runs shifted QR steps on an upper Hessenberg matrix until it is triangular.
+///
+/// If `z` is given, the rotations are applied to the whole of `h` and accumulated in `z`, so that the Schur form is obtained.
+/// Otherwise only the active block is updated, which is enough to find the eigenvalues.
+fn qr_iterate<R>(h: &mut Array2<Complex<R>>, mut z: Option<&mut Array2<Complex<R>>>) -> Result<(), LinalgError>
+where
+    R: Float
+{
+    let n = h.nrows();
+    let full = z.is_some();
+
+    let eps = R::epsilon();
+    let max_iter = 30*n.max(1);
+    let mut hi = n;
+    let mut iter = 0;
+    while hi > 0
+    {
+        // Find the start of the unreduced block ending at hi - 1.
+        let mut lo = hi - 1;
+        while lo > 0
+        {
+            let s = h[(lo - 1, lo - 1)].norm() + h[(lo, lo)].norm();
+            if h[(lo, lo - 1)].norm() <= eps*s
+            {
+                h[(lo, lo - 1)] = Complex::zero();
+                break
+            }
+            lo -= 1;
+        }
+
+        if lo == hi - 1
+        {
+            hi -= 1;
+            iter = 0;
+            continue
+        }
+
+        iter += 1;
+        if iter > max_iter
+        {
+            return Err(LinalgError::NoConvergence)
+        }
+
+        let mu = if iter % 10 == 0
+        {
+            // Exceptional shift, to break cycles.
+            h[(hi - 1, hi - 1)] + h[(hi - 1, hi - 2)].norm()*<R as NumCast>::from(0.75).unwrap()
+        }
+        else
+        {
+            wilkinson_shift(h[(hi - 2, hi - 2)], h[(hi - 2, hi - 1)], h[(hi - 1, hi - 2)], h[(hi - 1, hi - 1)])
+        };
+        let (row_end, col_start) = if full {(n, 0)} else {(hi, lo)};
+
+        // H - μI = QR, H ← RQ + μI
+        for k in lo..hi
+        {
+            h[(k, k)] = h[(k, k)] - mu
+        }
+        let mut rotations = Vec::with_capacity(hi - lo - 1);
+        for k in lo..hi - 1
+        {
+            let (c, s) = givens(h[(k, k)], h[(k + 1, k)]);
+            for j in k..row_end
+            {
+                let (x, y) = (h[(k, j)], h[(k + 1, j)]);
+                h[(k, j)] = x*c + s*y;
+                h[(k + 1, j)] = y*c - s.conj()*x;
+            }
+            rotations.push((c, s));
+        }
+        for (k, (c, s)) in (lo..).zip(rotations)
+        {
+            for i in col_start..=(k + 2).min(hi - 1)
+            {
+                let (x, y) = (h[(i, k)], h[(i, k + 1)]);
+                h[(i, k)] = x*c + y*s.conj();
+                h[(i, k + 1)] = y*c - x*s;
+            }
+            if let Some(z) = &mut z
+            {
+                for i in 0..n
+                {
+                    let (x, y) = (z[(i, k)], z[(i, k + 1)]);
+                    z[(i, k)] = x*c + y*s.conj();
+                    z[(i, k + 1)] = y*c - x*s;
+                }
+            }
+        }
+        for k in lo..hi
+        {
+            h[(k, k)] = h[(k, k)] + mu
+        }
+    }
+
+    Ok(())
+}
+
+/// The eigenvalue of the 2×2 matrix `[a b; c d]` closest to `d`.
+fn wilkinson_shift<R>(a: Complex<R>, b: Complex<R>, c: Complex<R>, d: Complex<R>) -> Complex<R>
+where
+    R: Float
+{
+    let two = R::one() + R::one();
+    let m = (a + d)/two;
+    let r = (((a - d)/two).powi(2) + b*c).sqrt();
+    let (l1, l2) = (m + r, m - r);
+    if (l1 - d).norm() <= (l2 - d).norm() {l1} else {l2}
+}
+
+/// A rotation `[c s; -s* c]` with real `c` which zeroes `y` in `[x; y]`.
+fn givens<R>(x: Complex<R>, y: Complex<R>) -> (R, Complex<R>)
+where
+    R: Float
+{
+    let r = x.norm().hypot(y.norm());
+    if r.is_zero()
+    {
+        return (R::one(), Complex::zero())
+    }
+    if x.is_zero()
+    {
+        return (R::zero(), y.conj()/y.norm())
+    }
+    let phase = x/x.norm();
+    (x.norm()/r, phase*y.conj()/r)
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+    use num::Complex;
+
+    use crate::util::{hessenberg, schur, EigVals};
+
+    #[test]
+    fn eigvals()
+    {
+        // Companion matrix of (x - 1)(x - 2)(x² + 1)
+        let a = array![
+            [3.0, -3.0, 3.0, -2.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0]
+        ];
+
+        let mut lambda: Vec<Complex<f64>> = a.eigvals().unwrap().to_vec();
+        // The real parts of the conjugate pair are only zero up to rounding, so they are compared with a tolerance.
+        lambda.sort_by(|a, b| if (a.re - b.re).abs() > 1e-9
+        {
+            a.re.partial_cmp(&b.re).unwrap()
+        }
+        else
+        {
+            a.im.partial_cmp(&b.im).unwrap()
+        });
+
+        let expected = [Complex::new(0.0, -1.0), Complex::new(0.0, 1.0), Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)];
+        for (l, e) in lambda.into_iter().zip(expected)
+        {
+            assert!((l - e).norm() < 1e-9, "{l} != {e}");
+        }
+    }
+
+    #[test]
+    fn hessenberg_form()
+    {
+        let a = array![
+            [4.0, 1.0, -2.0, 2.0],
+            [1.0, 2.0, 0.0, 1.0],
+            [-2.0, 0.0, 3.0, -2.0],
+            [2.0, 1.0, -2.0, -1.0]
+        ];
+
+        let (q, h) = hessenberg(&a).unwrap();
+        let e = q.dot(&h).dot(&q.t()) - &a;
+
+        assert!(e.iter().all(|e: &f64| e.abs() < 1e-12));
+        assert!(h[(2, 0)] == 0.0 && h[(3, 0)] == 0.0 && h[(3, 1)] == 0.0);
+    }
+
+    #[test]
+    fn schur_form()
+    {
+        let a = array![
+            [1.0, 2.0, 0.0],
+            [-2.0, 1.0, 3.0],
+            [0.5, 0.0, -1.0]
+        ];
+
+        let (z, t) = schur(&a).unwrap();
+        let e = z.dot(&t).dot(&z.t().map(|z| z.conj())) - a.map(|&a| Complex::from(a));
+
+        assert!(e.iter().all(|e| e.norm() < 1e-12));
+        assert!(t[(1, 0)].norm() < 1e-12 && t[(2, 0)].norm() < 1e-12 && t[(2, 1)].norm() < 1e-12);
+    }
+}
@@ -0,0 +1,45 @@
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum LinalgError
+{
+    #[error("Matrix must be square, but has {rows} rows and {cols} columns.")]
+    NotSquare
+    {
+        rows: i32,
+        cols: i32
+    },
+    #[error("Dimensions of the operands do not agree.")]
+    Shape,
+    #[error("Matrix is singular.")]
+    Singular,
+    #[error("Matrix is not positive definite.")]
+    NotPositiveDefinite,
+    #[error("Iteration did not converge.")]
+    NoConvergence,
+    #[cfg(feature = "lapack")]
+    #[error("{0}")]
+    Lapack(String)
+}
+
+#[cfg(feature = "lapack")]
+impl From<ndarray_linalg::error::LinalgError> for LinalgError
+{
+    fn from(error: ndarray_linalg::error::LinalgError) -> Self
+    {
+        Self::Lapack(error.to_string())
+    }
+}
+
+pub(crate) fn assert_square<T>(a: &ndarray::ArrayView2<T>) -> Result<usize, LinalgError>
+{
+    let (rows, cols) = a.dim();
+    if rows != cols
+    {
+        return Err(LinalgError::NotSquare {
+            rows: rows as i32,
+            cols: cols as i32
+        })
+    }
+    Ok(rows)
+}
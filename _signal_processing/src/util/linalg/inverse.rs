@@ -0,0 +1,57 @@
+use ndarray::{Array2, ArrayBase, Data, Ix2};
+
+use super::{Lapack, LinalgError};
+
+/// Inverts a square matrix.
+pub trait Inverse
+{
+    type Output;
+
+    fn inv(&self) -> Result<Self::Output, LinalgError>;
+}
+
+impl<T, S> Inverse for ArrayBase<S, Ix2>
+where
+    T: Lapack,
+    S: Data<Elem = T>
+{
+    type Output = Array2<T>;
+
+    fn inv(&self) -> Result<Self::Output, LinalgError>
+    {
+        T::_inv(self.view())
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::{array, Array2};
+    use num::Complex;
+
+    use crate::util::{Inverse, LinalgError};
+
+    #[test]
+    fn inv()
+    {
+        let a = array![
+            [Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)],
+            [Complex::new(0.0, -1.0), Complex::new(3.0, 0.5)]
+        ];
+
+        let i = a.dot(&a.inv().unwrap()) - Array2::<Complex<f64>>::eye(2);
+
+        assert!(i.iter().all(|i| i.norm() < 1e-12));
+    }
+
+    #[test]
+    fn singular()
+    {
+        let a = array![
+            [1.0, 2.0],
+            [2.0, 4.0]
+        ];
+
+        assert_eq!(a.inv(), Err(LinalgError::Singular));
+    }
+}
@@ -0,0 +1,101 @@
+use ndarray::{Array1, Array2, ArrayView1, ArrayView2};
+use num::{complex::ComplexFloat, Complex};
+
+use super::{cholesky, eig, least_squares, lu, qr, svd, LinalgError};
+
+/// The scalars which the linear algebra routines work with.
+///
+/// This is implemented for `f32`, `f64`, `Complex<f32>` and `Complex<f64>`.
+pub trait Lapack: ComplexFloat<Real: Into<Self>> + Into<Complex<<Self as ComplexFloat>::Real>> + 'static
+{
+    #[doc(hidden)]
+    fn _solve(a: ArrayView2<Self>, b: ArrayView1<Self>) -> Result<Array1<Self>, LinalgError>;
+    #[doc(hidden)]
+    fn _inv(a: ArrayView2<Self>) -> Result<Array2<Self>, LinalgError>;
+    #[doc(hidden)]
+    fn _cholesky(a: ArrayView2<Self>) -> Result<Array2<Self>, LinalgError>;
+    #[doc(hidden)]
+    fn _qr(a: ArrayView2<Self>) -> Result<(Array2<Self>, Array2<Self>), LinalgError>;
+    #[doc(hidden)]
+    fn _svd(a: ArrayView2<Self>, calc_u: bool, calc_vt: bool) -> Result<SvdResult<Self>, LinalgError>;
+    #[doc(hidden)]
+    fn _least_squares(a: ArrayView2<Self>, b: ArrayView1<Self>) -> Result<Array1<Self>, LinalgError>;
+    #[doc(hidden)]
+    fn _eigvals(a: ArrayView2<Self>) -> Result<Array1<Complex<<Self as ComplexFloat>::Real>>, LinalgError>;
+}
+
+/// The left singular vectors, the singular values in descending order and the conjugate-transposed right singular vectors.
+pub type SvdResult<T> = (Option<Array2<T>>, Array1<<T as ComplexFloat>::Real>, Option<Array2<T>>);
+
+macro_rules! impl_lapack {
+    ($($t:ty),*) => {
+        $(
+            #[cfg(not(feature = "lapack"))]
+            impl Lapack for $t
+            {
+                fn _solve(a: ArrayView2<Self>, b: ArrayView1<Self>) -> Result<Array1<Self>, LinalgError>
+                {
+                    lu::solve(a, b)
+                }
+                fn _inv(a: ArrayView2<Self>) -> Result<Array2<Self>, LinalgError>
+                {
+                    lu::inv(a)
+                }
+                fn _cholesky(a: ArrayView2<Self>) -> Result<Array2<Self>, LinalgError>
+                {
+                    cholesky::cholesky(a)
+                }
+                fn _qr(a: ArrayView2<Self>) -> Result<(Array2<Self>, Array2<Self>), LinalgError>
+                {
+                    Ok(qr::qr(a))
+                }
+                fn _svd(a: ArrayView2<Self>, calc_u: bool, calc_vt: bool) -> Result<SvdResult<Self>, LinalgError>
+                {
+                    svd::svd(a, calc_u, calc_vt)
+                }
+                fn _least_squares(a: ArrayView2<Self>, b: ArrayView1<Self>) -> Result<Array1<Self>, LinalgError>
+                {
+                    least_squares::least_squares(a, b)
+                }
+                fn _eigvals(a: ArrayView2<Self>) -> Result<Array1<Complex<<Self as ComplexFloat>::Real>>, LinalgError>
+                {
+                    eig::eigvals(a)
+                }
+            }
+
+            #[cfg(feature = "lapack")]
+            impl Lapack for $t
+            {
+                fn _solve(a: ArrayView2<Self>, b: ArrayView1<Self>) -> Result<Array1<Self>, LinalgError>
+                {
+                    Ok(ndarray_linalg::Solve::solve(&a, &b)?)
+                }
+                fn _inv(a: ArrayView2<Self>) -> Result<Array2<Self>, LinalgError>
+                {
+                    Ok(ndarray_linalg::Inverse::inv(&a)?)
+                }
+                fn _cholesky(a: ArrayView2<Self>) -> Result<Array2<Self>, LinalgError>
+                {
+                    Ok(ndarray_linalg::Cholesky::cholesky(&a, ndarray_linalg::UPLO::Lower)?)
+                }
+                fn _qr(a: ArrayView2<Self>) -> Result<(Array2<Self>, Array2<Self>), LinalgError>
+                {
+                    Ok(ndarray_linalg::QR::qr(&a)?)
+                }
+                fn _svd(a: ArrayView2<Self>, calc_u: bool, calc_vt: bool) -> Result<SvdResult<Self>, LinalgError>
+                {
+                    Ok(ndarray_linalg::SVD::svd(&a, calc_u, calc_vt)?)
+                }
+                fn _least_squares(a: ArrayView2<Self>, b: ArrayView1<Self>) -> Result<Array1<Self>, LinalgError>
+                {
+                    Ok(ndarray_linalg::LeastSquaresSvd::least_squares(&a, &b)?.solution)
+                }
+                fn _eigvals(a: ArrayView2<Self>) -> Result<Array1<Complex<<Self as ComplexFloat>::Real>>, LinalgError>
+                {
+                    Ok(ndarray_linalg::EigVals::eigvals(&a)?)
+                }
+            }
+        )*
+    };
+}
+impl_lapack!(f32, f64, Complex<f32>, Complex<f64>);
@@ -0,0 +1,88 @@
+use ndarray::{Array1, ArrayBase, ArrayView1, ArrayView2, Data, Ix1, Ix2};
+use num::{complex::ComplexFloat, Float, NumCast, Zero};
+
+use super::{svd, Lapack, LinalgError};
+
+/// Finds the minimum-norm solution of `Ax = b` in the least-squares sense.
+///
+/// Singular values below `ε·max(m, n)·σ₀` are treated as zero.
+pub trait LeastSquares<T>
+{
+    fn least_squares<S>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<T>, LinalgError>
+    where
+        S: Data<Elem = T>;
+}
+
+impl<T, A> LeastSquares<T> for ArrayBase<A, Ix2>
+where
+    T: Lapack,
+    A: Data<Elem = T>
+{
+    fn least_squares<S>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<T>, LinalgError>
+    where
+        S: Data<Elem = T>
+    {
+        T::_least_squares(self.view(), b.view())
+    }
+}
+
+pub(super) fn least_squares<T>(a: ArrayView2<T>, b: ArrayView1<T>) -> Result<Array1<T>, LinalgError>
+where
+    T: ComplexFloat<Real: Into<T>>
+{
+    let (m, n) = a.dim();
+    if b.len() != m
+    {
+        return Err(LinalgError::Shape)
+    }
+
+    let (u, s, vt) = svd::svd(a, true, true)?;
+    let (u, vt) = (u.unwrap(), vt.unwrap());
+
+    let tol = s.first()
+        .copied()
+        .unwrap_or_else(Zero::zero)*T::Real::epsilon()*<T::Real as NumCast>::from(m.max(n)).unwrap();
+
+    // x = VΣ⁺Uᴴb
+    let mut x = Array1::zeros(n);
+    for (k, &s) in s.iter()
+        .enumerate()
+        .take_while(|&(_, &s)| s > tol)
+    {
+        let c = (0..m).fold(T::zero(), |c, i| c + u[(i, k)].conj()*b[i])/s.into();
+        for j in 0..n
+        {
+            x[j] = x[j] + vt[(k, j)].conj()*c
+        }
+    }
+    Ok(x)
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::util::LeastSquares;
+
+    #[test]
+    fn least_squares()
+    {
+        // Fit y = 1 + 2x
+        let a = array![
+            [1.0, 0.0],
+            [1.0, 1.0],
+            [1.0, 2.0],
+            [1.0, 3.0]
+        ];
+        let b = array![1.0, 3.0, 5.0, 7.0];
+
+        let x = a.least_squares(&b).unwrap();
+        assert!((x[0] - 1.0f64).abs() < 1e-12);
+        assert!((x[1] - 2.0f64).abs() < 1e-12);
+
+        // Underdetermined, so the minimum-norm solution is picked.
+        let x = array![[1.0, 1.0]].least_squares(&array![2.0]).unwrap();
+        assert!((x[0] - 1.0f64).abs() < 1e-12 && (x[1] - 1.0f64).abs() < 1e-12);
+    }
+}
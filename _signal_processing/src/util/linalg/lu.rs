@@ -0,0 +1,117 @@
+use core::cmp::Ordering;
+
+use ndarray::{Array1, Array2, ArrayView1, ArrayView2};
+use num::complex::ComplexFloat;
+
+use super::{error, LinalgError};
+
+/// An LU factorization with partial pivoting, `PA = LU`.
+///
+/// `L` and `U` are stored together, with the unit diagonal of `L` left implicit.
+pub(super) struct Lu<T>
+{
+    lu: Array2<T>,
+    perm: Vec<usize>
+}
+
+impl<T> Lu<T>
+where
+    T: ComplexFloat
+{
+    pub(super) fn new(a: ArrayView2<T>) -> Result<Self, LinalgError>
+    {
+        let n = error::assert_square(&a)?;
+        let mut lu = a.to_owned();
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        for k in 0..n
+        {
+            let p = (k..n).max_by(|&i, &j| lu[(i, k)].abs()
+                    .partial_cmp(&lu[(j, k)].abs())
+                    .unwrap_or(Ordering::Equal)
+                ).unwrap();
+            if lu[(p, k)].is_zero()
+            {
+                return Err(LinalgError::Singular)
+            }
+            if p != k
+            {
+                for j in 0..n
+                {
+                    lu.swap((p, j), (k, j))
+                }
+                perm.swap(p, k);
+            }
+
+            let d = lu[(k, k)];
+            for i in k + 1..n
+            {
+                let l = lu[(i, k)]/d;
+                lu[(i, k)] = l;
+                for j in k + 1..n
+                {
+                    let u = lu[(k, j)];
+                    lu[(i, j)] = lu[(i, j)] - l*u
+                }
+            }
+        }
+
+        Ok(Self {
+            lu,
+            perm
+        })
+    }
+
+    pub(super) fn solve(&self, b: ArrayView1<T>) -> Result<Array1<T>, LinalgError>
+    {
+        let n = self.perm.len();
+        if b.len() != n
+        {
+            return Err(LinalgError::Shape)
+        }
+
+        let mut x: Array1<T> = self.perm.iter()
+            .map(|&p| b[p])
+            .collect();
+        for i in 0..n
+        {
+            for j in 0..i
+            {
+                x[i] = x[i] - self.lu[(i, j)]*x[j]
+            }
+        }
+        for i in (0..n).rev()
+        {
+            for j in i + 1..n
+            {
+                x[i] = x[i] - self.lu[(i, j)]*x[j]
+            }
+            x[i] = x[i]/self.lu[(i, i)]
+        }
+        Ok(x)
+    }
+}
+
+pub(super) fn solve<T>(a: ArrayView2<T>, b: ArrayView1<T>) -> Result<Array1<T>, LinalgError>
+where
+    T: ComplexFloat
+{
+    Lu::new(a)?.solve(b)
+}
+
+pub(super) fn inv<T>(a: ArrayView2<T>) -> Result<Array2<T>, LinalgError>
+where
+    T: ComplexFloat
+{
+    let lu = Lu::new(a)?;
+    let n = a.nrows();
+
+    let mut y = Array2::zeros((n, n));
+    for j in 0..n
+    {
+        let mut e = Array1::zeros(n);
+        e[j] = T::one();
+        y.column_mut(j).assign(&lu.solve(e.view())?);
+    }
+    Ok(y)
+}
@@ -0,0 +1,117 @@
+use ndarray::{Array1, Array2, ArrayBase, Data, Ix2};
+use num::{complex::ComplexFloat, Complex, Float, One};
+
+use super::{error, schur, Lapack, LinalgError};
+use crate::util::TruncateIm;
+
+/// Solves the continuous Lyapunov equation `AX + XAᴴ + Q = 0` with the Bartels-Stewart algorithm.
+///
+/// The solution is unique as long as no two eigenvalues of `A` sum to zero, which holds for any stable `A`.
+pub fn lyap<T, SA, SQ>(a: &ArrayBase<SA, Ix2>, q: &ArrayBase<SQ, Ix2>) -> Result<Array2<T>, LinalgError>
+where
+    T: Lapack,
+    SA: Data<Elem = T>,
+    SQ: Data<Elem = T>
+{
+    bartels_stewart(a, q, false)
+}
+
+/// Solves the discrete Lyapunov equation `AXAᴴ - X + Q = 0` with the Bartels-Stewart algorithm.
+///
+/// The solution is unique as long as no product of two eigenvalues of `A` is one, which holds for any stable `A`.
+pub fn dlyap<T, SA, SQ>(a: &ArrayBase<SA, Ix2>, q: &ArrayBase<SQ, Ix2>) -> Result<Array2<T>, LinalgError>
+where
+    T: Lapack,
+    SA: Data<Elem = T>,
+    SQ: Data<Elem = T>
+{
+    bartels_stewart(a, q, true)
+}
+
+fn bartels_stewart<T, SA, SQ>(a: &ArrayBase<SA, Ix2>, q: &ArrayBase<SQ, Ix2>, discrete: bool) -> Result<Array2<T>, LinalgError>
+where
+    T: Lapack,
+    SA: Data<Elem = T>,
+    SQ: Data<Elem = T>
+{
+    let n = error::assert_square(&a.view())?;
+    if q.dim() != (n, n)
+    {
+        return Err(LinalgError::Shape)
+    }
+
+    // With A = UTUᴴ, the equation becomes TY + YTᴴ + F = 0 (or TYTᴴ - Y + F = 0) for Y = UᴴXU and F = UᴴQU.
+    let (u, t) = schur(a)?;
+    let uh = u.t().map(|u| u.conj());
+    let f = uh.dot(&q.map(|&q| q.into())).dot(&u);
+
+    // Since Tᴴ is lower triangular, the columns of Y can be found from the last one and backwards.
+    let mut y: Array2<Complex<T::Real>> = Array2::zeros((n, n));
+    let mut ty: Array2<Complex<T::Real>> = Array2::zeros((n, n));
+    for j in (0..n).rev()
+    {
+        let mut r: Array1<_> = f.column(j).map(|&f| -f);
+        for k in j + 1..n
+        {
+            let tjk = t[(j, k)].conj();
+            let yk = if discrete {ty.column(k)} else {y.column(k)};
+            r.zip_mut_with(&yk, |r, &yk| *r = *r - yk*tjk);
+        }
+
+        // Back-substitution with (T + t̄ⱼⱼI) or (t̄ⱼⱼT - I)
+        let (alpha, beta) = if discrete {(t[(j, j)].conj(), -Complex::one())} else {(Complex::one(), t[(j, j)].conj())};
+        for i in (0..n).rev()
+        {
+            let mut s = r[i];
+            for l in i + 1..n
+            {
+                s = s - alpha*t[(i, l)]*y[(l, j)];
+            }
+            let d = alpha*t[(i, i)] + beta;
+            if d.norm() <= T::Real::epsilon()*(t[(i, i)].norm() + t[(j, j)].norm() + One::one())
+            {
+                return Err(LinalgError::Singular)
+            }
+            y[(i, j)] = s/d;
+        }
+        if discrete
+        {
+            let tyj = t.dot(&y.column(j));
+            ty.column_mut(j).assign(&tyj);
+        }
+    }
+
+    Ok(u.dot(&y).dot(&uh).map(|&x| x.truncate_im::<T>()))
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::util::{dlyap, lyap};
+
+    #[test]
+    fn continuous()
+    {
+        let a = array![[-1.0, 2.0], [0.0, -3.0]];
+        let q = array![[1.0, 0.5], [0.5, 2.0]];
+
+        let x = lyap(&a, &q).unwrap();
+        let e = a.dot(&x) + x.dot(&a.t()) + &q;
+
+        assert!(e.iter().all(|e: &f64| e.abs() < 1e-12));
+    }
+
+    #[test]
+    fn discrete()
+    {
+        let a = array![[0.5, 0.2, 0.0], [-0.1, 0.3, 0.4], [0.0, 0.0, -0.6]];
+        let q = array![[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 1.0]];
+
+        let x = dlyap(&a, &q).unwrap();
+        let e = a.dot(&x).dot(&a.t()) - &x + &q;
+
+        assert!(e.iter().all(|e: &f64| e.abs() < 1e-12));
+    }
+}
@@ -0,0 +1,28 @@
+//! Dense linear algebra in pure Rust.
+//!
+//! The routines here cover what the rest of the crate needs: LU, Cholesky and QR factorizations, least squares,
+//! eigenvalues and the Schur form through Hessenberg reduction and shifted QR iteration, the singular value decomposition
+//! and Lyapunov equations.
+//!
+//! With the `lapack` feature, the factorizations are instead delegated to `ndarray-linalg`, which must then be given a LAPACK backend.
+
+#![cfg_attr(feature = "lapack", allow(dead_code, unused_imports))]
+
+moddef::moddef!(
+    flat(pub) mod {
+        cholesky,
+        eig,
+        error,
+        inverse,
+        lapack,
+        least_squares,
+        lyapunov,
+        norm,
+        qr,
+        solve,
+        svd
+    },
+    mod {
+        lu
+    }
+);
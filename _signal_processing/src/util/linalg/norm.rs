@@ -0,0 +1,47 @@
+use ndarray::{ArrayBase, Data, Ix2};
+use num::{complex::ComplexFloat, Float, Zero};
+
+use super::LinalgError;
+
+/// Operator norms of a matrix.
+pub trait OperationNorm
+{
+    type Output;
+
+    /// The largest absolute column sum.
+    fn opnorm_one(&self) -> Result<Self::Output, LinalgError>;
+    /// The largest absolute row sum.
+    fn opnorm_inf(&self) -> Result<Self::Output, LinalgError>;
+    /// The Frobenius norm.
+    fn opnorm_fro(&self) -> Result<Self::Output, LinalgError>;
+}
+
+impl<T, S> OperationNorm for ArrayBase<S, Ix2>
+where
+    T: ComplexFloat,
+    S: Data<Elem = T>
+{
+    type Output = T::Real;
+
+    fn opnorm_one(&self) -> Result<Self::Output, LinalgError>
+    {
+        Ok(self.columns()
+            .into_iter()
+            .map(|c| c.iter().fold(T::Real::zero(), |s, c| s + c.abs()))
+            .fold(T::Real::zero(), Float::max))
+    }
+
+    fn opnorm_inf(&self) -> Result<Self::Output, LinalgError>
+    {
+        Ok(self.rows()
+            .into_iter()
+            .map(|r| r.iter().fold(T::Real::zero(), |s, r| s + r.abs()))
+            .fold(T::Real::zero(), Float::max))
+    }
+
+    fn opnorm_fro(&self) -> Result<Self::Output, LinalgError>
+    {
+        Ok(Float::sqrt(self.iter()
+            .fold(T::Real::zero(), |s, a| s + Float::powi(a.abs(), 2))))
+    }
+}
@@ -0,0 +1,186 @@
+use ndarray::{s, Array2, ArrayBase, ArrayView2, Data, DataMut, Ix2};
+use num::{complex::ComplexFloat, Float, Zero};
+
+use super::{Lapack, LinalgError};
+
+/// The reduced QR factorization `A = QR` of an `m×n` matrix.
+///
+/// `Q` is `m×k` with orthonormal columns and `R` is `k×n` upper-triangular, where `k = min(m, n)`.
+pub trait QR
+{
+    type Q;
+    type R;
+
+    fn qr(&self) -> Result<(Self::Q, Self::R), LinalgError>;
+}
+
+/// Like [QR], but consumes the matrix.
+pub trait QRInto
+{
+    type Q;
+    type R;
+
+    fn qr_into(self) -> Result<(Self::Q, Self::R), LinalgError>;
+}
+
+impl<T, S> QR for ArrayBase<S, Ix2>
+where
+    T: Lapack,
+    S: Data<Elem = T>
+{
+    type Q = Array2<T>;
+    type R = Array2<T>;
+
+    fn qr(&self) -> Result<(Self::Q, Self::R), LinalgError>
+    {
+        T::_qr(self.view())
+    }
+}
+
+impl<T, S> QRInto for ArrayBase<S, Ix2>
+where
+    T: Lapack,
+    S: Data<Elem = T>
+{
+    type Q = Array2<T>;
+    type R = Array2<T>;
+
+    fn qr_into(self) -> Result<(Self::Q, Self::R), LinalgError>
+    {
+        self.qr()
+    }
+}
+
+pub(super) fn qr<T>(a: ArrayView2<T>) -> (Array2<T>, Array2<T>)
+where
+    T: ComplexFloat<Real: Into<T>>
+{
+    let (m, n) = a.dim();
+    let k = m.min(n);
+
+    let mut r = a.to_owned();
+    let mut q = Array2::eye(m);
+    for j in 0..k
+    {
+        if let Some(v) = householder((j..m).map(|i| r[(i, j)]))
+        {
+            reflect_left(&mut r, &v, j);
+            reflect_right(&mut q, &v, j);
+            for i in j + 1..m
+            {
+                r[(i, j)] = T::zero()
+            }
+        }
+    }
+
+    (q.slice_move(s![.., ..k]), r.slice_move(s![..k, ..]))
+}
+
+/// Finds the unit vector `v` of the Householder reflection `I - 2vvᴴ` which maps `x` onto a multiple of the first unit vector.
+///
+/// Returns `None` if `x` is zero.
+pub(super) fn householder<T>(x: impl IntoIterator<Item = T>) -> Option<Vec<T>>
+where
+    T: ComplexFloat<Real: Into<T>>
+{
+    let mut v: Vec<T> = x.into_iter().collect();
+    let norm = norm(&v);
+    if norm.is_zero()
+    {
+        return None
+    }
+
+    // Reflecting onto -e^(j∠x₀)‖x‖ avoids cancellation.
+    let x0 = v[0];
+    let phase = if x0.is_zero() {T::one()} else {x0/x0.abs().into()};
+    v[0] = x0 + phase*norm.into();
+
+    let norm = self::norm(&v);
+    for v in v.iter_mut()
+    {
+        *v = *v/norm.into()
+    }
+    Some(v)
+}
+
+/// The euclidean norm of a vector.
+pub(super) fn norm<T>(v: &[T]) -> T::Real
+where
+    T: ComplexFloat
+{
+    v.iter()
+        .map(|v| v.abs())
+        .fold(T::Real::zero(), Float::hypot)
+}
+
+/// Applies `I - 2vvᴴ` from the left to the rows from `offset` on.
+pub(super) fn reflect_left<T, S>(a: &mut ArrayBase<S, Ix2>, v: &[T], offset: usize)
+where
+    T: ComplexFloat,
+    S: DataMut<Elem = T>
+{
+    let two = T::one() + T::one();
+    for j in 0..a.ncols()
+    {
+        let d = v.iter()
+            .enumerate()
+            .fold(T::zero(), |d, (i, &v)| d + v.conj()*a[(offset + i, j)]);
+        for (i, &v) in v.iter()
+            .enumerate()
+        {
+            a[(offset + i, j)] = a[(offset + i, j)] - two*v*d
+        }
+    }
+}
+
+/// Applies `I - 2vvᴴ` from the right to the columns from `offset` on.
+pub(super) fn reflect_right<T, S>(a: &mut ArrayBase<S, Ix2>, v: &[T], offset: usize)
+where
+    T: ComplexFloat,
+    S: DataMut<Elem = T>
+{
+    let two = T::one() + T::one();
+    for i in 0..a.nrows()
+    {
+        let d = v.iter()
+            .enumerate()
+            .fold(T::zero(), |d, (j, &v)| d + a[(i, offset + j)]*v);
+        for (j, &v) in v.iter()
+            .enumerate()
+        {
+            a[(i, offset + j)] = a[(i, offset + j)] - two*d*v.conj()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::{array, Array2};
+
+    use crate::util::{QRInto, QR};
+
+    #[test]
+    fn qr()
+    {
+        let a = array![
+            [1.0, 2.0],
+            [3.0, 4.0],
+            [5.0, 7.0]
+        ];
+
+        let (q, r) = a.qr().unwrap();
+        assert_eq!(q.dim(), (3, 2));
+        assert_eq!(r.dim(), (2, 2));
+        assert_eq!(r[(1, 0)], 0.0);
+
+        let e = q.dot(&r) - &a;
+        assert!(e.iter().all(|e: &f64| e.abs() < 1e-12));
+
+        let e = q.t().dot(&q) - Array2::<f64>::eye(2);
+        assert!(e.iter().all(|e: &f64| e.abs() < 1e-12));
+
+        let (_, r) = a.t().to_owned().qr_into().unwrap();
+        assert_eq!(r.dim(), (2, 3));
+    }
+}
@@ -0,0 +1,48 @@
+use ndarray::{Array1, ArrayBase, Data, Ix1, Ix2};
+
+use super::{Lapack, LinalgError};
+
+/// Solves a square linear system `Ax = b`.
+pub trait Solve<T>
+{
+    fn solve<S>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<T>, LinalgError>
+    where
+        S: Data<Elem = T>;
+}
+
+impl<T, A> Solve<T> for ArrayBase<A, Ix2>
+where
+    T: Lapack,
+    A: Data<Elem = T>
+{
+    fn solve<S>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<T>, LinalgError>
+    where
+        S: Data<Elem = T>
+    {
+        T::_solve(self.view(), b.view())
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use crate::util::Solve;
+
+    #[test]
+    fn solve()
+    {
+        let a = array![
+            [0.0, 2.0, 1.0],
+            [1.0, 1.0, 0.0],
+            [3.0, 0.0, 1.0]
+        ];
+        let b = array![5.0, 3.0, 6.0];
+
+        let x = a.solve(&b).unwrap();
+        let r = a.dot(&x) - b;
+
+        assert!(r.iter().all(|r: &f64| r.abs() < 1e-12));
+    }
+}
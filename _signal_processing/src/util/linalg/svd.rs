@@ -0,0 +1,254 @@
+use core::cmp::Ordering;
+
+use ndarray::{Array1, Array2, ArrayBase, ArrayView2, Data, Ix2};
+use num::{complex::ComplexFloat, Float, NumCast, One, Zero};
+
+use super::{qr, Lapack, LinalgError, SvdResult};
+
+const MAX_SWEEPS: usize = 64;
+
+/// The singular value decomposition `A = UΣVᴴ`.
+///
+/// `U` and `Vᴴ` are only computed if asked for, and are then square. The singular values are in descending order.
+pub trait SVD
+{
+    type U;
+    type VT;
+    type Sigma;
+
+    fn svd(&self, calc_u: bool, calc_vt: bool) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>), LinalgError>;
+}
+
+/// Like [SVD], but consumes the matrix.
+pub trait SVDInto
+{
+    type U;
+    type VT;
+    type Sigma;
+
+    fn svd_into(self, calc_u: bool, calc_vt: bool) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>), LinalgError>;
+}
+
+impl<T, S> SVD for ArrayBase<S, Ix2>
+where
+    T: Lapack,
+    S: Data<Elem = T>
+{
+    type U = Array2<T>;
+    type VT = Array2<T>;
+    type Sigma = Array1<T::Real>;
+
+    fn svd(&self, calc_u: bool, calc_vt: bool) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>), LinalgError>
+    {
+        T::_svd(self.view(), calc_u, calc_vt)
+    }
+}
+
+impl<T, S> SVDInto for ArrayBase<S, Ix2>
+where
+    T: Lapack,
+    S: Data<Elem = T>
+{
+    type U = Array2<T>;
+    type VT = Array2<T>;
+    type Sigma = Array1<T::Real>;
+
+    fn svd_into(self, calc_u: bool, calc_vt: bool) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>), LinalgError>
+    {
+        self.svd(calc_u, calc_vt)
+    }
+}
+
+/// One-sided Jacobi SVD.
+///
+/// Pairs of columns are rotated until they are all mutually orthogonal, at which point their norms are the singular values.
+pub(super) fn svd<T>(a: ArrayView2<T>, calc_u: bool, calc_vt: bool) -> Result<SvdResult<T>, LinalgError>
+where
+    T: ComplexFloat<Real: Into<T>>
+{
+    let (m, n) = a.dim();
+    if m < n
+    {
+        // A = (Aᴴ)ᴴ = VΣUᴴ
+        let (u, s, vt) = svd(conj_t(a).view(), calc_vt, calc_u)?;
+        return Ok((vt.map(|vt| conj_t(vt.view())), s, u.map(|u| conj_t(u.view()))))
+    }
+
+    let one = T::Real::one();
+    let eps = T::Real::epsilon();
+    // The inner products of the columns are only accurate to about `m` roundings.
+    let tol = eps*<T::Real as NumCast>::from(m).unwrap();
+    // Columns this small are rounding noise of zero singular values, and never become orthogonal to the others.
+    let negligible = tol*tol*a.iter()
+        .fold(T::Real::zero(), |s, a| s + Float::powi(a.abs(), 2));
+
+    let mut u = a.to_owned();
+    let mut v = Array2::eye(n);
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS
+    {
+        let mut rotated = false;
+        for p in 0..n
+        {
+            for q in p + 1..n
+            {
+                let (alpha, beta, gamma) = (0..m).fold((T::Real::zero(), T::Real::zero(), T::zero()), |(alpha, beta, gamma), i| {
+                    let (x, y) = (u[(i, p)], u[(i, q)]);
+                    (alpha + Float::powi(x.abs(), 2), beta + Float::powi(y.abs(), 2), gamma + x.conj()*y)
+                });
+                let g = gamma.abs();
+                if g.is_zero() || g <= tol*Float::sqrt(alpha*beta) || Float::min(alpha, beta) <= negligible
+                {
+                    continue
+                }
+                rotated = true;
+
+                let e = gamma/g.into();
+                let zeta = (beta - alpha)/(g + g);
+                let t = Float::signum(zeta)/(Float::abs(zeta) + Float::sqrt(one + zeta*zeta));
+                let c = Float::recip(Float::sqrt(one + t*t));
+                let s = c*t;
+                rotate(&mut u, p, q, c, s, e);
+                rotate(&mut v, p, q, c, s, e);
+            }
+        }
+        if !rotated
+        {
+            converged = true;
+            break
+        }
+    }
+    if !converged
+    {
+        return Err(LinalgError::NoConvergence)
+    }
+
+    let mut order: Vec<(T::Real, usize)> = (0..n).map(|j| (qr::norm(&u.column(j).to_vec()), j))
+        .collect();
+    order.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(Ordering::Equal));
+    let sigma = order.iter()
+        .map(|&(s, _)| s)
+        .collect();
+
+    let u = calc_u.then(|| {
+        let tol = order.first()
+            .map(|&(s, _)| s)
+            .unwrap_or_else(Zero::zero)*eps*<T::Real as NumCast>::from(m).unwrap();
+        let mut basis: Vec<Array1<T>> = order.iter()
+            .filter(|&&(s, _)| s > tol)
+            .map(|&(s, j)| u.column(j).map(|&u| u/s.into()))
+            .collect();
+        complete(&mut basis, m);
+        Array2::from_shape_fn((m, m), |(i, j)| basis[j][i])
+    });
+    let vt = calc_vt.then(|| Array2::from_shape_fn((n, n), |(i, j)| v[(j, order[i].1)].conj()));
+
+    Ok((u, sigma, vt))
+}
+
+/// Rotates columns `p` and `q` as `[c -se*; se c]`.
+fn rotate<T>(a: &mut Array2<T>, p: usize, q: usize, c: T::Real, s: T::Real, e: T)
+where
+    T: ComplexFloat<Real: Into<T>>
+{
+    let (c, s): (T, T) = (c.into(), s.into());
+    for i in 0..a.nrows()
+    {
+        let (x, y) = (a[(i, p)], a[(i, q)]);
+        a[(i, p)] = x*c - y*s*e.conj();
+        a[(i, q)] = x*s*e + y*c;
+    }
+}
+
+fn conj_t<T>(a: ArrayView2<T>) -> Array2<T>
+where
+    T: ComplexFloat
+{
+    a.t().map(|a| a.conj())
+}
+
+/// Extends a set of orthonormal vectors to an orthonormal basis, by Gram-Schmidt orthogonalization of the unit vectors.
+fn complete<T>(basis: &mut Vec<Array1<T>>, m: usize)
+where
+    T: ComplexFloat<Real: Into<T>>
+{
+    while basis.len() < m
+    {
+        let (v, norm) = (0..m).map(|k| {
+                let mut v = Array1::zeros(m);
+                v[k] = T::one();
+                // Orthogonalizing twice keeps the result orthogonal in finite precision.
+                for _ in 0..2
+                {
+                    for u in basis.iter()
+                    {
+                        let d = u.iter()
+                            .zip(v.iter())
+                            .fold(T::zero(), |d, (&u, &v)| d + u.conj()*v);
+                        v.zip_mut_with(u, |v, &u| *v = *v - u*d);
+                    }
+                }
+                let norm = qr::norm(&v.to_vec());
+                (v, norm)
+            }).max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+            .unwrap();
+        basis.push(v.map(|&v| v/norm.into()));
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::{array, Array2};
+    use num::Complex;
+
+    use crate::util::{SVDInto, SVD};
+
+    #[test]
+    fn svd()
+    {
+        let a = array![
+            [1.0, 2.0, 0.0],
+            [2.0, 4.0, 0.0],
+            [0.0, 1.0, 3.0],
+            [1.0, 0.0, 1.0]
+        ];
+
+        let (u, s, vt) = a.svd(true, true).unwrap();
+        let (u, vt) = (u.unwrap(), vt.unwrap());
+        assert_eq!(u.dim(), (4, 4));
+        assert_eq!(vt.dim(), (3, 3));
+        assert!(s.to_vec().windows(2).all(|s| s[0] >= s[1]));
+
+        let mut sigma = Array2::zeros((4, 3));
+        for (i, &s) in s.iter().enumerate()
+        {
+            sigma[(i, i)] = s
+        }
+        let e = u.dot(&sigma).dot(&vt) - &a;
+        assert!(e.iter().all(|e: &f64| e.abs() < 1e-12));
+
+        let e = u.t().dot(&u) - Array2::<f64>::eye(4);
+        assert!(e.iter().all(|e: &f64| e.abs() < 1e-12));
+    }
+
+    #[test]
+    fn svd_wide_complex()
+    {
+        let a = array![
+            [Complex::new(1.0, 1.0), Complex::new(0.0, 2.0), Complex::new(1.0, 0.0)],
+            [Complex::new(2.0, 0.0), Complex::new(1.0, -1.0), Complex::new(0.0, 0.0)]
+        ];
+
+        let (u, s, vt) = a.clone().svd_into(true, true).unwrap();
+        let (u, vt) = (u.unwrap(), vt.unwrap());
+
+        let mut sigma = Array2::zeros((2, 3));
+        for (i, &s) in s.iter().enumerate()
+        {
+            sigma[(i, i)] = Complex::from(s)
+        }
+        let e = u.dot(&sigma).dot(&vt) - &a;
+        assert!(e.iter().all(|e| e.norm() < 1e-12));
+    }
+}
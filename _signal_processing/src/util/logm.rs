@@ -0,0 +1,113 @@
+use ndarray::Array2;
+use num::{Float, NumCast, One};
+
+use super::{Inverse, Lapack, LinalgError, OperationNorm};
+
+const MAX_SQRT_ITER: usize = 64;
+const MAX_SQUARE_ROOTS: usize = 64;
+
+/// The principal matrix logarithm, by inverse scaling and squaring.
+///
+/// Square roots are taken with the Denman-Beavers iteration until the matrix is close to the identity,
+/// at which point the Mercator series of `log(I + X)` converges quickly.
+pub(crate) fn logm<T>(a: Array2<T>) -> Result<Array2<T>, LinalgError>
+where
+    T: Lapack
+{
+    let (rows, cols) = a.dim();
+    if rows != cols
+    {
+        return Err(LinalgError::NotSquare {
+            rows: rows as i32,
+            cols: cols as i32
+        })
+    }
+    let n = rows;
+    let eye = Array2::<T>::eye(n);
+    let quarter = <T::Real as NumCast>::from(0.25).unwrap();
+
+    let mut x = a;
+    let mut k = 0;
+    while (&x - &eye).opnorm_one()? > quarter
+    {
+        if k >= MAX_SQUARE_ROOTS
+        {
+            return Err(LinalgError::NoConvergence)
+        }
+        x = sqrtm(x)?;
+        k += 1;
+    }
+
+    // log(I + Y) = Y - Y²/2 + Y³/3 - ...
+    let y = &x - &eye;
+    let mut term = y.clone();
+    let mut log = y.clone();
+    let eps = T::Real::epsilon();
+    for j in 2..
+    {
+        term = -term.dot(&y);
+        let scale: T = Float::recip(<T::Real as NumCast>::from(j).unwrap()).into();
+        let dlog = term.map(|&t| t*scale);
+        log = log + &dlog;
+        if dlog.opnorm_one()? <= eps*log.opnorm_one()?
+        {
+            break
+        }
+    }
+
+    let scale: T = Float::powi(<T::Real as NumCast>::from(2.0).unwrap(), k as i32).into();
+    Ok(log.map(|&l| l*scale))
+}
+
+/// The principal matrix square root, by the Denman-Beavers iteration.
+fn sqrtm<T>(a: Array2<T>) -> Result<Array2<T>, LinalgError>
+where
+    T: Lapack
+{
+    let half: T = <T::Real as NumCast>::from(0.5).unwrap().into();
+    let tol = Float::sqrt(T::Real::epsilon());
+
+    let mut y = a.clone();
+    let mut z = Array2::<T>::eye(a.nrows());
+    for _ in 0..MAX_SQRT_ITER
+    {
+        let y_next = (&y + &z.inv()?).map(|&y| y*half);
+        let z_next = (&z + &y.inv()?).map(|&z| z*half);
+        let dy = (&y_next - &y).opnorm_one()?;
+        y = y_next;
+        z = z_next;
+        if dy <= tol*Float::max(y.opnorm_one()?, One::one())
+        {
+            // A couple of extra steps, since convergence is quadratic.
+            for _ in 0..2
+            {
+                let y_next = (&y + &z.inv()?).map(|&y| y*half);
+                z = (&z + &y.inv()?).map(|&z| z*half);
+                y = y_next;
+            }
+            return Ok(y)
+        }
+    }
+    Err(LinalgError::NoConvergence)
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::array;
+
+    use super::{super::expm::expm, logm};
+
+    #[test]
+    fn inverts_expm()
+    {
+        let a = array![
+            [-1.0, 0.5, 0.0],
+            [0.2, -2.0, 1.0],
+            [0.0, 0.3, -0.5]
+        ];
+
+        let e = logm(expm(&a).unwrap()).unwrap() - &a;
+        assert!(e.iter().all(|e: &f64| e.abs() < 1e-9));
+    }
+}
@@ -1,7 +1,6 @@
 use core::ops::Mul;
 
 use ndarray::{prelude::Axis, Array2, Slice};
-use ndarray_linalg::{error::LinalgError, Lapack, SVDInto, Scalar};
 use num::{complex::ComplexFloat, traits::FloatConst, Complex, Float, Integer, NumCast, ToPrimitive, Unsigned};
 
 moddef::moddef!(
@@ -9,15 +8,17 @@ moddef::moddef!(
         chain,
         complex_op,
         len_eq,
+        linalg,
         maybe_len_eq,
         not_range,
         overlay,
         result_or_ok,
         truncate_im,
-        two_sided_range,
+        two_sided_range
     },
     mod {
-        expm, // This should be pushed to the ndarray_linalg crate and does not belong here.
+        expm,
+        logm
     }
 );
 
@@ -57,7 +58,7 @@ where
 
 pub(crate) fn expm<T>(m: Array2<T>) -> Result<Array2<T>, LinalgError>
 where
-    T: Scalar + Lapack + ComplexFloat<Real: Into<T>>
+    T: Lapack
 {
     Ok(expm::expm(&m.map(|&a| {
         Complex::new(a.re().to_f64().unwrap(), a.im().to_f64().unwrap())
@@ -67,9 +68,11 @@ where
     }))
 }
 
+pub(crate) use logm::logm;
+
 pub(crate) fn pinv<T>(m: Array2<T>) -> Array2<T>
 where
-    T: Lapack<Real: Into<T>> + Mul<T::Real, Output = T>
+    T: Lapack + Mul<T::Real, Output = T>
 {
     let mdim = m.dim();
     let (u, s, v_h) = m.svd_into(true, true).unwrap();
@@ -86,7 +89,7 @@ where
         for (&sing_val, mut v_h_t_col) in s.iter().zip(v_h_t.columns_mut()) {
             if sing_val > threshold {
                 let sing_val_recip = sing_val.recip();
-                v_h_t_col.map_inplace(|v_h_t| *v_h_t = T::from_real(sing_val_recip) * v_h_t.conj());
+                v_h_t_col.map_inplace(|v_h_t| *v_h_t = v_h_t.conj()*sing_val_recip);
                 num_keep += 1;
             } else {
                 break;
@@ -10,13 +10,7 @@ pub(crate) fn validate_filter_bands<T, const F: usize>(
 where
     T: Float
 {
-    if let Some(sampling_frequency) = sampling_frequency
-    {
-        if !(sampling_frequency > T::zero()) || !sampling_frequency.is_finite()
-        {
-            return Err(FilterBandError::InvalidSamplingFrequency)
-        }
-    }
+    validate_sampling_frequency(sampling_frequency)?;
     if !passband_frequencies.is_sorted()
     {
         return Err(FilterBandError::EdgesNotNondecreasing)
@@ -37,4 +31,49 @@ where
     }*/
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Validates the edges of a list of bands, and normalizes them so that 1 is the Nyquist frequency.
+pub(crate) fn validate_band_edges<T>(
+    edges: &mut [T],
+    sampling_frequency: Option<T>
+) -> Result<(), FilterBandError>
+where
+    T: Float
+{
+    validate_sampling_frequency(sampling_frequency)?;
+    if let Some(sampling_frequency) = sampling_frequency
+    {
+        let two = T::one() + T::one();
+        for f in edges.iter_mut()
+        {
+            *f = *f*two/sampling_frequency
+        }
+    }
+    if edges.iter()
+        .any(|f| !(*f >= T::zero() && *f <= T::one()))
+    {
+        return Err(FilterBandError::EdgesOutOfRange)
+    }
+    if !edges.is_sorted()
+    {
+        return Err(FilterBandError::EdgesNotNondecreasing)
+    }
+
+    Ok(())
+}
+
+fn validate_sampling_frequency<T>(sampling_frequency: Option<T>) -> Result<(), FilterBandError>
+where
+    T: Float
+{
+    if let Some(sampling_frequency) = sampling_frequency
+    {
+        if !(sampling_frequency > T::zero()) || !sampling_frequency.is_finite()
+        {
+            return Err(FilterBandError::InvalidSamplingFrequency)
+        }
+    }
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use core::{borrow::{Borrow, BorrowMut}, ops::Mul};
+use alloc::vec::Vec;
 
 use array_trait::length;
 use bulks::{AsBulk, Bulk, IntoBulk};
@@ -2,8 +2,8 @@ use core::borrow::{Borrow, BorrowMut};
 
 use bulks::{AsBulk, Bulk, IntoBulk};
 use num_complex::{Complex, ComplexFloat};
-use num_traits::{Float, FloatConst};
-use crate::{Dft, Permute, util::TruncateIm};
+use num_traits::{Float, FloatConst, Zero};
+use crate::{Dft, Permute, ScratchLength, util::TruncateIm};
 
 /// Discrete Hartley transform
 pub trait Dht<T>: Permute<T>
@@ -21,12 +21,17 @@ where
 {
     fn dht(&mut self)
     {
-        let mut y = (*self).bulk_mut()
-            .map(|x| Complex { re: x.borrow().re(), im: x.borrow().im() })
-            .collect::<Vec<_>, _>();
+        let len = self.bulk_mut().length();
+        let mut y = ScratchLength::scratch_space(len, Complex::zero());
+        let y: &mut [_] = y.borrow_mut();
+        for (y, x) in y.iter_mut()
+            .zip(self.bulk_mut())
+        {
+            *y = Complex { re: x.borrow().re(), im: x.borrow().im() }
+        }
         y.dft();
 
-        for (y, mut x) in y.into_iter()
+        for (y, mut x) in y.iter()
             .zip(self.bulk_mut())
         {
             let y = y.re - y.im;
@@ -1,9 +1,10 @@
 use core::borrow::{Borrow, BorrowMut};
 
 use array_trait::length;
-use bulks::{AsBulk, Bulk, CollectNearest, IntoBulk};
+use bulks::{AsBulk, Bulk, IntoBulk};
 use num_complex::{Complex, ComplexFloat};
-use crate::{Dft, Permute, SpectrumScaling, util::{IntoComplex, MulAssignSpec, TruncateIm}};
+use num_traits::Zero;
+use crate::{Dft, Permute, ScratchLength, SpectrumScaling, util::{IntoComplex, MulAssignSpec, TruncateIm}};
 
 /// Discrete Hilbert transform
 pub trait Hilbert<T>: Permute<T>
@@ -24,11 +25,13 @@ where
         let n = self.bulk_mut().length();
         let n_half = length::value::div(n, [(); 2]);
         
-        let mut y = unsafe {(self as *mut Self).as_mut_unchecked()}
-            .bulk_mut()
-            .map(|x| x.borrow().into_complex())
-            .collect_nearest();
+        let mut y = ScratchLength::scratch_space(n, Complex::zero());
         let y: &mut [_] = y.borrow_mut();
+        for (y, x) in y.iter_mut()
+            .zip(self.bulk_mut())
+        {
+            *y = x.borrow().into_complex()
+        }
         
         y.dft_scaled(SpectrumScaling::Summed);
 
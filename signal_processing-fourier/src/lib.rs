@@ -8,12 +8,20 @@
 #![feature(generic_const_exprs)]
 #![feature(specialization)]
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("Either the `std` or the `libm` feature must be enabled.");
+
 moddef::moddef!(
     flat(pub) mod {
         permute
     },
     flat(pub) mod {
-        czt,
+        czt for cfg(feature = "alloc"),
         dct_2d for cfg(feature = "ndarray"),
         dct,
         dft_2d for cfg(feature = "ndarray"),
@@ -28,11 +36,15 @@ moddef::moddef!(
     mod {
         util
     },
-    flat mod {
+    flat(pub) mod {
         scratch_space
     }
 );
 
+/// Borrows scratch space of length `len` from `temp`, or creates it if `temp` is missing or too short.
+///
+/// The recursive transforms need scratch space of the length of the slices they recurse on, which is only known at run-time.
+/// They are therefore only compiled with the `alloc` feature, and without it, only the transforms of fixed radix are used.
 macro_rules! temp {
     ($temp:ident for $len:expr) => {
         temp!($temp for $len => $temp)
@@ -1,6 +1,14 @@
 use core::borrow::BorrowMut;
+#[cfg(not(feature = "alloc"))]
+use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
 
-use array_trait::{length::{self, LengthValue}, same::Same};
+#[cfg(feature = "alloc")]
+use array_trait::same::Same;
+#[cfg(feature = "alloc")]
+use array_trait::length;
+use array_trait::length::LengthValue;
 use bulks::{AsBulk, IntoBulk};
 
 pub const trait ScratchLength<T = <Self as IntoIterator>::Item>: LengthValue
@@ -11,6 +19,7 @@ where
 
     fn scratch_space(len: Self, fill: T) -> Self::ScratchSpace;
 }
+#[cfg(feature = "alloc")]
 impl<I, T> ScratchLength<T> for I
 where
     I: LengthValue,
@@ -23,6 +32,30 @@ where
         vec![fill; length::value::len(len)].same().ok().unwrap()
     }
 }
+/// Without an allocator, scratch space can only be created for lengths known at compile-time.
+/// 
+/// Using a transform which needs scratch space of dynamic length is then a compile-time error.
+#[cfg(not(feature = "alloc"))]
+impl<I, T> ScratchLength<T> for I
+where
+    I: LengthValue,
+    T: Copy
+{
+    default type ScratchSpace = [T; 0];
+
+    default fn scratch_space(_len: Self, _fill: T) -> Self::ScratchSpace
+    {
+        struct DynamicLength<I>(PhantomData<I>);
+        impl<I> DynamicLength<I>
+        {
+            const REQUIRES_ALLOC: () = panic!("Scratch space of dynamic length requires the `alloc` feature.");
+        }
+
+        // Never returns, as the constant fails to evaluate.
+        const { DynamicLength::<I>::REQUIRES_ALLOC };
+        unreachable!()
+    }
+}
 const impl<T, const N: usize> ScratchLength<T> for [(); N]
 where
     T: Copy
@@ -39,7 +39,7 @@ where
 
     let mut temp = temp.as_mut()
         .map(|temp| unsafe {
-            core::slice::from_raw_parts_mut(temp.as_mut_ptr().cast::<Complex<T>>(), temp.len()/(std::mem::size_of::<Complex<T>>()/std::mem::size_of::<C>()).max(1))
+            core::slice::from_raw_parts_mut(temp.as_mut_ptr().cast::<Complex<T>>(), temp.len()/(core::mem::size_of::<Complex<T>>()/core::mem::size_of::<C>()).max(1))
         });
     temp!(temp for len_buf);
 
@@ -1,9 +1,16 @@
 use core::{borrow::BorrowMut, f64::consts::{FRAC_PI_2, SQRT_2}};
 
-use crate::{Dft, SpectrumScaling, temp, util::{self, AddAssignSpec, IntoComplex, MulAssignSpec, RealDiv, RealMul, TruncateIm}};
+use crate::{Dft, SpectrumScaling, temp, util::{AddAssignSpec, IntoComplex, MulAssignSpec, RealDiv, RealMul, TruncateIm}};
 
-use array_trait::length::{self, LengthValue};
-use bulks::{AsBulk, Bulk, CollectNearest, IntoBulk};
+#[cfg(feature = "alloc")]
+use crate::util;
+
+use array_trait::length;
+#[cfg(feature = "alloc")]
+use array_trait::length::LengthValue;
+#[cfg(feature = "alloc")]
+use bulks::CollectNearest;
+use bulks::{AsBulk, Bulk, IntoBulk};
 use num_complex::{Complex, ComplexFloat};
 use num_traits::{Float, FloatConst, NumCast, One, Zero};
 
@@ -23,6 +30,7 @@ where
     dct_ii_direct_unscaled(sequence, &mut temp);
 }
 
+#[cfg(feature = "alloc")]
 pub fn partial_fct_ii_unscaled<B, C, T, M>(sequence: &mut B, temp: &mut [C], m: M)
 where
     for<'a> &'a mut B: IntoBulk<Item: BorrowMut<C>>,
@@ -147,6 +155,7 @@ where
 
 /// Algorithm by Byeong Gi Lee, 1984. For details, see:
 /// See: http://citeseerx.ist.psu.edu/viewdoc/download?doi=10.1.1.118.3056&rep=rep1&type=pdf#page=34
+#[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
 pub fn fct_ii_radix2_unscaled<B, C, T>(sequence: &mut B, temp: &mut Option<&mut [C]>) -> bool
 where
     for<'a> &'a mut B: IntoBulk<Item: BorrowMut<C>>,
@@ -157,75 +166,76 @@ where
     let len = sequence.bulk_mut().length();
     if length::value::eq(length::value::rem(len, [(); 2]), [(); 0])
     {
-        // Recursive FFT
+        #[cfg(feature = "alloc")]
+        {
+            temp!(temp for len);
+
+            let ldiv = length::value::len(len)/2;
+            let wn_half = Complex::cis(<T as NumCast>::from(FRAC_PI_2/length::value::len(len) as f64).unwrap());
+            let wn = wn_half*wn_half;
+            let mut wn_pk = wn_half;
 
-        temp!(temp for len);
+            {
+                let mut x = temp.chunks_mut(ldiv);
+                let x = bulks::repeat_n_with(|| x.next().unwrap(), [(); 2])
+                    .collect_nearest();
+                for k in 0..ldiv
+                {
+                    let [x1, x2] = sequence.bulk_mut()
+                        .skip(k)
+                        .step_by(length::value::len(len) - k*2 - 1)
+                        .map(|mut x| *x.borrow_mut())
+                        .map(Some)
+                        .resize_with([(); _], || None)
+                        .try_collect_array()
+                        .unwrap();
+                
+                    let p = x1 + x2;
+                    let q = x1 - x2;
 
-        let ldiv = length::value::len(len)/2;
-        let wn_half = Complex::cis(<T as NumCast>::from(FRAC_PI_2/length::value::len(len) as f64).unwrap());
-        let wn = wn_half*wn_half;
-        let mut wn_pk = wn_half;
+                    x[0][k] = p;
+                    x[1][k] = q._real_div(wn_pk.re + wn_pk.re);
 
-        {
-            let mut x = temp.chunks_mut(ldiv);
+                    wn_pk._mul_assign(wn);
+                }
+            }
+            partial_fct_ii_unscaled(sequence, temp, [(); 2]);
+            let mut x = temp.chunks(ldiv);
             let x = bulks::repeat_n_with(|| x.next().unwrap(), [(); 2])
                 .collect_nearest();
-            for k in 0..ldiv
+            for k in 0..ldiv.saturating_sub(1)
             {
-                let [x1, x2] = sequence.bulk_mut()
-                    .skip(k)
-                    .step_by(length::value::len(len) - k*2 - 1)
-                    .map(|mut x| *x.borrow_mut())
+                let p = x[0][k];
+                let q = x[1][k];
+                let r = x[1][k + 1];
+
+                let [mut x1, mut x2] = sequence.bulk_mut()
+                    .skip(k*2)
                     .map(Some)
                     .resize_with([(); _], || None)
                     .try_collect_array()
                     .unwrap();
-                
-                let p = x1 + x2;
-                let q = x1 - x2;
-
-                x[0][k] = p;
-                x[1][k] = q._real_div(wn_pk.re + wn_pk.re);
-
-                wn_pk._mul_assign(wn);
-            }
-        }
-        partial_fct_ii_unscaled(sequence, temp, [(); 2]);
-        let mut x = temp.chunks(ldiv);
-        let x = bulks::repeat_n_with(|| x.next().unwrap(), [(); 2])
-            .collect_nearest();
-        for k in 0..ldiv.saturating_sub(1)
-        {
-            let p = x[0][k];
-            let q = x[1][k];
-            let r = x[1][k + 1];
-
-            let [mut x1, mut x2] = sequence.bulk_mut()
-                .skip(k*2)
-                .map(Some)
-                .resize_with([(); _], || None)
-                .try_collect_array()
-                .unwrap();
             
-            *x1.borrow_mut() = p;
-            *x2.borrow_mut() = q + r;
-        }
-        if let Some(k) = ldiv.checked_sub(1)
-        {
-            let p = x[0][k];
-            let q = x[1][k];
+                *x1.borrow_mut() = p;
+                *x2.borrow_mut() = q + r;
+            }
+            if let Some(k) = ldiv.checked_sub(1)
+            {
+                let p = x[0][k];
+                let q = x[1][k];
 
-            let [mut x1, mut x2] = sequence.bulk_mut()
-                .skip(k*2)
-                .map(Some)
-                .resize_with([(); _], || None)
-                .try_collect_array()
-                .unwrap();
+                let [mut x1, mut x2] = sequence.bulk_mut()
+                    .skip(k*2)
+                    .map(Some)
+                    .resize_with([(); _], || None)
+                    .try_collect_array()
+                    .unwrap();
             
-            *x1.borrow_mut() = p;
-            *x2.borrow_mut() = q;
+                *x1.borrow_mut() = p;
+                *x2.borrow_mut() = q;
+            }
+            return true;
         }
-        return true;
     }
     false
 }
@@ -244,7 +254,7 @@ where
     let len_buf = length::value::mul(len, [(); 2]);
     let mut temp = temp.as_mut()
         .map(|temp| unsafe {
-            core::slice::from_raw_parts_mut(temp.as_mut_ptr().cast::<Complex<T>>(), temp.len()/(std::mem::size_of::<Complex<T>>()/std::mem::size_of::<C>()).max(1))
+            core::slice::from_raw_parts_mut(temp.as_mut_ptr().cast::<Complex<T>>(), temp.len()/(core::mem::size_of::<Complex<T>>()/core::mem::size_of::<C>()).max(1))
         });
     temp!(temp for len_buf);
 
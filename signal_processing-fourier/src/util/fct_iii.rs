@@ -1,9 +1,16 @@
 use core::{borrow::BorrowMut, f64::consts::{FRAC_PI_2, SQRT_2}};
 
-use crate::{Dft, SpectrumScaling, temp, util::{self, AddAssignSpec, IntoComplex, MulAssignSpec, RealDiv, RealMul, TruncateIm}};
+use crate::{Dft, SpectrumScaling, temp, util::{AddAssignSpec, IntoComplex, MulAssignSpec, RealDiv, RealMul, TruncateIm}};
 
-use array_trait::length::{self, LengthValue};
-use bulks::{AsBulk, Bulk, CollectNearest, IntoBulk};
+#[cfg(feature = "alloc")]
+use crate::util;
+
+use array_trait::length;
+#[cfg(feature = "alloc")]
+use array_trait::length::LengthValue;
+#[cfg(feature = "alloc")]
+use bulks::CollectNearest;
+use bulks::{AsBulk, Bulk, IntoBulk};
 use num_complex::{Complex, ComplexFloat};
 use num_traits::{Float, FloatConst, NumCast, Zero};
 
@@ -115,6 +122,7 @@ where
     false
 }
 
+#[cfg(feature = "alloc")]
 pub fn partial_fct_iii_unscaled<B, C, T, M>(sequence: &mut B, temp: &mut [C], m: M)
 where
     for<'a> &'a mut B: IntoBulk<Item: BorrowMut<C>>,
@@ -158,6 +166,7 @@ where
 
 /// Algorithm by Byeong Gi Lee, 1984. For details, see:
 /// https://www.nayuki.io/res/fast-discrete-cosine-transform-algorithms/lee-new-algo-discrete-cosine-transform.pdf
+#[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
 pub fn fct_iii_radix2_unscaled<B, C, T>(sequence: &mut B, temp: &mut Option<&mut [C]>) -> bool
 where
     for<'a> &'a mut B: IntoBulk<Item: BorrowMut<C>>,
@@ -168,68 +177,69 @@ where
     let len = sequence.bulk_mut().length();
     if length::value::eq(length::value::rem(len, [(); 2]), [(); 0])
     {
-        // Recursive FFT
+        #[cfg(feature = "alloc")]
+        {
+            temp!(temp for len);
 
-        temp!(temp for len);
+            let ldiv = length::value::len(len)/2;
+            {
+                let mut x = temp.chunks_mut(ldiv);
+                let x = bulks::repeat_n_with(|| x.next().unwrap(), [(); 2])
+                    .collect_nearest();
+                {
+                    let [p, q] = sequence.bulk_mut()
+                        .map(|mut x| *x.borrow_mut())
+                        .map(Some)
+                        .resize_with([(); _], || None)
+                        .try_collect_array()
+                        .unwrap();
+
+                    x[0][0] = p;
+                    x[1][0] = q;
+                }
+                for k in 1..ldiv
+                {
+                    let [p, q, r] = sequence.bulk_mut()
+                        .skip(2*k - 1)
+                        .map(|mut x| *x.borrow_mut())
+                        .map(Some)
+                        .resize_with([(); _], || None)
+                        .try_collect_array()
+                        .unwrap();
+
+                    x[0][k] = q;
+                    x[1][k] = p + r;
+                }
+            }
+            partial_fct_iii_unscaled(sequence, temp, [(); 2]);
 
-        let ldiv = length::value::len(len)/2;
-        {
-            let mut x = temp.chunks_mut(ldiv);
+            let wn_half = Complex::cis(<T as NumCast>::from(FRAC_PI_2/length::value::len(len) as f64).unwrap());
+            let wn = wn_half*wn_half;
+            let mut wn_pk = wn_half;
+
+            let mut x = temp.chunks(ldiv);
             let x = bulks::repeat_n_with(|| x.next().unwrap(), [(); 2])
                 .collect_nearest();
+            for k in 0..ldiv
             {
-                let [p, q] = sequence.bulk_mut()
-                    .map(|mut x| *x.borrow_mut())
-                    .map(Some)
-                    .resize_with([(); _], || None)
-                    .try_collect_array()
-                    .unwrap();
+                let p = x[0][k];
+                let q = x[1][k]._real_div(wn_pk.re + wn_pk.re);
 
-                x[0][0] = p;
-                x[1][0] = q;
-            }
-            for k in 1..ldiv
-            {
-                let [p, q, r] = sequence.bulk_mut()
-                    .skip(2*k - 1)
-                    .map(|mut x| *x.borrow_mut())
+                let [mut x1, mut x2] = sequence.bulk_mut()
+                    .skip(k)
+                    .step_by(length::value::len(len) - k*2 - 1)
                     .map(Some)
                     .resize_with([(); _], || None)
                     .try_collect_array()
                     .unwrap();
-
-                x[0][k] = q;
-                x[1][k] = p + r;
-            }
-        }
-        partial_fct_iii_unscaled(sequence, temp, [(); 2]);
-
-        let wn_half = Complex::cis(<T as NumCast>::from(FRAC_PI_2/length::value::len(len) as f64).unwrap());
-        let wn = wn_half*wn_half;
-        let mut wn_pk = wn_half;
-
-        let mut x = temp.chunks(ldiv);
-        let x = bulks::repeat_n_with(|| x.next().unwrap(), [(); 2])
-            .collect_nearest();
-        for k in 0..ldiv
-        {
-            let p = x[0][k];
-            let q = x[1][k]._real_div(wn_pk.re + wn_pk.re);
-
-            let [mut x1, mut x2] = sequence.bulk_mut()
-                .skip(k)
-                .step_by(length::value::len(len) - k*2 - 1)
-                .map(Some)
-                .resize_with([(); _], || None)
-                .try_collect_array()
-                .unwrap();
             
-            *x1.borrow_mut() = p + q;
-            *x2.borrow_mut() = p - q;
+                *x1.borrow_mut() = p + q;
+                *x2.borrow_mut() = p - q;
 
-            wn_pk._mul_assign(wn);
+                wn_pk._mul_assign(wn);
+            }
+            return true;
         }
-        return true;
     }
     false
 }
@@ -249,7 +259,7 @@ where
     let len_buf = length::value::mul(len, [(); 2]);
     let mut temp = temp.as_mut()
         .map(|temp| unsafe {
-            core::slice::from_raw_parts_mut(temp.as_mut_ptr().cast::<Complex<T>>(), temp.len()/(std::mem::size_of::<Complex<T>>()/std::mem::size_of::<C>()).max(1))
+            core::slice::from_raw_parts_mut(temp.as_mut_ptr().cast::<Complex<T>>(), temp.len()/(core::mem::size_of::<Complex<T>>()/core::mem::size_of::<C>()).max(1))
         });
     temp!(temp for len_buf);
 
@@ -1,12 +1,14 @@
 use core::borrow::{Borrow, BorrowMut};
-use std::{f64::consts::TAU};
+use core::f64::consts::TAU;
 
 use array_trait::length::{self, LengthValue};
-use bulks::{AsBulk, Bulk, CollectNearest, IntoBulk};
+#[cfg(feature = "alloc")]
+use bulks::CollectNearest;
+use bulks::{AsBulk, Bulk, IntoBulk};
 use num_complex::Complex;
 use num_traits::{Float, NumCast, One, Zero};
 
-use crate::{permute::Permute, temp, util::{self, MulAssignSpec, AddAssignSpec}};
+use crate::{permute::Permute, ScratchLength, temp, util::{self, MulAssignSpec, AddAssignSpec}};
 
 pub fn fft_unscaled<B, T, const I: bool>(sequence: &mut B, mut temp: Option<&mut [Complex<T>]>)
 where
@@ -52,6 +54,7 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
 pub fn partial_fft_unscaled<B, T, const I: bool, M>(sequence: &mut B, temp: &mut [Complex<T>], m: M)
 where
     for<'a> &'a mut B: IntoBulk<Item: BorrowMut<Complex<T>>>,
@@ -107,6 +110,7 @@ where
 }
 
 
+#[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
 pub fn fft_radix2_unscaled<T, B, const I: bool>(sequence: &mut B, temp: &mut Option<&mut [Complex<T>]>) -> bool
 where
     for<'a> &'a mut B: IntoBulk<Item: BorrowMut<Complex<T>>>,
@@ -150,41 +154,43 @@ where
             }
             return true
         }
-        // Recursive FFT
-
-        temp!(temp for len);
+        #[cfg(feature = "alloc")]
+        {
+            temp!(temp for len);
 
-        let ldiv = length::value::len(len)/2;
-        partial_fft_unscaled::<_, _, I, _>(sequence, temp, 2);
-        let mut x = temp.chunks(ldiv);
-        let x = bulks::repeat_n_with(|| x.next().unwrap(), [(); 2])
-            .collect_nearest();
+            let ldiv = length::value::len(len)/2;
+            partial_fft_unscaled::<_, _, I, _>(sequence, temp, 2);
+            let mut x = temp.chunks(ldiv);
+            let x = bulks::repeat_n_with(|| x.next().unwrap(), [(); 2])
+                .collect_nearest();
 
-        let wn = Complex::cis(<T as NumCast>::from(if I {TAU} else {-TAU}/length::value::len(len) as f64).unwrap());
-        let mut wn_pk = Complex::one();
-        for k in 0..ldiv
-        {
-            let p = x[0][k];
-            let q = wn_pk*x[1][k];
-
-            let [mut x1, mut x2] = sequence.bulk_mut()
-                .skip(k)
-                .step_by(ldiv)
-                .map(Some)
-                .resize_with([(); _], || None)
-                .try_collect_array()
-                .unwrap();
+            let wn = Complex::cis(<T as NumCast>::from(if I {TAU} else {-TAU}/length::value::len(len) as f64).unwrap());
+            let mut wn_pk = Complex::one();
+            for k in 0..ldiv
+            {
+                let p = x[0][k];
+                let q = wn_pk*x[1][k];
+
+                let [mut x1, mut x2] = sequence.bulk_mut()
+                    .skip(k)
+                    .step_by(ldiv)
+                    .map(Some)
+                    .resize_with([(); _], || None)
+                    .try_collect_array()
+                    .unwrap();
             
-            *x1.borrow_mut() = p + q;
-            *x2.borrow_mut() = p - q;
+                *x1.borrow_mut() = p + q;
+                *x2.borrow_mut() = p - q;
 
-            wn_pk._mul_assign(wn);
+                wn_pk._mul_assign(wn);
+            }
+            return true;
         }
-        return true;
     }
     false
 }
 
+#[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
 pub fn fft_radix3_unscaled<T, B, const I: bool>(sequence: &mut B, temp: &mut Option<&mut [Complex<T>]>) -> bool
 where
     for<'a> &'a mut B: IntoBulk<Item: BorrowMut<Complex<T>>>,
@@ -238,46 +244,48 @@ where
             }
             return true
         }
-        // Recursive FFT
-
-        temp!(temp for len);
+        #[cfg(feature = "alloc")]
+        {
+            temp!(temp for len);
 
-        let ldiv = length::value::len(len)/P;
-        partial_fft_unscaled::<_, _, I, _>(sequence, temp, P);
-        let mut x = temp.chunks(ldiv);
-        let x = bulks::repeat_n_with(|| x.next().unwrap(), [(); P])
-            .collect_nearest();
+            let ldiv = length::value::len(len)/P;
+            partial_fft_unscaled::<_, _, I, _>(sequence, temp, P);
+            let mut x = temp.chunks(ldiv);
+            let x = bulks::repeat_n_with(|| x.next().unwrap(), [(); P])
+                .collect_nearest();
 
-        let wn = Complex::cis(<T as NumCast>::from(if I {TAU} else {-TAU}/length::value::len(len) as f64).unwrap()).into();
-        let mut w = Complex::one();
-        for k in 0..ldiv
-        {
-            let x1 = &x[0][k];
-            let x2 = &x[1][k];
-            let x3 = &x[2][k];
+            let wn = Complex::cis(<T as NumCast>::from(if I {TAU} else {-TAU}/length::value::len(len) as f64).unwrap()).into();
+            let mut w = Complex::one();
+            for k in 0..ldiv
+            {
+                let x1 = &x[0][k];
+                let x2 = &x[1][k];
+                let x3 = &x[2][k];
             
-            let p = *x1 + (*x2 + *x3*w)*w;
-            let q = *x1 + (*x2*w3 + *x3*w3_p2*w)*w;
-            let r = *x1 + (*x2*w3_p2 + *x3*w3*w)*w;
-
-            let [mut x1, mut x2, mut x3] = sequence.bulk_mut()
-                .skip(k)
-                .step_by(ldiv)
-                .map(Some)
-                .resize_with([(); _], || None)
-                .try_collect_array()
-                .unwrap();
-            *x1.borrow_mut() = p;
-            *x2.borrow_mut() = q;
-            *x3.borrow_mut() = r;
-
-            w._mul_assign(wn);
+                let p = *x1 + (*x2 + *x3*w)*w;
+                let q = *x1 + (*x2*w3 + *x3*w3_p2*w)*w;
+                let r = *x1 + (*x2*w3_p2 + *x3*w3*w)*w;
+
+                let [mut x1, mut x2, mut x3] = sequence.bulk_mut()
+                    .skip(k)
+                    .step_by(ldiv)
+                    .map(Some)
+                    .resize_with([(); _], || None)
+                    .try_collect_array()
+                    .unwrap();
+                *x1.borrow_mut() = p;
+                *x2.borrow_mut() = q;
+                *x3.borrow_mut() = r;
+
+                w._mul_assign(wn);
+            }
+            return true;
         }
-        return true;
     }
     false
 }
 
+#[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
 pub fn fft_radix5_unscaled<T, B, const I: bool>(sequence: &mut B, temp: &mut Option<&mut [Complex<T>]>) -> bool
 where
     for<'a> &'a mut B: IntoBulk<Item: BorrowMut<Complex<T>>>,
@@ -337,53 +345,55 @@ where
             }
             return true
         }
-        // Recursive FFT
-
-        temp!(temp for len);
+        #[cfg(feature = "alloc")]
+        {
+            temp!(temp for len);
 
-        let ldiv = length::value::len(len)/P;
-        partial_fft_unscaled::<_, _, I, _>(sequence, temp, P);
-        let mut x = temp.chunks(ldiv);
-        let x = bulks::repeat_n_with(|| x.next().unwrap(), [(); P])
-            .collect_nearest();
+            let ldiv = length::value::len(len)/P;
+            partial_fft_unscaled::<_, _, I, _>(sequence, temp, P);
+            let mut x = temp.chunks(ldiv);
+            let x = bulks::repeat_n_with(|| x.next().unwrap(), [(); P])
+                .collect_nearest();
 
-        let wn = Complex::cis(<T as NumCast>::from(if I {TAU} else {-TAU}/length::value::len(len) as f64).unwrap());
-        let mut w = Complex::one();
-        for k in 0..ldiv
-        {
-            let x1 = &x[0][k];
-            let x2 = &x[1][k];
-            let x3 = &x[2][k];
-            let x4 = &x[3][k];
-            let x5 = &x[4][k];
+            let wn = Complex::cis(<T as NumCast>::from(if I {TAU} else {-TAU}/length::value::len(len) as f64).unwrap());
+            let mut w = Complex::one();
+            for k in 0..ldiv
+            {
+                let x1 = &x[0][k];
+                let x2 = &x[1][k];
+                let x3 = &x[2][k];
+                let x4 = &x[3][k];
+                let x5 = &x[4][k];
                 
-            let p = *x1 + (*x2 + (*x3 + (*x4 + *x5*w)*w)*w)*w;
-            let q = *x1 + (*x2*w5 + (*x3*w5_p2 + (*x4*w5_p3 + *x5*w5_p4*w)*w)*w)*w;
-            let r = *x1 + (*x2*w5_p2 + (*x3*w5_p4 + (*x4*w5 + *x5*w5_p3*w)*w)*w)*w;
-            let s = *x1 + (*x2*w5_p3 + (*x3*w5 + (*x4*w5_p4 + *x5*w5_p2*w)*w)*w)*w;
-            let t = *x1 + (*x2*w5_p4 + (*x3*w5_p3 + (*x4*w5_p2 + *x5*w5*w)*w)*w)*w;
-
-            let [mut x1, mut x2, mut x3, mut x4, mut x5] = sequence.bulk_mut()
-                .skip(k)
-                .step_by(ldiv)
-                .map(Some)
-                .resize_with([(); _], || None)
-                .try_collect_array()
-                .unwrap();
+                let p = *x1 + (*x2 + (*x3 + (*x4 + *x5*w)*w)*w)*w;
+                let q = *x1 + (*x2*w5 + (*x3*w5_p2 + (*x4*w5_p3 + *x5*w5_p4*w)*w)*w)*w;
+                let r = *x1 + (*x2*w5_p2 + (*x3*w5_p4 + (*x4*w5 + *x5*w5_p3*w)*w)*w)*w;
+                let s = *x1 + (*x2*w5_p3 + (*x3*w5 + (*x4*w5_p4 + *x5*w5_p2*w)*w)*w)*w;
+                let t = *x1 + (*x2*w5_p4 + (*x3*w5_p3 + (*x4*w5_p2 + *x5*w5*w)*w)*w)*w;
+
+                let [mut x1, mut x2, mut x3, mut x4, mut x5] = sequence.bulk_mut()
+                    .skip(k)
+                    .step_by(ldiv)
+                    .map(Some)
+                    .resize_with([(); _], || None)
+                    .try_collect_array()
+                    .unwrap();
             
-            *x1.borrow_mut() = p;
-            *x2.borrow_mut() = q;
-            *x3.borrow_mut() = r;
-            *x4.borrow_mut() = s;
-            *x5.borrow_mut() = t;
+                *x1.borrow_mut() = p;
+                *x2.borrow_mut() = q;
+                *x3.borrow_mut() = r;
+                *x4.borrow_mut() = s;
+                *x5.borrow_mut() = t;
 
-            w._mul_assign(wn);
+                w._mul_assign(wn);
+            }
+            return true;
         }
-        return true;
     }
     false
 }
 
+#[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
 pub fn fft_radix7_unscaled<T, B, const I: bool>(sequence: &mut B, temp: &mut Option<&mut [Complex<T>]>) -> bool
 where
     for<'a> &'a mut B: IntoBulk<Item: BorrowMut<Complex<T>>>,
@@ -449,59 +459,61 @@ where
             }
             return true
         }
-        // Recursive FFT
-
-        temp!(temp for len);
+        #[cfg(feature = "alloc")]
+        {
+            temp!(temp for len);
 
-        let ldiv = length::value::len(len)/P;
-        partial_fft_unscaled::<_, _, I, _>(&mut *sequence, temp, P);
-        let mut x = temp.chunks(ldiv);
-        let x = bulks::repeat_n_with(|| x.next().unwrap(), [(); P])
-            .collect_nearest();
+            let ldiv = length::value::len(len)/P;
+            partial_fft_unscaled::<_, _, I, _>(&mut *sequence, temp, P);
+            let mut x = temp.chunks(ldiv);
+            let x = bulks::repeat_n_with(|| x.next().unwrap(), [(); P])
+                .collect_nearest();
 
-        let wn = Complex::cis(<T as NumCast>::from(if I {TAU} else {-TAU}/length::value::len(len) as f64).unwrap());
-        let mut w = Complex::one();
-        for k in 0..ldiv
-        {
-            let x1 = &x[0][k];
-            let x2 = &x[1][k];
-            let x3 = &x[2][k];
-            let x4 = &x[3][k];
-            let x5 = &x[4][k];
-            let x6 = &x[5][k];
-            let x7 = &x[6][k];
+            let wn = Complex::cis(<T as NumCast>::from(if I {TAU} else {-TAU}/length::value::len(len) as f64).unwrap());
+            let mut w = Complex::one();
+            for k in 0..ldiv
+            {
+                let x1 = &x[0][k];
+                let x2 = &x[1][k];
+                let x3 = &x[2][k];
+                let x4 = &x[3][k];
+                let x5 = &x[4][k];
+                let x6 = &x[5][k];
+                let x7 = &x[6][k];
                 
-            let p = *x1 + (*x2 + (*x3 + (*x4 + (*x5 + (*x6 + *x7*w)*w)*w)*w)*w)*w;
-            let q = *x1 + (*x2*w7 + (*x3*w7_p2 + (*x4*w7_p3 + (*x5*w7_p4 + (*x6*w7_p5 + *x7*w7_p6*w)*w)*w)*w)*w)*w;
-            let r = *x1 + (*x2*w7_p2 + (*x3*w7_p4 + (*x4*w7_p6 + (*x5*w7 + (*x6*w7_p3 + *x7*w7_p5*w)*w)*w)*w)*w)*w;
-            let s = *x1 + (*x2*w7_p3 + (*x3*w7_p6 + (*x4*w7_p2 + (*x5*w7_p5 + (*x6*w7 + *x7*w7_p4*w)*w)*w)*w)*w)*w;
-            let t = *x1 + (*x2*w7_p4 + (*x3*w7 + (*x4*w7_p5 + (*x5*w7_p2 + (*x6*w7_p6 + *x7*w7_p3*w)*w)*w)*w)*w)*w;
-            let u = *x1 + (*x2*w7_p5 + (*x3*w7_p3 + (*x4*w7 + (*x5*w7_p6 + (*x6*w7_p4 + *x7*w7_p2*w)*w)*w)*w)*w)*w;
-            let v = *x1 + (*x2*w7_p6 + (*x3*w7_p5 + (*x4*w7_p4 + (*x5*w7_p3 + (*x6*w7_p2 + *x7*w7*w)*w)*w)*w)*w)*w;
-
-            let [mut x1, mut x2, mut x3, mut x4, mut x5, mut x6, mut x7]: [_; _] = sequence.bulk_mut()
-                .skip(k)
-                .step_by(ldiv)
-                .map(Some)
-                .resize_with([(); _], || None)
-                .try_collect_array()
-                .unwrap();
-
-            *x1.borrow_mut() = p;
-            *x2.borrow_mut() = q;
-            *x3.borrow_mut() = r;
-            *x4.borrow_mut() = s;
-            *x5.borrow_mut() = t;
-            *x6.borrow_mut() = u;
-            *x7.borrow_mut() = v;
-
-            w._mul_assign(wn);
+                let p = *x1 + (*x2 + (*x3 + (*x4 + (*x5 + (*x6 + *x7*w)*w)*w)*w)*w)*w;
+                let q = *x1 + (*x2*w7 + (*x3*w7_p2 + (*x4*w7_p3 + (*x5*w7_p4 + (*x6*w7_p5 + *x7*w7_p6*w)*w)*w)*w)*w)*w;
+                let r = *x1 + (*x2*w7_p2 + (*x3*w7_p4 + (*x4*w7_p6 + (*x5*w7 + (*x6*w7_p3 + *x7*w7_p5*w)*w)*w)*w)*w)*w;
+                let s = *x1 + (*x2*w7_p3 + (*x3*w7_p6 + (*x4*w7_p2 + (*x5*w7_p5 + (*x6*w7 + *x7*w7_p4*w)*w)*w)*w)*w)*w;
+                let t = *x1 + (*x2*w7_p4 + (*x3*w7 + (*x4*w7_p5 + (*x5*w7_p2 + (*x6*w7_p6 + *x7*w7_p3*w)*w)*w)*w)*w)*w;
+                let u = *x1 + (*x2*w7_p5 + (*x3*w7_p3 + (*x4*w7 + (*x5*w7_p6 + (*x6*w7_p4 + *x7*w7_p2*w)*w)*w)*w)*w)*w;
+                let v = *x1 + (*x2*w7_p6 + (*x3*w7_p5 + (*x4*w7_p4 + (*x5*w7_p3 + (*x6*w7_p2 + *x7*w7*w)*w)*w)*w)*w)*w;
+
+                let [mut x1, mut x2, mut x3, mut x4, mut x5, mut x6, mut x7]: [_; _] = sequence.bulk_mut()
+                    .skip(k)
+                    .step_by(ldiv)
+                    .map(Some)
+                    .resize_with([(); _], || None)
+                    .try_collect_array()
+                    .unwrap();
+
+                *x1.borrow_mut() = p;
+                *x2.borrow_mut() = q;
+                *x3.borrow_mut() = r;
+                *x4.borrow_mut() = s;
+                *x5.borrow_mut() = t;
+                *x6.borrow_mut() = u;
+                *x7.borrow_mut() = v;
+
+                w._mul_assign(wn);
+            }
+            return true;
         }
-        return true;
     }
     false
 }
 
+#[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
 pub fn fft_radix_p_unscaled<T, B, P, const I: bool>(sequence: &mut B, temp: &mut Option<&mut [Complex<T>]>, p: P) -> bool
 where
     for<'a> &'a mut B: IntoBulk<Item: BorrowMut<Complex<T>>>,
@@ -515,15 +527,16 @@ where
     if length::value::eq(length::value::rem(len, length::value::max(p, [(); 1])), [(); 0])
     {
         let pf = length::value::len(p) as f64;
-        let wp = bulks::once(Complex::one())
-            .chain(
-                bulks::range([(); 1], p)
-                    .map(|i| {
-                        Complex::cis(<T as NumCast>::from(if I {TAU} else {-TAU}*i as f64/pf).unwrap()).into()
-                    })
-            ).collect_nearest();
-        let mut y = bulks::repeat_n(Complex::zero(), p)
-            .collect_nearest();
+        let mut wp = ScratchLength::scratch_space(p, Complex::one());
+        for (i, w) in BorrowMut::<[Complex<T>]>::borrow_mut(&mut wp)
+            .iter_mut()
+            .enumerate()
+            .skip(1)
+        {
+            *w = Complex::cis(<T as NumCast>::from(if I {TAU} else {-TAU}*i as f64/pf).unwrap())
+        }
+        let wp: &[Complex<T>] = wp.borrow();
+        let mut y = ScratchLength::scratch_space(p, Complex::<T>::zero());
 
         if util::is_power_of(len, p)
         {
@@ -531,8 +544,7 @@ where
 
             sequence.digit_rev_permute(p);
 
-            let mut x = bulks::repeat_n_with(|| core::ptr::null_mut::<Complex<T>>(), p)
-                .collect_nearest();
+            let mut x = ScratchLength::scratch_space(p, core::ptr::null_mut::<Complex<T>>());
             
             let mut m = length::value::len(p);
             for _s in 0..length::value::len(len).ilog(length::value::len(p))
@@ -595,52 +607,52 @@ where
             }
             return true
         }
-        // Recursive FFT
+        #[cfg(feature = "alloc")]
+        {
+            temp!(temp for len);
 
-        temp!(temp for len);
+            partial_fft_unscaled::<_, _, I, _>(sequence, temp, p);
+            let m = length::value::div(len, length::value::max(p, [(); 1]));
 
-        partial_fft_unscaled::<_, _, I, _>(sequence, temp, p);
-        let m = length::value::div(len, length::value::max(p, [(); 1]));
-        let x: Vec<_> = temp.chunks(length::value::len(m)).collect();
+            let wn = Complex::cis(<T as NumCast>::from(
+                if I
+                {
+                    TAU
+                }
+                else
+                {
+                    -TAU
+                }/length::value::len(len) as f64
+            ).unwrap());
 
-        let wn = Complex::cis(<T as NumCast>::from(
-            if I
-            {
-                TAU
-            }
-            else
+            let mut w = Complex::one();
+            for k in 0..length::value::len(m)
             {
-                -TAU
-            }/length::value::len(len) as f64
-        ).unwrap());
-
-        let mut w = Complex::one();
-        for k in 0..length::value::len(m)
-        {
-            for (i, y) in y.borrow_mut()
-                .bulk_mut()
-                .enumerate()
-            {
-                *y.borrow_mut() = x.iter()
+                for (i, y) in y.borrow_mut()
+                    .bulk_mut()
                     .enumerate()
-                    .map(|(j, x)| x[k]*wp[(j*i) % length::value::len(p)])
-                    .rev()
-                    .fold(Complex::zero(), |y, z| {
-                        z + y*w
-                    });
-            }
+                {
+                    *y.borrow_mut() = temp.chunks(length::value::len(m))
+                        .enumerate()
+                        .map(|(j, x)| x[k]*wp[(j*i) % length::value::len(p)])
+                        .rev()
+                        .fold(Complex::zero(), |y, z| {
+                            z + y*w
+                        });
+                }
 
-            for (mut x, y) in sequence.bulk_mut()
-                .skip(k)
-                .step_by(m)
-                .zip(y.borrow())
-            {
-                *x.borrow_mut() = *y
-            }
+                for (mut x, y) in sequence.bulk_mut()
+                    .skip(k)
+                    .step_by(m)
+                    .zip(y.borrow())
+                {
+                    *x.borrow_mut() = *y
+                }
             
-            w._mul_assign(wn);
+                w._mul_assign(wn);
+            }
+            return true;
         }
-        return true;
     }
     false
 }
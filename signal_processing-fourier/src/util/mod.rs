@@ -1,6 +1,8 @@
+#[cfg(feature = "alloc")]
 use core::borrow::BorrowMut;
 
 use array_trait::length::{self, LengthValue};
+#[cfg(feature = "alloc")]
 use bulks::IntoBulk;
 use moddef::moddef;
 
@@ -27,6 +29,7 @@ moddef!(
     }
 );
 
+#[cfg(feature = "alloc")]
 pub fn recurse_buffer<B, T>(buffer: &mut B) -> Option<&mut [T]>
 where
     B: ?Sized,
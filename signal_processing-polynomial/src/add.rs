@@ -0,0 +1,23 @@
+use core::ops::Add;
+
+use bulks::{Bulk, IntoBulk, Merge};
+
+use crate::Polynomial;
+
+impl<I1, I2, T> Add<Polynomial<I2>> for Polynomial<I1>
+where
+    I1: IntoBulk<Item: Add<I2::Item, Output = T> + Into<T>>,
+    I2: IntoBulk<Item: Into<T>>
+{
+    type Output = Polynomial<Merge<I1::IntoBulk, I2::IntoBulk, fn(I1::Item, I2::Item) -> T>>;
+
+    fn add(self, rhs: Polynomial<I2>) -> Self::Output
+    {
+        let Self(lhs) = self;
+        let Polynomial(rhs) = rhs;
+        let lhs = lhs.into_bulk();
+        let rhs = rhs.into_bulk();
+
+        Polynomial(lhs.merge(rhs, Add::add))
+    }
+}
\ No newline at end of file
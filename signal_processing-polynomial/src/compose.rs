@@ -0,0 +1,63 @@
+use core::{borrow::Borrow, ops::{Add, Mul}};
+
+use bulks::IntoBulk;
+use num_traits::Zero;
+
+use crate::{Polynomial, util};
+
+impl<T, const N: usize> Polynomial<[T; N]>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy
+{
+    /// Computes the composition `p(q(x))`, where `p` is `self`.
+    pub fn compose<const M: usize>(self, q: Polynomial<[T; M]>) -> Polynomial<[T; (N - 1)*(M - 1) + 1]>
+    where
+        [(); (N - 1)*(M - 1) + 1]:
+    {
+        let Self(p) = self;
+        let Polynomial(q) = q;
+
+        let mut y = [T::zero(); _];
+        let mut tmp = [T::zero(); (N - 1)*(M - 1) + 1];
+        util::compose(&p, &q, &mut y, &mut tmp);
+        Polynomial(y)
+    }
+}
+
+impl<T> Polynomial<Vec<T>>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy
+{
+    /// Computes the composition `p(q(x))`, where `p` is `self`.
+    pub fn compose<I>(self, q: Polynomial<I>) -> Polynomial<Vec<T>>
+    where
+        I: IntoBulk + Borrow<[T]>
+    {
+        let Self(p) = self;
+        let Polynomial(q) = q;
+        let q = q.borrow();
+
+        let len = p.len().saturating_sub(1)*q.len().saturating_sub(1) + 1;
+        let mut y = vec![T::zero(); len];
+        let mut tmp = vec![T::zero(); len];
+        util::compose(&p, q, &mut y, &mut tmp);
+        Polynomial(y)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::Polynomial;
+
+    #[test]
+    fn compose()
+    {
+        // p(x) = x² + 1, q(x) = 2x - 1
+        let p = Polynomial::new([1.0, 0.0, 1.0]);
+        let q = Polynomial::new([-1.0, 2.0]);
+
+        assert_eq!(p.compose(q).into_inner(), [2.0, -4.0, 4.0]);
+        assert_eq!(Polynomial::new(p.into_inner().to_vec()).compose(q).into_inner(), vec![2.0, -4.0, 4.0]);
+    }
+}
@@ -0,0 +1,53 @@
+use core::ops::Mul;
+
+use num_traits::NumCast;
+
+use crate::Polynomial;
+
+impl<T, const N: usize> Polynomial<[T; N]>
+where
+    T: Mul<Output = T> + NumCast + Copy
+{
+    /// Differentiates the polynomial with respect to its variable.
+    pub fn derivative(self) -> Polynomial<[T; N.saturating_sub(1)]>
+    where
+        [(); N.saturating_sub(1)]:
+    {
+        let Self(c) = self;
+
+        Polynomial(core::array::from_fn(|i| c[i + 1]*T::from(i + 1).unwrap()))
+    }
+}
+
+impl<T> Polynomial<Vec<T>>
+where
+    T: Mul<Output = T> + NumCast + Copy
+{
+    /// Differentiates the polynomial with respect to its variable.
+    pub fn derivative(self) -> Polynomial<Vec<T>>
+    {
+        let Self(c) = self;
+
+        Polynomial(c.into_iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| c*T::from(i).unwrap())
+            .collect()
+        )
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::Polynomial;
+
+    #[test]
+    fn derivative()
+    {
+        let p = Polynomial::new([5.0, 3.0, 2.0, 1.0]);
+
+        assert_eq!(p.derivative().into_inner(), [3.0, 4.0, 3.0]);
+        assert_eq!(Polynomial::new(p.into_inner().to_vec()).derivative().into_inner(), vec![3.0, 4.0, 3.0]);
+    }
+}
@@ -0,0 +1,156 @@
+use core::{borrow::Borrow, ops::{Div, Mul, Rem, Sub}};
+
+use bulks::IntoBulk;
+use num_traits::Zero;
+
+use crate::{Polynomial, util};
+
+impl<T, const N: usize> Polynomial<[T; N]>
+where
+    T: Mul<Output = T> + Sub<Output = T> + Div<Output = T> + Zero + Copy
+{
+    /// Euclidean division, returning the quotient and the remainder.
+    /// 
+    /// The leading coefficient of the divisor must be non-zero.
+    /// 
+    /// # Panics
+    /// 
+    /// If the divisor is the zero polynomial, or if its leading coefficient is zero.
+    pub fn div_rem<const M: usize>(self, rhs: Polynomial<[T; M]>) -> (Polynomial<[T; N + 1 - M]>, Polynomial<[T; M - 1]>)
+    where
+        [(); N + 1 - M]:,
+        [(); M - 1]:
+    {
+        let Self(mut r) = self;
+        let Polynomial(b) = rhs;
+
+        let mut q = [T::zero(); _];
+        util::div_rem(&mut r, &b, &mut q);
+
+        let mut rem = [T::zero(); _];
+        let n = rem.len().min(N);
+        rem[..n].copy_from_slice(&r[..n]);
+
+        (Polynomial(q), Polynomial(rem))
+    }
+}
+
+impl<T, const N: usize, const M: usize> Div<Polynomial<[T; M]>> for Polynomial<[T; N]>
+where
+    T: Mul<Output = T> + Sub<Output = T> + Div<Output = T> + Zero + Copy,
+    [(); N + 1 - M]:,
+    [(); M - 1]:
+{
+    type Output = Polynomial<[T; N + 1 - M]>;
+
+    fn div(self, rhs: Polynomial<[T; M]>) -> Self::Output
+    {
+        self.div_rem(rhs).0
+    }
+}
+
+impl<T, const N: usize, const M: usize> Rem<Polynomial<[T; M]>> for Polynomial<[T; N]>
+where
+    T: Mul<Output = T> + Sub<Output = T> + Div<Output = T> + Zero + Copy,
+    [(); N + 1 - M]:,
+    [(); M - 1]:
+{
+    type Output = Polynomial<[T; M - 1]>;
+
+    fn rem(self, rhs: Polynomial<[T; M]>) -> Self::Output
+    {
+        self.div_rem(rhs).1
+    }
+}
+
+/// Euclidean division of polynomials of dynamic length.
+/// 
+/// Trailing zeros in the divisor are ignored.
+/// 
+/// # Panics
+/// 
+/// If the divisor is the zero polynomial.
+fn div_rem_dyn<T>(a: &[T], b: &[T]) -> (Polynomial<Vec<T>>, Polynomial<Vec<T>>)
+where
+    T: Mul<Output = T> + Sub<Output = T> + Div<Output = T> + Zero + Copy
+{
+    let db = b.iter()
+        .rposition(|b| !b.is_zero())
+        .expect("Division by the zero polynomial.");
+
+    let mut r = a.to_vec();
+    let mut q = vec![T::zero(); (a.len() + 1).saturating_sub(db + 1)];
+    util::div_rem(&mut r, b, &mut q);
+    r.truncate(db);
+
+    (Polynomial(q), Polynomial(r))
+}
+
+macro_rules! impl_div {
+    ([$($g:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<$($g)*> Div<Polynomial<$rhs>> for Polynomial<$lhs>
+        where
+            T: Mul<Output = T> + Sub<Output = T> + Div<Output = T> + Zero + Copy
+        {
+            type Output = Polynomial<Vec<T>>;
+
+            fn div(self, rhs: Polynomial<$rhs>) -> Self::Output
+            {
+                div_rem_dyn(&self.0, &rhs.0).0
+            }
+        }
+        impl<$($g)*> Rem<Polynomial<$rhs>> for Polynomial<$lhs>
+        where
+            T: Mul<Output = T> + Sub<Output = T> + Div<Output = T> + Zero + Copy
+        {
+            type Output = Polynomial<Vec<T>>;
+
+            fn rem(self, rhs: Polynomial<$rhs>) -> Self::Output
+            {
+                div_rem_dyn(&self.0, &rhs.0).1
+            }
+        }
+    };
+}
+crate::impl_dynamic!(impl_div);
+
+impl<T> Polynomial<Vec<T>>
+where
+    T: Mul<Output = T> + Sub<Output = T> + Div<Output = T> + Zero + Copy
+{
+    /// Euclidean division, returning the quotient and the remainder.
+    /// 
+    /// Trailing zeros in the divisor are ignored.
+    /// 
+    /// # Panics
+    /// 
+    /// If the divisor is the zero polynomial.
+    pub fn div_rem<I>(self, rhs: Polynomial<I>) -> (Polynomial<Vec<T>>, Polynomial<Vec<T>>)
+    where
+        I: IntoBulk + Borrow<[T]>
+    {
+        div_rem_dyn(&self.0, rhs.0.borrow())
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::Polynomial;
+
+    #[test]
+    fn div_rem()
+    {
+        // (x² + 2x + 3)(2x - 1) + 5
+        let a = Polynomial::new([2.0, 4.0, 3.0, 2.0]);
+        let b = Polynomial::new([-1.0, 2.0]);
+
+        let (q, r) = a.div_rem(Polynomial::new([3.0, 2.0, 1.0]));
+        assert_eq!(q.into_inner(), [-1.0, 2.0]);
+        assert_eq!(r.into_inner(), [5.0, 0.0]);
+
+        let (q, r) = Polynomial::new(a.into_inner().to_vec()).div_rem(b);
+        assert_eq!(q.into_inner(), vec![3.0, 2.0, 1.0]);
+        assert_eq!(r.into_inner(), vec![5.0]);
+    }
+}
@@ -0,0 +1,43 @@
+use core::ops::{Add, Mul};
+
+use array_trait::AsSlice;
+use bulks::IntoBulk;
+use num_traits::Zero;
+
+use crate::{Polynomial, util};
+
+impl<I> Polynomial<I>
+where
+    I: IntoBulk + AsSlice<Elem: Copy>
+{
+    /// Evaluates the polynomial at `x` using Horner's method.
+    /// 
+    /// `x` may be of another type than the coefficients, as long as it can be added with them,
+    /// such that a polynomial with real coefficients can be evaluated at a complex point.
+    #[doc(alias = "polyval")]
+    pub fn eval<X>(&self, x: X) -> X
+    where
+        X: Mul<Output = X> + Add<I::Elem, Output = X> + Zero + Copy
+    {
+        util::horner(self.0.as_slice(), x)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use num_complex::Complex;
+
+    use crate::Polynomial;
+
+    #[test]
+    fn eval()
+    {
+        // x² + 1
+        let p = Polynomial::new([1.0, 0.0, 1.0]);
+
+        assert_eq!(p.eval(2.0), 5.0);
+        assert_eq!(p.eval(Complex::new(0.0, 1.0)), Complex::new(0.0, 0.0));
+        assert_eq!(Polynomial::new(vec![1.0, 0.0, 1.0]).eval(-3.0), 10.0);
+    }
+}
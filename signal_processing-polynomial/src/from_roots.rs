@@ -0,0 +1,75 @@
+use core::ops::{Mul, Sub};
+
+use num_traits::{One, Zero};
+
+use crate::Polynomial;
+
+/// Multiplies the polynomial `y`, of which only the first `len` coefficients are non-zero, with `(x - r)`.
+fn mul_linear<T>(y: &mut [T], len: usize, r: T)
+where
+    T: Mul<Output = T> + Sub<Output = T> + Zero + Copy
+{
+    for i in (1..=len).rev()
+    {
+        y[i] = y[i - 1] - r*y[i]
+    }
+    y[0] = T::zero() - r*y[0]
+}
+
+impl<T, const N: usize> Polynomial<[T; N]>
+where
+    T: Mul<Output = T> + Sub<Output = T> + Zero + One + Copy
+{
+    /// Expands the monic polynomial with the given roots, i.e. the product of the linear factors `(x - rᵢ)`.
+    #[doc(alias = "poly")]
+    pub fn from_roots(roots: [T; N - 1]) -> Self
+    where
+        [(); N - 1]:
+    {
+        let mut y = [T::zero(); N];
+        y[0] = T::one();
+        for (len, r) in roots.into_iter()
+            .enumerate()
+        {
+            mul_linear(&mut y, len + 1, r)
+        }
+        Polynomial(y)
+    }
+}
+
+impl<T> Polynomial<Vec<T>>
+where
+    T: Mul<Output = T> + Sub<Output = T> + Zero + One + Copy
+{
+    /// Expands the monic polynomial with the given roots, i.e. the product of the linear factors `(x - rᵢ)`.
+    #[doc(alias = "poly")]
+    pub fn from_roots<R>(roots: R) -> Self
+    where
+        R: IntoIterator<Item = T>
+    {
+        let mut y = vec![T::one()];
+        for r in roots
+        {
+            y.push(T::zero());
+            let len = y.len() - 1;
+            mul_linear(&mut y, len, r)
+        }
+        Polynomial(y)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::Polynomial;
+
+    #[test]
+    fn from_roots()
+    {
+        let p = Polynomial::<[_; 4]>::from_roots([1.0, 2.0, 3.0]);
+        assert_eq!(p.into_inner(), [-6.0, 11.0, -6.0, 1.0]);
+
+        let p = Polynomial::<Vec<_>>::from_roots([1.0, 2.0, 3.0]);
+        assert_eq!(p.into_inner(), vec![-6.0, 11.0, -6.0, 1.0]);
+    }
+}
@@ -0,0 +1,61 @@
+use core::ops::Div;
+
+use num_traits::NumCast;
+
+use crate::Polynomial;
+
+impl<T, const N: usize> Polynomial<[T; N]>
+where
+    T: Div<Output = T> + NumCast + Copy
+{
+    /// Integrates the polynomial with respect to its variable, with `c` as the constant of integration.
+    pub fn integral(self, c: T) -> Polynomial<[T; N + 1]>
+    where
+        [(); N + 1]:
+    {
+        let Self(p) = self;
+
+        Polynomial(core::array::from_fn(|i| if i == 0
+        {
+            c
+        }
+        else
+        {
+            p[i - 1]/T::from(i).unwrap()
+        }))
+    }
+}
+
+impl<T> Polynomial<Vec<T>>
+where
+    T: Div<Output = T> + NumCast + Copy
+{
+    /// Integrates the polynomial with respect to its variable, with `c` as the constant of integration.
+    pub fn integral(self, c: T) -> Polynomial<Vec<T>>
+    {
+        let Self(p) = self;
+
+        Polynomial(core::iter::once(c)
+            .chain(p.into_iter()
+                .enumerate()
+                .map(|(i, p)| p/T::from(i + 1).unwrap())
+            ).collect()
+        )
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::Polynomial;
+
+    #[test]
+    fn integral()
+    {
+        let p = Polynomial::new([3.0, 4.0, 3.0]);
+
+        assert_eq!(p.integral(5.0).into_inner(), [5.0, 3.0, 2.0, 1.0]);
+        assert_eq!(p.integral(5.0).derivative().into_inner(), p.into_inner());
+        assert_eq!(Polynomial::new(p.into_inner().to_vec()).integral(0.0).into_inner(), vec![0.0, 3.0, 2.0, 1.0]);
+    }
+}
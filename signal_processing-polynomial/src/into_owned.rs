@@ -0,0 +1,150 @@
+use bulks::{Bulk, Chain, IntoBulk, Map, Merge, array, vec};
+
+/// A bulk of coefficients which can be collected into an owned list.
+///
+/// Bulks of fixed length are collected into arrays, and all others into vectors.
+/// Unlike with [CollectNearest](bulks::CollectNearest), the owned list is known even when the length is not,
+/// so results of operations on coefficient lists of dynamic length can be operated on further.
+pub trait IntoOwned: Bulk
+{
+    type Owned: OwnedList<Item = Self::Item>;
+
+    fn into_owned(self) -> Self::Owned
+    where
+        Self: Sized
+    {
+        Self::Owned::collect_owned(self)
+    }
+}
+
+/// An owned list of coefficients, which is either an array or a vector.
+pub trait OwnedList: IntoBulk + Sized
+{
+    /// The list of the same length with elements of type `U`.
+    type Mapped<U>: OwnedList<Item = U>;
+
+    /// Collects a bulk of the same length into the list.
+    fn collect_owned<I>(bulk: I) -> Self
+    where
+        I: Bulk<Item = Self::Item>;
+}
+
+/// The owned lists resulting from combining two owned lists element-wise, or one after the other.
+pub trait OwnedListPair<Rhs>: OwnedList
+where
+    Rhs: OwnedList
+{
+    /// The list as long as the longest of the two, with elements of type `V`.
+    type Merged<V>: OwnedList<Item = V>;
+    /// The list as long as both together.
+    type Chained: OwnedList<Item = Self::Item>;
+}
+
+impl<T, const N: usize> OwnedList for [T; N]
+{
+    type Mapped<U> = [U; N];
+
+    fn collect_owned<I>(bulk: I) -> Self
+    where
+        I: Bulk<Item = T>
+    {
+        let mut iter = bulk.into_iter();
+        core::array::from_fn(|_| iter.next().expect("The bulk is shorter than the array."))
+    }
+}
+
+impl<T> OwnedList for Vec<T>
+{
+    type Mapped<U> = Vec<U>;
+
+    fn collect_owned<I>(bulk: I) -> Self
+    where
+        I: Bulk<Item = T>
+    {
+        bulk.into_iter().collect()
+    }
+}
+
+impl<T, U, const N: usize, const M: usize> OwnedListPair<[U; M]> for [T; N]
+where
+    [(); core::cmp::max(N, M)]:,
+    [(); N + M]:
+{
+    type Merged<V> = [V; core::cmp::max(N, M)];
+    type Chained = [T; N + M];
+}
+
+/// Implements [OwnedListPair] for every pair of owned lists where at least one is of dynamic length.
+macro_rules! impl_owned_list_pair_dynamic {
+    ($([$($g:tt)*] $lhs:ty, $rhs:ty);*) => {
+        $(
+            impl<T, U, $($g)*> OwnedListPair<$rhs> for $lhs
+            {
+                type Merged<V> = Vec<V>;
+                type Chained = Vec<T>;
+            }
+        )*
+    };
+}
+impl_owned_list_pair_dynamic!(
+    [] Vec<T>, Vec<U>;
+    [const N: usize] [T; N], Vec<U>;
+    [const M: usize] Vec<T>, [U; M]
+);
+
+impl<T, const N: usize> IntoOwned for array::IntoBulk<T, N>
+{
+    type Owned = [T; N];
+}
+
+impl<T> IntoOwned for vec::IntoBulk<T>
+{
+    type Owned = Vec<T>;
+}
+
+impl<I, F, U> IntoOwned for Map<I, F>
+where
+    I: IntoOwned,
+    F: FnMut(I::Item) -> U
+{
+    type Owned = <I::Owned as OwnedList>::Mapped<U>;
+}
+
+impl<L, R, F, V> IntoOwned for Merge<L, R, F>
+where
+    L: IntoOwned<Item: Into<V>, Owned: OwnedListPair<R::Owned>>,
+    R: IntoOwned<Item: Into<V>>,
+    F: FnMut(L::Item, R::Item) -> V
+{
+    type Owned = <L::Owned as OwnedListPair<R::Owned>>::Merged<V>;
+}
+
+impl<A, B> IntoOwned for Chain<A, B>
+where
+    A: IntoOwned<Owned: OwnedListPair<B::Owned>>,
+    B: IntoOwned<Item = A::Item>
+{
+    type Owned = <A::Owned as OwnedListPair<B::Owned>>::Chained;
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::Polynomial;
+
+    #[test]
+    fn into_owned()
+    {
+        let p = Polynomial::new([1.0, 2.0]);
+        let q = Polynomial::new([1.0, 1.0, 1.0]);
+
+        let r: [f64; 3] = (p - q).into_owned().into_inner();
+        assert_eq!(r, [0.0, 1.0, -1.0]);
+
+        let r: Vec<f64> = (p + Polynomial::new(q.into_inner().to_vec())).into_owned().into_inner();
+        assert_eq!(r, [2.0, 3.0, 1.0]);
+
+        let r: Vec<f64> = (-(p*Polynomial::new(vec![1.0, -1.0]))).into_owned().into_inner();
+        assert_eq!(r, [-1.0, -1.0, 2.0]);
+    }
+}
@@ -1,11 +1,44 @@
 #![feature(const_trait_impl)]
 #![feature(const_precise_live_drops)]
+#![feature(generic_const_exprs)]
+#![feature(const_cmp)]
 
-use core::ops::Add;
+#![allow(incomplete_features)]
 
 use array_trait::{AsSlice};
-use bulks::{Bulk, CollectNearest, IntoBulk, Merge};
+use bulks::IntoBulk;
 
+moddef::moddef!(
+    mod {
+        add,
+        compose,
+        derivative,
+        div,
+        eval,
+        from_roots,
+        integral,
+        mul,
+        neg,
+        pow,
+        roots,
+        scalar,
+        sub
+    },
+    pub mod {
+        orthogonal
+    },
+    flat(pub) mod {
+        into_owned
+    },
+    pub(crate) mod {
+        util
+    }
+);
+
+/// A polynomial.
+/// 
+/// The coefficients are stored in order of ascending powers, such that the `i`-th coefficient is the factor of `xⁱ`.
+#[derive(Clone, Copy, Debug)]
 pub struct Polynomial<I>(I)
 where
     I: IntoBulk;
@@ -25,10 +58,13 @@ where
         bulk
     }
 
-    pub fn into_owned(self) -> Polynomial<<I::IntoBulk as CollectNearest>::Nearest>
+    /// Collects the coefficients into an owned list. See [IntoOwned].
+    pub fn into_owned(self) -> Polynomial<<I::IntoBulk as IntoOwned>::Owned>
+    where
+        I::IntoBulk: IntoOwned
     {
         let Self(bulk) = self;
-        Polynomial(bulk.into_bulk().collect_nearest())
+        Polynomial(bulk.into_bulk().into_owned())
     }
 }
 
@@ -49,20 +85,19 @@ where
     }
 }
 
-impl<I1, I2, T> Add<Polynomial<I2>> for Polynomial<I1>
-where
-    I1: IntoBulk<Item: Add<I2::Item, Output = T> + Into<T>>,
-    I2: IntoBulk<Item: Into<T>>
-{
-    type Output = Polynomial<Merge<I1::IntoBulk, I2::IntoBulk, fn(I1::Item, I2::Item) -> T>>;
-
-    fn add(self, rhs: Polynomial<I2>) -> Self::Output
-    {
-        let Self(lhs) = self;
-        let Polynomial(rhs) = rhs;
-        let lhs = lhs.into_bulk();
-        let rhs = rhs.into_bulk();
-
-        Polynomial(lhs.merge(rhs, Add::add))
-    }
-}
\ No newline at end of file
+/// Implements a binary polynomial operation for every combination of coefficient containers of dynamic length.
+/// 
+/// The macro is given the name of another macro, which implements the operation for a single pair of containers.
+macro_rules! impl_dynamic {
+    ($impl:ident) => {
+        $impl!([T] Vec<T>, Vec<T>);
+        $impl!(['a, T] &'a [T], Vec<T>);
+        $impl!(['b, T] Vec<T>, &'b [T]);
+        $impl!(['a, 'b, T] &'a [T], &'b [T]);
+        $impl!([T, const N: usize] [T; N], Vec<T>);
+        $impl!([T, const M: usize] Vec<T>, [T; M]);
+        $impl!(['b, T, const N: usize] [T; N], &'b [T]);
+        $impl!(['a, T, const M: usize] &'a [T], [T; M]);
+    };
+}
+use impl_dynamic as impl_dynamic;
@@ -0,0 +1,82 @@
+use core::ops::{Add, Mul};
+
+use num_traits::Zero;
+
+use crate::{Polynomial, util};
+
+/// The number of coefficients of the product of polynomials with `n` and `m` coefficients.
+///
+/// The product with an empty polynomial, which is zero, is empty.
+pub const fn product_len(n: usize, m: usize) -> usize
+{
+    if n == 0 || m == 0
+    {
+        0
+    }
+    else
+    {
+        n + m - 1
+    }
+}
+
+impl<T, const N: usize, const M: usize> Mul<Polynomial<[T; M]>> for Polynomial<[T; N]>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy,
+    [(); product_len(N, M)]:
+{
+    type Output = Polynomial<[T; product_len(N, M)]>;
+
+    fn mul(self, rhs: Polynomial<[T; M]>) -> Self::Output
+    {
+        let Self(lhs) = self;
+        let Polynomial(rhs) = rhs;
+
+        let mut y = [T::zero(); _];
+        util::conv(&lhs, &rhs, &mut y);
+        Polynomial(y)
+    }
+}
+
+macro_rules! impl_mul {
+    ([$($g:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<$($g)*> Mul<Polynomial<$rhs>> for Polynomial<$lhs>
+        where
+            T: Mul<Output = T> + Add<Output = T> + Zero + Copy
+        {
+            type Output = Polynomial<Vec<T>>;
+
+            fn mul(self, rhs: Polynomial<$rhs>) -> Self::Output
+            {
+                let Self(lhs) = self;
+                let Polynomial(rhs) = rhs;
+
+                let mut y = vec![T::zero(); product_len(lhs.len(), rhs.len())];
+                util::conv(&lhs, &rhs, &mut y);
+                Polynomial(y)
+            }
+        }
+    };
+}
+crate::impl_dynamic!(impl_mul);
+
+#[cfg(test)]
+mod test
+{
+    use crate::Polynomial;
+
+    #[test]
+    fn mul()
+    {
+        let p = Polynomial::new([1.0, 2.0]);
+        let q = Polynomial::new([-1.0, 0.0, 3.0]);
+
+        let r = (p*q).into_inner();
+        assert_eq!(r, [-1.0, -2.0, 3.0, 6.0]);
+
+        let r = (p*Polynomial::new(q.into_inner().to_vec())).into_inner();
+        assert_eq!(r, vec![-1.0, -2.0, 3.0, 6.0]);
+
+        let r: [f64; 0] = (p*Polynomial::new([])).into_inner();
+        assert_eq!(r, []);
+    }
+}
@@ -0,0 +1,19 @@
+use core::ops::Neg;
+
+use bulks::{Bulk, IntoBulk};
+
+use crate::Polynomial;
+
+impl<I, T> Neg for Polynomial<I>
+where
+    I: IntoBulk<Item: Neg<Output = T>>
+{
+    type Output = Polynomial<bulks::Map<I::IntoBulk, fn(I::Item) -> T>>;
+
+    fn neg(self) -> Self::Output
+    {
+        let Self(bulk) = self;
+
+        Polynomial(bulk.into_bulk().map(Neg::neg))
+    }
+}
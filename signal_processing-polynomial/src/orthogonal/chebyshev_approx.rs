@@ -0,0 +1,112 @@
+use num_traits::{Float, FloatConst};
+
+use crate::{Polynomial, orthogonal::{self, ChebyshevT}};
+
+/// Approximation of a function on an interval, as a truncated series of Chebyshev polynomials of the first kind.
+#[derive(Clone, Debug)]
+pub struct ChebyshevApprox<T>
+where
+    T: Float
+{
+    /// The coefficients of the Chebyshev series, on the interval mapped to `[-1, 1]`.
+    pub coeffs: Vec<T>,
+    /// The interval `[a, b]` of the approximation.
+    pub domain: (T, T),
+    /// An estimate of the maximum absolute error on the interval.
+    pub error: T
+}
+
+impl<T> ChebyshevApprox<T>
+where
+    T: Float + FloatConst
+{
+    /// Interpolates `f` at `n` Chebyshev nodes of the first kind on the interval `domain`.
+    /// 
+    /// The error is estimated from the magnitude of the two highest-order coefficients,
+    /// which for smooth functions decay about as fast as the rest of the truncated series.
+    #[doc(alias = "chebfit")]
+    pub fn interpolate<F>(f: F, n: usize, domain: (T, T)) -> Self
+    where
+        F: Fn(T) -> T
+    {
+        let (a, b) = domain;
+        let one = T::one();
+        let two = one + one;
+        let half = two.recip();
+        let nf = T::from(n).unwrap();
+
+        let fx: Vec<_> = (0..n).map(|j| {
+                let t = (T::PI()*(T::from(j).unwrap() + half)/nf).cos();
+                f(half*(b - a)*t + half*(a + b))
+            }).collect();
+
+        let coeffs: Vec<_> = (0..n).map(|k| {
+                let s = fx.iter()
+                    .enumerate()
+                    .map(|(j, &fx)| fx*(T::PI()*T::from(k).unwrap()*(T::from(j).unwrap() + half)/nf).cos())
+                    .fold(T::zero(), |s, x| s + x);
+                let c = two*s/nf;
+                if k == 0 {c*half} else {c}
+            }).collect();
+
+        let error = coeffs.iter()
+            .rev()
+            .take(2)
+            .map(|c| c.abs())
+            .fold(T::zero(), |s, c| s + c);
+
+        Self {
+            coeffs,
+            domain,
+            error
+        }
+    }
+
+    /// Evaluates the approximation at `x`.
+    pub fn eval(&self, x: T) -> T
+    {
+        orthogonal::clenshaw(ChebyshevT, &self.coeffs, self.normalize(x))
+    }
+
+    /// Converts the approximation to a polynomial in monomial form, in the original variable.
+    /// 
+    /// The monomial form is badly conditioned for high orders, so prefer [eval](ChebyshevApprox::eval) where possible.
+    pub fn to_polynomial(&self) -> Polynomial<Vec<T>>
+    {
+        let (a, b) = self.domain;
+        let two = T::one() + T::one();
+
+        Polynomial::from_basis(ChebyshevT, &self.coeffs)
+            .compose(Polynomial::new(vec![-(a + b)/(b - a), two/(b - a)]))
+    }
+
+    fn normalize(&self, x: T) -> T
+    {
+        let (a, b) = self.domain;
+        let two = T::one() + T::one();
+        (two*x - a - b)/(b - a)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::orthogonal::ChebyshevApprox;
+
+    #[test]
+    fn interpolate()
+    {
+        let f = |x: f64| x.exp()*x.sin();
+        let approx = ChebyshevApprox::interpolate(f, 16, (0.0, 2.0));
+
+        assert!(approx.error < 1e-10);
+
+        let p = approx.to_polynomial();
+        for i in 0..=20
+        {
+            let x = i as f64/10.0;
+            assert!((approx.eval(x) - f(x)).abs() < 1e-10);
+            assert!((p.eval(x) - f(x)).abs() < 1e-8);
+        }
+    }
+}
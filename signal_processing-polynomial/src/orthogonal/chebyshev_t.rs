@@ -0,0 +1,24 @@
+use num_traits::Float;
+
+use crate::orthogonal::OrthogonalBasis;
+
+/// Chebyshev polynomials of the first kind, `Tₙ(x)`.
+/// 
+/// Orthogonal on `[-1, 1]` with weight `1/√(1 - x²)`.
+#[derive(Clone, Copy, Debug)]
+pub struct ChebyshevT;
+
+impl OrthogonalBasis for ChebyshevT
+{
+    fn recurrence<T>(self, n: usize) -> (T, T, T)
+    where
+        T: Float
+    {
+        let one = T::one();
+        if n == 0
+        {
+            return (one, T::zero(), T::zero())
+        }
+        (one + one, T::zero(), one)
+    }
+}
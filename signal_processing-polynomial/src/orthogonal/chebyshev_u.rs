@@ -0,0 +1,25 @@
+use num_traits::Float;
+
+use crate::orthogonal::OrthogonalBasis;
+
+/// Chebyshev polynomials of the second kind, `Uₙ(x)`.
+/// 
+/// Orthogonal on `[-1, 1]` with weight `√(1 - x²)`.
+#[derive(Clone, Copy, Debug)]
+pub struct ChebyshevU;
+
+impl OrthogonalBasis for ChebyshevU
+{
+    fn recurrence<T>(self, n: usize) -> (T, T, T)
+    where
+        T: Float
+    {
+        let one = T::one();
+        let two = one + one;
+        if n == 0
+        {
+            return (two, T::zero(), T::zero())
+        }
+        (two, T::zero(), one)
+    }
+}
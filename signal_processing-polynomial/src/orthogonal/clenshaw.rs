@@ -0,0 +1,48 @@
+use num_traits::Float;
+
+use crate::orthogonal::OrthogonalBasis;
+
+/// Evaluates the series `Σ cₖPₖ(x)` in an orthogonal basis using Clenshaw's algorithm.
+pub fn clenshaw<B, T>(basis: B, coeffs: &[T], x: T) -> T
+where
+    B: OrthogonalBasis,
+    T: Float
+{
+    let mut b1 = T::zero();
+    let mut b2 = T::zero();
+    for (k, &c) in coeffs.iter()
+        .enumerate()
+        .rev()
+    {
+        let (a, b, _) = basis.recurrence::<T>(k);
+        let (_, _, c_next) = basis.recurrence::<T>(k + 1);
+        let bk = c + (a*x + b)*b1 - c_next*b2;
+        b2 = b1;
+        b1 = bk;
+    }
+    b1
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{Polynomial, orthogonal::{self, ChebyshevU, Hermite}};
+
+    #[test]
+    fn clenshaw()
+    {
+        let c = [0.5f64, -1.0, 2.0, 0.25];
+
+        let p = Polynomial::from_basis(ChebyshevU, &c);
+        for x in [-0.9, -0.3, 0.0, 0.4, 1.0]
+        {
+            assert!((orthogonal::clenshaw(ChebyshevU, &c, x) - p.eval(x)).abs() < 1e-12);
+        }
+
+        let p = Polynomial::from_basis(Hermite, &c);
+        for x in [-1.5, 0.2, 2.0]
+        {
+            assert!((orthogonal::clenshaw(Hermite, &c, x) - p.eval(x)).abs() < 1e-10);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use num_traits::Float;
+
+use crate::orthogonal::OrthogonalBasis;
+
+/// Fits a series of degree `degree` in an orthogonal basis to the sampled data `(x, y)`, in the least-squares sense.
+/// 
+/// Returns the coefficients `cₖ` of the series `Σ cₖPₖ(x)`.
+/// The least-squares problem is solved by QR-factorization of the generalized Vandermonde matrix, using Householder reflections.
+/// 
+/// # Panics
+/// 
+/// If `x` and `y` are of different length.
+#[doc(alias = "polyfit")]
+pub fn fit<B, T>(basis: B, x: &[T], y: &[T], degree: usize) -> Vec<T>
+where
+    B: OrthogonalBasis,
+    T: Float
+{
+    assert_eq!(x.len(), y.len(), "There must be as many samples as sample points.");
+
+    let m = x.len();
+    let n = degree + 1;
+
+    // Row-major generalized Vandermonde matrix, where element (i, k) is Pₖ(xᵢ).
+    let mut a = vec![T::zero(); m*n];
+    for (row, &x) in a.chunks_mut(n)
+        .zip(x)
+    {
+        let mut p_prev = T::zero();
+        let mut p = T::one();
+        for (k, v) in row.iter_mut()
+            .enumerate()
+        {
+            *v = p;
+            let (ak, bk, ck) = basis.recurrence::<T>(k);
+            let p_next = (ak*x + bk)*p - ck*p_prev;
+            p_prev = p;
+            p = p_next;
+        }
+    }
+    let mut b = y.to_vec();
+
+    // Householder QR
+    let r = n.min(m);
+    for j in 0..r
+    {
+        let norm = (j..m).map(|i| a[i*n + j]*a[i*n + j])
+            .fold(T::zero(), |s, x| s + x)
+            .sqrt();
+        if norm.is_zero()
+        {
+            continue
+        }
+        let alpha = if a[j*n + j].is_sign_positive() {-norm} else {norm};
+        let mut v: Vec<_> = (j..m).map(|i| a[i*n + j])
+            .collect();
+        v[0] = v[0] - alpha;
+        let v_norm_sqr = v.iter()
+            .fold(T::zero(), |s, &v| s + v*v);
+        if v_norm_sqr.is_zero()
+        {
+            continue
+        }
+        let two = T::one() + T::one();
+        for k in j..n
+        {
+            let s = v.iter()
+                .enumerate()
+                .fold(T::zero(), |s, (i, &v)| s + v*a[(i + j)*n + k]);
+            let f = two*s/v_norm_sqr;
+            for (i, &v) in v.iter()
+                .enumerate()
+            {
+                a[(i + j)*n + k] = a[(i + j)*n + k] - f*v
+            }
+        }
+        let s = v.iter()
+            .enumerate()
+            .fold(T::zero(), |s, (i, &v)| s + v*b[i + j]);
+        let f = two*s/v_norm_sqr;
+        for (i, &v) in v.iter()
+            .enumerate()
+        {
+            b[i + j] = b[i + j] - f*v
+        }
+    }
+
+    // Back-substitution
+    let mut c = vec![T::zero(); n];
+    for j in (0..r).rev()
+    {
+        let d = a[j*n + j];
+        if d.is_zero()
+        {
+            continue
+        }
+        let s = (j + 1..r).map(|k| a[j*n + k]*c[k])
+            .fold(T::zero(), |s, x| s + x);
+        c[j] = (b[j] - s)/d
+    }
+    c
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{Polynomial, orthogonal::{self, Laguerre, Legendre}};
+
+    #[test]
+    fn fit()
+    {
+        let c = [1.0, -0.5, 0.25, 2.0];
+        let p = Polynomial::from_basis(Legendre, &c);
+
+        let x: Vec<_> = (0..32).map(|i| -1.0 + i as f64/16.0).collect();
+        let y: Vec<_> = x.iter().map(|&x| p.eval(x)).collect();
+
+        let c_fit = orthogonal::fit(Legendre, &x, &y, 3);
+        for (c, c_fit) in c.iter().zip(&c_fit)
+        {
+            assert!((c - c_fit).abs() < 1e-10);
+        }
+
+        let c_fit = orthogonal::fit(Laguerre, &x, &y, 3);
+        let q = Polynomial::from_basis(Laguerre, &c_fit);
+        for &x in &x
+        {
+            assert!((p.eval(x) - q.eval(x)).abs() < 1e-10);
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use num_traits::Float;
+
+use crate::orthogonal::OrthogonalBasis;
+
+/// Physicists' Hermite polynomials, `Hₙ(x)`.
+/// 
+/// Orthogonal on `(-∞, ∞)` with weight `e^(-x²)`.
+#[derive(Clone, Copy, Debug)]
+pub struct Hermite;
+
+impl OrthogonalBasis for Hermite
+{
+    fn recurrence<T>(self, n: usize) -> (T, T, T)
+    where
+        T: Float
+    {
+        let n = T::from(n).unwrap();
+        let two = T::one() + T::one();
+        (two, T::zero(), two*n)
+    }
+}
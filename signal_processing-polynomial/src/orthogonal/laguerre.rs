@@ -0,0 +1,22 @@
+use num_traits::Float;
+
+use crate::orthogonal::OrthogonalBasis;
+
+/// Laguerre polynomials, `Lₙ(x)`.
+/// 
+/// Orthogonal on `[0, ∞)` with weight `e^(-x)`.
+#[derive(Clone, Copy, Debug)]
+pub struct Laguerre;
+
+impl OrthogonalBasis for Laguerre
+{
+    fn recurrence<T>(self, n: usize) -> (T, T, T)
+    where
+        T: Float
+    {
+        let n = T::from(n).unwrap();
+        let one = T::one();
+        let two = one + one;
+        (-(n + one).recip(), (two*n + one)/(n + one), n/(n + one))
+    }
+}
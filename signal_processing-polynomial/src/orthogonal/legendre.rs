@@ -0,0 +1,22 @@
+use num_traits::Float;
+
+use crate::orthogonal::OrthogonalBasis;
+
+/// Legendre polynomials, `Pₙ(x)`.
+/// 
+/// Orthogonal on `[-1, 1]` with unit weight.
+#[derive(Clone, Copy, Debug)]
+pub struct Legendre;
+
+impl OrthogonalBasis for Legendre
+{
+    fn recurrence<T>(self, n: usize) -> (T, T, T)
+    where
+        T: Float
+    {
+        let n = T::from(n).unwrap();
+        let one = T::one();
+        let two = one + one;
+        ((two*n + one)/(n + one), T::zero(), n/(n + one))
+    }
+}
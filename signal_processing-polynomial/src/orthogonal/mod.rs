@@ -0,0 +1,143 @@
+use array_trait::AsSlice;
+use bulks::IntoBulk;
+use num_traits::Float;
+
+use crate::Polynomial;
+
+moddef::moddef!(
+    flat(pub) mod {
+        chebyshev_approx,
+        chebyshev_t,
+        chebyshev_u,
+        clenshaw,
+        fit,
+        hermite,
+        laguerre,
+        legendre
+    }
+);
+
+/// A family of orthogonal polynomials, defined by its three-term recurrence
+/// 
+/// `Pₙ₊₁(x) = (aₙx + bₙ)Pₙ(x) - cₙPₙ₋₁(x)`
+/// 
+/// with `P₀(x) = 1` and `P₋₁(x) = 0`.
+pub trait OrthogonalBasis: Copy
+{
+    /// Returns the recurrence coefficients `(aₙ, bₙ, cₙ)`.
+    fn recurrence<T>(self, n: usize) -> (T, T, T)
+    where
+        T: Float;
+
+    /// Returns the `n`-th polynomial of the family, in monomial form.
+    fn polynomial<T>(self, n: usize) -> Polynomial<Vec<T>>
+    where
+        T: Float
+    {
+        let mut p_prev = vec![];
+        let mut p = vec![T::one()];
+        for k in 0..n
+        {
+            let (a, b, c) = self.recurrence::<T>(k);
+            let mut p_next = vec![T::zero(); k + 2];
+            for (i, &p) in p.iter()
+                .enumerate()
+            {
+                p_next[i + 1] = p_next[i + 1] + a*p;
+                p_next[i] = p_next[i] + b*p;
+            }
+            for (p_next, &p_prev) in p_next.iter_mut()
+                .zip(p_prev.iter())
+            {
+                *p_next = *p_next - c*p_prev
+            }
+            p_prev = core::mem::replace(&mut p, p_next);
+        }
+        Polynomial::new(p)
+    }
+}
+
+impl<T> Polynomial<Vec<T>>
+where
+    T: Float
+{
+    /// Converts a series `Σ cₖPₖ(x)` in an orthogonal basis to monomial form.
+    pub fn from_basis<B>(basis: B, coeffs: &[T]) -> Self
+    where
+        B: OrthogonalBasis
+    {
+        let mut y = vec![T::zero(); coeffs.len().max(1)];
+        for (k, &c) in coeffs.iter()
+            .enumerate()
+        {
+            let p = basis.polynomial::<T>(k);
+            for (y, &p) in y.iter_mut()
+                .zip(p.as_slice())
+            {
+                *y = *y + c*p
+            }
+        }
+        Polynomial::new(y)
+    }
+}
+
+impl<I, T> Polynomial<I>
+where
+    I: IntoBulk + AsSlice<Elem = T>,
+    T: Float
+{
+    /// Converts the polynomial to a series `Σ cₖPₖ(x)` in an orthogonal basis, returning the coefficients `cₖ`.
+    pub fn to_basis<B>(&self, basis: B) -> Vec<T>
+    where
+        B: OrthogonalBasis
+    {
+        let mut m = self.as_slice().to_vec();
+        let mut c = vec![T::zero(); m.len()];
+        for k in (0..m.len()).rev()
+        {
+            let p = basis.polynomial::<T>(k);
+            let p = p.as_slice();
+            let ck = m[k]/p[k];
+            for (m, &p) in m.iter_mut()
+                .zip(p)
+            {
+                *m = *m - ck*p
+            }
+            c[k] = ck
+        }
+        c
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use array_trait::AsSlice;
+
+    use crate::{Polynomial, orthogonal::{ChebyshevT, Hermite, Laguerre, Legendre, OrthogonalBasis}};
+
+    fn approx_eq(a: &[f64], b: &[f64]) -> bool
+    {
+        a.len() == b.len() && a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-12)
+    }
+
+    #[test]
+    fn polynomials()
+    {
+        assert!(approx_eq(ChebyshevT.polynomial::<f64>(4).as_slice(), &[1.0, 0.0, -8.0, 0.0, 8.0]));
+        assert!(approx_eq(Legendre.polynomial::<f64>(3).as_slice(), &[0.0, -1.5, 0.0, 2.5]));
+        assert!(approx_eq(Hermite.polynomial::<f64>(3).as_slice(), &[0.0, -12.0, 0.0, 8.0]));
+        assert!(approx_eq(Laguerre.polynomial::<f64>(2).as_slice(), &[1.0, -2.0, 0.5]));
+    }
+
+    #[test]
+    fn basis_conversion()
+    {
+        let p = Polynomial::new(vec![1.0, -2.0, 0.5, 3.0, 0.25]);
+
+        let c = p.to_basis(Legendre);
+        let q = Polynomial::from_basis(Legendre, &c);
+
+        assert!(approx_eq(p.as_slice(), q.as_slice()));
+    }
+}
@@ -0,0 +1,56 @@
+use core::ops::{Add, Mul};
+
+use num_traits::{One, Zero};
+
+use crate::{Polynomial, util};
+
+impl<T, const N: usize> Polynomial<[T; N]>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + One + Copy
+{
+    /// Raises the polynomial to the `P`-th power.
+    pub fn pow<const P: usize>(self) -> Polynomial<[T; (N - 1)*P + 1]>
+    where
+        [(); (N - 1)*P + 1]:
+    {
+        let Self(base) = self;
+
+        let mut y = [T::zero(); _];
+        let mut tmp = [T::zero(); (N - 1)*P + 1];
+        util::pow(&base, P, &mut y, &mut tmp);
+        Polynomial(y)
+    }
+}
+
+impl<T> Polynomial<Vec<T>>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + One + Copy
+{
+    /// Raises the polynomial to the `n`-th power.
+    pub fn pow(self, n: usize) -> Polynomial<Vec<T>>
+    {
+        let Self(base) = self;
+
+        let len = base.len().saturating_sub(1)*n + 1;
+        let mut y = vec![T::zero(); len];
+        let mut tmp = vec![T::zero(); len];
+        util::pow(&base, n, &mut y, &mut tmp);
+        Polynomial(y)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::Polynomial;
+
+    #[test]
+    fn pow()
+    {
+        let p = Polynomial::new([1.0, 1.0]);
+
+        assert_eq!(p.pow::<4>().into_inner(), [1.0, 4.0, 6.0, 4.0, 1.0]);
+        assert_eq!(p.pow::<0>().into_inner(), [1.0]);
+        assert_eq!(Polynomial::new(vec![1.0, 1.0]).pow(3).into_inner(), vec![1.0, 3.0, 3.0, 1.0]);
+    }
+}
@@ -0,0 +1,403 @@
+use array_trait::AsSlice;
+use bulks::IntoBulk;
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{Float, NumCast, Zero};
+
+use crate::{Polynomial, util};
+
+const MAX_ITERATIONS: usize = 1000;
+const POLISH_ITERATIONS: usize = 3;
+
+impl<I> Polynomial<I>
+where
+    I: IntoBulk + AsSlice<Elem: ComplexFloat>
+{
+    /// Finds the roots of the polynomial.
+    /// 
+    /// The roots are found with the Aberth-Ehrlich iteration, and then polished with a few Newton steps on the original polynomial.
+    /// 
+    /// If all coefficients are real, complex roots are returned as exact conjugate pairs, with the negative imaginary part first,
+    /// followed by the real roots in ascending order.
+    /// 
+    /// Leading zero coefficients are ignored, and trailing zero coefficients yield roots in zero.
+    #[doc(alias = "polyroots")]
+    pub fn roots(&self) -> Vec<Complex<<I::Elem as ComplexFloat>::Real>>
+    {
+        let c: Vec<_> = self.as_slice()
+            .iter()
+            .map(|c| Complex::new(c.re(), c.im()))
+            .collect();
+        let is_real = self.as_slice()
+            .iter()
+            .all(|c| c.im().is_zero());
+
+        let mut z = roots(&c);
+        if is_real
+        {
+            conj_pair(&mut z);
+        }
+        z
+    }
+
+    /// Finds the roots of the polynomial, and groups repeated roots together.
+    /// 
+    /// Roots are considered equal if they are within a relative distance of `tol` of each other, similar to Matlab's `mpoles`.
+    /// Each cluster is replaced by its centroid, which is then refined with Newton's method on the derivative that has it as a simple root.
+    /// 
+    /// Returns each distinct root together with its multiplicity.
+    pub fn roots_with_multiplicity(&self, tol: <I::Elem as ComplexFloat>::Real) -> Vec<(Complex<<I::Elem as ComplexFloat>::Real>, usize)>
+    {
+        let c: Vec<_> = self.as_slice()
+            .iter()
+            .map(|c| Complex::new(c.re(), c.im()))
+            .collect();
+        let is_real = self.as_slice()
+            .iter()
+            .all(|c| c.im().is_zero());
+
+        let mut z = roots(&c);
+        let mut clusters = cluster(&c, &mut z, tol);
+        if is_real
+        {
+            for (z, _) in clusters.iter_mut()
+            {
+                if Float::abs(z.im) <= tol*Complex::norm(*z)
+                {
+                    z.im = Zero::zero()
+                }
+            }
+        }
+        clusters
+    }
+}
+
+/// Strips the coefficients down to the part that has non-zero roots.
+/// 
+/// Returns the number of roots in zero.
+fn trim<R>(c: &[Complex<R>]) -> (&[Complex<R>], usize)
+where
+    R: Float
+{
+    let end = c.iter()
+        .rposition(|c| !c.is_zero())
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let c = &c[..end];
+    let start = c.iter()
+        .position(|c| !c.is_zero())
+        .unwrap_or(0);
+    (&c[start..], start)
+}
+
+fn roots<R>(c: &[Complex<R>]) -> Vec<Complex<R>>
+where
+    R: Float
+{
+    let (c, zeros) = trim(c);
+    let n = c.len().saturating_sub(1);
+
+    let mut z = vec![Complex::zero(); n + zeros];
+    let (z_nonzero, _) = z.split_at_mut(n);
+    match n
+    {
+        0 => (),
+        1 => z_nonzero[0] = -c[0]/c[1],
+        _ => {
+            aberth(c, z_nonzero);
+            polish(c, z_nonzero);
+        }
+    }
+    z
+}
+
+/// Evaluates the polynomial and its derivative using Horner's method.
+fn eval_with_derivative<R>(c: &[Complex<R>], z: Complex<R>) -> (Complex<R>, Complex<R>)
+where
+    R: Float
+{
+    let mut p = Complex::zero();
+    let mut dp = Complex::zero();
+    for &c in c.iter()
+        .rev()
+    {
+        dp = dp*z + p;
+        p = p*z + c;
+    }
+    (p, dp)
+}
+
+/// An upper bound of the rounding error when evaluating the polynomial at `z`.
+fn rounding_error<R>(c: &[Complex<R>], z: Complex<R>) -> R
+where
+    R: Float
+{
+    let r = z.norm();
+    let bound = c.iter()
+        .rev()
+        .fold(R::zero(), |b, c| b*r + c.norm());
+    bound*R::epsilon()*<R as NumCast>::from(4).unwrap()
+}
+
+fn aberth<R>(c: &[Complex<R>], z: &mut [Complex<R>])
+where
+    R: Float
+{
+    let n = z.len();
+    let cn = c[n].norm();
+    let c0 = c[0].norm();
+
+    // Initial guesses are spread on a circle with the geometric mean of the root moduli as radius.
+    let radius = (c0/cn).powf(<R as NumCast>::from(n).unwrap().recip());
+    let offset = <R as NumCast>::from(0.4).unwrap();
+    let tau = <R as NumCast>::from(core::f64::consts::TAU).unwrap();
+    for (k, z) in z.iter_mut()
+        .enumerate()
+    {
+        let theta = tau*<R as NumCast>::from(k).unwrap()/<R as NumCast>::from(n).unwrap() + offset;
+        *z = Complex::from_polar(radius, theta)
+    }
+
+    let mut done = vec![false; n];
+    for _ in 0..MAX_ITERATIONS
+    {
+        let mut converged = true;
+        for k in 0..n
+        {
+            if done[k]
+            {
+                continue
+            }
+            let (p, dp) = eval_with_derivative(c, z[k]);
+            if p.norm() <= rounding_error(c, z[k])
+            {
+                done[k] = true;
+                continue
+            }
+            converged = false;
+
+            let ratio = p/dp;
+            let s = z.iter()
+                .enumerate()
+                .filter(|&(j, _)| j != k)
+                .map(|(_, &zj)| (z[k] - zj).inv())
+                .fold(Complex::zero(), |s, x| s + x);
+            let w = ratio/(Complex::new(R::one(), R::zero()) - ratio*s);
+            if is_finite(w)
+            {
+                z[k] = z[k] - w;
+                if w.norm() <= R::epsilon()*z[k].norm()
+                {
+                    done[k] = true
+                }
+            }
+        }
+        if converged
+        {
+            break
+        }
+    }
+}
+
+/// Newton-steps on the original polynomial. Steps that do not decrease the residual are discarded.
+fn polish<R>(c: &[Complex<R>], z: &mut [Complex<R>])
+where
+    R: Float
+{
+    for z in z.iter_mut()
+    {
+        for _ in 0..POLISH_ITERATIONS
+        {
+            let (p, dp) = eval_with_derivative(c, *z);
+            let z_next = *z - p/dp;
+            if !is_finite(z_next) || util::horner(c, z_next).norm() >= p.norm()
+            {
+                break
+            }
+            *z = z_next
+        }
+    }
+}
+
+fn is_finite<R>(z: Complex<R>) -> bool
+where
+    R: Float
+{
+    Float::is_finite(z.re) && Float::is_finite(z.im)
+}
+
+/// Groups roots that are within a relative distance of `tol` of each other.
+fn cluster<R>(c: &[Complex<R>], z: &mut [Complex<R>], tol: R) -> Vec<(Complex<R>, usize)>
+where
+    R: Float
+{
+    let mut clusters: Vec<(Complex<R>, usize)> = vec![];
+    for &z in z.iter()
+    {
+        match clusters.iter_mut()
+            .find(|(zc, _)| (z - *zc).norm() <= tol*zc.norm().max(R::one()))
+        {
+            Some((zc, m)) => {
+                *zc = (*zc*<R as NumCast>::from(*m).unwrap() + z)/<R as NumCast>::from(*m + 1).unwrap();
+                *m += 1
+            },
+            None => clusters.push((z, 1))
+        }
+    }
+
+    // A root of multiplicity m is a simple root of the (m - 1)-th derivative.
+    let mut d = c.to_vec();
+    for (zc, m) in clusters.iter_mut()
+    {
+        if *m <= 1 || zc.is_zero()
+        {
+            continue
+        }
+        d.clear();
+        d.extend_from_slice(c);
+        for _ in 1..*m
+        {
+            d = d.iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &d)| d*<R as NumCast>::from(i).unwrap())
+                .collect();
+        }
+        polish(&d, core::slice::from_mut(zc));
+    }
+    clusters.sort_by(|(a, _), (b, _)| a.re.partial_cmp(&b.re).unwrap_or(core::cmp::Ordering::Equal));
+    clusters
+}
+
+/// Forces roots of a polynomial with real coefficients into exact conjugate pairs.
+fn conj_pair<R>(z: &mut Vec<Complex<R>>)
+where
+    R: Float
+{
+    let tol = <R as NumCast>::from(100).unwrap()*R::epsilon();
+
+    let mut real = vec![];
+    let mut pairs = vec![];
+    let mut positive = vec![];
+    let mut negative = vec![];
+    for &z in z.iter()
+    {
+        if Float::abs(z.im) <= tol*z.norm()
+        {
+            real.push(z.re)
+        }
+        else if Float::is_sign_positive(z.im)
+        {
+            positive.push(z)
+        }
+        else
+        {
+            negative.push(z)
+        }
+    }
+    for zp in positive
+    {
+        let nearest = negative.iter()
+            .enumerate()
+            .map(|(i, zn)| (i, (zn.conj() - zp).norm()))
+            .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+        match nearest
+        {
+            Some((i, _)) => {
+                let zn = negative.swap_remove(i);
+                let z = (zp + zn.conj())/<R as NumCast>::from(2).unwrap();
+                pairs.push(z)
+            },
+            // Non-real roots of a real polynomial come in pairs, so a root without a partner is real.
+            None => real.push(zp.re)
+        }
+    }
+    real.extend(negative.into_iter().map(|z| z.re));
+    pairs.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap_or(core::cmp::Ordering::Equal));
+    real.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+
+    z.clear();
+    for p in pairs
+    {
+        z.push(p.conj());
+        z.push(p);
+    }
+    z.extend(real.into_iter().map(|re| Complex::new(re, R::zero())));
+}
+
+#[cfg(test)]
+mod test
+{
+    use num_complex::Complex;
+
+    use crate::Polynomial;
+
+    fn approx_eq(a: &[Complex<f64>], b: &[Complex<f64>], tol: f64) -> bool
+    {
+        a.len() == b.len() && a.iter().zip(b).all(|(a, b)| (a - b).norm() <= tol)
+    }
+
+    #[test]
+    fn roots()
+    {
+        // (x - 1)(x - 2)(x - 3)
+        let p = Polynomial::new([-6.0, 11.0, -6.0, 1.0]);
+        let z = p.roots();
+        assert!(approx_eq(&z, &[Complex::from(1.0), Complex::from(2.0), Complex::from(3.0)], 1e-12));
+
+        // x(x² + 1), with a leading zero
+        let p = Polynomial::new([0.0, 1.0, 0.0, 1.0, 0.0]);
+        let z = p.roots();
+        assert!(approx_eq(&z, &[-Complex::i(), Complex::i(), Complex::from(0.0)], 1e-12));
+        assert_eq!(z[0], z[1].conj());
+    }
+
+    #[test]
+    fn odd_real_roots()
+    {
+        // (x - 1)³(x² + 1), where the triple root is perturbed off the real axis
+        let p = Polynomial::new([-1.0, 3.0, -4.0, 4.0, -3.0, 1.0]);
+        let z: Vec<Complex<f64>> = p.roots();
+        assert_eq!(z.len(), 5);
+        assert!(z.iter().all(|z| (z - Complex::from(1.0)).norm() < 1e-4 || (z.norm() - 1.0).abs() < 1e-10));
+        for pair in z.chunks(2)
+            .take_while(|pair| pair.len() == 2 && pair[0].im != 0.0)
+        {
+            assert_eq!(pair[0], pair[1].conj());
+        }
+
+        // (x + 1)(x - 2)(x - 3)(x² + 2x + 5)
+        let p = Polynomial::new([30.0, 17.0, -12.0, -2.0, -2.0, 1.0]);
+        let z = p.roots();
+        let expected = [Complex::new(-1.0, -2.0), Complex::new(-1.0, 2.0), Complex::from(-1.0), Complex::from(2.0), Complex::from(3.0)];
+        assert!(approx_eq(&z, &expected, 1e-10));
+    }
+
+    #[test]
+    fn roots_with_multiplicity()
+    {
+        // (x - 1)³(x + 2)
+        let p = Polynomial::new([-2.0, 5.0, -3.0, -1.0, 1.0]);
+        let z = p.roots_with_multiplicity(1e-3);
+
+        assert_eq!(z.len(), 2);
+        assert!((z[0].0 - Complex::from(-2.0)).norm() < 1e-12 && z[0].1 == 1);
+        assert!((z[1].0 - Complex::from(1.0)).norm() < 1e-10 && z[1].1 == 3);
+    }
+
+    #[test]
+    fn from_roots()
+    {
+        let r = [Complex::new(-0.5, 2.0), Complex::new(-0.5, -2.0), Complex::from(3.0), Complex::from(0.25)];
+        let p = Polynomial::<[_; 5]>::from_roots(r);
+        let z = p.roots();
+
+        assert_eq!(z.len(), r.len());
+        for r in r
+        {
+            assert!(z.iter().any(|z| (z - r).norm() < 1e-10));
+        }
+
+        let p = Polynomial::<Vec<_>>::from_roots(r);
+        assert!(approx_eq(&p.roots(), &z, 1e-10));
+    }
+}
@@ -0,0 +1,68 @@
+use core::ops::{Add, Div, Mul, Sub};
+
+use bulks::{Bulk, IntoBulk};
+
+use crate::Polynomial;
+
+/// The polynomial with each coefficient of `I` mapped by `F`.
+type Mapped<I, F> = Polynomial<bulks::Map<<I as IntoBulk>::IntoBulk, F>>;
+
+impl<I> Polynomial<I>
+where
+    I: IntoBulk
+{
+    /// Adds a scalar to the constant term.
+    pub fn add_scalar<U>(self, rhs: U) -> <Self as Add<Polynomial<[U; 1]>>>::Output
+    where
+        Self: Add<Polynomial<[U; 1]>>
+    {
+        self + Polynomial::new([rhs])
+    }
+
+    /// Subtracts a scalar from the constant term.
+    pub fn sub_scalar<U>(self, rhs: U) -> <Self as Sub<Polynomial<[U; 1]>>>::Output
+    where
+        Self: Sub<Polynomial<[U; 1]>>
+    {
+        self - Polynomial::new([rhs])
+    }
+
+    /// Multiplies every coefficient with a scalar.
+    pub fn mul_scalar<U>(self, rhs: U) -> Mapped<I, impl FnMut(I::Item) -> <I::Item as Mul<U>>::Output>
+    where
+        I::Item: Mul<U>,
+        U: Copy
+    {
+        let Self(bulk) = self;
+
+        Polynomial(bulk.into_bulk().map(move |c| c*rhs))
+    }
+
+    /// Divides every coefficient by a scalar.
+    pub fn div_scalar<U>(self, rhs: U) -> Mapped<I, impl FnMut(I::Item) -> <I::Item as Div<U>>::Output>
+    where
+        I::Item: Div<U>,
+        U: Copy
+    {
+        let Self(bulk) = self;
+
+        Polynomial(bulk.into_bulk().map(move |c| c/rhs))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::Polynomial;
+
+    #[test]
+    fn scalar()
+    {
+        let p = Polynomial::new([1.0, 2.0, 3.0]);
+
+        assert_eq!(p.mul_scalar(2.0).into_owned().into_inner(), [2.0, 4.0, 6.0]);
+        assert_eq!(p.div_scalar(2.0).into_owned().into_inner(), [0.5, 1.0, 1.5]);
+        assert_eq!(p.add_scalar(1.0).into_owned().into_inner(), [2.0, 2.0, 3.0]);
+        assert_eq!(p.sub_scalar(1.0).into_owned().into_inner(), [0.0, 2.0, 3.0]);
+    }
+}
@@ -0,0 +1,45 @@
+use core::ops::{Add, Neg, Sub};
+
+use bulks::{Bulk, IntoBulk, Merge};
+
+use crate::Polynomial;
+
+impl<I1, I2, N, T> Sub<Polynomial<I2>> for Polynomial<I1>
+where
+    I1: IntoBulk<Item: Add<N, Output = T> + Into<T>>,
+    I2: IntoBulk<Item: Neg<Output = N>>,
+    N: Into<T>
+{
+    type Output = Polynomial<Merge<I1::IntoBulk, bulks::Map<I2::IntoBulk, fn(I2::Item) -> N>, fn(I1::Item, N) -> T>>;
+
+    fn sub(self, rhs: Polynomial<I2>) -> Self::Output
+    {
+        let Self(lhs) = self;
+        let Polynomial(rhs) = rhs;
+        let lhs = lhs.into_bulk();
+        let rhs = rhs.into_bulk()
+            .map(Neg::neg as fn(_) -> _);
+
+        Polynomial(lhs.merge(rhs, Add::add))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::Polynomial;
+
+    #[test]
+    fn sub()
+    {
+        let p = Polynomial::new([1.0, 2.0, 3.0]);
+        let q = Polynomial::new(vec![1.0, 1.0, 1.0, 1.0, 1.0]);
+
+        // The length of the difference is only known at runtime, so it is collected into a vector.
+        let r: Vec<f64> = (p - q).into_inner()
+            .into_iter()
+            .collect();
+
+        assert_eq!(r.as_slice(), &[0.0, 1.0, 2.0, -1.0, -1.0]);
+    }
+}
@@ -0,0 +1,123 @@
+use core::ops::{Add, Div, Mul, Sub};
+
+use num_traits::{One, Zero};
+
+/// Convolves `a` with `b`, writing the result into `y`.
+///
+/// `y` must be at least `a.len() + b.len() - 1` long, or may be empty if either is. Any excess is zeroed.
+pub(crate) fn conv<T>(a: &[T], b: &[T], y: &mut [T])
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy
+{
+    y.fill(T::zero());
+    for (i, &a) in a.iter()
+        .enumerate()
+        .take(y.len())
+    {
+        for (y, &b) in y[i..].iter_mut()
+            .zip(b)
+        {
+            *y = *y + a*b
+        }
+    }
+}
+
+/// Divides `r` by `b` in-place, writing the quotient into `q`.
+///
+/// After returning, the remainder is stored in the first `b.len() - 1` elements of `r`, and the rest is zeroed.
+///
+/// # Panics
+///
+/// If `b` is the zero polynomial, or if `q` is too short to hold the quotient.
+pub(crate) fn div_rem<T>(r: &mut [T], b: &[T], q: &mut [T])
+where
+    T: Mul<Output = T> + Sub<Output = T> + Div<Output = T> + Zero + Copy
+{
+    let db = b.iter()
+        .rposition(|b| !b.is_zero())
+        .expect("Division by the zero polynomial.");
+    let lead = b[db];
+
+    q.fill(T::zero());
+    for k in (db..r.len()).rev()
+    {
+        let c = r[k]/lead;
+        if c.is_zero()
+        {
+            continue
+        }
+        *q.get_mut(k - db)
+            .expect("Quotient does not fit. The leading coefficient of the divisor must be non-zero.") = c;
+        for (r, &b) in r[k - db..=k].iter_mut()
+            .zip(&b[..=db])
+        {
+            *r = *r - c*b
+        }
+        r[k] = T::zero()
+    }
+}
+
+/// Raises `base` to the power `n`, writing the result into `y`.
+///
+/// `y` and `tmp` must both be at least `(base.len() - 1)*n + 1` long.
+pub(crate) fn pow<T>(base: &[T], n: usize, y: &mut [T], tmp: &mut [T])
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + One + Copy
+{
+    y.fill(T::zero());
+    if let Some(y) = y.first_mut()
+    {
+        *y = T::one()
+    }
+    let mut len = 1;
+    for _ in 0..n
+    {
+        let len_next = len + base.len().saturating_sub(1);
+        conv(&y[..len], base, &mut tmp[..len_next]);
+        y[..len_next].copy_from_slice(&tmp[..len_next]);
+        len = len_next
+    }
+}
+
+/// Computes the composition `p(q(x))`, writing the result into `y`.
+///
+/// `y` and `tmp` must both be at least `(p.len() - 1)*(q.len() - 1) + 1` long.
+pub(crate) fn compose<T>(p: &[T], q: &[T], y: &mut [T], tmp: &mut [T])
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy
+{
+    y.fill(T::zero());
+    let Some((&p_last, p)) = p.split_last()
+    else
+    {
+        return
+    };
+    if let Some(y) = y.first_mut()
+    {
+        *y = p_last
+    }
+    let mut len = 1;
+    for &p in p.iter()
+        .rev()
+    {
+        let len_next = len + q.len().saturating_sub(1);
+        conv(&y[..len], q, &mut tmp[..len_next]);
+        y[..len_next].copy_from_slice(&tmp[..len_next]);
+        if let Some(y) = y.first_mut()
+        {
+            *y = *y + p
+        }
+        len = len_next
+    }
+}
+
+/// Evaluates the polynomial with coefficients `c` at `x` using Horner's method.
+pub(crate) fn horner<T, X>(c: &[T], x: X) -> X
+where
+    T: Copy,
+    X: Mul<Output = X> + Add<T, Output = X> + Zero + Copy
+{
+    c.iter()
+        .rev()
+        .fold(X::zero(), |y, &c| y*x + c)
+}
@@ -1,5 +1,13 @@
+#![feature(decl_macro)]
+#![feature(generic_const_exprs)]
+
+#![allow(incomplete_features)]
+
 moddef::moddef!(
     flat(pub) mod {
+        matrix,
+        plane,
+        system,
         systems
     }
-);
\ No newline at end of file
+);
@@ -0,0 +1,48 @@
+/// A dense matrix, stored as a list of rows.
+pub trait Matrix<T>
+{
+    fn height(&self) -> usize;
+    fn width(&self) -> usize;
+    fn row(&self, i: usize) -> &[T];
+
+    fn get(&self, i: usize, j: usize) -> T
+    where
+        T: Copy
+    {
+        self.row(i)[j]
+    }
+}
+
+impl<T, const H: usize, const W: usize> Matrix<T> for [[T; W]; H]
+{
+    fn height(&self) -> usize
+    {
+        H
+    }
+    fn width(&self) -> usize
+    {
+        W
+    }
+    fn row(&self, i: usize) -> &[T]
+    {
+        &self[i]
+    }
+}
+
+impl<T> Matrix<T> for Vec<Vec<T>>
+{
+    fn height(&self) -> usize
+    {
+        self.len()
+    }
+    fn width(&self) -> usize
+    {
+        self.first()
+            .map(Vec::len)
+            .unwrap_or(0)
+    }
+    fn row(&self, i: usize) -> &[T]
+    {
+        &self[i]
+    }
+}
@@ -0,0 +1,9 @@
+/// The complex plane a system's variable lives in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Plane
+{
+    /// The Laplace domain of continuous-time systems, where the imaginary axis is the boundary of stability.
+    S,
+    /// The Z-transform domain of discrete-time systems, where the unit circle is the boundary of stability.
+    Z
+}
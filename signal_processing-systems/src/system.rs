@@ -0,0 +1,8 @@
+use num_complex::ComplexFloat;
+
+/// A linear time-invariant system.
+pub trait System
+{
+    /// The type of the system's coefficients.
+    type Set: ComplexFloat;
+}
@@ -1,6 +1,11 @@
 use core::marker::PhantomData;
 
+use array_trait::AsSlice;
+use bulks::IntoBulk;
 use num_complex::ComplexFloat;
+use num_traits::{Float, NumCast};
+
+use crate::systems::Tf;
 
 moddef::moddef!(
     mod {
@@ -8,12 +13,15 @@ moddef::moddef!(
     }
 );
 
+/// One or more autoregressive models, each given by its polynomial `a` and the variance of its driving noise.
+///
+/// Like [Polynomial](signal_processing_polynomial::Polynomial), the coefficients of `a` are stored in order of ascending powers.
 #[derive(Clone, Copy)]
-pub struct Ar<T, A, AV>
+pub struct Ar<T, A = Vec<T>, AV = Vec<(A, <T as ComplexFloat>::Real)>>
 where
     T: ComplexFloat,
-    A: MaybeList<T>,
-    AV: ListOrSingle<(A, T::Real)>
+    A: IntoBulk<Item = T>,
+    AV: IntoBulk<Item = (A, T::Real)>
 {
     pub av: AV,
     phantom: PhantomData<(A, T)>
@@ -22,8 +30,8 @@ where
 impl<T, A, AV> Ar<T, A, AV>
 where
     T: ComplexFloat,
-    A: MaybeList<T>,
-    AV: ListOrSingle<(A, T::Real)>
+    A: IntoBulk<Item = T>,
+    AV: IntoBulk<Item = (A, T::Real)>
 {
     pub fn new(av: AV) -> Self
     {
@@ -32,4 +40,16 @@ where
             phantom: PhantomData
         }
     }
-}
\ No newline at end of file
+
+    /// The all-pole transfer functions `σ/a(x)` that shape white noise of unit variance into each model's process.
+    pub fn to_tf(&self) -> Vec<Tf<T, [T; 1], Vec<T>>>
+    where
+        AV: AsSlice<Elem = (A, T::Real)>,
+        A: AsSlice<Elem = T>
+    {
+        self.av.as_slice()
+            .iter()
+            .map(|(a, v)| Tf::new([<T as NumCast>::from(Float::sqrt(*v)).unwrap()], a.as_slice().to_vec()))
+            .collect()
+    }
+}
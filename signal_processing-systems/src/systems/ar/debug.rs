@@ -1,18 +1,20 @@
 use core::fmt::Debug;
-use num::complex::ComplexFloat;
 
-use crate::{systems::Ar, quantities::{ListOrSingle, MaybeList}};
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+
+use crate::systems::Ar;
 
 impl<T, A, AV> Debug for Ar<T, A, AV>
 where
     T: ComplexFloat,
-    A: MaybeList<T>,
-    AV: ListOrSingle<(A, T::Real)> + Debug
+    A: IntoBulk<Item = T>,
+    AV: IntoBulk<Item = (A, T::Real)> + Debug
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
     {
         f.debug_struct("Ar")
             .field("av", &self.av)
             .finish()
     }
-}
\ No newline at end of file
+}
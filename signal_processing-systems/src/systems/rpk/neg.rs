@@ -1,28 +1,32 @@
 use core::ops::Neg;
 
-use num::complex::ComplexFloat;
+use bulks::{Bulk, IntoBulk};
+use num_complex::ComplexFloat;
+use signal_processing_polynomial::{IntoOwned, Polynomial};
 
-use crate::{quantities::{MaybeList, Polynomial, SumSequence}, systems::Rpk};
+use crate::systems::Rpk;
 
-impl<T, T2, R, P, RP, K, K2> Neg for Rpk<T, R, P, RP, K>
+impl<T, R, P, RP, K1, K2> Neg for Rpk<T, R, P, RP, K1>
 where
     T: ComplexFloat,
-    T2: ComplexFloat<Real = T::Real>,
     R: ComplexFloat<Real = T::Real>,
     P: ComplexFloat<Real = T::Real>,
-    RP: MaybeList<(R, P)>,
-    RP::MaybeMapped<(R, P)>: MaybeList<(R, P)>,
-    K: MaybeList<T>,
-    K2: MaybeList<T2>,
-    Polynomial<T, K>: Neg<Output = Polynomial<T2, K2>>
+    RP: IntoBulk<Item = (R, P)>,
+    K1: IntoBulk<Item = T>,
+    K2: IntoBulk<Item = T>,
+    bulks::Map<RP::IntoBulk, fn((R, P)) -> (R, P)>: IntoOwned<Item = (R, P)>,
+    K2::IntoBulk: IntoOwned<Item = T>,
+    Polynomial<K1>: Neg<Output = Polynomial<K2>>
 {
-    type Output = Rpk<T2, R, P, RP::MaybeMapped<(R, P)>, K2>;
+    type Output = Rpk<T, R, P, <bulks::Map<RP::IntoBulk, fn((R, P)) -> (R, P)> as IntoOwned>::Owned, <K2::IntoBulk as IntoOwned>::Owned>;
 
     fn neg(self) -> Self::Output
     {
         Rpk {
-            rp: SumSequence::new(self.rp.into_inner().maybe_map_into_owned(|(r, p)| (-r, p))),
-            k: -self.k
+            rp: self.rp.into_bulk()
+                .map((|(r, p)| (-r, p)) as fn(_) -> _)
+                .into_owned(),
+            k: (-self.k).into_owned()
         }
     }
-}
\ No newline at end of file
+}
@@ -1,28 +1,26 @@
 use core::ops::{Add, Neg, Sub};
 
-use num::complex::ComplexFloat;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
 
-use crate::{quantities::MaybeList, systems::Rpk};
+use crate::systems::Rpk;
 
-impl<T1, T2, R1, R2, P1, P2, RP1, RP2, K1, K2> Sub<Rpk<T2, R2, P2, RP2, K2>> for Rpk<T1, R1, P1, RP1, K1>
+impl<T, R, P, RP1, RP2, K1, K2, O> Sub<Rpk<T, R, P, RP2, K2>> for Rpk<T, R, P, RP1, K1>
 where
-    T1: ComplexFloat,
-    T2: ComplexFloat,
-    R1: ComplexFloat<Real = T1::Real>,
-    R2: ComplexFloat<Real = T2::Real>,
-    P1: ComplexFloat<Real = T1::Real>,
-    P2: ComplexFloat<Real = T2::Real>,
-    RP1: MaybeList<(R1, P1)>,
-    RP2: MaybeList<(R2, P2)>,
-    K1: MaybeList<T1>,
-    K2: MaybeList<T2>,
-    Rpk<T2, R2, P2, RP2, K2>: Neg,
-    Self: Add<<Rpk<T2, R2, P2, RP2, K2> as Neg>::Output>,
+    T: ComplexFloat,
+    R: ComplexFloat<Real = T::Real>,
+    P: ComplexFloat<Real = T::Real>,
+    RP1: IntoBulk<Item = (R, P)>,
+    RP2: IntoBulk<Item = (R, P)>,
+    K1: IntoBulk<Item = T>,
+    K2: IntoBulk<Item = T>,
+    Rpk<T, R, P, RP2, K2>: Neg,
+    Self: Add<<Rpk<T, R, P, RP2, K2> as Neg>::Output, Output = O>
 {
-    type Output = <Self as Add<<Rpk<T2, R2, P2, RP2, K2> as Neg>::Output>>::Output;
+    type Output = O;
 
-    fn sub(self, rhs: Rpk<T2, R2, P2, RP2, K2>) -> Self::Output
+    fn sub(self, rhs: Rpk<T, R, P, RP2, K2>) -> Self::Output
     {
-        self + (-rhs)
+        self + -rhs
     }
-}
\ No newline at end of file
+}
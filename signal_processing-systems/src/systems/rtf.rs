@@ -1,7 +1,8 @@
-use num::complex::ComplexFloat;
-use option_trait::Maybe;
+use num_complex::ComplexFloat;
 
 use crate::System;
+
+/// A system together with its response at a set of points.
 pub struct Rtf<W, S>
 where
     W: ComplexFloat<Real = <S::Set as ComplexFloat>::Real>,
@@ -18,12 +19,11 @@ where
     S::Set: Into<W>,
     S: System
 {
-    pub fn new<WW: Maybe<Vec<W>>>(sys: S, w: WW) -> Self
+    pub fn new(sys: S, w: Option<Vec<W>>) -> Self
     {
         Rtf {
             sys,
-            w: w.option()
-                .unwrap_or_else(std::vec::Vec::new)
+            w: w.unwrap_or_default()
         }
     }
-}
\ No newline at end of file
+}
@@ -1,9 +1,12 @@
-use core::ops::{Add, Div, Mul, Neg, Sub};
+use core::{borrow::Borrow, marker::PhantomData, ops::{Add, Div, Mul, Neg, Sub}};
 
-use num::{complex::ComplexFloat, traits::Inv};
-use option_trait::Maybe;
+use array_trait::AsSlice;
+use bulks::{Bulk, IntoBulk};
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{Float, Inv, NumCast, Zero};
+use signal_processing_polynomial::Polynomial;
 
-use crate::{quantities::{MaybeList, MaybeOwnedList, Polynomial, ProductSequence}, systems::Tf, transforms::system::ToTf};
+use crate::{System, systems::{ComplexZpk, Tf, tf, zpk}};
 
 moddef::moddef!(
     mod {
@@ -23,107 +26,185 @@ moddef::moddef!(
     }
 );
 
+/// A second-order section.
+pub type Biquad<T> = Tf<T, [T; 3], [T; 3]>;
+
+/// A cascade of second-order sections.
+///
+/// Each section is a biquadratic transfer function, and the system is the product of all sections.
+/// The list of sections may be an array or a vector.
 #[derive(Debug, Clone, Copy)]
-pub struct Sos<T: ComplexFloat, B: Maybe<[T; 3]> + MaybeOwnedList<T>, A: Maybe<[T; 3]> + MaybeOwnedList<T>, S: MaybeList<Tf<T, B, A>> = ()>
+pub struct Sos<T, S = Vec<Biquad<T>>>
+where
+    T: ComplexFloat,
+    S: IntoBulk<Item: Borrow<Biquad<T>>>
 {
-    pub sos: ProductSequence<Tf<T, B, A>, S>
+    pub sos: S,
+    phantom: PhantomData<T>
 }
 
-impl<T, S, B, A> Sos<T, B, A, S>
+impl<T, S> Sos<T, S>
 where
     T: ComplexFloat,
-    B: Maybe<[T; 3]> + MaybeOwnedList<T>,
-    A: Maybe<[T; 3]> + MaybeOwnedList<T>,
-    S: MaybeList<Tf<T, B, A>>
+    S: IntoBulk<Item: Borrow<Biquad<T>>>
 {
     pub fn new(sos: S) -> Self
     {
         Self {
-            sos: ProductSequence::new(sos)
+            sos,
+            phantom: PhantomData
         }
     }
 
-    pub type View<'a> = Sos<T, B, A, S::View<'a>>
+    /// Borrows the sections of the system.
+    pub fn as_view(&self) -> Sos<T, &[Biquad<T>]>
     where
-        S: 'a,
-        S::View<'a>: MaybeList<Tf<T, B, A>>;
+        S: AsSlice<Elem = Biquad<T>>
+    {
+        Sos::new(self.sos.as_slice())
+    }
 
-    pub fn as_view<'a>(&'a self) -> Sos<T, B, A, S::View<'a>>
+    /// Copies the sections of the system into a vector.
+    pub fn to_owned(&self) -> Sos<T>
     where
-        S: 'a,
-        S::View<'a>: MaybeList<Tf<T, B, A>>
+        S: Clone
     {
-        Sos {
-            sos: self.sos.as_view()
-        }
+        Sos::new(self.sos.clone()
+            .into_bulk()
+            .map(|sos| *sos.borrow())
+            .collect::<Vec<_>, _>()
+        )
     }
-    
+
     pub fn one() -> Self
     where
-        Self: Default,
+        Self: Default
     {
         Sos::default()
     }
+
+    /// A single section with a numerator of zero.
     pub fn zero() -> Self
     where
-        Polynomial<T, [T; 0]>: Into<Polynomial<T, B>>,
-        Polynomial<T, ()>: Into<Polynomial<T, A>>,
-        ProductSequence<Tf<T, B, A>, [Tf<T, B, A>; 1]>: Into<ProductSequence<Tf<T, B, A>, S>>
+        S: From<[Biquad<T>; 1]>
     {
-        Sos {
-            sos: ProductSequence::new([Tf::zero()]).into()
-        }
+        Sos::new(S::from([Tf::new([T::zero(); 3], zpk::unit())]))
     }
-    pub fn is_one(&self) -> bool
+
+    pub fn is_zero(&self) -> bool
     where
-        Self: ToTf<T, Vec<T>, Vec<T>, (), ()> + Clone
+        S: AsSlice<Elem = Biquad<T>>
     {
-        self.clone()
-            .to_tf((), ())
-            .is_one()
+        self.sos.as_slice()
+            .iter()
+            .any(Tf::is_zero)
     }
-    pub fn is_zero(&self) -> bool
+
+    /// Evaluates the system at the point `x`.
+    pub fn eval<X>(&self, x: X) -> X
+    where
+        S: AsSlice<Elem = Biquad<T>>,
+        X: Mul<Output = X> + Add<T, Output = X> + Div<Output = X> + Zero + Copy
+    {
+        self.sos.as_slice()
+            .iter()
+            .map(|sos| sos.eval(x))
+            .reduce(Mul::mul)
+            .unwrap_or_else(|| X::zero() + T::one())
+    }
+
+    /// Multiplies all sections together into a single transfer function.
+    #[doc(alias = "sos2tf")]
+    pub fn to_tf(&self) -> Tf<T, Vec<T>, Vec<T>>
+    where
+        S: AsSlice<Elem = Biquad<T>>
     {
-        self.sos.as_view_slice_option()
-            .is_some_and(|sos| {
-                sos.iter()
-                    .any(|sos| sos.is_zero())
+        self.sos.as_slice()
+            .iter()
+            .fold(Tf::one(), |y: Tf<T, Vec<T>, Vec<T>>, &sos| {
+                Tf::new((y.b*sos.b).into_inner(), (y.a*sos.a).into_inner())
             })
     }
 
-    pub fn s() -> Self
+    /// Finds the zeros, poles and gain of each section, and gathers them.
+    #[doc(alias = "sos2zp")]
+    pub fn to_zpk(&self) -> ComplexZpk<T>
     where
-        Polynomial<T, [T; 3]>: Into<Polynomial<T, B>>,
-        Polynomial<T, ()>: Into<Polynomial<T, A>>,
-        ProductSequence<Tf<T, B, A>, [Tf<T, B, A>; 1]>: Into<ProductSequence<Tf<T, B, A>, S>>
+        S: AsSlice<Elem = Biquad<T>>
     {
-        Sos {
-            sos: ProductSequence::new([
-                Tf {
-                    b: Polynomial::new([T::zero(), T::one(), T::zero()]).into(),
-                    a: Polynomial::new(()).into()
-                }
-            ]).into()
-        }
+        self.sos.as_slice()
+            .iter()
+            .map(Tf::to_zpk)
+            .product()
+    }
+}
+
+impl<T, S> System for Sos<T, S>
+where
+    T: ComplexFloat,
+    S: IntoBulk<Item: Borrow<Biquad<T>>>
+{
+    type Set = T;
+}
+
+/// Factors the numerator `b` into second-order sections, and puts them over the sections of the denominator `a`.
+///
+/// The gain is applied to the first section. If `T` is real, complex conjugate zeros are paired, so the sections stay real.
+pub(crate) fn from_numerator<T>(b: &[T], a: impl IntoIterator<Item = Polynomial<[T; 3]>>) -> Sos<T>
+where
+    T: ComplexFloat
+{
+    let b = tf::trim(b);
+    let (k, z) = match b.last()
+    {
+        Some(&k) => (k, Polynomial::new(b.to_vec()).roots()),
+        None => (T::zero(), vec![])
+    };
+    let tol = Float::sqrt(T::Real::epsilon());
+    let mut b = zpk::pair_conjugates(&z, tol)
+        .into_iter()
+        .map(|z| zpk::quadratic(&z).map(from_complex::<T>));
+    let mut a = a.into_iter()
+        .map(Polynomial::into_inner);
+
+    let mut sos = vec![];
+    loop
+    {
+        let (b, a) = match (b.next(), a.next())
+        {
+            (None, None) if !sos.is_empty() => break,
+            (b, a) => (b.unwrap_or_else(zpk::unit), a.unwrap_or_else(zpk::unit))
+        };
+        sos.push(Tf::new(b, a))
     }
+    sos[0].b = Polynomial::new(sos[0].b.into_inner().map(|b| b*k));
+    Sos::new(sos)
+}
+
+/// The value of `T` closest to `c`, which drops the imaginary part if `T` is real.
+fn from_complex<T>(c: Complex<T::Real>) -> T
+where
+    T: ComplexFloat
+{
+    // The imaginary unit, if `T` has one. Negating one would give `-1 - 0i`, whose root lies on the wrong side of the branch cut.
+    let i = (T::zero() - T::one()).sqrt();
+    let i = if i.is_nan() {T::zero()} else {i};
+    <T as NumCast>::from(c.re).unwrap() + i*<T as NumCast>::from(c.im).unwrap()
 }
 
 macro_rules! impl_op1_extra {
     ($t:ident :: $f:tt) => {
-        impl<'a, T, B, A, S, O> $t for &'a Sos<T, B, A, S>
+        impl<'a, T, S, O> $t for &'a Sos<T, S>
         where
             T: ComplexFloat,
-            B: Maybe<[T; 3]> + MaybeOwnedList<T>,
-            A: Maybe<[T; 3]> + MaybeOwnedList<T>,
-            S: MaybeList<Tf<T, B, A>>,
-            S::View<'a>: MaybeList<Tf<T, B, A>>,
-            Sos<T, B, A, S::View<'a>>: $t<Output = O>
+            S: IntoBulk<Item: Borrow<Biquad<T>>>,
+            Sos<T, S>: Clone + $t<Output = O>
         {
             type Output = O;
 
             fn $f(self) -> Self::Output
             {
-                self.as_view().$f()
+                self.clone().$f()
             }
         }
     };
@@ -133,89 +214,48 @@ impl_op1_extra!(Inv::inv);
 
 macro_rules! impl_op2_extra {
     ($t:ident :: $f:tt) => {
-        impl<'a, T1, T2, B1, B2, A1, A2, S1, S2, O> $t<Sos<T2, B2, A2, S2>> for &'a Sos<T1, B1, A1, S1>
-        where
-            T1: ComplexFloat,
-            T2: ComplexFloat,
-            B1: Maybe<[T1; 3]> + MaybeOwnedList<T1>,
-            B2: Maybe<[T2; 3]> + MaybeOwnedList<T2>,
-            A1: Maybe<[T1; 3]> + MaybeOwnedList<T1>,
-            A2: Maybe<[T2; 3]> + MaybeOwnedList<T2>,
-            S1: MaybeList<Tf<T1, B1, A1>>,
-            S2: MaybeList<Tf<T2, B2, A2>>,
-            S1::View<'a>: MaybeList<Tf<T1, B1, A1>>,
-            Sos<T1, B1, A1, S1::View<'a>>: $t<Sos<T2, B2, A2, S2>, Output = O>
-        {
-            type Output = O;
-
-            fn $f(self, rhs: Sos<T2, B2, A2, S2>) -> Self::Output
-            {
-                self.as_view().$f(rhs)
-            }
-        }
-        impl<'b, T1, T2, B1, B2, A1, A2, S1, S2, O> $t<&'b Sos<T2, B2, A2, S2>> for Sos<T1, B1, A1, S1>
+        impl<'a, T, S1, S2, O> $t<Sos<T, S2>> for &'a Sos<T, S1>
         where
-            T1: ComplexFloat,
-            T2: ComplexFloat,
-            B1: Maybe<[T1; 3]> + MaybeOwnedList<T1>,
-            B2: Maybe<[T2; 3]> + MaybeOwnedList<T2>,
-            A1: Maybe<[T1; 3]> + MaybeOwnedList<T1>,
-            A2: Maybe<[T2; 3]> + MaybeOwnedList<T2>,
-            S1: MaybeList<Tf<T1, B1, A1>>,
-            S2: MaybeList<Tf<T2, B2, A2>>,
-            S2::View<'b>: MaybeList<Tf<T2, B2, A2>>,
-            Sos<T1, B1, A1, S1>: $t<Sos<T2, B2, A2, S2::View<'b>>, Output = O>
+            T: ComplexFloat,
+            S1: IntoBulk<Item: Borrow<Biquad<T>>>,
+            S2: IntoBulk<Item: Borrow<Biquad<T>>>,
+            Sos<T, S1>: Clone + $t<Sos<T, S2>, Output = O>
         {
             type Output = O;
 
-            fn $f(self, rhs: &'b Sos<T2, B2, A2, S2>) -> Self::Output
+            fn $f(self, rhs: Sos<T, S2>) -> Self::Output
             {
-                self.$f(rhs.as_view())
+                self.clone().$f(rhs)
             }
         }
-        impl<'a, 'b, T1, T2, B1, B2, A1, A2, S1, S2, O> $t<&'b Sos<T2, B2, A2, S2>> for &'a Sos<T1, B1, A1, S1>
+        impl<'b, T, S1, S2, O> $t<&'b Sos<T, S2>> for Sos<T, S1>
         where
-            T1: ComplexFloat,
-            T2: ComplexFloat,
-            B1: Maybe<[T1; 3]> + MaybeOwnedList<T1>,
-            B2: Maybe<[T2; 3]> + MaybeOwnedList<T2>,
-            A1: Maybe<[T1; 3]> + MaybeOwnedList<T1>,
-            A2: Maybe<[T2; 3]> + MaybeOwnedList<T2>,
-            S1: MaybeList<Tf<T1, B1, A1>>,
-            S2: MaybeList<Tf<T2, B2, A2>>,
-            S1::View<'a>: MaybeList<Tf<T1, B1, A1>>,
-            S2::View<'b>: MaybeList<Tf<T2, B2, A2>>,
-            Sos<T1, B1, A1, S1::View<'a>>: $t<Sos<T2, B2, A2, S2::View<'b>>, Output = O>
+            T: ComplexFloat,
+            S1: IntoBulk<Item: Borrow<Biquad<T>>>,
+            S2: IntoBulk<Item: Borrow<Biquad<T>>>,
+            Sos<T, S2>: Clone,
+            Self: $t<Sos<T, S2>, Output = O>
         {
             type Output = O;
 
-            fn $f(self, rhs: &'b Sos<T2, B2, A2, S2>) -> Self::Output
+            fn $f(self, rhs: &'b Sos<T, S2>) -> Self::Output
             {
-                self.as_view().$f(rhs.as_view())
+                self.$f(rhs.clone())
             }
         }
-
-        impl<T, B, A, S, O> $t<T> for Sos<T, B, A, S>
+        impl<'a, 'b, T, S1, S2, O> $t<&'b Sos<T, S2>> for &'a Sos<T, S1>
         where
             T: ComplexFloat,
-            B: Maybe<[T; 3]> + MaybeOwnedList<T>,
-            A: Maybe<[T; 3]> + MaybeOwnedList<T>,
-            (): Maybe<[T; 3]>,
-            S: MaybeList<Tf<T, B, A>>,
-            Self: $t<Sos<T, [T; 3], (), [Tf<T, [T; 3], ()>; 1]>, Output = O>
+            S1: IntoBulk<Item: Borrow<Biquad<T>>>,
+            S2: IntoBulk<Item: Borrow<Biquad<T>>>,
+            Sos<T, S1>: Clone + $t<Sos<T, S2>, Output = O>,
+            Sos<T, S2>: Clone
         {
             type Output = O;
 
-            fn $f(self, rhs: T) -> Self::Output
+            fn $f(self, rhs: &'b Sos<T, S2>) -> Self::Output
             {
-                self.$f(Sos {
-                    sos: ProductSequence::new([
-                        Tf {
-                            b: Polynomial::new([T::zero(), T::zero(), rhs]),
-                            a: Polynomial::new(())
-                        }
-                    ])
-                })
+                self.clone().$f(rhs.clone())
             }
         }
     };
@@ -225,101 +265,24 @@ impl_op2_extra!(Sub::sub);
 impl_op2_extra!(Mul::mul);
 impl_op2_extra!(Div::div);
 
-#[allow(unused)]
-macro s {
-    (s) => {},
-    (z) => {},
-}
-pub macro sos {
-    ($t:path[$s:ident]= {$e:expr}) => {
-        {
-            Sos::<$t, [$t; 3], (), [_; 1]>::$s();
-            s!($s);
-            $e
-        }
-    },
-    ($t:path[$s:ident]=) => {
-        {
-            Sos::<$t, [$t; 3], (), [_; 1]>::$s();
-            s!($s);
-            Tf::<$t, _, _>::new([], ())
-        }
-    },
-    ($t:path[$s:ident]= $c:literal) => {
-        {
-            Sos::<$t, [$t; 3], (), [_; 1]>::$s();
-            s!($s);
-            Sos::<$t, _, _, _>::new([Tf::<$t, _, _>::new([<$t as num::NumCast>::from(0).unwrap(), <$t as num::NumCast>::from(0).unwrap(), <$t as num::NumCast>::from($c).unwrap()], ())])
-        }
-    },
-    ($t:path[$s:ident]= $c:literal + $ci:literal j) => {
-        {
-            Sos::<$t, [$t; 3], (), [_; 1]>::$s();
-            s!($s);
-            Sos::<num::Complex<<$t as num::complex::ComplexFloat>::Real>, _, _, _>::new([Tf::<num::Complex<<$t as num::complex::ComplexFloat>::Real>, _, _>::new([num::Complex::new(<$t as num::NumCast>::from(0).unwrap(), <$t as num::NumCast>::from(0).unwrap()), num::Complex::new(<$t as num::NumCast>::from(0).unwrap(), <$t as num::NumCast>::from(0).unwrap()), num::Complex::new(<$t as num::NumCast>::from($c).unwrap(), <$t as num::NumCast>::from($ci).unwrap())], ())])
-        }
-    },
-    ($t:path[$s:ident]= $c:literal - $ci:literal j) => {
-        {
-            Sos::<$t, [$t; 3], (), [_; 1]>::$s();
-            s!($s);
-            Sos::<num::Complex<<$t as num::complex::ComplexFloat>::Real>, _, _, _>::new([Tf::<num::Complex<<$t as num::complex::ComplexFloat>::Real>, _, _>::new([num::Complex::new(<$t as num::NumCast>::from(0).unwrap(), <$t as num::NumCast>::from(0).unwrap()), num::Complex::new(<$t as num::NumCast>::from(0).unwrap(), <$t as num::NumCast>::from(0).unwrap()), num::Complex::new(<$t as num::NumCast>::from($c).unwrap(), -<$t as num::NumCast>::from($ci).unwrap())], ())])
-        }
-    },
-    ($t:path[$s:ident]= $ci:literal j) => {
-        {
-            Sos::<$t, [$t; 3], (), [_; 1]>::$s();
-            s!($s);
-            Sos::<num::Complex<<$t as num::complex::ComplexFloat>::Real>, _, _, _>::new([Tf::<num::Complex<<$t as num::complex::ComplexFloat>::Real>, _, _>::new([num::Complex::new(<$t as num::NumCast>::from(0).unwrap(), <$t as num::NumCast>::from(0).unwrap()), num::Complex::new(<$t as num::NumCast>::from(0).unwrap(), <$t as num::NumCast>::from(0).unwrap()), num::Complex::new(<$t as num::NumCast>::from(0).unwrap(), <$t as num::NumCast>::from($ci).unwrap())], ())])
-        }
-    },
-    ($t:path[$s:ident]= $ss:ident) => {
-        {
-            #[allow(unused)]
-            let $s = Sos::<$t, [$t; 3], (), [_; 1]>::$s();
-            s!($s);
-            s!($ss);
-            Sos::<$t, [$t; 3], (), [_; 1]>::from($ss)
-        }
-    },
-    ($t:path[$s:ident]= $c:literal^$pc:literal) => {
-        {
-            Sos::<$t, [$t; 3], (), [_; 1]>::$s();
-            s!($s);
-            num::traits::Pow::pow(Sos::<$t, _, _, _>::new([Tf::<$t, _, _>::new([<$t as num::NumCast>::from(0).unwrap(), <$t as num::NumCast>::from(0).unwrap(), <$t as num::NumCast>::from($c).unwrap()], ())]), $pc)
-        }
-    },
-    ($t:path[$s:ident]= $ss:ident^$ps:literal) => {
-        {
-            #[allow(unused)]
-            let $s = Sos::<$t, [$t; 3], (), [_; 1]>::$s();
-            s!($s);
-            s!($ss);
-            num::traits::Pow::pow(Sos::<$t, [$t; 3], (), [_; 1]>::from($ss), $ps)
-        }
-    },
-    ($t:path[$s:ident]= ($($lhs:tt)*)^$lp:literal $($op:tt ($($rhs:tt)*))?) => {
-        num::traits::Pow::pow(sos!($t[$s]= $($lhs)*), $lp)$($op sos!($t[$s]= $($rhs)*))*
-    },
-    ($t:path[$s:ident]= ($($lhs:tt)*) $($op:tt ($($rhs:tt)*))?) => {
-        sos!($t[$s]= $($lhs)*)$($op sos!($t[$s]= $($rhs)*))*
-    },
-    ($t:path[$s:ident]= $lhs:tt$(^$lp:literal)? $($op:tt $rhs:tt$(^$rp:literal)?)*) => {
-        sos!($t[$s]= $lhs$(^$lp)?)$($op sos!($t[$s]= $rhs$(^$rp)?))*
-    },
-}
-
 #[cfg(test)]
 mod test
 {
-    use super::sos;
+    use num_complex::Complex;
+
+    use crate::systems::{Sos, Tf};
 
     #[test]
-    fn test()
+    fn to_tf()
     {
-        let h = sos!(f64[s] = (((2*(s^-3))^-2) + 1 + 2*(s^2))/(s + 1)*2);
-        let h2 = sos!(f64[s] = {h}*2);
+        let sos = Sos::new([
+            Tf::new([1.0, 2.0, 1.0], [0.5, 0.0, 1.0]),
+            Tf::new([2.0, 0.0, 0.0], [0.2, 1.0, 0.0])
+        ]);
+        let tf = sos.to_tf();
 
-        println!("{:?}", h2);
+        let x = Complex::new(0.1, 0.9);
+        assert!((sos.eval(x) - tf.eval(x)).norm() < 1e-12);
+        assert!((sos.to_zpk().eval(x) - tf.eval(x)).norm() < 1e-12);
     }
-}
\ No newline at end of file
+}
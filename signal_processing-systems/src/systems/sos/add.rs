@@ -1,51 +1,66 @@
-use core::ops::{Add, Mul};
+use core::ops::Add;
 
-use num::complex::ComplexFloat;
-use option_trait::Maybe;
+use array_trait::AsSlice;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
 
-use crate::{quantities::{MaybeList, MaybeOwnedList}, systems::Sos, decompositions::SplitNumerDenom, systems::Tf, transforms::system::{ToSos, ToTf}};
+use crate::systems::{sos, tf, Biquad, Sos, Tf};
 
-impl<T1, T2, T3, B1, B2, A1, A2, A3, S1, S2, S3, S4> Add<Sos<T2, B2, A2, S2>> for Sos<T1, B1, A1, S1>
+impl<T, S1, S2> Add<Sos<T, S2>> for Sos<T, S1>
 where
-    T1: ComplexFloat,
-    T2: ComplexFloat,
-    T3: ComplexFloat,
-    B1: Maybe<[T1; 3]> + MaybeOwnedList<T1> + Clone,
-    B2: Maybe<[T2; 3]> + MaybeOwnedList<T2> + Clone,
-    A1: Maybe<[T1; 3]> + MaybeOwnedList<T1> + Clone,
-    A2: Maybe<[T2; 3]> + MaybeOwnedList<T2> + Clone,
-    A3: Maybe<[T3; 3]> + MaybeOwnedList<T3>,
-    S1: MaybeList<Tf<T1, B1, A1>>,
-    S2: MaybeList<Tf<T2, B2, A2>>,
-    S3: MaybeList<Tf<T3, (), A3>>,
-    S4: MaybeList<Tf<T3, [T3; 3], A3>>,
-    
-    Self: SplitNumerDenom,
-    Sos<T2, B2, A2, S2>: SplitNumerDenom,
-
-    <Self as SplitNumerDenom>::OutputNum: ToTf<T1, Vec<T1>, (), (), ()>,
-    <Sos<T2, B2, A2, S2> as SplitNumerDenom>::OutputNum: ToTf<T2, Vec<T2>, (), (), ()>,
-
-    Tf<T1, Vec<T1>>: Add<Tf<T2, Vec<T2>>, Output = Tf<T3, Vec<T3>>>,
-    Tf<T3, Vec<T3>>: ToSos<T3, [T3; 3], (), Vec<Tf<T3, [T3; 3]>>, (), ()>,
-
-    <Self as SplitNumerDenom>::OutputDen: Mul<<Sos<T2, B2, A2, S2> as SplitNumerDenom>::OutputDen, Output = Sos<T3, (), A3, S3>>,
-    Sos<T3, [T3; 3], (), Vec<Tf<T3, [T3; 3]>>>: Mul<Sos<T3, (), A3, S3>, Output = Sos<T3, [T3; 3], A3, S4>>
+    T: ComplexFloat,
+    S1: AsSlice<Elem = Biquad<T>> + IntoBulk<Item = Biquad<T>>,
+    S2: AsSlice<Elem = Biquad<T>> + IntoBulk<Item = Biquad<T>>
 {
-    type Output = Sos<T3, [T3; 3], A3, S4>;
+    type Output = Sos<T>;
 
-    fn add(self, rhs: Sos<T2, B2, A2, S2>) -> Self::Output
+    fn add(self, rhs: Sos<T, S2>) -> Self::Output
     {
-        let (n1, d1) = self.split_numer_denom();
-        let (n2, d2) = rhs.split_numer_denom();
+        // b₁/a₁ + b₂/a₂ = (b₁a₂ + b₂a₁)/(a₁a₂), where only the new numerator needs to be factored.
+        let Tf {b: b1, a: a1, ..} = self.to_tf();
+        let Tf {b: b2, a: a2, ..} = rhs.to_tf();
 
-        let n1 = n1.to_tf((), ());
-        let n2 = n2.to_tf((), ());
+        let b1 = (b1*a2).into_inner();
+        let b2 = (b2*a1).into_inner();
 
-        let n = n1 + n2;
-        let n = n.to_sos((), ());
+        let a = self.sos.as_slice()
+            .iter()
+            .chain(rhs.sos.as_slice())
+            .map(|sos| sos.a);
+        sos::from_numerator(&tf::add_coeffs(&b1, &b2), a)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use num_complex::Complex;
+
+    use crate::systems::{Sos, Tf};
+
+    #[test]
+    fn add()
+    {
+        let h1 = Sos::new([Tf::new([1.0, 2.0, 1.0], [0.5, 0.0, 1.0])]);
+        let h2 = Sos::new([Tf::new([2.0, 0.0, 0.0], [0.2, 1.0, 0.0])]);
+
+        let x = Complex::new(0.1, 0.9);
+        let y = h1.eval(x) + h2.eval(x);
+        let h = h1 + h2;
+
+        assert!((h.eval(x) - y).norm() < 1e-9);
+    }
+
+    #[test]
+    fn add_complex()
+    {
+        let c = Complex::<f64>::new;
+        let h1 = Sos::new(vec![Tf::new([c(1.0, 1.0), c(0.0, 2.0), c(1.0, 0.0)], [c(0.5, 0.0), c(0.0, 0.0), c(1.0, 0.0)])]);
+        let h2 = Sos::new(vec![Tf::new([c(2.0, 0.0), c(0.0, -1.0), c(0.0, 0.0)], [c(0.2, 0.3), c(1.0, 0.0), c(0.0, 0.0)])]);
+
+        let x = Complex::new(0.1, 0.9);
+        let h = &h1 + &h2;
 
-        let d = d1*d2;
-        n*d
+        assert!((h.eval(x) - h1.eval(x) - h2.eval(x)).norm() < 1e-9);
     }
-}
\ No newline at end of file
+}
@@ -1,20 +1,15 @@
-use num::complex::ComplexFloat;
-use option_trait::Maybe;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
 
-use crate::{quantities::{List, MaybeOwnedList, ProductSequence}, systems::{Sos, Tf}};
+use crate::systems::{Biquad, Sos};
 
-impl<T, B, A, S> Default for Sos<T, B, A, S>
+impl<T, S> Default for Sos<T, S>
 where
     T: ComplexFloat,
-    B: Maybe<[T; 3]> + MaybeOwnedList<T>,
-    A: Maybe<[T; 3]> + MaybeOwnedList<T>,
-    S: List<Tf<T, B, A>>,
-    ProductSequence<Tf<T, B, A>, S>: Default
+    S: IntoBulk<Item = Biquad<T>> + From<[Biquad<T>; 0]>
 {
     fn default() -> Self
     {
-        Self {
-            sos: Default::default()
-        }
+        Sos::new(S::from([]))
     }
-}
\ No newline at end of file
+}
@@ -1,27 +1,28 @@
-use core::ops::{Div, Mul};
+use core::{marker::PhantomData, ops::Div};
 
-use num::{complex::ComplexFloat, traits::Inv};
-use option_trait::Maybe;
+use bulks::{Bulk, Chain, IntoBulk};
+use num_complex::ComplexFloat;
+use signal_processing_polynomial::IntoOwned;
+use num_traits::Inv;
 
-use crate::{quantities::{MaybeList, MaybeOwnedList}, systems::{Sos, Tf}};
+use crate::systems::{Biquad, Sos};
 
-impl<T1, T2, B1, B2, A1, A2, S1, S2, O> Div<Sos<T2, B2, A2, S2>> for Sos<T1, B1, A1, S1>
+impl<T, S1, S2> Div<Sos<T, S2>> for Sos<T, S1>
 where
-    T1: ComplexFloat,
-    T2: ComplexFloat,
-    B1: Maybe<[T1; 3]> + MaybeOwnedList<T1>,
-    B2: Maybe<[T2; 3]> + MaybeOwnedList<T2>,
-    A1: Maybe<[T1; 3]> + MaybeOwnedList<T1>,
-    A2: Maybe<[T2; 3]> + MaybeOwnedList<T2>,
-    S1: MaybeList<Tf<T1, B1, A1>>,
-    S2: MaybeList<Tf<T2, B2, A2>>,
-    Sos<T2, B2, A2, S2>: Inv,
-    Self: Mul<<Sos<T2, B2, A2, S2> as Inv>::Output, Output = O>
+    T: ComplexFloat,
+    S1: IntoBulk<Item = Biquad<T>>,
+    S2: IntoBulk<Item = Biquad<T>>,
+    Chain<S1::IntoBulk, bulks::Map<S2::IntoBulk, fn(Biquad<T>) -> Biquad<T>>>: IntoOwned<Item = Biquad<T>>
 {
-    type Output = O;
+    type Output = Sos<T, <Chain<S1::IntoBulk, bulks::Map<S2::IntoBulk, fn(Biquad<T>) -> Biquad<T>>> as IntoOwned>::Owned>;
 
-    fn div(self, rhs: Sos<T2, B2, A2, S2>) -> Self::Output
+    fn div(self, rhs: Sos<T, S2>) -> Self::Output
     {
-        self*rhs.inv()
+        Sos {
+            sos: self.sos.into_bulk()
+                .chain(rhs.sos.into_bulk().map(Inv::inv as fn(_) -> _))
+                .into_owned(),
+            phantom: PhantomData
+        }
     }
-}
\ No newline at end of file
+}
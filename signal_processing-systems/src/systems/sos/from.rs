@@ -1,25 +1,34 @@
-use num::complex::ComplexFloat;
-use option_trait::Maybe;
+use core::{borrow::Borrow, marker::PhantomData};
 
-use crate::{quantities::{List, MaybeOwnedList, ProductSequence}, systems::{Sos, Tf}};
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
 
-impl<'a, T1, T2, B1, B2, A1, A2, S1, S2> From<&'a Sos<T1, B1, A1, S1>> for Sos<T2, B2, A2, S2>
+use crate::systems::{Biquad, Sos};
+
+impl<T, const N: usize> From<Sos<T, [Biquad<T>; N]>> for Sos<T, Vec<Biquad<T>>>
+where
+    T: ComplexFloat
+{
+    fn from(sos: Sos<T, [Biquad<T>; N]>) -> Self
+    {
+        Sos {
+            sos: sos.sos.to_vec(),
+            phantom: PhantomData
+        }
+    }
+}
+
+impl<'a, T, S1, S2> From<&'a Sos<T, S1>> for Sos<T, S2>
 where
-    T1: ComplexFloat,
-    T2: ComplexFloat,
-    B1: Maybe<[T1; 3]> + MaybeOwnedList<T1>,
-    B2: Maybe<[T2; 3]> + MaybeOwnedList<T2>,
-    A1: Maybe<[T1; 3]> + MaybeOwnedList<T1>,
-    A2: Maybe<[T2; 3]> + MaybeOwnedList<T2>,
-    S1: List<Tf<T1, B1, A1>>,
-    S2: List<Tf<T2, B2, A2>>,
-    S1::View<'a>: List<Tf<T1, B1, A1>>,
-    ProductSequence<Tf<T1, B1, A1>, S1::View<'a>>: Into<ProductSequence<Tf<T2, B2, A2>, S2>>
+    T: ComplexFloat,
+    S1: IntoBulk<Item: Borrow<Biquad<T>>> + Clone + Into<S2>,
+    S2: IntoBulk<Item: Borrow<Biquad<T>>>
 {
-    fn from(sos: &'a Sos<T1, B1, A1, S1>) -> Self
+    fn from(sos: &'a Sos<T, S1>) -> Self
     {
         Sos {
-            sos: sos.sos.as_view().into()
+            sos: sos.sos.clone().into(),
+            phantom: PhantomData
         }
     }
-}
\ No newline at end of file
+}
@@ -1,41 +1,49 @@
-use core::ops::Mul;
+use core::{marker::PhantomData, ops::Mul};
 
-use num::complex::ComplexFloat;
-use option_trait::{Maybe, PureStaticMaybe, StaticMaybe, ops::MaybeOr};
+use bulks::{Bulk, Chain, IntoBulk};
+use num_complex::ComplexFloat;
+use signal_processing_polynomial::IntoOwned;
 
-use crate::{util::ComplexOp, quantities::{MaybeList, MaybeOwnedList, ProductSequence}, systems::{Sos, Tf}, transforms::system::ToTf};
+use crate::systems::{Biquad, Sos};
 
-impl<T1, T2, T3, B1, B2, B3, A1, A2, A3, S1, S2, S3> Mul<Sos<T2, B2, A2, S2>> for Sos<T1, B1, A1, S1>
+impl<T, S1, S2> Mul<Sos<T, S2>> for Sos<T, S1>
 where
-    T1: ComplexFloat + ComplexOp<T2, Output = T3>,
-    T2: ComplexFloat + Into<T3>,
-    T3: ComplexFloat,
-    B1: Maybe<[T1; 3]> + MaybeOwnedList<T1> + Clone,
-    B2: Maybe<[T2; 3]> + MaybeOwnedList<T2> + Clone,
-    B3: Maybe<[T3; 3]> + MaybeOwnedList<T3>,
-    A1: Maybe<[T1; 3]> + MaybeOwnedList<T1> + Clone,
-    A2: Maybe<[T2; 3]> + MaybeOwnedList<T2> + Clone,
-    A3: Maybe<[T3; 3]> + MaybeOwnedList<T3>,
-    S1: MaybeList<Tf<T1, B1, A1>>,
-    S2: MaybeList<Tf<T2, B2, A2>>,
-    S3: MaybeList<Tf<T3, B3, A3>>,
-    S1::MaybeMapped<Tf<T3, B3, A3>>: MaybeList<Tf<T3, B3, A3>>,
-    S2::MaybeMapped<Tf<T3, B3, A3>>: MaybeList<Tf<T3, B3, A3>>,
-    ProductSequence<Tf<T3, B3, A3>, S1::MaybeMapped<Tf<T3, B3, A3>>>: Mul<ProductSequence<Tf<T3, B3, A3>, S2::MaybeMapped<Tf<T3, B3, A3>>>, Output = ProductSequence<Tf<T3, B3, A3>, S3>>,
-    Tf<T1, B1, A1>: ToTf<T3, B3, A3, (), ()>,
-    Tf<T2, B2, A2>: ToTf<T3, B3, A3, (), ()>,
-    B1::MaybeMapped<T3>: MaybeOr<[T3; 3], B2::MaybeMapped<T3>, Output = B3>,
-    A1::MaybeMapped<T3>: MaybeOr<[T3; 3], A2::MaybeMapped<T3>, Output = A3>,
-    B2::MaybeMapped<T3>: PureStaticMaybe<[T3; 3]>,
-    A2::MaybeMapped<T3>: PureStaticMaybe<[T3; 3]>
+    T: ComplexFloat,
+    S1: IntoBulk<Item = Biquad<T>>,
+    S2: IntoBulk<Item = Biquad<T>>,
+    Chain<S1::IntoBulk, S2::IntoBulk>: IntoOwned<Item = Biquad<T>>
 {
-    type Output = Sos<T3, B3, A3, S3>;
+    type Output = Sos<T, <Chain<S1::IntoBulk, S2::IntoBulk> as IntoOwned>::Owned>;
 
-    fn mul(self, rhs: Sos<T2, B2, A2, S2>) -> Self::Output
+    fn mul(self, rhs: Sos<T, S2>) -> Self::Output
     {
         Sos {
-            sos: ProductSequence::new(self.sos.into_inner().maybe_map_into_owned(|sos| sos.to_tf((), ())))
-                *ProductSequence::new(rhs.sos.into_inner().maybe_map_into_owned(|sos| sos.to_tf((), ())))
+            sos: self.sos.into_bulk()
+                .chain(rhs.sos)
+                .into_owned(),
+            phantom: PhantomData
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test
+{
+    use num_complex::Complex;
+
+    use crate::systems::{Sos, Tf};
+
+    #[test]
+    fn mul()
+    {
+        let h1 = Sos::new([Tf::new([1.0, 2.0, 1.0], [0.5, 0.0, 1.0])]);
+        let h2 = Sos::new(vec![Tf::new([2.0, 0.0, 0.0], [0.2, 1.0, 0.0])]);
+
+        let x = Complex::new(0.1, 0.9);
+        let y = h1.eval(x)*h2.eval(x);
+        let h = h1*h2;
+
+        assert_eq!(h.sos.len(), 2);
+        assert!((h.eval(x) - y).norm() < 1e-12);
+    }
+}
@@ -1,26 +1,27 @@
-use std::ops::Mul;
+use core::ops::Mul;
 
-use num::{complex::ComplexFloat, One};
-use option_trait::Maybe;
+use array_trait::AsSlice;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+use num_traits::One;
 
-use crate::{quantities::{MaybeList, MaybeOwnedList}, systems::{Sos, Tf}, transforms::system::ToTf};
+use crate::systems::{Biquad, Sos, Tf};
 
-impl<T, B, A, S> One for Sos<T, B, A, S>
+impl<T, S> One for Sos<T, S>
 where
     T: ComplexFloat,
-    B: Maybe<[T; 3]> + MaybeOwnedList<T>,
-    A: Maybe<[T; 3]> + MaybeOwnedList<T>,
-    S: MaybeList<Tf<T, B, A>>,
-    Self: Mul<Output = Self> + Default + ToTf<T, Vec<T>, Vec<T>, (), ()> + Clone,
+    S: IntoBulk<Item = Biquad<T>> + AsSlice<Elem = Biquad<T>>,
+    Self: Default + Mul<Output = Self>
 {
     fn one() -> Self
     {
-        Self::one()
+        Sos::default()
     }
+
     fn is_one(&self) -> bool
-    where
-        Self: PartialEq,
     {
-        self.is_one()
+        self.sos.as_slice()
+            .iter()
+            .all(Tf::is_one)
     }
-}
\ No newline at end of file
+}
@@ -1,57 +1,32 @@
-use core::ops::{BitAnd, Shr};
+use array_trait::AsSlice;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+use num_traits::{Inv, Pow, PrimInt};
 
-use num::{complex::ComplexFloat, pow::Pow, traits::Inv, Integer};
-use option_trait::{Maybe, ops::MaybeOr};
+use crate::systems::{Biquad, Sos};
 
-use crate::{quantities::{MaybeList, MaybeOwnedList}, systems::{Sos, Tf}, transforms::system::ToSos};
-
-impl<T, B, A, S, I> Pow<I> for Sos<T, B, A, S>
+impl<T, S, I> Pow<I> for Sos<T, S>
 where
     T: ComplexFloat,
-    B: Maybe<[T; 3]> + MaybeOwnedList<T>,
-    A: Maybe<[T; 3]> + MaybeOwnedList<T>,
-    [T; 3]: MaybeOr<[T; 3], [T; 3], Output = [T; 3]>,
-    S: MaybeList<Tf<T, B, A>>,
-    I: Integer + BitAnd<I, Output = I> + Shr<usize, Output = I> + Copy,
-    Self: ToSos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>, (), ()> + Inv<Output: ToSos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>, (), ()>>,
+    S: IntoBulk<Item = Biquad<T>> + AsSlice<Elem = Biquad<T>>,
+    I: PrimInt
 {
-    type Output = Sos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>>;
+    type Output = Sos<T, Vec<Biquad<T>>>;
 
-    fn pow(self, mut n: I) -> Self::Output
+    fn pow(self, n: I) -> Self::Output
     {
-        let mut x: Sos<T, [T; 3], [T; 3], Vec<Tf<T, [T; 3], [T; 3]>>> = if n < I::zero()
-        {
-            self.inv()
-                .to_sos((), ())
-        }
-        else
+        let sos = self.sos.as_slice();
+        let sos = if n < I::zero()
         {
-            self.to_sos((), ())
-        };
-        let mut r = if (n & I::one()) == I::one()
-        {
-            x.clone()
+            sos.iter()
+                .map(|&sos| sos.inv())
+                .collect::<Vec<_>>()
+                .repeat((I::zero() - n).to_usize().unwrap())
         }
         else
         {
-            Sos::one()
+            sos.repeat(n.to_usize().unwrap())
         };
-    
-        let two = I::one() + I::one();
-        loop
-        {
-            n = n/two;
-            if n == I::zero()
-            {
-                break;
-            }
-            x = x.as_view()*x.as_view();
-            if (n & I::one()) == I::one()
-            {
-                r = r*x.as_view();
-            }
-        }
-    
-        r
+        Sos::new(sos)
     }
-}
\ No newline at end of file
+}
@@ -1,27 +1,22 @@
-use std::{iter::Product, ops::Mul};
+use core::iter::Product;
 
-use num::{complex::ComplexFloat, One};
-use option_trait::Maybe;
+use bulks::{Bulk, IntoBulk};
+use num_complex::ComplexFloat;
 
-use crate::{quantities::{MaybeList, MaybeOwnedList}, systems::{Sos, Tf}, transforms::system::ToSos};
+use crate::systems::{Biquad, Sos};
 
-impl<T1, B1, A1, S1, T2, B2, A2, S2> Product<Sos<T1, B1, A1, S1>> for Sos<T2, B2, A2, S2>
+impl<T, S> Product<Sos<T, S>> for Sos<T, Vec<Biquad<T>>>
 where
-    T1: ComplexFloat,
-    T2: ComplexFloat,
-    B1: Maybe<[T1; 3]> + MaybeOwnedList<T1>,
-    B2: Maybe<[T2; 3]> + MaybeOwnedList<T2>,
-    A1: Maybe<[T1; 3]> + MaybeOwnedList<T1>,
-    A2: Maybe<[T2; 3]> + MaybeOwnedList<T2>,
-    S1: MaybeList<Tf<T1, B1, A1>>,
-    S2: MaybeList<Tf<T2, B2, A2>>,
-    Sos<T1, B1, A1, S1>: ToSos<T2, B2, A2, S2, (), ()>,
-    Sos<T2, B2, A2, S2>: Mul<Output = Sos<T2, B2, A2, S2>> + One
+    T: ComplexFloat,
+    S: IntoBulk<Item = Biquad<T>>
 {
-    fn product<I: Iterator<Item = Sos<T1, B1, A1, S1>>>(iter: I) -> Self
+    fn product<I: Iterator<Item = Sos<T, S>>>(iter: I) -> Self
     {
-        iter.map(|sos| sos.to_sos((), ()))
-            .reduce(|a, b| a*b)
-            .unwrap_or_else(One::one)
+        let mut sos = vec![];
+        for Sos {sos: s, ..} in iter
+        {
+            sos.append(&mut s.into_bulk().collect::<Vec<_>, _>())
+        }
+        Sos::new(sos)
     }
-}
\ No newline at end of file
+}
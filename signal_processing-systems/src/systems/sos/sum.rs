@@ -1,27 +1,45 @@
-use core::{iter::Sum, ops::Add};
+use core::iter::Sum;
 
-use num::{complex::ComplexFloat, Zero};
-use option_trait::Maybe;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+use num_traits::Zero;
 
-use crate::{quantities::{MaybeList, MaybeOwnedList}, systems::{Sos, Tf}, transforms::system::ToSos};
+use crate::systems::{Biquad, Sos};
 
-impl<T1, B1, A1, S1, T2, B2, A2, S2> Sum<Sos<T1, B1, A1, S1>> for Sos<T2, B2, A2, S2>
+impl<T, S1, S2> Sum<Sos<T, S1>> for Sos<T, S2>
 where
-    T1: ComplexFloat,
-    T2: ComplexFloat,
-    B1: Maybe<[T1; 3]> + MaybeOwnedList<T1>,
-    B2: Maybe<[T2; 3]> + MaybeOwnedList<T2>,
-    A1: Maybe<[T1; 3]> + MaybeOwnedList<T1>,
-    A2: Maybe<[T2; 3]> + MaybeOwnedList<T2>,
-    S1: MaybeList<Tf<T1, B1, A1>>,
-    S2: MaybeList<Tf<T2, B2, A2>>,
-    Sos<T1, B1, A1, S1>: ToSos<T2, B2, A2, S2, (), ()>,
-    Sos<T2, B2, A2, S2>: Add<Output = Sos<T2, B2, A2, S2>> + Zero
+    T: ComplexFloat,
+    S1: IntoBulk<Item = Biquad<T>>,
+    S2: IntoBulk<Item = Biquad<T>>,
+    Sos<T, S1>: Into<Sos<T, S2>>,
+    Sos<T, S2>: Zero
 {
-    fn sum<I: Iterator<Item = Sos<T1, B1, A1, S1>>>(iter: I) -> Self
+    fn sum<I: Iterator<Item = Sos<T, S1>>>(iter: I) -> Self
     {
-        iter.map(|sos| sos.to_sos((), ()))
+        iter.map(Into::into)
             .reduce(|a, b| a + b)
             .unwrap_or_else(Zero::zero)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test
+{
+    use num_complex::Complex;
+
+    use crate::systems::{Sos, Tf};
+
+    #[test]
+    fn sum()
+    {
+        let h1 = Sos::new([Tf::new([1.0, 2.0, 1.0], [0.5, 0.0, 1.0])]);
+        let h2 = Sos::new([Tf::new([2.0, 0.0, 0.0], [0.2, 1.0, 0.0])]);
+
+        let x = Complex::new(0.1, 0.9);
+        let y = h1.eval(x) + h2.eval(x);
+        let h: Sos<f64> = [h1, h2].into_iter().sum();
+
+        assert!((h.eval(x) - y).norm() < 1e-9);
+        assert!(core::iter::empty::<Sos<f64>>().sum::<Sos<f64>>().eval(x).norm() == 0.0);
+    }
+}
@@ -1,27 +1,41 @@
 use core::ops::Add;
 
-use num::{complex::ComplexFloat, Zero};
-use option_trait::Maybe;
+use array_trait::AsSlice;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+use num_traits::Zero;
 
-use crate::{quantities::{MaybeList, MaybeOwnedList, ProductSequence, Polynomial}, systems::{Sos, Tf}};
+use crate::systems::{Biquad, Sos};
 
-impl<T, B, A, S> Zero for Sos<T, B, A, S>
+impl<T, S> Zero for Sos<T, S>
 where
     T: ComplexFloat,
-    B: Maybe<[T; 3]> + MaybeOwnedList<T>,
-    A: Maybe<[T; 3]> + MaybeOwnedList<T>,
-    S: MaybeList<Tf<T, B, A>>,
-    Polynomial<T, [T; 0]>: Into<Polynomial<T, B>>,
-    Polynomial<T, ()>: Into<Polynomial<T, A>>,
-    ProductSequence<Tf<T, B, A>, [Tf<T, B, A>; 1]>: Into<ProductSequence<Tf<T, B, A>, S>>,
+    S: IntoBulk<Item = Biquad<T>> + AsSlice<Elem = Biquad<T>> + From<[Biquad<T>; 1]>,
     Self: Add<Output = Self>
 {
     fn zero() -> Self
     {
-        Self::zero()
+        Sos::zero()
     }
     fn is_zero(&self) -> bool
     {
         self.is_zero()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test
+{
+    use num_traits::Zero;
+
+    use crate::systems::{Sos, Tf};
+
+    #[test]
+    fn zero()
+    {
+        let h: Sos<f64> = Zero::zero();
+
+        assert!(Zero::is_zero(&h));
+        assert!(!Zero::is_zero(&Sos::new(vec![Tf::new([1.0, 0.0, 0.0], [1.0, 0.5, 0.0])])));
+    }
+}
@@ -1,56 +1,27 @@
 use core::marker::PhantomData;
 
-use num::complex::ComplexFloat;
-use option_trait::Maybe;
-
-use crate::{quantities::Matrix};
-
-pub trait SsAMatrix<T, B: Matrix<T>, C: Matrix<T>, D: Matrix<T>>: Matrix<T> {}
-impl<T, A, B, C, D, N> SsAMatrix<T, B, C, D> for A
-where
-    N: Maybe<usize>,
-    A: Matrix<T, Height = N, Width = N>,
-    B: Matrix<T, Height = A::Height, Width = D::Width>,
-    C: Matrix<T, Height = D::Height, Width = A::Width>,
-    D: Matrix<T>
-{}
-pub trait SsBMatrix<T, A: Matrix<T>, C: Matrix<T>, D: Matrix<T>>: Matrix<T> {}
-impl<T, A, B, C, D, N> SsBMatrix<T, A, C, D> for B
-where
-    N: Maybe<usize>,
-    A: Matrix<T, Height = N, Width = N>,
-    B: Matrix<T, Height = A::Height, Width = D::Width>,
-    C: Matrix<T, Height = D::Height, Width = A::Width>,
-    D: Matrix<T>
-{}
-pub trait SsCMatrix<T, A: Matrix<T>, B: Matrix<T>, D: Matrix<T>>: Matrix<T> {}
-impl<T, A, B, C, D, N> SsCMatrix<T, A, B, D> for C
-where
-    N: Maybe<usize>,
-    A: Matrix<T, Height = N, Width = N>,
-    B: Matrix<T, Height = A::Height, Width = D::Width>,
-    C: Matrix<T, Height = D::Height, Width = A::Width>,
-    D: Matrix<T>
-{}
-pub trait SsDMatrix<T, A: Matrix<T>, B: Matrix<T>, C: Matrix<T>>: Matrix<T> {}
-impl<T, A, B, C, D, N> SsDMatrix<T, A, B, C> for D
-where
-    N: Maybe<usize>,
-    A: Matrix<T, Height = N, Width = N>,
-    B: Matrix<T, Height = A::Height, Width = D::Width>,
-    C: Matrix<T, Height = D::Height, Width = A::Width>,
-    D: Matrix<T>
-{}
-
-
+use num_complex::ComplexFloat;
+use num_traits::NumCast;
+
+use crate::{Matrix, System, systems::{Tf, tf}};
+
+/// A system in state-space form.
+///
+/// ```txt
+/// x' = Ax + Bu
+/// y  = Cx + Du
+/// ```
+///
+/// With `n` states, `p` inputs and `q` outputs, `A` is `n×n`, `B` is `n×p`, `C` is `q×n` and `D` is `q×p`.
+/// The matrices may be nested arrays or nested vectors.
 #[derive(Debug, Clone, Copy)]
-pub struct Ss<T, A, B, C, D>
+pub struct Ss<T, A = Vec<Vec<T>>, B = Vec<Vec<T>>, C = Vec<Vec<T>>, D = Vec<Vec<T>>>
 where
     T: ComplexFloat,
-    A: SsAMatrix<T, B, C, D>,
-    B: SsBMatrix<T, A, C, D>,
-    C: SsCMatrix<T, A, B, D>,
-    D: SsDMatrix<T, A, B, C>
+    A: Matrix<T>,
+    B: Matrix<T>,
+    C: Matrix<T>,
+    D: Matrix<T>
 {
     pub a: A,
     pub b: B,
@@ -62,64 +33,23 @@ where
 impl<T, A, B, C, D> Ss<T, A, B, C, D>
 where
     T: ComplexFloat,
-    A: SsAMatrix<T, B, C, D>,
-    B: SsBMatrix<T, A, C, D>,
-    C: SsCMatrix<T, A, B, D>,
-    D: SsDMatrix<T, A, B, C>
+    A: Matrix<T>,
+    B: Matrix<T>,
+    C: Matrix<T>,
+    D: Matrix<T>
 {
-    pub type View<'a> = Ss<T, A::View<'a>, B::View<'a>, C::View<'a>, D::View<'a>>
-    where
-        A: 'a,
-        B: 'a,
-        C: 'a,
-        D: 'a,
-        A::View<'a>: SsAMatrix<T, B::View<'a>, C::View<'a>, D::View<'a>>,
-        B::View<'a>: SsBMatrix<T, A::View<'a>, C::View<'a>, D::View<'a>>,
-        C::View<'a>: SsCMatrix<T, A::View<'a>, B::View<'a>, D::View<'a>>,
-        D::View<'a>: SsDMatrix<T, A::View<'a>, B::View<'a>, C::View<'a>>;
-    pub type Owned = Ss<T, A::Owned, B::Owned, C::Owned, D::Owned>
-    where
-        A::Owned: SsAMatrix<T, B::Owned, C::Owned, D::Owned>,
-        B::Owned: SsBMatrix<T, A::Owned, C::Owned, D::Owned>,
-        C::Owned: SsCMatrix<T, A::Owned, B::Owned, D::Owned>,
-        D::Owned: SsDMatrix<T, A::Owned, B::Owned, C::Owned>;
-
-    pub fn as_view<'a>(&'a self) -> Ss<T, A::View<'a>, B::View<'a>, C::View<'a>, D::View<'a>>
-    where
-        A: 'a,
-        B: 'a,
-        C: 'a,
-        D: 'a,
-        A::View<'a>: SsAMatrix<T, B::View<'a>, C::View<'a>, D::View<'a>>,
-        B::View<'a>: SsBMatrix<T, A::View<'a>, C::View<'a>, D::View<'a>>,
-        C::View<'a>: SsCMatrix<T, A::View<'a>, B::View<'a>, D::View<'a>>,
-        D::View<'a>: SsDMatrix<T, A::View<'a>, B::View<'a>, C::View<'a>>
-    {
-        Ss::new(self.a.as_view(), self.b.as_view(), self.c.as_view(), self.d.as_view())
-    }
-    pub fn to_owned(&self) -> Ss<T, A::Owned, B::Owned, C::Owned, D::Owned>
-    where
-        T: Clone,
-        A::Owned: SsAMatrix<T, B::Owned, C::Owned, D::Owned>,
-        B::Owned: SsBMatrix<T, A::Owned, C::Owned, D::Owned>,
-        C::Owned: SsCMatrix<T, A::Owned, B::Owned, D::Owned>,
-        D::Owned: SsDMatrix<T, A::Owned, B::Owned, C::Owned>
-    {
-        Ss::new(self.a.to_owned(), self.b.to_owned(), self.c.to_owned(), self.d.to_owned())
-    }
-    pub fn into_owned(self) -> Ss<T, A::Owned, B::Owned, C::Owned, D::Owned>
-    where
-        T: Clone,
-        A::Owned: SsAMatrix<T, B::Owned, C::Owned, D::Owned>,
-        B::Owned: SsBMatrix<T, A::Owned, C::Owned, D::Owned>,
-        C::Owned: SsCMatrix<T, A::Owned, B::Owned, D::Owned>,
-        D::Owned: SsDMatrix<T, A::Owned, B::Owned, C::Owned>
-    {
-        Ss::new(self.a.into_owned(), self.b.into_owned(), self.c.into_owned(), self.d.into_owned())
-    }
-
+    /// # Panics
+    ///
+    /// If the dimensions of the matrices don't agree.
     pub fn new(a: A, b: B, c: C, d: D) -> Self
     {
+        let n = a.height();
+        assert_eq!(a.width(), n, "A must be square");
+        assert_eq!(b.height(), n, "B must have as many rows as A");
+        assert_eq!(c.width(), n, "C must have as many columns as A");
+        assert_eq!(d.height(), c.height(), "D must have as many rows as C");
+        assert_eq!(d.width(), b.width(), "D must have as many columns as B");
+
         Self {
             a,
             b,
@@ -129,6 +59,77 @@ where
         }
     }
 
+    /// The number of states.
+    pub fn order(&self) -> usize
+    {
+        self.a.height()
+    }
+
+    /// The number of inputs.
+    pub fn inputs(&self) -> usize
+    {
+        self.b.width()
+    }
+
+    /// The number of outputs.
+    pub fn outputs(&self) -> usize
+    {
+        self.c.height()
+    }
+
+    /// Finds the transfer function from the given input to the given output.
+    ///
+    /// The characteristic polynomial and the adjugate of `sI - A` are found with the Faddeev-LeVerrier algorithm.
+    #[doc(alias = "ss2tf")]
+    pub fn to_tf(&self, input: usize, output: usize) -> Tf<T, Vec<T>, Vec<T>>
+    {
+        let n = self.order();
+        let a = |i, j| self.a.get(i, j);
+
+        // a(s) = Σ cₖsᵏ, adj(sI - A) = Σ Mₖsⁿ⁻ᵏ
+        let mut c = vec![T::zero(); n + 1];
+        c[n] = T::one();
+        let mut m = vec![vec![T::zero(); n]; n];
+        let mut b = vec![T::zero(); n + 1];
+        for k in 1..=n
+        {
+            // Mₖ = AMₖ₋₁ + cₙ₋ₖ₊₁I
+            m = (0..n).map(|i| (0..n).map(|j| {
+                    let am = (0..n).map(|l| a(i, l)*m[l][j])
+                        .fold(T::zero(), |s, x| s + x);
+                    if i == j {am + c[n - k + 1]} else {am}
+                }).collect()
+            ).collect();
+
+            let tr = (0..n).map(|i| (0..n).map(|l| a(i, l)*m[l][i])
+                    .fold(T::zero(), |s, x| s + x)
+                ).fold(T::zero(), |s, x| s + x);
+            c[n - k] = -tr/<T as NumCast>::from(k).unwrap();
+
+            b[n - k] = (0..n).map(|i| (0..n).map(|j| self.c.get(output, i)*m[i][j]*self.b.get(j, input))
+                    .fold(T::zero(), |s, x| s + x)
+                ).fold(T::zero(), |s, x| s + x);
+        }
+
+        let d = self.d.get(output, input);
+        let b = c.iter()
+            .zip(b)
+            .map(|(&c, b)| b + d*c)
+            .collect::<Vec<_>>();
+
+        Tf::new(tf::trim(&b).to_vec(), c)
+    }
+}
+
+impl<T, A, B, C, D> System for Ss<T, A, B, C, D>
+where
+    T: ComplexFloat,
+    A: Matrix<T>,
+    B: Matrix<T>,
+    C: Matrix<T>,
+    D: Matrix<T>
+{
+    type Set = T;
 }
 
 #[allow(unused)]
@@ -152,18 +153,18 @@ pub macro ss {
         {
             s!($s);
 
-            const N: usize = [$($([$($({let _ = $am; ()}),*)?]),*)?].len();
-            const P: usize = [$($([$($({let _ = $dm; ()}),*)?]),*)?][0].len();
-            const Q: usize = [$($([$($({let _ = $dm; ()}),*)?]),*)?].len();
+            const N: usize = [$($([$($({let _ = $am;}),*)?]),*)?].len();
+            const P: usize = [$($([$($({let _ = $dm;}),*)?]),*)?][0].len();
+            const Q: usize = [$($([$($({let _ = $dm;}),*)?]),*)?].len();
 
             #[allow(non_snake_case)]
-            let $a : [[$t; N]; N] = [$($([$($(<$t as num::NumCast>::from($am).unwrap()),*)?]),*)?];
+            let $a : [[$t; N]; N] = [$($([$($(<$t as num_traits::NumCast>::from($am).unwrap()),*)?]),*)?];
             #[allow(non_snake_case)]
-            let $b : [[$t; P]; N] = [$($([$($(<$t as num::NumCast>::from($bm).unwrap()),*)?]),*)?];
+            let $b : [[$t; P]; N] = [$($([$($(<$t as num_traits::NumCast>::from($bm).unwrap()),*)?]),*)?];
             #[allow(non_snake_case)]
-            let $c : [[$t; N]; Q] = [$($([$($(<$t as num::NumCast>::from($cm).unwrap()),*)?]),*)?];
+            let $c : [[$t; N]; Q] = [$($([$($(<$t as num_traits::NumCast>::from($cm).unwrap()),*)?]),*)?];
             #[allow(non_snake_case)]
-            let $d : [[$t; P]; Q] = [$($([$($(<$t as num::NumCast>::from($dm).unwrap()),*)?]),*)?];
+            let $d : [[$t; P]; Q] = [$($([$($(<$t as num_traits::NumCast>::from($dm).unwrap()),*)?]),*)?];
             abcd!($a, $b, $c, $d);
             Ss::<$t, [[$t; N]; N], [[$t; P]; N], [[$t; N]; Q], [[$t; P]; Q]>::new($a, $b, $c, $d)
         }
@@ -176,7 +177,33 @@ mod test
     use crate::systems::ss;
 
     #[test]
-    fn test()
+    fn to_tf()
+    {
+        // 1/(s² + 3s + 2)
+        let h = ss!(f64[s]
+            let A = [
+                [0, 1],
+                [-2, -3]
+            ],
+            let B = [
+                [0],
+                [1]
+            ],
+            let C = [
+                [1, 0]
+            ],
+            let D = [
+                [0]
+            ]
+        );
+        let tf = h.to_tf(0, 0);
+
+        assert_eq!(tf.b.into_inner(), vec![1.0]);
+        assert_eq!(tf.a.into_inner(), vec![2.0, 3.0, 1.0]);
+    }
+
+    #[test]
+    fn feedthrough()
     {
         let h = ss!(f64[z]
             let A = [
@@ -194,7 +221,9 @@ mod test
                 [0.2929]
             ]
         );
+        let tf = h.to_tf(0, 0);
 
-        println!("{:?}", h);
+        // The leading coefficient of the numerator is D, since the denominator is monic.
+        assert!((tf.b.into_inner()[2] - 0.2929).abs() < 1e-12);
     }
 }
@@ -1,8 +1,12 @@
-use core::ops::{Add, Div, Mul, Neg, Sub};
+use core::{marker::PhantomData, ops::{Add, Div, Mul, Neg, Sub}};
 
-use num::{complex::ComplexFloat, traits::Inv};
+use array_trait::AsSlice;
+use bulks::{Bulk, IntoBulk};
+use num_complex::ComplexFloat;
+use num_traits::{Inv, Zero};
+use signal_processing_polynomial::{IntoOwned, Polynomial};
 
-use crate::quantities::{MaybeList, MaybeLists, Polynomial};
+use crate::{System, systems::{ComplexZpk, Zpk}};
 
 moddef::moddef!(
     mod {
@@ -19,277 +23,376 @@ moddef::moddef!(
         sub,
         sum,
         zero
+    },
+    flat(pub) mod {
+        numerator
     }
 );
 
+/// A transfer function with the coefficients of `B` and its denominator copied into vectors.
+pub type OwnedTf<T, B> = Tf<T, Vec<<<B as IntoIterator>::Item as Numerator<T>>::Owned>, Vec<T>>;
+
+/// A transfer function, given as the ratio of a numerator polynomial `b` and a denominator polynomial `a`.
+///
+/// Like [Polynomial], the coefficients are stored in order of ascending powers of the system's variable (`s` or `z`).
+///
+/// The coefficient lists may be arrays or vectors. Operations between systems with fixed-size coefficients yield fixed-size coefficients.
+/// A system with several outputs sharing a denominator has one list of numerator coefficients per output. See [Numerator].
 #[derive(Debug, Clone, Copy)]
-pub struct Tf<T: ComplexFloat, B: MaybeLists<T> = (), A: MaybeList<T> = ()>
+pub struct Tf<T, B = Vec<T>, A = Vec<T>>
+where
+    T: ComplexFloat,
+    B: IntoBulk<Item: Numerator<T>>,
+    A: IntoBulk<Item: Coefficient<T>>
 {
-    pub b: Polynomial<T, B>,
-    pub a: Polynomial<T, A>
+    pub b: Polynomial<B>,
+    pub a: Polynomial<A>,
+    phantom: PhantomData<T>
 }
 
-impl<T: ComplexFloat, B: MaybeLists<T>, A: MaybeList<T>> Tf<T, B, A>
+impl<T, B, A> Tf<T, B, A>
+where
+    T: ComplexFloat,
+    B: IntoBulk<Item: Numerator<T>>,
+    A: IntoBulk<Item: Coefficient<T>>
 {
-    pub type View<'a> = Tf<T, B::View<'a>, A::View<'a>>
-    where
-        B::View<'a>: MaybeLists<T>,
-        A::View<'a>: MaybeList<T>,
-        B: 'a,
-        A: 'a;
-    pub type Owned = Tf<T, B::Owned, A::Owned>
+    pub fn new(b: B, a: A) -> Self
+    {
+        Self {
+            b: Polynomial::new(b),
+            a: Polynomial::new(a),
+            phantom: PhantomData
+        }
+    }
+
+    /// Borrows the coefficients of the system.
+    pub fn as_view<'a>(&'a self) -> Tf<T, &'a [B::Elem], &'a [T]>
     where
-        B::Owned: MaybeLists<T>,
-        A::Owned: MaybeList<T>;
+        B: AsSlice,
+        A: AsSlice<Elem = T>,
+        &'a B::Elem: Numerator<T>
+    {
+        Tf::new(self.b.as_slice(), self.a.as_slice())
+    }
 
-    pub fn as_view<'a>(&'a self) -> Tf<T, B::View<'a>, A::View<'a>>
+    /// Copies the coefficients of the system into vectors.
+    pub fn to_owned(&self) -> OwnedTf<T, B>
     where
-        B::View<'a>: MaybeLists<T>,
-        A::View<'a>: MaybeList<T>
+        B: Clone,
+        A: Clone
     {
-        Tf {
-            b: self.b.as_view(),
-            a: self.a.as_view()
-        }
+        Tf::new(
+            self.b.clone()
+                .into_inner()
+                .into_bulk()
+                .map(Numerator::into_owned)
+                .collect::<Vec<_>, _>(),
+            self.a.clone()
+                .into_inner()
+                .into_bulk()
+                .map(Numerator::into_owned)
+                .collect::<Vec<_>, _>()
+        )
     }
-    pub fn to_owned(&self) -> Tf<T, B::Owned, A::Owned>
+
+    /// The transfer function to the `i`-th output of a system with several outputs.
+    pub fn output(&self, i: usize) -> Option<Tf<T, Vec<T>, Vec<T>>>
     where
-        B::Owned: MaybeLists<T>,
-        A::Owned: MaybeList<T>
+        B: AsSlice<Elem: AsSlice<Elem = T>>,
+        A: AsSlice<Elem = T>
     {
-        Tf {
-            b: self.b.to_owned(),
-            a: self.a.to_owned()
-        }
+        self.b.as_slice()
+            .get(i)
+            .map(|b| Tf::new(b.as_slice().to_vec(), self.a.as_slice().to_vec()))
     }
-    pub fn into_owned(self) -> Tf<T, B::Owned, A::Owned>
+}
+
+impl<T, B, A> Tf<T, B, A>
+where
+    T: ComplexFloat,
+    B: IntoBulk<Item = T>,
+    A: IntoBulk<Item = T>
+{
+    pub fn into_owned(self) -> Tf<T, <B::IntoBulk as IntoOwned>::Owned, <A::IntoBulk as IntoOwned>::Owned>
     where
-        B::Owned: MaybeLists<T>,
-        A::Owned: MaybeList<T>
+        B::IntoBulk: IntoOwned,
+        A::IntoBulk: IntoOwned
     {
         Tf {
             b: self.b.into_owned(),
-            a: self.a.into_owned()
-        }
-    }
-    pub fn new(b: B, a: A) -> Self
-    {
-        Self {
-            b: Polynomial::new(b),
-            a: Polynomial::new(a)
+            a: self.a.into_owned(),
+            phantom: PhantomData
         }
     }
+
     pub fn one() -> Self
     where
-        Self: Default,
+        Self: Default
     {
         Tf::default()
     }
+
     pub fn zero() -> Self
     where
-        Polynomial<T, [T; 0]>: Into<Polynomial<T, B>>,
-        Polynomial<T, ()>: Into<Polynomial<T, A>>
+        B: From<[T; 0]>,
+        A: From<[T; 1]>
     {
-        Tf {
-            b: Polynomial::new([]).into(),
-            a: Polynomial::new(()).into()
-        }
+        Tf::new(B::from([]), A::from([T::one()]))
     }
-    pub fn is_one(&self) -> bool
+
+    /// The Laplace variable `s`.
+    pub fn s() -> Self
+    where
+        B: From<[T; 2]>,
+        A: From<[T; 1]>
+    {
+        Tf::new(B::from([T::zero(), T::one()]), A::from([T::one()]))
+    }
+
+    /// The Z-transform variable `z`.
+    pub fn z() -> Self
     where
-        B: MaybeList<T>
+        B: From<[T; 2]>,
+        A: From<[T; 1]>
     {
-        !self.b.is_zero() && !self.a.is_zero() && self.a == self.b
+        Tf::new(B::from([T::zero(), T::one()]), A::from([T::one()]))
     }
+
     pub fn is_zero(&self) -> bool
     where
-        B: MaybeLists<T>
+        B: AsSlice<Elem = T>,
+        A: AsSlice<Elem = T>
     {
-        self.b.is_zero() && !self.a.is_zero()
+        self.b.as_slice().iter().all(Zero::is_zero) && !self.a.as_slice().iter().all(Zero::is_zero)
     }
-    pub fn s() -> Self
+
+    pub fn is_one(&self) -> bool
     where
-        for<'a> &'a Tf<T, [T; 2], ()>: Into<Self>
+        B: AsSlice<Elem = T>,
+        A: AsSlice<Elem = T>
     {
-        (&Tf {
-            b: Polynomial::new([T::one(), T::zero()]),
-            a: Polynomial::new(())
-        }).into()
+        let b = trim(self.b.as_slice());
+        let a = trim(self.a.as_slice());
+        !a.is_empty() && a == b
     }
-    pub fn z() -> Self
+
+    /// Keeps the `N` and `M` coefficients of the lowest powers of the numerator and denominator, padding with zeros.
+    pub fn truncate<const N: usize, const M: usize>(&self) -> Tf<T, [T; N], [T; M]>
     where
-        for<'a> &'a Tf<T, [T; 2], ()>: Into<Self>
+        B: AsSlice<Elem = T>,
+        A: AsSlice<Elem = T>
     {
-        (&Tf {
-            b: Polynomial::new([T::one(), T::zero()]),
-            a: Polynomial::new(())
-        }).into()
+        let b = self.b.as_slice();
+        let a = self.a.as_slice();
+        Tf::new(
+            core::array::from_fn(|i| b.get(i).copied().unwrap_or_else(T::zero)),
+            core::array::from_fn(|i| a.get(i).copied().unwrap_or_else(T::zero))
+        )
     }
-    pub fn truncate<'a, const N: usize, const M: usize>(&'a self) -> Tf<T, [T; N], [T; M]>
+
+    /// Evaluates the transfer function at the point `x`.
+    pub fn eval<X>(&self, x: X) -> X
     where
-        B::View<'a>: MaybeLists<T>,
-        A::View<'a>: MaybeLists<T>,
-        Polynomial<T, B::View<'a>>: Into<Polynomial<T, Vec<T>>>,
-        Polynomial<T, A::View<'a>>: Into<Polynomial<T, Vec<T>>>
+        B: AsSlice<Elem = T>,
+        A: AsSlice<Elem = T>,
+        X: Mul<Output = X> + Add<T, Output = X> + Div<Output = X> + Zero + Copy
     {
-        Tf {
-            b: self.b.as_view().truncate(),
-            a: self.a.as_view().truncate()
-        }
+        self.b.eval(x)/self.a.eval(x)
+    }
+
+    /// Finds the zeros, poles and gain of the transfer function.
+    ///
+    /// The gain is the ratio of the highest-power non-zero coefficients of the numerator and denominator.
+    #[doc(alias = "tf2zpk")]
+    pub fn to_zpk(&self) -> ComplexZpk<T>
+    where
+        B: AsSlice<Elem = T>,
+        A: AsSlice<Elem = T>
+    {
+        let b = trim(self.b.as_slice());
+        let a = trim(self.a.as_slice());
+
+        let (Some(&bn), Some(&an)) = (b.last(), a.last())
+        else
+        {
+            return Zpk::new(vec![], vec![], T::zero())
+        };
+
+        Zpk::new(
+            Polynomial::new(b.to_vec()).roots(),
+            Polynomial::new(a.to_vec()).roots(),
+            bn/an
+        )
     }
 }
 
-macro_rules! impl_op1_extra {
+/// Strips zero coefficients of the highest powers.
+pub(crate) fn trim<T>(c: &[T]) -> &[T]
+where
+    T: Zero
+{
+    let n = c.iter()
+        .rposition(|c| !c.is_zero())
+        .map(|n| n + 1)
+        .unwrap_or(0);
+    &c[..n]
+}
+
+/// Adds the coefficients of two polynomials of possibly different lengths.
+pub(crate) fn add_coeffs<T>(lhs: &[T], rhs: &[T]) -> Vec<T>
+where
+    T: Add<Output = T> + Zero + Copy
+{
+    (0..lhs.len().max(rhs.len())).map(|i| {
+        lhs.get(i).copied().unwrap_or_else(T::zero) + rhs.get(i).copied().unwrap_or_else(T::zero)
+    }).collect()
+}
+
+impl<T, B, A> System for Tf<T, B, A>
+where
+    T: ComplexFloat,
+    B: IntoBulk<Item: Numerator<T>>,
+    A: IntoBulk<Item: Coefficient<T>>
+{
+    type Set = T;
+}
+
+macro_rules! impl_op_scalar {
     ($t:ident :: $f:tt) => {
-        impl<'a, T, B, A, O> $t for &'a Tf<T, B, A>
+        impl<T, B, A, O> $t<T> for Tf<T, B, A>
         where
             T: ComplexFloat,
-            B: MaybeLists<T>,
-            A: MaybeList<T>,
-            B::View<'a>: MaybeLists<T>,
-            A::View<'a>: MaybeList<T>,
-            Tf<T, B::View<'a>, A::View<'a>>: $t<Output = O>
+            B: IntoBulk<Item: Numerator<T>>,
+            A: IntoBulk<Item: Coefficient<T>>,
+            Self: $t<Tf<T, [T; 1], [T; 1]>, Output = O>
         {
             type Output = O;
 
-            fn $f(self) -> Self::Output
+            fn $f(self, rhs: T) -> Self::Output
             {
-                self.as_view().$f()
+                self.$f(Tf::new([rhs], [T::one()]))
             }
         }
     };
 }
-impl_op1_extra!(Neg::neg);
-impl_op1_extra!(Inv::inv);
+impl_op_scalar!(Add::add);
+impl_op_scalar!(Sub::sub);
+impl_op_scalar!(Mul::mul);
+impl_op_scalar!(Div::div);
 
-macro_rules! impl_op2_extra {
+macro_rules! impl_op1_extra {
     ($t:ident :: $f:tt) => {
-        impl<'a, T1, B1, A1, T2, B2, A2, O> $t<Tf<T2, B2, A2>> for &'a Tf<T1, B1, A1>
+        impl<'a, T, B, A, O> $t for &'a Tf<T, B, A>
         where
-            T1: ComplexFloat,
-            T2: ComplexFloat,
-            B1: MaybeLists<T1>,
-            A1: MaybeList<T1>,
-            B2: MaybeLists<T2>,
-            A2: MaybeList<T2>,
-            B1::View<'a>: MaybeLists<T1>,
-            A1::View<'a>: MaybeList<T1>,
-            Tf<T1, B1::View<'a>, A1::View<'a>>: $t<Tf<T2, B2, A2>, Output = O>
+            T: ComplexFloat,
+            B: IntoBulk<Item: Numerator<T>>,
+            A: IntoBulk<Item: Coefficient<T>>,
+            Tf<T, B, A>: Clone + $t<Output = O>
         {
             type Output = O;
 
-            fn $f(self, rhs: Tf<T2, B2, A2>) -> Self::Output
+            fn $f(self) -> Self::Output
             {
-                self.as_view().$f(rhs)
+                self.clone().$f()
             }
         }
-        impl<'b, T1, B1, A1, T2, B2, A2, O> $t<&'b Tf<T2, B2, A2>> for Tf<T1, B1, A1>
+    };
+}
+impl_op1_extra!(Neg::neg);
+impl_op1_extra!(Inv::inv);
+
+macro_rules! impl_op2_extra {
+    ($t:ident :: $f:tt) => {
+        impl<'a, T, B1, A1, B2, A2, O> $t<Tf<T, B2, A2>> for &'a Tf<T, B1, A1>
         where
-            T1: ComplexFloat,
-            T2: ComplexFloat,
-            B1: MaybeLists<T1>,
-            A1: MaybeList<T1>,
-            B2: MaybeLists<T2>,
-            A2: MaybeList<T2>,
-            B2::View<'b>: MaybeLists<T2>,
-            A2::View<'b>: MaybeList<T2>,
-            Tf<T1, B1, A1>: $t<Tf<T2, B2::View<'b>, A2::View<'b>>, Output = O>
+            T: ComplexFloat,
+            B1: IntoBulk<Item: Numerator<T>>,
+            A1: IntoBulk<Item: Coefficient<T>>,
+            B2: IntoBulk<Item: Numerator<T>>,
+            A2: IntoBulk<Item: Coefficient<T>>,
+            Tf<T, B1, A1>: Clone + $t<Tf<T, B2, A2>, Output = O>
         {
             type Output = O;
 
-            fn $f(self, rhs: &'b Tf<T2, B2, A2>) -> Self::Output
+            fn $f(self, rhs: Tf<T, B2, A2>) -> Self::Output
             {
-                self.$f(rhs.as_view())
+                self.clone().$f(rhs)
             }
         }
-        impl<'a, 'b, T1, B1, A1, T2, B2, A2, O> $t<&'b Tf<T2, B2, A2>> for &'a Tf<T1, B1, A1>
+        impl<'b, T, B1, A1, B2, A2, O> $t<&'b Tf<T, B2, A2>> for Tf<T, B1, A1>
         where
-            T1: ComplexFloat,
-            T2: ComplexFloat,
-            B1: MaybeLists<T1>,
-            A1: MaybeList<T1>,
-            B2: MaybeLists<T2>,
-            A2: MaybeList<T2>,
-            B1::View<'a>: MaybeLists<T1>,
-            A1::View<'a>: MaybeList<T1>,
-            B2::View<'b>: MaybeLists<T2>,
-            A2::View<'b>: MaybeList<T2>,
-            Tf<T1, B1::View<'a>, A1::View<'a>>: $t<Tf<T2, B2::View<'b>, A2::View<'b>>, Output = O>
+            T: ComplexFloat,
+            B1: IntoBulk<Item: Numerator<T>>,
+            A1: IntoBulk<Item: Coefficient<T>>,
+            B2: IntoBulk<Item: Numerator<T>>,
+            A2: IntoBulk<Item: Coefficient<T>>,
+            Tf<T, B2, A2>: Clone,
+            Self: $t<Tf<T, B2, A2>, Output = O>
         {
             type Output = O;
 
-            fn $f(self, rhs: &'b Tf<T2, B2, A2>) -> Self::Output
+            fn $f(self, rhs: &'b Tf<T, B2, A2>) -> Self::Output
             {
-                self.as_view().$f(rhs.as_view())
+                self.$f(rhs.clone())
             }
         }
-
-        impl<T, B, A, O> $t<T> for Tf<T, B, A>
+        impl<'a, 'b, T, B1, A1, B2, A2, O> $t<&'b Tf<T, B2, A2>> for &'a Tf<T, B1, A1>
         where
             T: ComplexFloat,
-            B: MaybeLists<T>,
-            A: MaybeList<T>,
-            Self: $t<Tf<T, [T; 1], ()>, Output = O>
+            B1: IntoBulk<Item: Numerator<T>>,
+            A1: IntoBulk<Item: Coefficient<T>>,
+            B2: IntoBulk<Item: Numerator<T>>,
+            A2: IntoBulk<Item: Coefficient<T>>,
+            Tf<T, B1, A1>: Clone + $t<Tf<T, B2, A2>, Output = O>,
+            Tf<T, B2, A2>: Clone
         {
             type Output = O;
 
-            fn $f(self, rhs: T) -> Self::Output
+            fn $f(self, rhs: &'b Tf<T, B2, A2>) -> Self::Output
             {
-                self.$f(Tf {
-                    b: Polynomial::new([rhs]),
-                    a: Polynomial::new(())
-                })
+                self.clone().$f(rhs.clone())
             }
         }
         impl<'a, T, B, A, O> $t<T> for &'a Tf<T, B, A>
         where
             T: ComplexFloat,
-            B: MaybeLists<T>,
-            A: MaybeList<T>,
-            Self: $t<Tf<T, [T; 1], ()>, Output = O>
+            B: IntoBulk<Item: Numerator<T>>,
+            A: IntoBulk<Item: Coefficient<T>>,
+            Tf<T, B, A>: Clone + $t<T, Output = O>
         {
             type Output = O;
 
             fn $f(self, rhs: T) -> Self::Output
             {
-                self.$f(Tf {
-                    b: Polynomial::new([rhs]),
-                    a: Polynomial::new(())
-                })
+                self.clone().$f(rhs)
             }
         }
         impl<'b, T, B, A, O> $t<&'b T> for Tf<T, B, A>
         where
             T: ComplexFloat,
-            B: MaybeLists<T>,
-            A: MaybeList<T>,
-            Self: $t<Tf<T, &'b [T; 1], ()>, Output = O>
+            B: IntoBulk<Item: Numerator<T>>,
+            A: IntoBulk<Item: Coefficient<T>>,
+            Self: $t<T, Output = O>
         {
             type Output = O;
 
             fn $f(self, rhs: &'b T) -> Self::Output
             {
-                self.$f(Tf {
-                    b: Polynomial::new(core::array::from_ref(rhs)),
-                    a: Polynomial::new(())
-                })
+                self.$f(*rhs)
             }
         }
         impl<'a, 'b, T, B, A, O> $t<&'b T> for &'a Tf<T, B, A>
         where
             T: ComplexFloat,
-            B: MaybeLists<T>,
-            A: MaybeList<T>,
-            Self: $t<Tf<T, &'b [T; 1], ()>, Output = O>
+            B: IntoBulk<Item: Numerator<T>>,
+            A: IntoBulk<Item: Coefficient<T>>,
+            Tf<T, B, A>: Clone + $t<T, Output = O>
         {
             type Output = O;
 
             fn $f(self, rhs: &'b T) -> Self::Output
             {
-                self.$f(Tf {
-                    b: Polynomial::new(core::array::from_ref(rhs)),
-                    a: Polynomial::new(())
-                })
+                self.clone().$f(*rhs)
             }
         }
     };
@@ -307,73 +410,82 @@ macro s {
 pub macro tf {
     ($t:path[$s:ident]= {$e:expr}) => {
         {
-            Tf::<$t, [$t; 2], ()>::$s();
+            Tf::<$t, [$t; 2], [$t; 1]>::$s();
             s!($s);
             $e
         }
     },
     ($t:path[$s:ident]=) => {
         {
-            Tf::<$t, [$t; 2], ()>::$s();
+            Tf::<$t, [$t; 2], [$t; 1]>::$s();
             s!($s);
-            Tf::<$t, _, _>::new([], ())
+            Tf::<$t, [$t; 0], [$t; 1]>::zero()
         }
     },
     ($t:path[$s:ident]= $c:literal) => {
         {
-            Tf::<$t, [$t; 2], ()>::$s();
+            Tf::<$t, [$t; 2], [$t; 1]>::$s();
             s!($s);
-            Tf::<$t, _, _>::new([<$t as num::NumCast>::from($c).unwrap()], ())
+            Tf::<$t, _, _>::new([<$t as num_traits::NumCast>::from($c).unwrap()], [<$t as num_traits::One>::one()])
         }
     },
     ($t:path[$s:ident]= $c:literal + $ci:literal j) => {
         {
-            Tf::<$t, [$t; 2], ()>::$s();
+            Tf::<$t, [$t; 2], [$t; 1]>::$s();
             s!($s);
-            Tf::<num::Complex<<$t as num::complex::ComplexFloat>::Real>, _, _>::new([num::Complex::new(<$t as num::NumCast>::from($c).unwrap(), <$t as num::NumCast>::from($ci).unwrap())], ())
+            Tf::<num_complex::Complex<<$t as num_complex::ComplexFloat>::Real>, _, _>::new(
+                [num_complex::Complex::new(<$t as num_traits::NumCast>::from($c).unwrap(), <$t as num_traits::NumCast>::from($ci).unwrap())],
+                [<num_complex::Complex<<$t as num_complex::ComplexFloat>::Real> as num_traits::One>::one()]
+            )
         }
     },
     ($t:path[$s:ident]= $c:literal - $ci:literal j) => {
         {
-            Tf::<$t, [$t; 2], ()>::$s();
+            Tf::<$t, [$t; 2], [$t; 1]>::$s();
             s!($s);
-            Tf::<num::Complex<<$t as num::complex::ComplexFloat>::Real>, _, _>::new([num::Complex::new(<$t as num::NumCast>::from($c).unwrap(), -<$t as num::NumCast>::from($ci).unwrap())], ())
+            Tf::<num_complex::Complex<<$t as num_complex::ComplexFloat>::Real>, _, _>::new(
+                [num_complex::Complex::new(<$t as num_traits::NumCast>::from($c).unwrap(), -<$t as num_traits::NumCast>::from($ci).unwrap())],
+                [<num_complex::Complex<<$t as num_complex::ComplexFloat>::Real> as num_traits::One>::one()]
+            )
         }
     },
     ($t:path[$s:ident]= $ci:literal j) => {
         {
-            Tf::<$t, [$t; 2], ()>::$s();
+            Tf::<$t, [$t; 2], [$t; 1]>::$s();
             s!($s);
-            Tf::<num::Complex<<$t as num::complex::ComplexFloat>::Real>, _, _>::new([num::Complex::new(<$t as num::NumCast>::from(0).unwrap(), <$t as num::NumCast>::from($ci).unwrap())], ())
+            Tf::<num_complex::Complex<<$t as num_complex::ComplexFloat>::Real>, _, _>::new(
+                [num_complex::Complex::new(<$t as num_traits::NumCast>::from(0).unwrap(), <$t as num_traits::NumCast>::from($ci).unwrap())],
+                [<num_complex::Complex<<$t as num_complex::ComplexFloat>::Real> as num_traits::One>::one()]
+            )
         }
     },
     ($t:path[$s:ident]= $ss:ident) => {
         {
             #[allow(unused)]
-            let $s: Tf::<$t, [$t; 2], ()> = Tf::<$t, [$t; 2], ()>::$s();
+            let $s: Tf::<$t, [$t; 2], [$t; 1]> = Tf::<$t, [$t; 2], [$t; 1]>::$s();
             s!($s);
             s!($ss);
-            Tf::<$t, [_; 2], ()>::from($ss)
+            Tf::<$t, [_; 2], [_; 1]>::from($ss)
         }
     },
     ($t:path[$s:ident]= $c:literal^$pc:literal) => {
         {
-            Tf::<$t, [$t; 2], ()>::$s();
+            Tf::<$t, [$t; 2], [$t; 1]>::$s();
             s!($s);
-            num::traits::Pow::pow(Tf::<$t, _, _>::new([<$t as num::NumCast>::from($c).unwrap()], ()), $pc)
+            num_traits::Pow::pow(Tf::<$t, _, _>::new([<$t as num_traits::NumCast>::from($c).unwrap()], [<$t as num_traits::One>::one()]), $pc)
         }
     },
     ($t:path[$s:ident]= $ss:ident^$ps:literal) => {
         {
             #[allow(unused)]
-            let $s: Tf::<$t, [$t; 2], ()> = Tf::<$t, [$t; 2], ()>::$s();
+            let $s: Tf::<$t, [$t; 2], [$t; 1]> = Tf::<$t, [$t; 2], [$t; 1]>::$s();
             s!($s);
             s!($ss);
-            num::traits::Pow::pow(Tf::<$t, [_; 2], ()>::from($ss), $ps)
+            num_traits::Pow::pow(Tf::<$t, [_; 2], [_; 1]>::from($ss), $ps)
         }
     },
     ($t:path[$s:ident]= ($($lhs:tt)*)^$lp:literal $($op:tt ($($rhs:tt)*))?) => {
-        num::traits::Pow::pow(tf!($t[$s]= $($lhs)*), $lp)$($op tf!($t[$s]= $($rhs)*))*
+        num_traits::Pow::pow(tf!($t[$s]= $($lhs)*), $lp)$($op tf!($t[$s]= $($rhs)*))*
     },
     ($t:path[$s:ident]= ($($lhs:tt)*) $($op:tt ($($rhs:tt)*))?) => {
         tf!($t[$s]= $($lhs)*)$($op tf!($t[$s]= $($rhs)*))*
@@ -386,14 +498,75 @@ pub macro tf {
 #[cfg(test)]
 mod test
 {
-    use super::tf;
+    use num_complex::Complex;
+
+    use crate::systems::{Tf, tf};
 
     #[test]
-    fn test()
+    fn macro_expr()
     {
         let h = tf!(f64[s] = (((2*(s^-3))^-2) + 1 + 2*(s^2))/(s + 1)*2);
-        let h2 = tf!(f64[s] = {h}*2);
+        let h2 = tf!(f64[s] = {h.clone()}*2);
+
+        let x = Complex::new(0.3, 0.7);
+        let y = ((x.powi(6)/4.0 + 1.0 + 2.0*x*x)/(x + 1.0))*2.0;
+
+        assert!((h.eval(x) - y).norm() < 1e-12);
+        assert!((h2.eval(x) - y*2.0).norm() < 1e-12);
+    }
+
+    #[test]
+    fn to_zpk()
+    {
+        // (s + 2)/(s² + 3s + 2)
+        let h = Tf::new([2.0, 1.0], [2.0, 3.0, 1.0]);
+        let zpk = h.to_zpk();
+
+        assert_eq!(zpk.z.len(), 1);
+        assert!((zpk.z[0] - Complex::new(-2.0, 0.0)).norm() < 1e-12);
+        assert_eq!(zpk.p.len(), 2);
+        assert!((zpk.p[0] - Complex::new(-2.0, 0.0)).norm() < 1e-12);
+        assert!((zpk.p[1] - Complex::new(-1.0, 0.0)).norm() < 1e-12);
+        assert_eq!(zpk.k, 1.0);
+    }
+
+    #[test]
+    fn views()
+    {
+        let h = Tf::new(vec![1.0, 2.0], vec![1.0, 0.5, 0.25]);
+        let v = h.as_view().to_owned();
+
+        assert_eq!(v.b.into_inner(), vec![1.0, 2.0]);
+        assert_eq!(v.a.into_inner(), vec![1.0, 0.5, 0.25]);
+
+        let t = h.truncate::<1, 4>();
+
+        assert_eq!(t.b.into_inner(), [1.0]);
+        assert_eq!(t.a.into_inner(), [1.0, 0.5, 0.25, 0.0]);
+    }
+
+    #[test]
+    fn output()
+    {
+        let h = Tf::new([vec![1.0], vec![2.0, 1.0]], vec![1.0, 1.0]);
+        let h1 = h.output(1).unwrap();
 
-        println!("{:?}", h2);
+        assert_eq!(h1.b.into_inner(), vec![2.0, 1.0]);
+        assert_eq!(h1.a.into_inner(), vec![1.0, 1.0]);
+        assert!(h.output(2).is_none());
+        assert_eq!(h.to_owned().b.into_inner(), vec![vec![1.0], vec![2.0, 1.0]]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn ops_by_ref()
+    {
+        let h1 = Tf::new(vec![1.0, 2.0], vec![1.0, 0.5]);
+        let h2 = Tf::new(vec![0.5], vec![1.0, 1.0]);
+        let x = Complex::new(0.3, 0.7);
+
+        assert!(((&h1*&h2).eval(x) - h1.eval(x)*h2.eval(x)).norm() < 1e-12);
+        assert!(((&h1 + &h2).eval(x) - (h1.eval(x) + h2.eval(x))).norm() < 1e-12);
+        assert!(((-&h1).eval(x) + h1.eval(x)).norm() < 1e-12);
+        assert!(((&h1 + 2.0).eval(x) - (h1.eval(x) + 2.0)).norm() < 1e-12);
+    }
+}
@@ -1,33 +1,52 @@
-use core::ops::Mul;
-use std::ops::Add;
+use core::{marker::PhantomData, ops::{Add, Mul}};
 
-use num::complex::ComplexFloat;
+use bulks::{IntoBulk};
+use num_complex::ComplexFloat;
+use signal_processing_polynomial::{IntoOwned, Polynomial};
 
-use crate::{quantities::{MaybeList, MaybeLists, Polynomial}, operations::Simplify, systems::Tf};
+use crate::systems::Tf;
 
-impl<T1, B1, A1, T2, B2, A2, T3, B3, A3> Add<Tf<T2, B2, A2>> for Tf<T1, B1, A1>
+impl<T, B1, A1, B2, A2, BA, AB, B, A> Add<Tf<T, B2, A2>> for Tf<T, B1, A1>
 where
-    T1: ComplexFloat,
-    T2: ComplexFloat,
-    T3: ComplexFloat,
-    B1: MaybeLists<T1>,
-    A1: MaybeList<T1> + Clone,
-    B2: MaybeLists<T2>,
-    A2: MaybeList<T2> + Clone,
-    B3: MaybeLists<T3>,
-    A3: MaybeList<T3>,
-    Polynomial<T1, A1>: Mul<Polynomial<T2, A2>, Output = Polynomial<T3, A3>>,
-    Polynomial<T1, B1>: Mul<Polynomial<T2, A2>, Output: Add<<Polynomial<T2, B2> as Mul<Polynomial<T1, A1>>>::Output, Output = Polynomial<T3, B3>>>,
-    Polynomial<T2, B2>: Mul<Polynomial<T1, A1>>,
-    Tf<T3, B3, A3>: Simplify
+    T: ComplexFloat,
+    B1: IntoBulk<Item = T>,
+    A1: IntoBulk<Item = T> + Clone,
+    B2: IntoBulk<Item = T>,
+    A2: IntoBulk<Item = T> + Clone,
+    BA: IntoBulk<Item = T>,
+    AB: IntoBulk<Item = T>,
+    B: IntoBulk<Item = T>,
+    A: IntoBulk<Item = T>,
+    B::IntoBulk: IntoOwned<Item = T>,
+    Polynomial<B1>: Mul<Polynomial<A2>, Output = Polynomial<BA>>,
+    Polynomial<B2>: Mul<Polynomial<A1>, Output = Polynomial<AB>>,
+    Polynomial<BA>: Add<Polynomial<AB>, Output = Polynomial<B>>,
+    Polynomial<A1>: Mul<Polynomial<A2>, Output = Polynomial<A>>
 {
-    type Output = <Tf<T3, B3, A3> as Simplify>::Output;
+    type Output = Tf<T, <B::IntoBulk as IntoOwned>::Owned, A>;
 
-    fn add(self, rhs: Tf<T2, B2, A2>) -> Self::Output
+    fn add(self, rhs: Tf<T, B2, A2>) -> Self::Output
     {
         Tf {
-            b: self.b*rhs.a.clone() + rhs.b*self.a.clone(),
-            a: self.a*rhs.a
-        }.simplify()
+            b: (self.b*rhs.a.clone() + rhs.b*self.a.clone()).into_owned(),
+            a: self.a*rhs.a,
+            phantom: PhantomData
+        }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::systems::Tf;
+
+    #[test]
+    fn add()
+    {
+        // 1/(s + 1) + 1/(s + 2) = (2s + 3)/(s² + 3s + 2)
+        let h = Tf::new([1.0], [1.0, 1.0]) + Tf::new([1.0], [2.0, 1.0]);
+
+        assert_eq!(h.b.into_inner(), [3.0, 2.0]);
+        assert_eq!(h.a.into_inner(), [2.0, 3.0, 1.0]);
+    }
+}
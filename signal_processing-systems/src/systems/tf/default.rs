@@ -1,20 +1,16 @@
-use num::complex::ComplexFloat;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
 
-use crate::{quantities::{MaybeList, MaybeLists, Polynomial}, systems::Tf};
+use crate::systems::Tf;
 
 impl<T, B, A> Default for Tf<T, B, A>
 where
     T: ComplexFloat,
-    B: MaybeLists<T>,
-    A: MaybeList<T>,
-    Polynomial<T, B>: From<Polynomial<T, ()>>,
-    Polynomial<T, A>: From<Polynomial<T, ()>>
+    B: IntoBulk<Item = T> + From<[T; 1]>,
+    A: IntoBulk<Item = T> + From<[T; 1]>
 {
     fn default() -> Self
     {
-        Self {
-            b: Polynomial::new(()).into(),
-            a: Polynomial::new(()).into()
-        }
+        Tf::new(B::from([T::one()]), A::from([T::one()]))
     }
-}
\ No newline at end of file
+}
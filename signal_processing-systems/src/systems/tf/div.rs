@@ -1,32 +1,31 @@
-use core::ops::Div;
-use std::ops::Mul;
+use core::{marker::PhantomData, ops::{Div, Mul}};
 
-use num::complex::ComplexFloat;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+use signal_processing_polynomial::Polynomial;
 
-use crate::{quantities::{MaybeList, MaybeLists, Polynomial}, operations::Simplify, systems::Tf};
+use crate::systems::Tf;
 
-impl<T1, T2, T3, B1, B2, B3, A1, A2, A3> Div<Tf<T2, B2, A2>> for Tf<T1, B1, A1>
+impl<T, B1, A1, B2, A2, B, A> Div<Tf<T, B2, A2>> for Tf<T, B1, A1>
 where
-    T1: ComplexFloat,
-    T2: ComplexFloat,
-    T3: ComplexFloat,
-    B1: MaybeLists<T1>,
-    A1: MaybeList<T1>,
-    B2: MaybeLists<T2>,
-    A2: MaybeList<T2>,
-    B3: MaybeLists<T3>,
-    A3: MaybeList<T3>,
-    Polynomial<T1, B1>: Mul<Polynomial<T2, A2>, Output = Polynomial<T3, B3>>,
-    Polynomial<T1, A1>: Mul<Polynomial<T2, B2>, Output = Polynomial<T3, A3>>,
-    Tf<T3, B3, A3>: Simplify
+    T: ComplexFloat,
+    B1: IntoBulk<Item = T>,
+    A1: IntoBulk<Item = T>,
+    B2: IntoBulk<Item = T>,
+    A2: IntoBulk<Item = T>,
+    B: IntoBulk<Item = T>,
+    A: IntoBulk<Item = T>,
+    Polynomial<B1>: Mul<Polynomial<A2>, Output = Polynomial<B>>,
+    Polynomial<A1>: Mul<Polynomial<B2>, Output = Polynomial<A>>
 {
-    type Output = <Tf<T3, B3, A3> as Simplify>::Output;
+    type Output = Tf<T, B, A>;
 
-    fn div(self, rhs: Tf<T2, B2, A2>) -> Self::Output
+    fn div(self, rhs: Tf<T, B2, A2>) -> Self::Output
     {
         Tf {
             b: self.b*rhs.a,
-            a: self.a*rhs.b
-        }.simplify()
+            a: self.a*rhs.b,
+            phantom: PhantomData
+        }
     }
-}
\ No newline at end of file
+}
@@ -1,26 +1,20 @@
+use core::marker::PhantomData;
 
-use num::complex::ComplexFloat;
+use num_complex::ComplexFloat;
+use signal_processing_polynomial::Polynomial;
 
-use crate::{quantities::{MaybeList, MaybeLists, Polynomial}, systems::Tf};
+use crate::systems::Tf;
 
-impl<'a, T1, B1, A1, T2, B2, A2> From<&'a Tf<T1, B1, A1>> for Tf<T2, B2, A2>
+impl<T, const N: usize, const M: usize> From<Tf<T, [T; N], [T; M]>> for Tf<T, Vec<T>, Vec<T>>
 where
-    T1: ComplexFloat,
-    T2: ComplexFloat,
-    B1: MaybeLists<T1>,
-    A1: MaybeList<T1>,
-    B2: MaybeLists<T2>,
-    A2: MaybeList<T2>,
-    B1::View<'a>: MaybeLists<T1>,
-    A1::View<'a>: MaybeLists<T1>,
-    Polynomial<T1, B1::View<'a>>: Into<Polynomial<T2, B2>>,
-    Polynomial<T1, A1::View<'a>>: Into<Polynomial<T2, A2>>,
+    T: ComplexFloat
 {
-    fn from(tf: &'a Tf<T1, B1, A1>) -> Self
+    fn from(tf: Tf<T, [T; N], [T; M]>) -> Self
     {
         Tf {
-            b: tf.b.as_view().into(),
-            a: tf.a.as_view().into()
+            b: Polynomial::new(tf.b.into_inner().to_vec()),
+            a: Polynomial::new(tf.a.into_inner().to_vec()),
+            phantom: PhantomData
         }
     }
-}
\ No newline at end of file
+}
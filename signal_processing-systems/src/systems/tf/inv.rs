@@ -1,12 +1,16 @@
-use num::{complex::ComplexFloat, traits::Inv};
+use core::marker::PhantomData;
 
-use crate::{quantities::MaybeList, systems::Tf};
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+use num_traits::Inv;
+
+use crate::systems::Tf;
 
 impl<T, B, A> Inv for Tf<T, B, A>
 where
     T: ComplexFloat,
-    B: MaybeList<T>,
-    A: MaybeList<T>
+    B: IntoBulk<Item = T>,
+    A: IntoBulk<Item = T>
 {
     type Output = Tf<T, A, B>;
 
@@ -14,7 +18,8 @@ where
     {
         Tf {
             b: self.a,
-            a: self.b
+            a: self.b,
+            phantom: PhantomData
         }
     }
-}
\ No newline at end of file
+}
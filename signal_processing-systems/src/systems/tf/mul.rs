@@ -1,31 +1,52 @@
-use std::ops::Mul;
+use core::{marker::PhantomData, ops::Mul};
 
-use num::complex::ComplexFloat;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+use signal_processing_polynomial::Polynomial;
 
-use crate::{quantities::{MaybeList, MaybeLists, Polynomial}, operations::Simplify, systems::Tf};
+use crate::systems::Tf;
 
-impl<T1, T2, T3, B1, B2, B3, A1, A2, A3> Mul<Tf<T2, B2, A2>> for Tf<T1, B1, A1>
+impl<T, B1, A1, B2, A2, B, A> Mul<Tf<T, B2, A2>> for Tf<T, B1, A1>
 where
-    T1: ComplexFloat,
-    T2: ComplexFloat,
-    T3: ComplexFloat,
-    B1: MaybeLists<T1>,
-    A1: MaybeList<T1>,
-    B2: MaybeLists<T2>,
-    A2: MaybeList<T2>,
-    B3: MaybeLists<T3>,
-    A3: MaybeList<T3>,
-    Polynomial<T1, B1>: Mul<Polynomial<T2, B2>, Output = Polynomial<T3, B3>>,
-    Polynomial<T1, A1>: Mul<Polynomial<T2, A2>, Output = Polynomial<T3, A3>>,
-    Tf<T3, B3, A3>: Simplify
+    T: ComplexFloat,
+    B1: IntoBulk<Item = T>,
+    A1: IntoBulk<Item = T>,
+    B2: IntoBulk<Item = T>,
+    A2: IntoBulk<Item = T>,
+    B: IntoBulk<Item = T>,
+    A: IntoBulk<Item = T>,
+    Polynomial<B1>: Mul<Polynomial<B2>, Output = Polynomial<B>>,
+    Polynomial<A1>: Mul<Polynomial<A2>, Output = Polynomial<A>>
 {
-    type Output = <Tf<T3, B3, A3> as Simplify>::Output;
+    type Output = Tf<T, B, A>;
 
-    fn mul(self, rhs: Tf<T2, B2, A2>) -> Self::Output
+    fn mul(self, rhs: Tf<T, B2, A2>) -> Self::Output
     {
         Tf {
             b: self.b*rhs.b,
-            a: self.a*rhs.a
-        }.simplify()
+            a: self.a*rhs.a,
+            phantom: PhantomData
+        }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::systems::Tf;
+
+    #[test]
+    fn mul()
+    {
+        let h1 = Tf::new([1.0, 1.0], [2.0, 1.0]);
+        let h2 = Tf::new([3.0], [1.0, 0.0, 1.0]);
+
+        let h = h1*h2;
+        assert_eq!(h.b.into_inner(), [3.0, 3.0]);
+        assert_eq!(h.a.into_inner(), [2.0, 1.0, 2.0, 1.0]);
+
+        let h = h1*Tf::new(vec![3.0], vec![1.0, 0.0, 1.0]);
+        assert_eq!(h.b.into_inner(), vec![3.0, 3.0]);
+        assert_eq!(h.a.into_inner(), vec![2.0, 1.0, 2.0, 1.0]);
+    }
+}
@@ -1,24 +1,28 @@
-use core::ops::Neg;
+use core::{marker::PhantomData, ops::Neg};
 
-use num::complex::ComplexFloat;
+use bulks::{IntoBulk};
+use num_complex::ComplexFloat;
+use signal_processing_polynomial::{IntoOwned, Polynomial};
 
-use crate::{quantities::{MaybeList, MaybeLists, Polynomial}, systems::Tf};
+use crate::systems::Tf;
 
 impl<T, B1, B2, A> Neg for Tf<T, B1, A>
 where
     T: ComplexFloat,
-    B1: MaybeLists<T>,
-    B2: MaybeLists<T>,
-    A: MaybeList<T>,
-    Polynomial<T, B1>: Neg<Output = Polynomial<T, B2>>
+    B1: IntoBulk<Item = T>,
+    B2: IntoBulk<Item = T>,
+    A: IntoBulk<Item = T>,
+    B2::IntoBulk: IntoOwned<Item = T>,
+    Polynomial<B1>: Neg<Output = Polynomial<B2>>
 {
-    type Output = Tf<T, B2, A>;
+    type Output = Tf<T, <B2::IntoBulk as IntoOwned>::Owned, A>;
 
     fn neg(self) -> Self::Output
     {
         Tf {
-            b: -self.b,
-            a: self.a
+            b: (-self.b).into_owned(),
+            a: self.a,
+            phantom: PhantomData
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,111 @@
+use num_complex::ComplexFloat;
+
+/// An element of the numerator of a [Tf](crate::systems::Tf).
+///
+/// For a system with a single output, the elements of the numerator are its coefficients, either owned or borrowed.
+/// For a system with several outputs sharing a denominator, the numerator holds one list of coefficients per output.
+pub trait Numerator<T>
+where
+    T: ComplexFloat
+{
+    /// The element with its coefficients owned.
+    type Owned: Numerator<T>;
+
+    fn into_owned(self) -> Self::Owned;
+}
+
+/// A single coefficient of a [Tf](crate::systems::Tf), either owned or borrowed.
+pub trait Coefficient<T>: Numerator<T, Owned = T>
+where
+    T: ComplexFloat
+{}
+
+impl<T, C> Coefficient<T> for C
+where
+    T: ComplexFloat,
+    C: Numerator<T, Owned = T>
+{}
+
+impl<T> Numerator<T> for T
+where
+    T: ComplexFloat
+{
+    type Owned = T;
+
+    fn into_owned(self) -> Self::Owned
+    {
+        self
+    }
+}
+
+impl<T> Numerator<T> for &T
+where
+    T: ComplexFloat
+{
+    type Owned = T;
+
+    fn into_owned(self) -> Self::Owned
+    {
+        *self
+    }
+}
+
+impl<T, const N: usize> Numerator<T> for [T; N]
+where
+    T: ComplexFloat
+{
+    type Owned = [T; N];
+
+    fn into_owned(self) -> Self::Owned
+    {
+        self
+    }
+}
+
+impl<T, const N: usize> Numerator<T> for &[T; N]
+where
+    T: ComplexFloat
+{
+    type Owned = [T; N];
+
+    fn into_owned(self) -> Self::Owned
+    {
+        *self
+    }
+}
+
+impl<T> Numerator<T> for Vec<T>
+where
+    T: ComplexFloat
+{
+    type Owned = Vec<T>;
+
+    fn into_owned(self) -> Self::Owned
+    {
+        self
+    }
+}
+
+impl<T> Numerator<T> for &Vec<T>
+where
+    T: ComplexFloat
+{
+    type Owned = Vec<T>;
+
+    fn into_owned(self) -> Self::Owned
+    {
+        self.clone()
+    }
+}
+
+impl<T> Numerator<T> for &[T]
+where
+    T: ComplexFloat
+{
+    type Owned = Vec<T>;
+
+    fn into_owned(self) -> Self::Owned
+    {
+        self.to_vec()
+    }
+}
@@ -1,22 +1,25 @@
-use std::ops::Mul;
+use core::ops::Mul;
 
-use num::{complex::ComplexFloat, One};
+use array_trait::AsSlice;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+use num_traits::One;
 
-use crate::{quantities::MaybeList, systems::Tf};
+use crate::systems::Tf;
 
 impl<T, B, A> One for Tf<T, B, A>
 where
     T: ComplexFloat,
-    Self: Mul<Output = Self> + Default,
-    B: MaybeList<T>,
-    A: MaybeList<T>
+    B: IntoBulk<Item = T> + AsSlice<Elem = T>,
+    A: IntoBulk<Item = T> + AsSlice<Elem = T>,
+    Self: Mul<Output = Self> + Default
 {
     fn one() -> Self
     {
-        Self::one()
+        Tf::one()
     }
     fn is_one(&self) -> bool
     {
         self.is_one()
     }
-}
\ No newline at end of file
+}
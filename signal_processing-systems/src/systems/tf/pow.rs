@@ -1,55 +1,61 @@
-use core::ops::{AddAssign, BitAnd, Shr};
+use core::marker::PhantomData;
 
-use num::{complex::ComplexFloat, pow::Pow, traits::Inv, Integer, One};
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+use num_traits::{Pow, PrimInt};
 
-use crate::{quantities::MaybeList, operations::Simplify, systems::Tf, transforms::system::ToTf};
+use crate::systems::Tf;
 
 impl<T, B, A, I> Pow<I> for Tf<T, B, A>
 where
-    T: ComplexFloat + AddAssign,
-    B: MaybeList<T>,
-    A: MaybeList<T>,
-    I: Integer + BitAnd<I, Output = I> + Shr<usize, Output = I> + Copy,
-    Self: ToTf<T, Vec<T>, Vec<T>, (), ()> + Inv<Output: ToTf<T, Vec<T>, Vec<T>, (), ()>>,
-    Tf<T, Vec<T>, Vec<T>>: Simplify<Output = Tf<T, Vec<T>, Vec<T>>>
+    T: ComplexFloat,
+    B: IntoBulk<Item = T>,
+    A: IntoBulk<Item = T>,
+    I: PrimInt,
+    Self: Into<Tf<T, Vec<T>, Vec<T>>>
 {
     type Output = Tf<T, Vec<T>, Vec<T>>;
 
-    fn pow(self, mut n: I) -> Self::Output
+    fn pow(self, n: I) -> Self::Output
     {
-        let mut x = if n < I::zero()
+        let Tf {b, a, ..} = self.into();
+        let (b, a, n) = if n < I::zero()
         {
-            self.inv()
-                .to_tf((), ())
+            (a, b, I::zero() - n)
         }
         else
         {
-            self.to_tf((), ())
+            (b, a, n)
         };
-        let mut r = if (n & I::one()) == I::one()
-        {
-            x.clone()
-        }
-        else
-        {
-            One::one()
-        };
-    
-        let two = I::one() + I::one();
-        loop
-        {
-            n = n/two;
-            if n == I::zero()
-            {
-                break;
-            }
-            x = x.as_view()*x.as_view();
-            if (n & I::one()) == I::one()
-            {
-                r = r*x.as_view();
-            }
+        let n = n.to_usize()
+            .unwrap();
+
+        Tf {
+            b: b.pow(n),
+            a: a.pow(n),
+            phantom: PhantomData
         }
-    
-        r
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test
+{
+    use num_traits::Pow;
+
+    use crate::systems::Tf;
+
+    #[test]
+    fn pow()
+    {
+        let h = Tf::new([1.0, 1.0], [2.0]);
+
+        let h2 = h.pow(2);
+        assert_eq!(h2.b.into_inner(), vec![1.0, 2.0, 1.0]);
+        assert_eq!(h2.a.into_inner(), vec![4.0]);
+
+        let h2 = h.pow(-2);
+        assert_eq!(h2.b.into_inner(), vec![4.0]);
+        assert_eq!(h2.a.into_inner(), vec![1.0, 2.0, 1.0]);
+    }
+}
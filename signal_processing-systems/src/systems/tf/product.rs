@@ -1,24 +1,25 @@
-use std::{iter::Product, ops::Mul};
+use core::iter::Product;
 
-use num::{complex::ComplexFloat, One};
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+use num_traits::One;
 
-use crate::{quantities::{MaybeList, MaybeLists}, systems::Tf, transforms::system::ToTf};
+use crate::systems::Tf;
 
-impl<T1, B1, A1, T2, B2, A2> Product<Tf<T1, B1, A1>> for Tf<T2, B2, A2>
+impl<T, B1, A1, B2, A2> Product<Tf<T, B1, A1>> for Tf<T, B2, A2>
 where
-    T1: ComplexFloat,
-    T2: ComplexFloat,
-    B1: MaybeLists<T1>,
-    A1: MaybeList<T1>,
-    B2: MaybeLists<T2>,
-    A2: MaybeList<T2>,
-    Tf<T1, B1, A1>: ToTf<T2, B2, A2, (), ()>,
-    Tf<T2, B2, A2>: Mul<Output = Tf<T2, B2, A2>> + One
+    T: ComplexFloat,
+    B1: IntoBulk<Item = T>,
+    A1: IntoBulk<Item = T>,
+    B2: IntoBulk<Item = T>,
+    A2: IntoBulk<Item = T>,
+    Tf<T, B1, A1>: Into<Tf<T, B2, A2>>,
+    Tf<T, B2, A2>: One
 {
-    fn product<I: Iterator<Item = Tf<T1, B1, A1>>>(iter: I) -> Self
+    fn product<I: Iterator<Item = Tf<T, B1, A1>>>(iter: I) -> Self
     {
-        iter.map(|tf| tf.to_tf((), ()))
+        iter.map(Into::into)
             .reduce(|a, b| a*b)
             .unwrap_or_else(One::one)
     }
-}
\ No newline at end of file
+}
@@ -1,24 +1,25 @@
-use core::{iter::Sum, ops::Mul};
+use core::iter::Sum;
 
-use num::{complex::ComplexFloat, Zero};
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+use num_traits::Zero;
 
-use crate::{quantities::{MaybeList, MaybeLists}, systems::Tf};
+use crate::systems::Tf;
 
-impl<T1, B1, A1, T2, B2, A2> Sum<Tf<T1, B1, A1>> for Tf<T2, B2, A2>
+impl<T, B1, A1, B2, A2> Sum<Tf<T, B1, A1>> for Tf<T, B2, A2>
 where
-    T1: ComplexFloat,
-    T2: ComplexFloat,
-    B1: MaybeLists<T1>,
-    A1: MaybeList<T1>,
-    B2: MaybeLists<T2>,
-    A2: MaybeList<T2>,
-    Tf<T1, B1, A1>: Into<Tf<T2, B2, A2>>,
-    Tf<T2, B2, A2>: Mul<Output = Tf<T2, B2, A2>> + Zero
+    T: ComplexFloat,
+    B1: IntoBulk<Item = T>,
+    A1: IntoBulk<Item = T>,
+    B2: IntoBulk<Item = T>,
+    A2: IntoBulk<Item = T>,
+    Tf<T, B1, A1>: Into<Tf<T, B2, A2>>,
+    Tf<T, B2, A2>: Zero
 {
-    fn sum<I: Iterator<Item = Tf<T1, B1, A1>>>(iter: I) -> Self
+    fn sum<I: Iterator<Item = Tf<T, B1, A1>>>(iter: I) -> Self
     {
-        iter.map(|tf| tf.into())
+        iter.map(Into::into)
             .reduce(|a, b| a + b)
             .unwrap_or_else(Zero::zero)
     }
-}
\ No newline at end of file
+}
@@ -1,16 +1,17 @@
 use core::ops::Add;
 
-use num::{complex::ComplexFloat, Zero};
+use array_trait::AsSlice;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+use num_traits::Zero;
 
-use crate::{quantities::{MaybeList, MaybeLists, Polynomial}, systems::Tf};
+use crate::systems::Tf;
 
 impl<T, B, A> Zero for Tf<T, B, A>
 where
     T: ComplexFloat,
-    B: MaybeLists<T>,
-    A: MaybeList<T>,
-    Polynomial<T, [T; 0]>: Into<Polynomial<T, B>>,
-    Polynomial<T, ()>: Into<Polynomial<T, A>>,
+    B: IntoBulk<Item = T> + AsSlice<Elem = T> + From<[T; 0]>,
+    A: IntoBulk<Item = T> + AsSlice<Elem = T> + From<[T; 1]>,
     Self: Add<Output = Self>
 {
     fn zero() -> Self
@@ -21,4 +22,4 @@ where
     {
         self.is_zero()
     }
-}
\ No newline at end of file
+}
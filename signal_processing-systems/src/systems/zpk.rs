@@ -1,10 +1,12 @@
-use core::ops::{Div, Mul, Neg};
+use core::{borrow::Borrow, cmp::Ordering, marker::PhantomData, ops::{Add, Div, Mul, Neg, Sub}};
 
-use num::{complex::ComplexFloat, traits::Inv, Complex, Float, NumCast, One, Zero};
-use option_trait::Maybe;
-use thiserror::Error;
+use array_trait::AsSlice;
+use bulks::{Bulk, IntoBulk};
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{Float, Inv, One};
+use signal_processing_polynomial::Polynomial;
 
-use crate::{quantities::{ListOrSingle, MaybeList, ProductSequence}, transforms::system::ToZpk};
+use crate::{Plane, System, systems::{Sos, Tf}};
 
 moddef::moddef!(
     mod {
@@ -23,202 +25,238 @@ moddef::moddef!(
     }
 );
 
-#[derive(Debug, Clone, Copy, PartialEq, Error)]
-pub enum ComplexRealError
-{
-    #[error("Tolerance must be a number in the range [0.0, 1.0].")]
-    TolaranceOutOfRange,
-    #[error("Complex roots and/or poles did not come in conjugate pairs. Something is wrong with this system.")]
-    OddNumberComplex
-}
-
+/// A system given by its zeros `z`, poles `p` and gain `k`.
+///
+/// The zeros and poles may be arrays or vectors. Products and quotients of systems with fixed-size zeros and poles yield fixed-size zeros and poles.
 #[derive(Debug, Clone, Copy)]
-pub struct Zpk<T: ComplexFloat, Z: MaybeList<T> = (), P: MaybeList<T> = (), K: ComplexFloat<Real = T::Real> = T>
+pub struct Zpk<T, Z = Vec<T>, P = Vec<T>, K = T>
+where
+    T: ComplexFloat,
+    Z: IntoBulk<Item: Borrow<T>>,
+    P: IntoBulk<Item: Borrow<T>>,
+    K: ComplexFloat<Real = T::Real>
 {
-    pub z: ProductSequence<T, Z>,
-    pub p: ProductSequence<T, P>,
-    pub k: K
+    pub z: Z,
+    pub p: P,
+    pub k: K,
+    phantom: PhantomData<T>
 }
 
+/// The complex zeros and poles found by factoring the polynomials of a system with coefficients of type `T`, and its gain.
+pub type ComplexZpk<T> = Zpk<
+    Complex<<T as ComplexFloat>::Real>,
+    Vec<Complex<<T as ComplexFloat>::Real>>,
+    Vec<Complex<<T as ComplexFloat>::Real>>,
+    T
+>;
+
 impl<T, Z, P, K> Zpk<T, Z, P, K>
 where
     T: ComplexFloat,
-    Z: MaybeList<T>,
-    P: MaybeList<T>,
+    Z: IntoBulk<Item: Borrow<T>>,
+    P: IntoBulk<Item: Borrow<T>>,
     K: ComplexFloat<Real = T::Real>
 {
     pub fn new(z: Z, p: P, k: K) -> Self
     {
         Self {
-            z: ProductSequence::new(z),
-            p: ProductSequence::new(p),
-            k
+            z,
+            p,
+            k,
+            phantom: PhantomData
         }
     }
 
-    pub type View<'a> = Zpk<T, Z::View<'a>, P::View<'a>, K>
-    where
-        Z: 'a,
-        P: 'a,
-        Z::View<'a>: MaybeList<T>,
-        P::View<'a>: MaybeList<T>;
-    pub type Owned = Zpk<T, Z::Owned, P::Owned, K>
-    where
-        Z::Owned: MaybeList<T>,
-        P::Owned: MaybeList<T>;
-
-    pub fn as_view<'a>(&'a self) -> Zpk<T, Z::View<'a>, P::View<'a>, K>
-    where
-        Z::View<'a>: MaybeList<T>,
-        P::View<'a>: MaybeList<T>
-    {
-        Zpk {
-            z: self.z.as_view(),
-            p: self.p.as_view(),
-            k: self.k
-        }
-    }
-    pub fn to_owned(&self) -> Zpk<T, Z::Owned, P::Owned, K>
-    where
-        Z::Owned: MaybeList<T>,
-        P::Owned: MaybeList<T>
-    {
-        Zpk {
-            z: self.z.to_owned(),
-            p: self.p.to_owned(),
-            k: self.k
-        }
-    }
-    pub fn into_owned(self) -> Zpk<T, Z::Owned, P::Owned, K>
+    /// Borrows the zeros and poles of the system.
+    pub fn as_view(&self) -> Zpk<T, &[T], &[T], K>
     where
-        Z::Owned: MaybeList<T>,
-        P::Owned: MaybeList<T>
+        Z: AsSlice<Elem = T>,
+        P: AsSlice<Elem = T>
     {
-        Zpk {
-            z: self.z.into_owned(),
-            p: self.p.into_owned(),
-            k: self.k
-        }
+        Zpk::new(self.z.as_slice(), self.p.as_slice(), self.k)
     }
-    pub fn s() -> Self
-    where
-        T: Zero,
-        K: One,
-        ProductSequence<T, [T; 1]>: Into<ProductSequence<T, Z>>,
-        ProductSequence<T, ()>: Into<ProductSequence<T, P>>
-    {
-        Zpk {
-            z: ProductSequence::new([T::zero()]).into(),
-            p: ProductSequence::new(()).into(),
-            k: One::one()
-        }
-    }
-    pub fn z() -> Self
+
+    /// Copies the zeros and poles of the system into vectors.
+    pub fn to_owned(&self) -> Zpk<T, Vec<T>, Vec<T>, K>
     where
-        T: Zero,
-        K: One,
-        ProductSequence<T, [T; 1]>: Into<ProductSequence<T, Z>>,
-        ProductSequence<T, ()>: Into<ProductSequence<T, P>>
+        Z: Clone,
+        P: Clone
     {
-        Zpk {
-            z: ProductSequence::new([T::zero()]).into(),
-            p: ProductSequence::new(()).into(),
-            k: One::one()
-        }
+        Zpk::new(
+            self.z.clone()
+                .into_bulk()
+                .map(|z| *z.borrow())
+                .collect::<Vec<_>, _>(),
+            self.p.clone()
+                .into_bulk()
+                .map(|p| *p.borrow())
+                .collect::<Vec<_>, _>(),
+            self.k
+        )
     }
+
     pub fn one() -> Self
     where
-        Self: Default,
+        Self: Default
     {
         Zpk::default()
     }
+
     pub fn zero() -> Self
     where
-        Self: Default
-    {
-        Zpk {k: K::zero(), ..Default::default()}
-    }
-    pub fn is_one(&self) -> bool
+        Z: From<[T; 0]>,
+        P: From<[T; 0]>
     {
-        self.z.length() == 0 && self.p.length() == 0 && self.k.is_one()
+        Zpk::new(Z::from([]), P::from([]), K::zero())
     }
+
     pub fn is_zero(&self) -> bool
     {
         self.k.is_zero()
     }
 
-    pub fn poles(&self) -> &[T]
+    /// Evaluates the system at the point `x`.
+    pub fn eval<X>(&self, x: X) -> X
+    where
+        Z: AsSlice<Elem = T>,
+        P: AsSlice<Elem = T>,
+        X: ComplexFloat + From<T> + From<K>
     {
-        self.p.as_view_slice_option()
-            .unwrap_or(&[])
+        let num = self.z.as_slice()
+            .iter()
+            .fold(<X as From<K>>::from(self.k), |y, &z| y*(x - <X as From<T>>::from(z)));
+        let den = self.p.as_slice()
+            .iter()
+            .fold(X::one(), |y, &p| y*(x - <X as From<T>>::from(p)));
+        num/den
     }
-    pub fn zeros(&self) -> &[T]
+
+    /// Expands the zeros and poles into the numerator and denominator polynomials of a transfer function.
+    #[doc(alias = "zp2tf")]
+    pub fn to_tf(&self) -> Tf<T, Vec<T>, Vec<T>>
+    where
+        Z: AsSlice<Elem = T>,
+        P: AsSlice<Elem = T>,
+        T: Mul<K, Output = T>
     {
-        self.z.as_view_slice_option()
-            .unwrap_or(&[])
+        let b = Polynomial::<Vec<T>>::from_roots(self.z.as_slice().iter().copied())
+            .into_inner()
+            .into_iter()
+            .map(|b| b*self.k)
+            .collect();
+        let a = Polynomial::<Vec<T>>::from_roots(self.p.as_slice().iter().copied())
+            .into_inner();
+
+        Tf::new(b, a)
     }
 
-    pub fn complex_real<Tol>(self, tolerance: Tol) -> Result<(Vec<[Complex<T::Real>; 2]>, Vec<[Complex<T::Real>; 2]>, Vec<T::Real>, Vec<T::Real>, K), ComplexRealError>
+    /// Groups the zeros and poles into second-order sections.
+    ///
+    /// Zeros and poles are paired with their complex conjugates if they have one within `tol`, and the remaining ones are paired in order.
+    /// The poles closest to the boundary of stability of the `plane` end up in the last section. The gain is applied to the first section.
+    #[doc(alias = "zp2sos")]
+    pub fn to_sos(&self, plane: Plane, tol: T::Real) -> Sos<T>
     where
-        Tol: Maybe<T::Real>,
-        T: Into<Complex<T::Real>>,
-        Self: ToZpk<T, Vec<T>, Vec<T>, K, (), ()>
+        Z: AsSlice<Elem = T>,
+        P: AsSlice<Elem = T>,
+        T: Mul<K, Output = T>
     {
-        let tol = if let Some(tol) = tolerance.option()
+        let z = pair_conjugates(self.z.as_slice(), tol);
+        let mut p = pair_conjugates(self.p.as_slice(), tol);
+        let stability_margin = |p: T| match plane
         {
-            if tol < Zero::zero() || tol > One::one()
+            Plane::S => Float::abs(p.re()),
+            Plane::Z => Float::abs(T::Real::one() - p.abs())
+        };
+        p.sort_by(|a, b| {
+            let d = |p: &Vec<T>| p.iter()
+                .map(|&p| stability_margin(p))
+                .fold(T::Real::infinity(), Float::min);
+            d(b).partial_cmp(&d(a))
+                .unwrap_or(Ordering::Equal)
+        });
+
+        let n = z.len().max(p.len()).max(1);
+        let sos = (0..n).map(|i| {
+            let mut b = z.get(i)
+                .map(Vec::as_slice)
+                .map(quadratic)
+                .unwrap_or(unit());
+            let a = p.get(i)
+                .map(Vec::as_slice)
+                .map(quadratic)
+                .unwrap_or(unit());
+            if i == 0
             {
-                return Err(ComplexRealError::TolaranceOutOfRange)
+                b = b.map(|b| b*self.k)
             }
-            tol
-        }
-        else
-        {
-            <T::Real as NumCast>::from(100.0).unwrap()*<T::Real as Float>::epsilon()
-        };
+            Tf::new(b, a)
+        }).collect();
 
-        let Zpk {z, p, k}: Zpk<T, Vec<T>, Vec<T>, K> = self.to_zpk((), ());
-        let mut zc = vec![];
-        let mut pc = vec![];
-        let mut zr = vec![];
-        let mut pr = vec![];
+        Sos::new(sos)
+    }
+}
 
-        for (mut m, c, r) in [(z, &mut zc, &mut zr), (p, &mut pc, &mut pr)]
+/// The polynomial `(x - r₀)(x - r₁)`, or `(x - r₀)` if there is only one root.
+pub(crate) fn quadratic<T>(r: &[T]) -> [T; 3]
+where
+    T: ComplexFloat
+{
+    let mut y = unit();
+    for (len, &r) in r.iter()
+        .enumerate()
+    {
+        for i in (1..=len + 1).rev()
         {
-            while let Some(z) = m.pop()
-            {
-                if z.is_zero() || Float::abs(z.im()) <= tol*z.abs()
-                {
-                    r.push(z.re());
-                }
-                else
-                {
-                    let z_conj = z.conj();
-                    if let Some(i) = m.iter()
-                        .enumerate()
-                        .filter(|(_, z)| !(z.is_zero() || Float::abs(z.im()) <= tol*z.abs()))
-                        .reduce(|a, b| if (*a.1 - z_conj).abs() < (*b.1 - z_conj).abs()
-                        {
-                            a
-                        }
-                        else
-                        {
-                            b
-                        }).map(|(i, _)| i)
-                    {
-                        let z = [z.into(), m.remove(i).into()];
-                        c.push(z)
-                    }
-                    else
-                    {
-                        return Err(ComplexRealError::OddNumberComplex)
-                    }
-                }
-            }
+            y[i] = y[i - 1] - r*y[i]
         }
+        y[0] = -r*y[0];
+    }
+    y
+}
+
+pub(crate) fn unit<T>() -> [T; 3]
+where
+    T: ComplexFloat
+{
+    [T::one(), T::zero(), T::zero()]
+}
 
-        Ok((zc, pc, zr, pr, k))
+/// Groups roots two by two, putting complex conjugates together.
+pub(crate) fn pair_conjugates<T>(r: &[T], tol: T::Real) -> Vec<Vec<T>>
+where
+    T: ComplexFloat
+{
+    let mut r = r.to_vec();
+    let mut pairs = vec![];
+    let mut single = vec![];
+    while let Some(x) = r.pop()
+    {
+        if Float::abs(x.im()) <= tol
+        {
+            single.push(x);
+            continue
+        }
+        match r.iter()
+            .position(|&y| (y - x.conj()).abs() <= tol)
+        {
+            Some(j) => pairs.push(vec![x, r.swap_remove(j)]),
+            None => single.push(x)
+        }
     }
+    single.sort_by(|a, b| a.re().partial_cmp(&b.re()).unwrap_or(Ordering::Equal));
+    pairs.extend(single.chunks(2).map(<[T]>::to_vec));
+    pairs
+}
+
+impl<T, Z, P, K> System for Zpk<T, Z, P, K>
+where
+    T: ComplexFloat,
+    Z: IntoBulk<Item: Borrow<T>>,
+    P: IntoBulk<Item: Borrow<T>>,
+    K: ComplexFloat<Real = T::Real>
+{
+    type Set = K;
 }
 
 macro_rules! impl_op1_extra {
@@ -226,18 +264,16 @@ macro_rules! impl_op1_extra {
         impl<'a, T, Z, P, K, O> $t for &'a Zpk<T, Z, P, K>
         where
             T: ComplexFloat,
-            Z: MaybeList<T>,
-            P: MaybeList<T>,
+            Z: IntoBulk<Item: Borrow<T>>,
+            P: IntoBulk<Item: Borrow<T>>,
             K: ComplexFloat<Real = T::Real>,
-            Z::View<'a>: MaybeList<T>,
-            P::View<'a>: MaybeList<T>,
-            Zpk<T, Z::View<'a>, P::View<'a>, K>: $t<Output = O>
+            Zpk<T, Z, P, K>: Clone + $t<Output = O>
         {
             type Output = O;
 
             fn $f(self) -> Self::Output
             {
-                self.as_view().$f()
+                self.clone().$f()
             }
         }
     };
@@ -247,326 +283,114 @@ impl_op1_extra!(Inv::inv);
 
 macro_rules! impl_op2_extra {
     ($t:ident :: $f:tt) => {
-        impl<'a, T1, T2, Z1, Z2, P1, P2, K1, K2, O> $t<Zpk<T2, Z2, P2, K2>> for &'a Zpk<T1, Z1, P1, K1>
+        impl<'a, T, Z1, P1, Z2, P2, K, O> $t<Zpk<T, Z2, P2, K>> for &'a Zpk<T, Z1, P1, K>
         where
-            T1: ComplexFloat,
-            T2: ComplexFloat,
-            Z1: MaybeList<T1>,
-            Z2: MaybeList<T2>,
-            P1: MaybeList<T1>,
-            P2: MaybeList<T2>,
-            K1: ComplexFloat<Real = T1::Real>,
-            K2: ComplexFloat<Real = T2::Real>,
-            Z1::View<'a>: MaybeList<T1>,
-            P1::View<'a>: MaybeList<T1>,
-            Zpk<T1, Z1::View<'a>, P1::View<'a>, K1>: $t<Zpk<T2, Z2, P2, K2>, Output = O>
-        {
-            type Output = O;
-
-            fn $f(self, rhs: Zpk<T2, Z2, P2, K2>) -> Self::Output
-            {
-                self.as_view().$f(rhs)
-            }
-        }
-        impl<'b, T1, T2, Z1, Z2, P1, P2, K1, K2, O> $t<&'b Zpk<T2, Z2, P2, K2>> for Zpk<T1, Z1, P1, K1>
-        where
-            T1: ComplexFloat,
-            T2: ComplexFloat,
-            Z1: MaybeList<T1>,
-            Z2: MaybeList<T2>,
-            P1: MaybeList<T1>,
-            P2: MaybeList<T2>,
-            K1: ComplexFloat<Real = T1::Real>,
-            K2: ComplexFloat<Real = T2::Real>,
-            Z2::View<'b>: MaybeList<T2>,
-            P2::View<'b>: MaybeList<T2>,
-            Zpk<T1, Z1, P1, K1>: $t<Zpk<T2, Z2::View<'b>, P2::View<'b>, K2>, Output = O>
-        {
-            type Output = O;
-
-            fn $f(self, rhs: &'b Zpk<T2, Z2, P2, K2>) -> Self::Output
-            {
-                self.$f(rhs.as_view())
-            }
-        }
-        impl<'a, 'b, T1, T2, Z1, Z2, P1, P2, K1, K2, O> $t<&'b Zpk<T2, Z2, P2, K2>> for &'a Zpk<T1, Z1, P1, K1>
-        where
-            T1: ComplexFloat,
-            T2: ComplexFloat,
-            Z1: MaybeList<T1>,
-            Z2: MaybeList<T2>,
-            P1: MaybeList<T1>,
-            P2: MaybeList<T2>,
-            K1: ComplexFloat<Real = T1::Real>,
-            K2: ComplexFloat<Real = T2::Real>,
-            Z1::View<'a>: MaybeList<T1>,
-            P1::View<'a>: MaybeList<T1>,
-            Z2::View<'b>: MaybeList<T2>,
-            P2::View<'b>: MaybeList<T2>,
-            Zpk<T1, Z1::View<'a>, P1::View<'a>, K1>: $t<Zpk<T2, Z2::View<'b>, P2::View<'b>, K2>, Output = O>
+            T: ComplexFloat,
+            Z1: IntoBulk<Item: Borrow<T>>,
+            P1: IntoBulk<Item: Borrow<T>>,
+            Z2: IntoBulk<Item: Borrow<T>>,
+            P2: IntoBulk<Item: Borrow<T>>,
+            K: ComplexFloat<Real = T::Real>,
+            Zpk<T, Z1, P1, K>: Clone + $t<Zpk<T, Z2, P2, K>, Output = O>
         {
             type Output = O;
 
-            fn $f(self, rhs: &'b Zpk<T2, Z2, P2, K2>) -> Self::Output
+            fn $f(self, rhs: Zpk<T, Z2, P2, K>) -> Self::Output
             {
-                self.as_view().$f(rhs.as_view())
+                self.clone().$f(rhs)
             }
         }
-
-        impl<T, Z, P, K, O> $t<K> for Zpk<T, Z, P, K>
+        impl<'b, T, Z1, P1, Z2, P2, K, O> $t<&'b Zpk<T, Z2, P2, K>> for Zpk<T, Z1, P1, K>
         where
             T: ComplexFloat,
-            Z: MaybeList<T>,
-            P: MaybeList<T>,
+            Z1: IntoBulk<Item: Borrow<T>>,
+            P1: IntoBulk<Item: Borrow<T>>,
+            Z2: IntoBulk<Item: Borrow<T>>,
+            P2: IntoBulk<Item: Borrow<T>>,
             K: ComplexFloat<Real = T::Real>,
-            Self: $t<Zpk<T, (), (), K>, Output = O>
+            Zpk<T, Z2, P2, K>: Clone,
+            Self: $t<Zpk<T, Z2, P2, K>, Output = O>
         {
             type Output = O;
 
-            fn $f(self, rhs: K) -> Self::Output
+            fn $f(self, rhs: &'b Zpk<T, Z2, P2, K>) -> Self::Output
             {
-                self.$f(Zpk {
-                    z: ProductSequence::new(()),
-                    p: ProductSequence::new(()),
-                    k: rhs
-                })
+                self.$f(rhs.clone())
             }
         }
-        impl<'b, T, Z, P, K, O> $t<K> for &'b Zpk<T, Z, P, K>
+        impl<'a, 'b, T, Z1, P1, Z2, P2, K, O> $t<&'b Zpk<T, Z2, P2, K>> for &'a Zpk<T, Z1, P1, K>
         where
             T: ComplexFloat,
-            Z: MaybeList<T>,
-            P: MaybeList<T>,
+            Z1: IntoBulk<Item: Borrow<T>>,
+            P1: IntoBulk<Item: Borrow<T>>,
+            Z2: IntoBulk<Item: Borrow<T>>,
+            P2: IntoBulk<Item: Borrow<T>>,
             K: ComplexFloat<Real = T::Real>,
-            Self: $t<Zpk<T, (), (), K>, Output = O>
+            Zpk<T, Z1, P1, K>: Clone + $t<Zpk<T, Z2, P2, K>, Output = O>,
+            Zpk<T, Z2, P2, K>: Clone
         {
             type Output = O;
 
-            fn $f(self, rhs: K) -> Self::Output
+            fn $f(self, rhs: &'b Zpk<T, Z2, P2, K>) -> Self::Output
             {
-                self.$f(Zpk {
-                    z: ProductSequence::new(()),
-                    p: ProductSequence::new(()),
-                    k: rhs
-                })
+                self.clone().$f(rhs.clone())
             }
         }
     };
 }
+impl_op2_extra!(Add::add);
+impl_op2_extra!(Sub::sub);
 impl_op2_extra!(Mul::mul);
 impl_op2_extra!(Div::div);
 
-#[allow(unused)]
-macro s {
-    (s) => {},
-    (z) => {},
-}
-
-pub macro zpk {
-    ($t:path[$s:ident]= {$e:expr}) => {
-        {
-            Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            $e
-        }
-    },
-    ($t:path[$s:ident]=) => {
-        {
-            Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            Zpk::<$t, _, _, $t>::new((), ())
-        }
-    },
-    ($t:path[$s:ident]= $c:literal) => {
-        {
-            Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            Zpk::<$t, _, _, $t>::new((), (), <$t as num::NumCast>::from($c).unwrap())
-        }
-    },
-    ($t:path[$s:ident]= $c:literal + $ci:literal j) => {
-        {
-            Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            Zpk::<$t, _, _, num::Complex<<$t as num::complex::ComplexFloat>::Real>>::new((), (), num::Complex::new(
-                <$t as num::NumCast>::from($c).unwrap(),
-                <$t as num::NumCast>::from($ci).unwrap()
-            ))
-        }
-    },
-    ($t:path[$s:ident]= $c:literal - $ci:literal j) => {
-        {
-            Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            Zpk::<$t, _, _, num::Complex<<$t as num::complex::ComplexFloat>::Real>>::new((), (), num::Complex::new(
-                <$t as num::NumCast>::from($c).unwrap(),
-                -<$t as num::NumCast>::from($ci).unwrap()
-            ))
-        }
-    },
-    ($t:path[$s:ident]= $ci:literal j) => {
-        {
-            Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            Zpk::<$t, _, _, num::Complex<<$t as num::complex::ComplexFloat>::Real>>::new((), (), num::Complex::new(
-                <$t as num::NumCast>::from(0).unwrap(),
-                <$t as num::NumCast>::from($ci).unwrap()
-            ))
-        }
-    },
-
-    ($t:path[$s:ident]= $ss:ident + $c:literal) => {
-        {
-            #[allow(unused)]
-            let $s = Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            s!($ss);
-            let _ = $ss;
-            Zpk::<$t, _, _, $t>::new([-<$t as num::NumCast>::from($c).unwrap()], (), <$t as num::NumCast>::from(1).unwrap())
-        }
-    },
-    ($t:path[$s:ident]= $ss:ident + $c:literal + $ci:literal j) => {
-        {
-            #[allow(unused)]
-            let $s = Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            s!($ss);
-            let _ = $ss;
-            Zpk::<num::Complex<<$t as num::complex::ComplexFloat>::Real>, _, _, $t>::new([num::Complex::new(
-                -<$t as num::NumCast>::from($c).unwrap(),
-                -<$t as num::NumCast>::from($ci).unwrap()
-            )], (), <$t as num::NumCast>::from(1).unwrap())
-        }
-    },
-    ($t:path[$s:ident]= $ss:ident + $c:literal - $ci:literal j) => {
-        {
-            #[allow(unused)]
-            let $s = Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            s!($ss);
-            let _ = $ss;
-            Zpk::<num::Complex<<$t as num::complex::ComplexFloat>::Real>, _, _, $t>::new([num::Complex::new(
-                -<$t as num::NumCast>::from($c).unwrap(),
-                <$t as num::NumCast>::from($ci).unwrap()
-            )], (), <$t as num::NumCast>::from(1).unwrap())
-        }
-    },
-    ($t:path[$s:ident]= $ss:ident + $ci:literal j) => {
-        {
-            #[allow(unused)]
-            let $s = Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            s!($ss);
-            let _ = $ss;
-            Zpk::<num::Complex<<$t as num::complex::ComplexFloat>::Real>, _, _, $t>::new([num::Complex::new(
-                <$t as num::NumCast>::from(0).unwrap(),
-                -<$t as num::NumCast>::from($ci).unwrap()
-            )], (), <$t as num::NumCast>::from(1).unwrap())
-        }
-    },
-    
-    ($t:path[$s:ident]= $ss:ident - $c:literal) => {
-        {
-            #[allow(unused)]
-            let $s = Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            s!($ss);
-            let _ = $ss;
-            Zpk::<$t, _, _, $t>::new([<$t as num::NumCast>::from($c).unwrap()], (), <$t as num::NumCast>::from(1).unwrap())
-        }
-    },
-    ($t:path[$s:ident]= $ss:ident - $c:literal + $ci:literal j) => {
-        {
-            #[allow(unused)]
-            let $s = Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            s!($ss);
-            let _ = $ss;
-            Zpk::<num::Complex<<$t as num::complex::ComplexFloat>::Real>, _, _, $t>::new([num::Complex::new(
-                <$t as num::NumCast>::from($c).unwrap(),
-                -<$t as num::NumCast>::from($ci).unwrap()
-            )], (), <$t as num::NumCast>::from(1).unwrap())
-        }
-    },
-    ($t:path[$s:ident]= $ss:ident - $c:literal - $ci:literal j) => {
-        {
-            #[allow(unused)]
-            let $s = Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            s!($ss);
-            let _ = $ss;
-            Zpk::<num::Complex<<$t as num::complex::ComplexFloat>::Real>, _, _, $t>::new([num::Complex::new(
-                <$t as num::NumCast>::from($c).unwrap(),
-                <$t as num::NumCast>::from($ci).unwrap()
-            )], (), <$t as num::NumCast>::from(1).unwrap())
-        }
-    },
-    ($t:path[$s:ident]= $ss:ident - $ci:literal j) => {
-        {
-            #[allow(unused)]
-            let $s = Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            s!($ss);
-            let _ = $ss;
-            Zpk::<num::Complex<<$t as num::complex::ComplexFloat>::Real>, _, _, $t>::new([num::Complex::new(
-                <$t as num::NumCast>::from(0).unwrap(),
-                <$t as num::NumCast>::from($ci).unwrap()
-            )], (), <$t as num::NumCast>::from(1).unwrap())
-        }
-    },
-
-    ($t:path[$s:ident]= $ss:ident) => {
-        {
-            #[allow(unused)]
-            let $s = Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            s!($ss);
-            Zpk::<$t, [_; 1], (), $t>::from($ss)
-        }
-    },
-    ($t:path[$s:ident]= $c:literal^$pc:literal) => {
-        {
-            #[allow(unused)]
-            let $s = Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            s!($ss);
-            let _ = $ss;
-            num::traits::Pow::pow(Zpk::<$t, _, _, $t>::new((), (), <$t as num::NumCast>::from($c).unwrap()), $pc)
-        }
-    },
-    ($t:path[$s:ident]= $ss:ident^$ps:literal) => {
-        {
-            #[allow(unused)]
-            let $s = Zpk::<$t, [_; 1], (), $t>::$s();
-            s!($s);
-            s!($ss);
-            num::traits::Pow::pow(Zpk::<$t, [_; 1], (), $t>::from($ss), $ps)
-        }
-    },
-    ($t:path[$s:ident]= ($($lhs:tt)*)^$lp:literal $($op:tt ($($rhs:tt)*))?) => {
-        num::traits::Pow::pow(zpk!($t[$s]= $($lhs)*), $lp)$($op zpk!($t[$s]= $($rhs)*))*
-    },
-    ($t:path[$s:ident]= ($($lhs:tt)*) $($op:tt ($($rhs:tt)*))?) => {
-        zpk!($t[$s]= $($lhs)*)$($op zpk!($t[$s]= $($rhs)*))*
-    },
-    ($t:path[$s:ident]= $lhs:tt$(^$lp:literal)? $($op:tt $rhs:tt$(^$rp:literal)?)*) => {
-        zpk!($t[$s]= $lhs$(^$lp)?)$($op zpk!($t[$s]= $rhs$(^$rp)?))*
-    },
-}
-
 #[cfg(test)]
 mod test
 {
-    use crate::systems::zpk;
+    use num_complex::Complex;
+
+    use crate::{Plane, systems::Zpk};
+
+    #[test]
+    fn to_tf()
+    {
+        let zpk = Zpk::new([-1.0], [-2.0, -3.0], 2.0);
+        let tf = zpk.to_tf();
+
+        assert_eq!(tf.b.into_inner(), vec![2.0, 2.0]);
+        assert_eq!(tf.a.into_inner(), vec![6.0, 5.0, 1.0]);
+    }
 
     #[test]
-    fn test()
+    fn to_sos()
     {
-        let h1 = zpk!(f64[s] = (s - 1 + 1 j)*(s - 1 - 1 j));
-        let h2 = zpk!(f64[s] = s + 2);
+        let p = Complex::from_polar(0.9, 0.5);
+        let zpk = Zpk::new(
+            vec![Complex::new(-1.0, 0.0), Complex::new(-1.0, 0.0), Complex::new(0.5, 0.0)],
+            vec![p, p.conj(), Complex::new(0.2, 0.0)],
+            Complex::new(3.0, 0.0)
+        );
+        let sos = zpk.to_sos(Plane::Z, 1e-9);
+        let tf = zpk.to_tf();
+
+        let x = Complex::new(0.3, -0.4);
+        assert!((sos.eval(x) - tf.eval(x)).norm() < 1e-9);
+    }
 
-        let h = (h1 + h2)/zpk!(f64[s] = 1 - s);
+    #[test]
+    fn to_sos_plane()
+    {
+        // The first pair is closer to the imaginary axis, and the second to the unit circle.
+        let p1 = Complex::new(-0.01, 0.2);
+        let p2 = Complex::new(-0.9, 0.3);
+        let zpk = Zpk::new(vec![], vec![p1, p1.conj(), p2, p2.conj()], Complex::new(1.0, 0.0));
 
-        println!("{:?}", h);
+        let last = |plane| zpk.to_sos(plane, 1e-9)
+            .sos
+            .last()
+            .unwrap()
+            .a
+            .into_inner();
+        assert!((last(Plane::S)[0] - p1.norm_sqr()).norm() < 1e-12);
+        assert!((last(Plane::Z)[0] - p2.norm_sqr()).norm() < 1e-12);
     }
-}
\ No newline at end of file
+}
@@ -1,22 +1,17 @@
-use num::complex::ComplexFloat;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
 
-use crate::{quantities::{MaybeList, ProductSequence}, systems::Zpk};
+use crate::systems::Zpk;
 
 impl<T, Z, P, K> Default for Zpk<T, Z, P, K>
 where
     T: ComplexFloat,
-    Z: MaybeList<T>,
-    P: MaybeList<T>,
     K: ComplexFloat<Real = T::Real>,
-    ProductSequence<T, Z>: Default,
-    ProductSequence<T, P>: Default
+    Z: IntoBulk<Item = T> + From<[T; 0]>,
+    P: IntoBulk<Item = T> + From<[T; 0]>
 {
     fn default() -> Self
     {
-        Self {
-            z: Default::default(),
-            p: Default::default(),
-            k: K::one()
-        }
+        Zpk::new(Z::from([]), P::from([]), K::one())
     }
-}
\ No newline at end of file
+}
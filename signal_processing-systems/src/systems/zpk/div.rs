@@ -1,42 +1,40 @@
-use core::ops::Div;
-use std::ops::Mul;
+use core::{marker::PhantomData, ops::Div};
 
-use num::complex::ComplexFloat;
+use bulks::{Bulk, Chain, IntoBulk};
+use num_complex::ComplexFloat;
+use signal_processing_polynomial::IntoOwned;
 
-use crate::{util::ComplexOp, quantities::{MaybeList, ProductSequence}, operations::Simplify, systems::Zpk};
+use crate::systems::Zpk;
 
-impl<T1, T2, T3, Z1, Z2, Z3, P1, P2, P3, K1, K2, K3> Div<Zpk<T2, Z2, P2, K2>> for Zpk<T1, Z1, P1, K1>
+impl<T, Z1, P1, Z2, P2, K> Div<Zpk<T, Z2, P2, K>> for Zpk<T, Z1, P1, K>
 where
-    T1: ComplexFloat + ComplexOp<T2, Output = T3>,
-    T2: ComplexFloat + Into<T3>,
-    T3: ComplexFloat,
-    K1: ComplexFloat<Real = T1::Real> + ComplexOp<K2, Output = K3>,
-    K2: ComplexFloat<Real = T2::Real> + Into<K3>,
-    K3: ComplexFloat<Real = T3::Real> + Div<Output = K3>,
-    Z1: MaybeList<T1>,
-    Z2: MaybeList<T2>,
-    Z3: MaybeList<T3>,
-    P1: MaybeList<T1>,
-    P2: MaybeList<T2>,
-    P3: MaybeList<T3>,
-    Z1::MaybeMapped<T3>: MaybeList<T3>,
-    Z2::MaybeMapped<T3>: MaybeList<T3>,
-    P1::MaybeMapped<T3>: MaybeList<T3>,
-    P2::MaybeMapped<T3>: MaybeList<T3>,
-    ProductSequence<T3, Z1::MaybeMapped<T3>>: Mul<ProductSequence<T3, P2::MaybeMapped<T3>>, Output = ProductSequence<T3, Z3>>,
-    ProductSequence<T3, P1::MaybeMapped<T3>>: Mul<ProductSequence<T3, Z2::MaybeMapped<T3>>, Output = ProductSequence<T3, P3>>,
-    Zpk<T3, Z3, P3, K3>: Simplify
+    T: ComplexFloat,
+    K: ComplexFloat<Real = T::Real>,
+    Z1: IntoBulk<Item = T>,
+    P1: IntoBulk<Item = T>,
+    Z2: IntoBulk<Item = T>,
+    P2: IntoBulk<Item = T>,
+    Chain<Z1::IntoBulk, P2::IntoBulk>: IntoOwned<Item = T>,
+    Chain<P1::IntoBulk, Z2::IntoBulk>: IntoOwned<Item = T>
 {
-    type Output = <Zpk<T3, Z3, P3, K3> as Simplify>::Output;
+    type Output = Zpk<
+        T,
+        <Chain<Z1::IntoBulk, P2::IntoBulk> as IntoOwned>::Owned,
+        <Chain<P1::IntoBulk, Z2::IntoBulk> as IntoOwned>::Owned,
+        K
+    >;
 
-    fn div(self, rhs: Zpk<T2, Z2, P2, K2>) -> Self::Output
+    fn div(self, rhs: Zpk<T, Z2, P2, K>) -> Self::Output
     {
         Zpk {
-            z: ProductSequence::new(self.z.into_inner().maybe_map_into_owned(|z| z.into()))
-                *ProductSequence::new(rhs.p.into_inner().maybe_map_into_owned(|p| p.into())),
-            p: ProductSequence::new(self.p.into_inner().maybe_map_into_owned(|p| p.into()))
-                *ProductSequence::new(rhs.z.into_inner().maybe_map_into_owned(|z| z.into())),
-            k: self.k.into()/rhs.k.into()
-        }.simplify()
+            z: self.z.into_bulk()
+                .chain(rhs.p)
+                .into_owned(),
+            p: self.p.into_bulk()
+                .chain(rhs.z)
+                .into_owned(),
+            k: self.k/rhs.k,
+            phantom: PhantomData
+        }
     }
-}
\ No newline at end of file
+}
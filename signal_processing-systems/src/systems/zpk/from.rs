@@ -1,28 +1,45 @@
-use num::complex::ComplexFloat;
+use core::{borrow::Borrow, marker::PhantomData};
 
-use crate::{quantities::{MaybeList, ProductSequence}, systems::Zpk};
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
 
-impl<'a, T1, T2, K1, K2, Z1, Z2, P1, P2> From<&'a Zpk<T1, Z1, P1, K1>> for Zpk<T2, Z2, P2, K2>
+use crate::systems::Zpk;
+
+impl<'a, T, Z1, P1, K1, Z2, P2, K2> From<&'a Zpk<T, Z1, P1, K1>> for Zpk<T, Z2, P2, K2>
 where
-    T1: ComplexFloat,
-    T2: ComplexFloat,
-    K1: ComplexFloat<Real = T1::Real> + Into<K2>,
-    K2: ComplexFloat<Real = T2::Real>,
-    Z1: MaybeList<T1>,
-    P1: MaybeList<T1>,
-    Z2: MaybeList<T2>,
-    P2: MaybeList<T2>,
-    Z1::View<'a>: MaybeList<T1>,
-    P1::View<'a>: MaybeList<T1>,
-    ProductSequence<T1, Z1::View<'a>>: Into<ProductSequence<T2, Z2>>,
-    ProductSequence<T1, P1::View<'a>>: Into<ProductSequence<T2, P2>>
+    T: ComplexFloat,
+    K1: ComplexFloat<Real = T::Real> + Into<K2>,
+    K2: ComplexFloat<Real = T::Real>,
+    Z1: IntoBulk<Item: Borrow<T>> + Clone + Into<Z2>,
+    P1: IntoBulk<Item: Borrow<T>> + Clone + Into<P2>,
+    Z2: IntoBulk<Item: Borrow<T>>,
+    P2: IntoBulk<Item: Borrow<T>>
 {
-    fn from(zpk: &'a Zpk<T1, Z1, P1, K1>) -> Self
+    fn from(zpk: &'a Zpk<T, Z1, P1, K1>) -> Self
     {
         Zpk {
-            z: zpk.z.as_view().into(),
-            p: zpk.p.as_view().into(),
-            k: zpk.k.into()
+            z: zpk.z.clone().into(),
+            p: zpk.p.clone().into(),
+            k: zpk.k.into(),
+            phantom: PhantomData
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::systems::Zpk;
+
+    #[test]
+    fn from()
+    {
+        let h = Zpk::new([1.0], [2.0, 3.0], 2.0);
+        let h2: Zpk<f64> = Zpk::from(&h);
+        assert_eq!(h2.z, vec![1.0]);
+        assert_eq!(h2.p, vec![2.0, 3.0]);
+
+        let h3: Zpk<f64> = Zpk::from(&h2.as_view());
+        assert_eq!(h3.p, h2.p);
+    }
+}
@@ -1,22 +1,27 @@
-use num::{complex::ComplexFloat, traits::Inv};
+use core::marker::PhantomData;
 
-use crate::{quantities::MaybeList, systems::Zpk};
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+use num_traits::Inv;
+
+use crate::systems::Zpk;
 
 impl<T, Z, P, K> Inv for Zpk<T, Z, P, K>
 where
     T: ComplexFloat,
-    K: ComplexFloat<Real = T::Real> + Inv<Output: ComplexFloat<Real = T::Real>>,
-    Z: MaybeList<T>,
-    P: MaybeList<T>
+    K: ComplexFloat<Real = T::Real>,
+    Z: IntoBulk<Item = T>,
+    P: IntoBulk<Item = T>
 {
-    type Output = Zpk<T, P, Z, <K as Inv>::Output>;
+    type Output = Zpk<T, P, Z, K>;
 
     fn inv(self) -> Self::Output
     {
         Zpk {
             z: self.p,
             p: self.z,
-            k: self.k.inv()
+            k: self.k.recip(),
+            phantom: PhantomData
         }
     }
-}
\ No newline at end of file
+}
@@ -1,24 +1,26 @@
-use core::ops::Neg;
+use core::{marker::PhantomData, ops::Neg};
 
-use num::complex::ComplexFloat;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
 
-use crate::{quantities::MaybeList, systems::Zpk};
+use crate::systems::Zpk;
 
 impl<T, Z, P, K> Neg for Zpk<T, Z, P, K>
 where
     T: ComplexFloat,
     K: ComplexFloat<Real = T::Real>,
-    Z: MaybeList<T>,
-    P: MaybeList<T>
+    Z: IntoBulk<Item = T>,
+    P: IntoBulk<Item = T>
 {
-    type Output = Zpk<T, Z, P, K>;
+    type Output = Self;
 
     fn neg(self) -> Self::Output
     {
         Zpk {
             z: self.z,
             p: self.p,
-            k: -self.k
+            k: -self.k,
+            phantom: PhantomData
         }
     }
-}
\ No newline at end of file
+}
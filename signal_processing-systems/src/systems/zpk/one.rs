@@ -1,15 +1,18 @@
 use core::ops::Mul;
 
-use num::{complex::ComplexFloat, One};
+use array_trait::AsSlice;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+use num_traits::One;
 
-use crate::{quantities::List, systems::Zpk};
+use crate::systems::Zpk;
 
 impl<T, Z, P, K> One for Zpk<T, Z, P, K>
 where
     T: ComplexFloat,
     K: ComplexFloat<Real = T::Real>,
-    Z: List<T>,
-    P: List<T>,
+    Z: IntoBulk<Item = T> + AsSlice<Elem = T>,
+    P: IntoBulk<Item = T> + AsSlice<Elem = T>,
     Self: Default + Mul<Output = Self>
 {
     fn one() -> Self
@@ -19,6 +22,6 @@ where
 
     fn is_one(&self) -> bool
     {
-        self.k.is_one() && self.p.as_view_slice().is_empty() && self.z.as_view_slice().is_empty()
+        self.k.is_one() && self.p.as_slice().is_empty() && self.z.as_slice().is_empty()
     }
-}
\ No newline at end of file
+}
@@ -1,56 +1,64 @@
-use core::ops::{BitAnd, Shr};
+use core::marker::PhantomData;
 
-use num::{complex::ComplexFloat, pow::Pow, traits::Inv, Integer, One};
-
-use crate::{util::ComplexOp, quantities::MaybeList, transforms::system::ToZpk, systems::Zpk};
+use array_trait::AsSlice;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+use num_traits::{Pow, PrimInt};
 
+use crate::systems::Zpk;
 
 impl<T, Z, P, K, I> Pow<I> for Zpk<T, Z, P, K>
 where
-    T: ComplexFloat + ComplexOp<T, Output = T>,
-    Z: MaybeList<T>,
-    P: MaybeList<T>,
-    K: ComplexFloat<Real = T::Real> + ComplexOp<K, Output = K>,
-    I: Integer + BitAnd<I, Output = I> + Shr<usize, Output = I> + Copy,
-    Self: ToZpk<T, Vec<T>, Vec<T>, K, (), ()> + Inv<Output: ToZpk<T, Vec<T>, Vec<T>, K, (), ()>>
+    T: ComplexFloat,
+    K: ComplexFloat<Real = T::Real>,
+    Z: IntoBulk<Item = T> + AsSlice<Elem = T>,
+    P: IntoBulk<Item = T> + AsSlice<Elem = T>,
+    I: PrimInt
 {
     type Output = Zpk<T, Vec<T>, Vec<T>, K>;
 
-    fn pow(self, mut n: I) -> Self::Output
+    fn pow(self, n: I) -> Self::Output
     {
-        let mut x = if n < I::zero()
-        {
-            self.inv()
-                .to_zpk((), ())
-        }
-        else
+        let (z, p, k, n) = if n < I::zero()
         {
-            self.to_zpk((), ())
-        };
-        let mut r = if (n & I::one()) == I::one()
-        {
-            x.clone()
+            (self.p.as_slice(), self.z.as_slice(), self.k.recip(), I::zero() - n)
         }
         else
         {
-            One::one()
+            (self.z.as_slice(), self.p.as_slice(), self.k, n)
         };
-    
-        let two = I::one() + I::one();
-        loop
-        {
-            n = n/two;
-            if n == I::zero()
-            {
-                break;
-            }
-            x = x.as_view()*x.as_view();
-            if (n & I::one()) == I::one()
-            {
-                r = r*x.as_view();
-            }
+        let n = n.to_usize()
+            .unwrap();
+
+        Zpk {
+            z: z.repeat(n),
+            p: p.repeat(n),
+            k: k.powi(n as i32),
+            phantom: PhantomData
         }
-    
-        r
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test
+{
+    use num_traits::Pow;
+
+    use crate::systems::Zpk;
+
+    #[test]
+    fn pow()
+    {
+        let h = Zpk::new([1.0], [2.0], 2.0);
+
+        let h2 = h.pow(2);
+        assert_eq!(h2.z, vec![1.0, 1.0]);
+        assert_eq!(h2.p, vec![2.0, 2.0]);
+        assert_eq!(h2.k, 4.0);
+
+        let h2 = h.pow(-1);
+        assert_eq!(h2.z, vec![2.0]);
+        assert_eq!(h2.p, vec![1.0]);
+        assert_eq!(h2.k, 0.5);
+    }
+}
@@ -1,27 +1,25 @@
-use core::ops::Mul;
-use std::iter::Product;
+use core::iter::Product;
 
-use num::{complex::ComplexFloat, One};
+use array_trait::AsSlice;
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
 
-use crate::{quantities::List, systems::Zpk};
+use crate::systems::Zpk;
 
-impl<'a, T1, T2, Z1, Z2, P1, P2, K1, K2> Product<&'a Zpk<T1, Z1, P1, K1>> for Zpk<T2, Z2, P2, K2>
+impl<T, Z, P, K> Product<Zpk<T, Z, P, K>> for Zpk<T, Vec<T>, Vec<T>, K>
 where
-    T1: ComplexFloat,
-    T2: ComplexFloat,
-    K1: ComplexFloat<Real = T1::Real>,
-    K2: ComplexFloat<Real = T2::Real>,
-    Z1: List<T1>,
-    P1: List<T1>,
-    Z2: List<T2>,
-    P2: List<T2>,
-    &'a Zpk<T1, Z1, P1, K1>: Into<Self>,
-    Self: One + 'a
+    T: ComplexFloat,
+    K: ComplexFloat<Real = T::Real>,
+    Z: IntoBulk<Item = T> + AsSlice<Elem = T>,
+    P: IntoBulk<Item = T> + AsSlice<Elem = T>
 {
-    fn product<I: Iterator<Item = &'a Zpk<T1, Z1, P1, K1>>>(iter: I) -> Self
+    fn product<I: Iterator<Item = Zpk<T, Z, P, K>>>(iter: I) -> Self
     {
-        iter.map(|zpk| zpk.into())
-            .reduce(Mul::mul)
-            .unwrap_or_else(One::one)
+        iter.fold(Zpk::one(), |mut y, x| {
+            y.z.extend_from_slice(x.z.as_slice());
+            y.p.extend_from_slice(x.p.as_slice());
+            y.k = y.k*x.k;
+            y
+        })
     }
-}
\ No newline at end of file
+}
@@ -1,23 +1,44 @@
-use core::ops::Add;
+use core::{borrow::Borrow, ops::Add};
 
-use num::{complex::ComplexFloat, Zero};
+use bulks::IntoBulk;
+use num_complex::ComplexFloat;
+use num_traits::Zero;
 
-use crate::{quantities::MaybeList, systems::Zpk};
+use crate::systems::Zpk;
 
 impl<T, Z, P, K> Zero for Zpk<T, Z, P, K>
 where
     T: ComplexFloat,
-    Z: MaybeList<T>,
-    P: MaybeList<T>,
     K: ComplexFloat<Real = T::Real>,
-    Self: Default + Add<Output = Self>
+    Z: IntoBulk<Item: Borrow<T>> + From<[T; 0]>,
+    P: IntoBulk<Item: Borrow<T>> + From<[T; 0]>,
+    Self: Add<Output = Self>
 {
     fn zero() -> Self
     {
-        Self::zero()
+        Zpk::zero()
     }
     fn is_zero(&self) -> bool
     {
         self.is_zero()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test
+{
+    use num_complex::Complex;
+    use num_traits::Zero;
+
+    use crate::systems::Zpk;
+
+    #[test]
+    fn zero()
+    {
+        let h: Zpk<Complex<f64>> = Zero::zero();
+        let c = Complex::new;
+
+        assert!(Zero::is_zero(&h));
+        assert!(!Zero::is_zero(&Zpk::new(vec![c(1.0, 0.0)], vec![c(2.0, 0.0)], c(1.0, 0.0))));
+    }
+}
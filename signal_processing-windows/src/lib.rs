@@ -1,5 +1,6 @@
 #![feature(impl_trait_in_assoc_type)]
 #![feature(const_trait_impl)]
+#![feature(const_array)]
 #![feature(const_destruct)]
 #![feature(const_precise_live_drops)]
 #![feature(unboxed_closures)]
@@ -11,6 +12,11 @@
 #![feature(try_trait_v2)]
 #![feature(generic_const_exprs)]
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::{marker::{Destruct, PhantomData}, ops::{Mul, Try}};
 
 use array_trait::length::{self, Length, LengthValue};
@@ -20,6 +26,10 @@ moddef::moddef!(
     pub mod {
         windows
     },
+    flat(pub) mod {
+        window_2d for cfg(feature = "ndarray"),
+        window_gen
+    },
     pub(crate) mod {
         util
     }
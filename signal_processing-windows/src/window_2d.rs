@@ -0,0 +1,293 @@
+use core::ops::Mul;
+
+use ndarray::{Array2, ArrayBase, DataMut, Ix2};
+use num_complex::ComplexFloat;
+use num_traits::Float;
+
+use crate::{Shape, WindowFn, WindowGenExt};
+
+/// A two-dimensional window function.
+pub trait WindowFn2D<T>: Copy
+{
+    type Functor: Fn((usize, usize)) -> T;
+
+    fn window_fn_2d(self, dim: (usize, usize), range: Shape) -> Self::Functor;
+}
+
+/// Separable two-dimensional window.
+///
+/// The first window is applied along the rows (axis 0), and the second along the columns (axis 1).
+/// The resulting window is the outer product of the two.
+#[derive(Clone, Copy, Debug)]
+pub struct Separable<W1, W2 = W1>(pub W1, pub W2);
+
+impl<W1, W2, T> WindowFn2D<T> for Separable<W1, W2>
+where
+    W1: WindowFn<[T]>,
+    W2: WindowFn<[T]>,
+    T: Mul<Output = T> + Copy
+{
+    type Functor = impl Fn((usize, usize)) -> T;
+
+    fn window_fn_2d(self, (m, n): (usize, usize), range: Shape) -> Self::Functor
+    {
+        let Self(w1, w2) = self;
+        let w1 = w1.window_vec(m, range);
+        let w2 = w2.window_vec(n, range);
+
+        move |(i, j)| w1[i]*w2[j]
+    }
+}
+
+/// Radially symmetric two-dimensional window.
+///
+/// The one-dimensional window is rotated about the center of the array, in the same manner as Matlab's `fwind2`.
+/// Points outside the inscribed ellipse are zero.
+#[derive(Clone, Copy, Debug)]
+pub struct Radial<W>(pub W);
+
+impl<W, T> WindowFn2D<T> for Radial<W>
+where
+    W: WindowFn<[T]>,
+    T: Float
+{
+    type Functor = impl Fn((usize, usize)) -> T;
+
+    fn window_fn_2d(self, (m, n): (usize, usize), range: Shape) -> Self::Functor
+    {
+        let Self(w) = self;
+
+        // The profile is sampled densely from the center and outwards, then interpolated linearly.
+        let k = 4*m.max(n) + 1;
+        let w = w.window_vec(k, Shape::Symmetric);
+        let half = k/2;
+
+        let one = T::one();
+        let two = one + one;
+        let coord = move |i: usize, len: usize| {
+            let l = range.window_len(len);
+            if l == 0
+            {
+                return T::zero()
+            }
+            two*T::from(i).unwrap()/T::from(l).unwrap() - one
+        };
+
+        move |(i, j)| {
+            let x = coord(i, m);
+            let y = coord(j, n);
+            let r = x.hypot(y);
+            if r > one
+            {
+                return T::zero()
+            }
+
+            let p = r*T::from(half).unwrap();
+            let p0 = p.floor();
+            let a = p - p0;
+            let i0 = half + p0.to_usize().unwrap();
+            let i1 = (i0 + 1).min(k - 1);
+
+            w[i0]*(one - a) + w[i1]*a
+        }
+    }
+}
+
+/// Two-dimensional window by the McClellan transformation, in the same manner as Matlab's `ftrans2`.
+///
+/// The one-dimensional window is treated as a zero-phase FIR filter, and transformed into a two-dimensional filter whose frequency response has nearly circular contours.
+/// The result is scaled so that its center matches the center of the one-dimensional window.
+/// The transformation gives a square array of odd size, which is cropped about its center to the given dimensions.
+#[derive(Clone, Copy, Debug)]
+pub struct McClellan<W>(pub W);
+
+impl<W, T> WindowFn2D<T> for McClellan<W>
+where
+    W: WindowFn<[T]>,
+    T: Float
+{
+    type Functor = impl Fn((usize, usize)) -> T;
+
+    fn window_fn_2d(self, (m, n): (usize, usize), range: Shape) -> Self::Functor
+    {
+        let Self(w) = self;
+
+        // A periodic window is the symmetric window one sample longer, without its last sample.
+        let (ms, ns) = match range
+        {
+            Shape::Symmetric => (m, n),
+            Shape::Periodic => (m + 1, n + 1)
+        };
+        let k = ms.max(ns)/2*2 + 1;
+        let w = w.window_vec(k, Shape::Symmetric);
+        let mut h = mcclellan(&w);
+        let center = h[(k/2, k/2)];
+        if !center.is_zero()
+        {
+            h.mapv_inplace(|h| h*w[k/2]/center)
+        }
+        let (di, dj) = ((k - ms)/2, (k - ns)/2);
+
+        move |(i, j)| h[(i + di, j + dj)]
+    }
+}
+
+/// Transforms a zero-phase FIR filter of odd length into a two-dimensional filter.
+///
+/// Each `cos(ω)` in the frequency response is replaced by `(cos(ω₁) + cos(ω₂) + cos(ω₁)cos(ω₂) - 1)/2`, which is the default transformation of `ftrans2`.
+fn mcclellan<T>(b: &[T]) -> Array2<T>
+where
+    T: Float
+{
+    let n = b.len()/2;
+    let k = 2*n + 1;
+    let one = T::one();
+    let two = one + one;
+    let eighth = (two*two*two).recip();
+
+    let t = |i: usize, j: usize| eighth*match (i == 1, j == 1)
+    {
+        (true, true) => -two*two,
+        (true, false) | (false, true) => two,
+        (false, false) => one
+    };
+    let transform = |p: &Array2<T>| Array2::from_shape_fn((k, k), |(i, j)| {
+        let mut y = T::zero();
+        for ti in 0..3
+        {
+            for tj in 0..3
+            {
+                if let (Some(pi), Some(pj)) = ((i + ti).checked_sub(1), (j + tj).checked_sub(1)) && pi < k && pj < k
+                {
+                    y = y + t(ti, tj)*p[(pi, pj)]
+                }
+            }
+        }
+        y
+    });
+
+    // The Chebyshev polynomials of the transformation, by Tₖ = 2tTₖ₋₁ - Tₖ₋₂.
+    let mut p0 = Array2::zeros((k, k));
+    p0[(n, n)] = one;
+    let mut p1 = transform(&p0);
+    let mut h = p0.mapv(|p| p*b[n]);
+    for i in 1..=n
+    {
+        if i > 1
+        {
+            let p2 = transform(&p1).mapv(|p| p*two) - &p0;
+            p0 = core::mem::replace(&mut p1, p2);
+        }
+        h = h + p1.mapv(|p| p*two*b[n + i]);
+    }
+    h
+}
+
+/// Generates a two-dimensional window with the given dimensions.
+pub fn window_2d_gen<W, T>(window: W, dim: (usize, usize), range: Shape) -> Array2<T>
+where
+    W: WindowFn2D<T>
+{
+    Array2::from_shape_fn(dim, window.window_fn_2d(dim, range))
+}
+
+/// # 2D windowing
+///
+/// Multiplies a two-dimensional array with a two-dimensional window in-place.
+/// This is useful for tapering the edges of an image before spectral analysis.
+pub trait Window2D<W>
+{
+    fn window_2d(&mut self, window: W, range: Shape);
+}
+impl<S, A, W> Window2D<W> for ArrayBase<S, Ix2, A>
+where
+    S: DataMut<Elem = A>,
+    A: ComplexFloat + Mul<A::Real, Output = A>,
+    W: WindowFn2D<A::Real>
+{
+    fn window_2d(&mut self, window: W, range: Shape)
+    {
+        let f = window.window_fn_2d(self.dim(), range);
+        for (ij, x) in self.indexed_iter_mut()
+        {
+            *x = *x*f(ij)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use ndarray::Array2;
+    use num_complex::Complex;
+
+    use crate::{McClellan, Radial, Separable, Shape, Window2D, WindowGen, window_2d_gen, windows::{Boxcar, Hann}};
+
+    #[test]
+    fn separable()
+    {
+        const M: usize = 9;
+        const N: usize = 16;
+
+        let w = window_2d_gen::<_, f64>(Separable(Boxcar, Hann), (M, N), Shape::Symmetric);
+        let h: [f64; N] = Hann.window_array(Shape::Symmetric);
+
+        for row in w.rows()
+        {
+            assert_eq!(row.as_slice().unwrap(), h.as_slice());
+        }
+    }
+
+    #[test]
+    fn radial()
+    {
+        const M: usize = 17;
+
+        let w = window_2d_gen::<_, f64>(Radial(Hann), (M, M), Shape::Symmetric);
+
+        assert!((w[(M/2, M/2)] - 1.0).abs() < 1e-12);
+        assert!(w[(0, 0)].abs() < 1e-12);
+        for i in 0..M
+        {
+            for j in 0..M
+            {
+                assert!((w[(i, j)] - w[(j, i)]).abs() < 1e-12);
+                assert!((w[(i, j)] - w[(M - 1 - i, j)]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn mcclellan()
+    {
+        const M: usize = 15;
+
+        let w = window_2d_gen::<_, f64>(McClellan(Hann), (M, M), Shape::Symmetric);
+        assert!((w[(M/2, M/2)] - 1.0).abs() < 1e-12);
+        assert!(w.row(0).iter().chain(w.column(0)).all(|w| w.abs() < 1e-12));
+        for i in 0..M
+        {
+            for j in 0..M
+            {
+                assert!((w[(i, j)] - w[(j, i)]).abs() < 1e-12);
+                assert!((w[(i, j)] - w[(M - 1 - i, j)]).abs() < 1e-12);
+            }
+        }
+
+        let p = window_2d_gen::<_, f64>(McClellan(Hann), (M - 1, M - 1), Shape::Periodic);
+        assert_eq!(p, w.slice(ndarray::s![..M - 1, ..M - 1]));
+    }
+
+    #[test]
+    fn in_place()
+    {
+        let mut x = Array2::from_elem((8, 8), Complex::new(1.0, 1.0));
+        x.window_2d(Separable(Hann, Hann), Shape::Periodic);
+
+        let w = window_2d_gen::<_, f64>(Separable(Hann, Hann), (8, 8), Shape::Periodic);
+        for (x, w) in x.iter().zip(w.iter())
+        {
+            assert_eq!(*x, Complex::new(*w, *w));
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use core::marker::Destruct;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use bulks::Bulk;
+use num_traits::Float;
+
+use crate::{Shape, WindowFn};
+
+/// How to scale a generated window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Normalization
+{
+    /// The largest coefficient is one.
+    Peak,
+    /// The coefficients sum to one.
+    Sum,
+    /// The squared coefficients sum to one.
+    Energy
+}
+
+impl Normalization
+{
+    /// Scales a window in place according to the normalization. A window of zeros is left as it is.
+    pub fn normalize<T>(self, w: &mut [T])
+    where
+        T: Float
+    {
+        let norm = match self
+        {
+            Normalization::Peak => w.iter()
+                .map(|w| w.abs())
+                .fold(T::zero(), T::max),
+            Normalization::Sum => w.iter()
+                .fold(T::zero(), |s, &w| s + w),
+            Normalization::Energy => w.iter()
+                .fold(T::zero(), |s, &w| s + w*w)
+                .sqrt()
+        };
+        if !norm.is_zero()
+        {
+            for w in w.iter_mut()
+            {
+                *w = *w/norm
+            }
+        }
+    }
+}
+
+/// Materializes a [WindowFn] into its coefficients.
+pub const trait WindowGen<T>: Copy
+{
+    /// Generates the window as a fixed-size array.
+    ///
+    /// This can be evaluated at compile-time if the window function is `const`.
+    fn window_array<const N: usize>(self, range: Shape) -> [T; N]
+    where
+        Self: ~const WindowFn<[T; N], Functor: ~const Fn(usize) -> T + ~const Destruct>,
+        T: ~const Destruct;
+}
+
+/// Materializes a [WindowFn] into its coefficients, where this cannot be done at compile-time.
+///
+/// This covers windows of dynamic length, and normalized windows.
+pub trait WindowGenExt<T>: WindowGen<T>
+{
+    /// Generates the window as a vector of length `len`.
+    #[cfg(feature = "alloc")]
+    fn window_vec(self, len: usize, range: Shape) -> Vec<T>
+    where
+        Self: WindowFn<[T]>;
+
+    /// Generates the window as an [Array1](ndarray::Array1) of length `len`.
+    #[cfg(feature = "ndarray")]
+    fn window_array1(self, len: usize, range: Shape) -> ndarray::Array1<T>
+    where
+        Self: WindowFn<[T]>;
+
+    /// Generates the window as a fixed-size array, scaled according to the given normalization.
+    fn window_array_normalized<const N: usize>(self, range: Shape, normalization: Normalization) -> [T; N]
+    where
+        Self: WindowFn<[T; N]>,
+        T: Float;
+
+    /// Generates the window as a vector of length `len`, scaled according to the given normalization.
+    #[cfg(feature = "alloc")]
+    fn window_vec_normalized(self, len: usize, range: Shape, normalization: Normalization) -> Vec<T>
+    where
+        Self: WindowFn<[T]>,
+        T: Float;
+
+    /// Generates the window as an [Array1](ndarray::Array1) of length `len`, scaled according to the given normalization.
+    #[cfg(feature = "ndarray")]
+    fn window_array1_normalized(self, len: usize, range: Shape, normalization: Normalization) -> ndarray::Array1<T>
+    where
+        Self: WindowFn<[T]>,
+        T: Float;
+}
+
+const impl<W, T> WindowGen<T> for W
+where
+    W: Copy
+{
+    fn window_array<const N: usize>(self, range: Shape) -> [T; N]
+    where
+        Self: ~const WindowFn<[T; N], Functor: ~const Fn(usize) -> T + ~const Destruct>,
+        T: ~const Destruct
+    {
+        core::array::from_fn(self.window_fn([(); N], range))
+    }
+}
+
+impl<W, T> WindowGenExt<T> for W
+where
+    W: Copy
+{
+    #[cfg(feature = "alloc")]
+    fn window_vec(self, len: usize, range: Shape) -> Vec<T>
+    where
+        Self: WindowFn<[T]>
+    {
+        bulks::range([(); 0], len)
+            .map(self.window_fn(len, range))
+            .collect::<Vec<_>, _>()
+    }
+
+    #[cfg(feature = "ndarray")]
+    fn window_array1(self, len: usize, range: Shape) -> ndarray::Array1<T>
+    where
+        Self: WindowFn<[T]>
+    {
+        ndarray::Array1::from_vec(self.window_vec(len, range))
+    }
+
+    fn window_array_normalized<const N: usize>(self, range: Shape, normalization: Normalization) -> [T; N]
+    where
+        Self: WindowFn<[T; N]>,
+        T: Float
+    {
+        let mut w = self.window_array(range);
+        normalization.normalize(&mut w);
+        w
+    }
+
+    #[cfg(feature = "alloc")]
+    fn window_vec_normalized(self, len: usize, range: Shape, normalization: Normalization) -> Vec<T>
+    where
+        Self: WindowFn<[T]>,
+        T: Float
+    {
+        let mut w = self.window_vec(len, range);
+        normalization.normalize(&mut w);
+        w
+    }
+
+    #[cfg(feature = "ndarray")]
+    fn window_array1_normalized(self, len: usize, range: Shape, normalization: Normalization) -> ndarray::Array1<T>
+    where
+        Self: WindowFn<[T]>,
+        T: Float
+    {
+        ndarray::Array1::from_vec(self.window_vec_normalized(len, range, normalization))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use array_trait::length::Length;
+    use bulks::Bulk;
+
+    use crate::{Normalization, Shape, Window, WindowFn, WindowGen, WindowGenExt, windows::{Hann, Hamming}};
+
+    /// A window simple enough to be evaluated at compile-time.
+    #[derive(Clone, Copy)]
+    struct Ramp;
+
+    #[derive(Clone, Copy)]
+    struct RampFn;
+
+    const impl FnOnce<(usize,)> for RampFn
+    {
+        type Output = f64;
+
+        extern "rust-call" fn call_once(self, (i,): (usize,)) -> f64
+        {
+            i as f64
+        }
+    }
+    const impl FnMut<(usize,)> for RampFn
+    {
+        extern "rust-call" fn call_mut(&mut self, (i,): (usize,)) -> f64
+        {
+            i as f64
+        }
+    }
+    const impl Fn<(usize,)> for RampFn
+    {
+        extern "rust-call" fn call(&self, (i,): (usize,)) -> f64
+        {
+            i as f64
+        }
+    }
+
+    const impl<const N: usize> WindowFn<[f64; N]> for Ramp
+    {
+        type Functor = RampFn;
+
+        fn window_fn(self, _len: <[f64; N] as Length>::Value, _range: Shape) -> Self::Functor
+        {
+            RampFn
+        }
+    }
+
+    #[test]
+    fn const_eval()
+    {
+        const W: [f64; 4] = Ramp.window_array(Shape::Symmetric);
+
+        assert_eq!(W, [0.0, 1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn matches_windowed()
+    {
+        const N: usize = 64;
+
+        let w: [f64; N] = Hamming.window_array(Shape::Symmetric);
+        let ones = bulks::repeat_n(1.0, [(); N])
+            .window(Hamming, Shape::Symmetric)
+            .collect_array();
+
+        assert_eq!(w, ones);
+    }
+
+    #[test]
+    fn normalized()
+    {
+        const N: usize = 33;
+
+        let peak: [f64; N] = Hann.window_array_normalized(Shape::Symmetric, Normalization::Peak);
+        assert!((peak.iter().copied().fold(0.0, f64::max) - 1.0).abs() < 1e-12);
+
+        let sum: [f64; N] = Hann.window_array_normalized(Shape::Periodic, Normalization::Sum);
+        assert!((sum.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+
+        let energy: [f64; N] = Hann.window_array_normalized(Shape::Symmetric, Normalization::Energy);
+        assert!((energy.iter().map(|w| w*w).sum::<f64>() - 1.0).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vec()
+    {
+        const N: usize = 33;
+
+        let w: [f64; N] = Hamming.window_array(Shape::Symmetric);
+        let v = Hamming.window_vec(N, Shape::Symmetric);
+        assert_eq!(w.as_slice(), v.as_slice());
+
+        let w: [f64; N] = Hann.window_array_normalized(Shape::Periodic, Normalization::Energy);
+        let v = Hann.window_vec_normalized(N, Shape::Periodic, Normalization::Energy);
+        assert_eq!(w.as_slice(), v.as_slice());
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn array1()
+    {
+        const N: usize = 16;
+
+        let w: [f64; N] = Hann.window_array(Shape::Periodic);
+        let a = Hann.window_array1(N, Shape::Periodic);
+
+        assert_eq!(w.as_slice(), a.as_slice().unwrap());
+    }
+}
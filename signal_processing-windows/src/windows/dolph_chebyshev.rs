@@ -1,13 +1,13 @@
-use core::borrow::{BorrowMut};
+use core::borrow::{Borrow, BorrowMut};
 
 use array_trait::length::{self, Length};
-use bulks::{Bulk, CollectNearest};
 use num_complex::Complex;
 use num_traits::{Float, FloatConst, Zero};
-use signal_processing_fourier::Dft;
+use signal_processing_fourier::{Dft, ScratchLength};
 
 use crate::{Shape, WindowFn};
 
+/// Needs the `alloc` feature, as the window is found by a DFT of dynamic length.
 #[derive(Clone, Copy)]
 pub struct DolphChebyshev<T>
 where
@@ -49,19 +49,20 @@ where
         let gamma = ten.powf(-self.alpha);
         let beta = (gamma.recip().acosh()/l).cosh();
 
-        let mut w = bulks::repeat_n((), len)
+        let mut w = ScratchLength::scratch_space(len, Complex::zero());
+        for (i, w) in BorrowMut::<[_]>::borrow_mut(&mut w).iter_mut()
             .enumerate()
-            .map(|(i, ())| {
-                let i = T::from(i).unwrap();
-                let x = beta*(T::PI()*i/(l + one)).cos();
-                Complex::from(t(x))
-            }).collect_nearest();
-        let wr = if m % 2 == 0
+        {
+            let i = T::from(i).unwrap();
+            let x = beta*(T::PI()*i/(l + one)).cos();
+            *w = Complex::from(t(x))
+        }
+        let mut window = ScratchLength::scratch_space(len, T::zero());
+        let wr: &mut [T] = window.borrow_mut();
+        if m % 2 == 0
         {
             let w: &mut [_] = w.borrow_mut();
             w.dft();
-            let mut wr = bulks::repeat_n(T::zero(), len)
-                .collect_nearest();
             let mm = (m + 2)/2;
             for k in 0..mm
             {
@@ -72,7 +73,6 @@ where
                     wr[k + m + 1 - mm] = ww;
                 }
             }
-            wr
         }
         else
         {
@@ -83,8 +83,6 @@ where
                 *w = *w * Complex::cis(T::PI()*T::from(k).unwrap()/(l + one))
             }
             w.dft();
-            let mut wr = bulks::repeat_n(T::zero(), len)
-                .collect_nearest();
             let mm = (m + 1)/2 + 1;
             for k in 1..mm
             {
@@ -95,20 +93,19 @@ where
                     wr[k + m + 1 - mm] = ww;
                 }
             }
-            wr
-        };
+        }
         move |i| {
             if m.is_zero()
             {
                 return T::one()
             }
             
-            wr[i]
+            window.borrow()[i]
         }
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod test
 {
     use crate::tests;
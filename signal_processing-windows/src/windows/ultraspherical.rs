@@ -1,6 +1,8 @@
+use core::borrow::{Borrow, BorrowMut};
+
 use array_trait::length::Length;
-use bulks::CollectNearest;
 use num_traits::{Float, FloatConst, NumCast, Zero};
+use signal_processing_fourier::ScratchLength;
 
 use crate::{Shape, WindowFn};
 
@@ -40,14 +42,14 @@ where
             //return None
         }
 
-        let mut w = bulks::repeat_n(zero, len)
-            .collect_nearest();
+        let mut window = ScratchLength::scratch_space(len, zero);
+        let w: &mut [T] = window.borrow_mut();
         let mut l = 0;
         let m = (nm1 + 2)/2;
         let idivs = m - 1;
         let c = one - (self.xmu*self.xmu).recip();
-        let mut v = bulks::repeat_n(zero, len)
-            .collect_nearest();
+        let mut v = ScratchLength::scratch_space(len, zero);
+        let v: &mut [T] = v.borrow_mut();
         if !m.is_zero()
         {
             for i in 0..m
@@ -126,7 +128,7 @@ where
                 return T::one()
             }
 
-            w[i]
+            window.borrow()[i]
         }
     }
 }
@@ -1,3 +1,8 @@
+pub mod systems
+{
+    pub use signal_processing_systems::*;
+}
+
 pub mod transforms
 {
     pub use signal_processing_transforms::*;